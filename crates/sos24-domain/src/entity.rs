@@ -1,16 +1,25 @@
 pub mod actor;
+pub mod api_token;
 pub mod common;
+pub mod custom_role;
 pub mod file_data;
+pub mod file_export;
 pub mod file_object;
+pub mod file_upload;
 pub mod firebase_user;
 pub mod form;
 pub mod form_answer;
+pub mod impersonation_log;
 pub mod invitation;
 pub mod news;
+pub mod notification;
 pub mod permission;
 pub mod project;
 pub mod project_application_period;
+pub mod project_storage;
 pub mod user;
+pub mod webhook;
+pub mod webhook_delivery;
 
 #[macro_export]
 macro_rules! impl_value_object {
@@ -1,7 +1,9 @@
 use getset::Getters;
 
 use super::{
+    custom_role::CustomRole,
     permission::Permissions,
+    project::{ProjectCategories, ProjectCategory},
     user::{UserId, UserRole},
 };
 
@@ -11,21 +13,124 @@ pub struct Actor {
     user_id: UserId,
     #[getset(get = "pub")]
     role: UserRole,
+    // ユーザーに割り当てられたカスタムロールのうち、企画区分を限定しないものの権限の和
+    #[getset(get = "pub")]
+    custom_permissions: Permissions,
+    // 企画区分を限定したカスタムロールの権限
+    // これらの権限は対象の企画区分の企画に対してのみ有効
+    #[getset(get = "pub")]
+    category_permissions: Vec<(ProjectCategories, Permissions)>,
+    // APIトークンで認証した場合は、ロールの権限のうちトークンのスコープに含まれるものだけを持つ
+    #[getset(get = "pub")]
+    scopes: Option<Permissions>,
 }
 
 impl Actor {
     pub fn new(user_id: UserId, role: UserRole) -> Self {
-        Self { user_id, role }
+        Self {
+            user_id,
+            role,
+            custom_permissions: Permissions::empty(),
+            category_permissions: Vec::new(),
+            scopes: None,
+        }
+    }
+
+    pub fn with_custom_roles(self, custom_roles: &[CustomRole]) -> Self {
+        let mut custom_permissions = Permissions::empty();
+        let mut category_permissions = Vec::new();
+        for role in custom_roles {
+            match role.project_categories() {
+                Some(categories) => {
+                    category_permissions.push((*categories, role.permissions().clone()))
+                }
+                None => custom_permissions |= role.permissions().clone(),
+            }
+        }
+        Self {
+            custom_permissions,
+            category_permissions,
+            ..self
+        }
+    }
+
+    pub fn with_scopes(self, scopes: Permissions) -> Self {
+        Self {
+            scopes: Some(scopes),
+            ..self
+        }
     }
 
     pub fn new_admin() -> Self {
         Self {
             user_id: UserId::new("admin".to_string()),
             role: UserRole::Administrator,
+            custom_permissions: Permissions::empty(),
+            category_permissions: Vec::new(),
+            scopes: None,
         }
     }
 
+    // 組み込みのロールとカスタムロールの権限の和を、APIトークンのスコープで制限したもの
+    // 企画区分を限定したカスタムロールの権限は含まない
+    pub fn permissions(&self) -> Permissions {
+        self.restrict_to_scopes(self.role().permissions() | self.custom_permissions.clone())
+    }
+
     pub fn has_permission(&self, permission: Permissions) -> bool {
-        self.role().permissions().contains(permission)
+        self.permissions().contains(permission)
+    }
+
+    // 特定の企画区分の企画に対して持つ権限
+    pub fn permissions_for(&self, category: ProjectCategory) -> Permissions {
+        let category_permissions = self
+            .category_permissions
+            .iter()
+            .filter(|(categories, _)| categories.matches(category))
+            .fold(Permissions::empty(), |acc, (_, permissions)| {
+                acc | permissions.clone()
+            });
+        self.permissions() | self.restrict_to_scopes(category_permissions)
+    }
+
+    pub fn has_project_permission(
+        &self,
+        permission: Permissions,
+        category: ProjectCategory,
+    ) -> bool {
+        self.permissions_for(category).contains(permission)
+    }
+
+    // いずれかの企画区分の企画に対して権限を持つか
+    // 一覧の取得など、対象の企画が決まる前の判定に用いる
+    pub fn has_permission_in_any_category(&self, permission: Permissions) -> bool {
+        self.has_permission(permission.clone())
+            || ProjectCategory::ALL
+                .iter()
+                .any(|category| self.has_project_permission(permission.clone(), *category))
+    }
+
+    // otherが持つ権限を、企画区分を限定した権限も含めて全て持っているか
+    pub fn has_all_permissions_of(&self, other: &Actor) -> bool {
+        self.permissions().contains(other.permissions())
+            && ProjectCategory::ALL.iter().all(|category| {
+                self.permissions_for(*category)
+                    .contains(other.permissions_for(*category))
+            })
+    }
+
+    // APIトークンで認証した場合に、スコープで許可された操作か
+    // 自分の企画や自分のユーザー情報のような、権限によらず所有者に許可する操作の判定に用いる
+    pub fn is_in_scope(&self, scope: Permissions) -> bool {
+        self.scopes
+            .as_ref()
+            .is_none_or(|scopes| scopes.contains(scope))
+    }
+
+    fn restrict_to_scopes(&self, permissions: Permissions) -> Permissions {
+        match &self.scopes {
+            Some(scopes) => permissions & scopes.clone(),
+            None => permissions,
+        }
     }
 }
@@ -0,0 +1,326 @@
+use getset::Getters;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use crate::ensure;
+use crate::impl_value_object;
+
+use super::{
+    actor::Actor,
+    common::datetime::DateTime,
+    permission::{PermissionDeniedError, Permissions},
+    user::UserId,
+};
+
+// スクリプトなどからFirebaseのIDトークンの代わりに用いる、長期間有効なトークン
+// トークンそのものは発行時にのみ返し、ハッシュ値だけを保存する
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+pub struct ApiToken {
+    #[getset(get = "pub")]
+    id: ApiTokenId,
+    #[getset(get = "pub")]
+    user_id: UserId,
+    #[getset(get = "pub")]
+    name: ApiTokenName,
+    #[getset(get = "pub")]
+    scopes: Vec<ApiTokenScope>,
+    #[getset(get = "pub")]
+    token_hash: ApiTokenHash,
+    #[getset(get = "pub")]
+    expires_at: Option<DateTime>,
+    #[getset(get = "pub")]
+    last_used_at: Option<DateTime>,
+    #[getset(get = "pub")]
+    created_at: DateTime,
+}
+
+impl ApiToken {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: ApiTokenId,
+        user_id: UserId,
+        name: ApiTokenName,
+        scopes: Vec<ApiTokenScope>,
+        token_hash: ApiTokenHash,
+        expires_at: Option<DateTime>,
+        last_used_at: Option<DateTime>,
+        created_at: DateTime,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            name,
+            scopes,
+            token_hash,
+            expires_at,
+            last_used_at,
+            created_at,
+        }
+    }
+
+    // 作成者の権限を超えるスコープを持つトークンは作成できない
+    pub fn create(
+        actor: &Actor,
+        name: ApiTokenName,
+        scopes: Vec<ApiTokenScope>,
+        expires_at: Option<DateTime>,
+    ) -> Result<(Self, ApiTokenSecret), ApiTokenError> {
+        if scopes.is_empty() {
+            return Err(ApiTokenError::EmptyScopes);
+        }
+        ensure!(scopes
+            .iter()
+            .all(|scope| actor.has_permission(scope.permissions())));
+
+        let now = DateTime::now();
+        if let Some(expires_at) = &expires_at {
+            if expires_at.clone().value() <= now.clone().value() {
+                return Err(ApiTokenError::AlreadyExpired);
+            }
+        }
+
+        let secret = ApiTokenSecret::generate();
+        let token = Self {
+            id: ApiTokenId::new(uuid::Uuid::new_v4()),
+            user_id: actor.user_id().clone(),
+            name,
+            scopes,
+            token_hash: secret.hash(),
+            expires_at,
+            last_used_at: None,
+            created_at: now,
+        };
+        Ok((token, secret))
+    }
+
+    pub fn destruct(self) -> DestructedApiToken {
+        DestructedApiToken {
+            id: self.id,
+            user_id: self.user_id,
+            name: self.name,
+            scopes: self.scopes,
+            token_hash: self.token_hash,
+            expires_at: self.expires_at,
+            last_used_at: self.last_used_at,
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DestructedApiToken {
+    pub id: ApiTokenId,
+    pub user_id: UserId,
+    pub name: ApiTokenName,
+    pub scopes: Vec<ApiTokenScope>,
+    pub token_hash: ApiTokenHash,
+    pub expires_at: Option<DateTime>,
+    pub last_used_at: Option<DateTime>,
+    pub created_at: DateTime,
+}
+
+impl ApiToken {
+    pub fn is_visible_to(&self, actor: &Actor) -> bool {
+        self.user_id() == actor.user_id()
+    }
+
+    pub fn is_expired(&self, now: &chrono::DateTime<chrono::Utc>) -> bool {
+        self.expires_at
+            .as_ref()
+            .is_some_and(|it| it.clone().value() <= *now)
+    }
+
+    // トークンで認証したリクエストでは、作成者の権限とスコープの両方に含まれる操作のみを許可する
+    pub fn permissions(&self) -> Permissions {
+        ApiTokenScope::to_permissions(&self.scopes)
+    }
+
+    pub fn set_last_used_at(&mut self, now: &chrono::DateTime<chrono::Utc>) {
+        self.last_used_at = Some(DateTime::new(*now));
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ApiTokenError {
+    #[error("At least one scope is required")]
+    EmptyScopes,
+    #[error("Expiration date is in the past")]
+    AlreadyExpired,
+    #[error(transparent)]
+    PermissionDenied(#[from] PermissionDeniedError),
+}
+
+impl_value_object!(ApiTokenId(uuid::Uuid));
+#[derive(Debug, Error)]
+pub enum ApiTokenIdError {
+    #[error("Invalid UUID")]
+    InvalidUuid,
+}
+
+impl TryFrom<String> for ApiTokenId {
+    type Error = ApiTokenIdError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let uuid = uuid::Uuid::parse_str(&value).map_err(|_| ApiTokenIdError::InvalidUuid)?;
+        Ok(Self(uuid))
+    }
+}
+
+impl_value_object!(ApiTokenName(String));
+impl_value_object!(ApiTokenHash(String));
+
+// トークンそのもの
+// Authorizationヘッダーに付けられたものがIDトークンかどうかを接頭辞で見分ける
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiTokenSecret(String);
+
+impl ApiTokenSecret {
+    pub const PREFIX: &'static str = "sos_";
+
+    pub fn new(value: String) -> Self {
+        Self(value)
+    }
+
+    pub fn generate() -> Self {
+        // UUIDv4 2つ分で244ビットの乱数になる
+        let random = format!(
+            "{}{}",
+            uuid::Uuid::new_v4().simple(),
+            uuid::Uuid::new_v4().simple()
+        );
+        Self(format!("{}{random}", Self::PREFIX))
+    }
+
+    pub fn is_api_token(value: &str) -> bool {
+        value.starts_with(Self::PREFIX)
+    }
+
+    // 十分な長さの乱数なので、パスワードと異なりストレッチングは行わない
+    pub fn hash(&self) -> ApiTokenHash {
+        ApiTokenHash(hex::encode(Sha256::digest(self.0.as_bytes())))
+    }
+
+    pub fn value(self) -> String {
+        self.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiTokenScope {
+    ReadProjects,
+    ReadUsers,
+    ReadNews,
+    ReadForms,
+    ReadFormAnswers,
+    ReadInvitations,
+    ReadFiles,
+}
+
+impl ApiTokenScope {
+    pub const ALL: [ApiTokenScope; 7] = [
+        ApiTokenScope::ReadProjects,
+        ApiTokenScope::ReadUsers,
+        ApiTokenScope::ReadNews,
+        ApiTokenScope::ReadForms,
+        ApiTokenScope::ReadFormAnswers,
+        ApiTokenScope::ReadInvitations,
+        ApiTokenScope::ReadFiles,
+    ];
+
+    pub fn permissions(&self) -> Permissions {
+        match self {
+            ApiTokenScope::ReadProjects => Permissions::READ_PROJECT_ALL,
+            ApiTokenScope::ReadUsers => Permissions::READ_USER_ALL,
+            ApiTokenScope::ReadNews => Permissions::READ_NEWS_ALL,
+            ApiTokenScope::ReadForms => Permissions::READ_FORM_ALL,
+            ApiTokenScope::ReadFormAnswers => Permissions::READ_FORM_ANSWER_ALL,
+            ApiTokenScope::ReadInvitations => Permissions::READ_INVITATION_ALL,
+            ApiTokenScope::ReadFiles => Permissions::READ_FILE_ALL,
+        }
+    }
+
+    pub fn to_permissions(scopes: &[ApiTokenScope]) -> Permissions {
+        scopes
+            .iter()
+            .fold(Permissions::empty(), |acc, scope| acc | scope.permissions())
+    }
+
+    // 保存されたビット列から、それに含まれるスコープを復元する
+    pub fn from_permissions(permissions: &Permissions) -> Vec<ApiTokenScope> {
+        Self::ALL
+            .into_iter()
+            .filter(|scope| permissions.contains(scope.permissions()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        entity::{
+            api_token::{ApiToken, ApiTokenError, ApiTokenName, ApiTokenScope, ApiTokenSecret},
+            common::datetime::DateTime,
+            permission::Permissions,
+            user::UserRole,
+        },
+        test::fixture,
+    };
+
+    #[test]
+    fn 作成者の権限の範囲内のスコープでトークンを作成できる() {
+        let actor = fixture::actor::actor1(UserRole::CommitteeViewer);
+        let (token, secret) = ApiToken::create(
+            &actor,
+            ApiTokenName::new("export".to_string()),
+            vec![ApiTokenScope::ReadProjects, ApiTokenScope::ReadFormAnswers],
+            None,
+        )
+        .unwrap();
+        assert!(ApiTokenSecret::is_api_token(&secret.clone().value()));
+        assert_eq!(token.token_hash(), &secret.hash());
+        assert_eq!(
+            ApiTokenScope::from_permissions(&token.permissions()),
+            vec![ApiTokenScope::ReadProjects, ApiTokenScope::ReadFormAnswers]
+        );
+    }
+
+    #[test]
+    fn 作成者の権限を超えるスコープのトークンは作成できない() {
+        let actor = fixture::actor::actor1(UserRole::CommitteeViewer);
+        let res = ApiToken::create(
+            &actor,
+            ApiTokenName::new("export".to_string()),
+            vec![ApiTokenScope::ReadUsers],
+            None,
+        );
+        assert!(matches!(res, Err(ApiTokenError::PermissionDenied(_))));
+    }
+
+    #[test]
+    fn 有効期限を過ぎたトークンは期限切れになる() {
+        let actor = fixture::actor::actor1(UserRole::CommitteeViewer);
+        let expires_at = chrono::Utc::now() + chrono::Duration::days(1);
+        let (token, _) = ApiToken::create(
+            &actor,
+            ApiTokenName::new("export".to_string()),
+            vec![ApiTokenScope::ReadProjects],
+            Some(DateTime::new(expires_at)),
+        )
+        .unwrap();
+        assert!(!token.is_expired(&chrono::Utc::now()));
+        assert!(token.is_expired(&(expires_at + chrono::Duration::seconds(1))));
+    }
+
+    #[test]
+    fn トークンで認証したユーザーはスコープに含まれる権限のみを持つ() {
+        let actor = fixture::actor::actor1(UserRole::Administrator).with_scopes(
+            ApiTokenScope::to_permissions(&[ApiTokenScope::ReadProjects]),
+        );
+        assert!(actor.has_permission(Permissions::READ_PROJECT_ALL));
+        assert!(!actor.has_permission(Permissions::UPDATE_PROJECT_ALL));
+
+        let actor = fixture::actor::actor1(UserRole::General).with_scopes(
+            ApiTokenScope::to_permissions(&[ApiTokenScope::ReadProjects]),
+        );
+        assert!(!actor.has_permission(Permissions::READ_PROJECT_ALL));
+    }
+}
@@ -1,2 +1,3 @@
 pub mod datetime;
 pub mod email;
+pub mod markdown;
@@ -1,5 +1,6 @@
 use thiserror::Error;
 
+use crate::entity::{actor::Actor, permission::Permissions, user::UserRole};
 use crate::impl_value_object_without_new;
 
 impl_value_object_without_new!(Email(String));
@@ -19,13 +20,134 @@ impl TryFrom<String> for Email {
         if !is_valid_email_format(&value) {
             return Err(EmailError::InvalidFormat);
         }
-        if !value.ends_with("tsukuba.ac.jp") {
-            return Err(EmailError::InvalidDomain);
-        }
         Ok(Self(value))
     }
 }
 
+impl Email {
+    // 退会したユーザーのメールアドレスを置き換えるための、配送されることのないアドレス
+    pub fn anonymous(key: &str) -> Self {
+        Self(format!("deleted-{key}@deleted.invalid"))
+    }
+
+    fn domain(&self) -> &str {
+        // 形式は検証済みなので、必ず@を含む
+        self.0.rsplit_once('@').map_or("", |(_, domain)| domain)
+    }
+}
+
+// 登録を許可するメールアドレスの規則
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EmailAllowlistEntry {
+    // example.ac.jp
+    Domain(String),
+    // *.example.ac.jp (サブドメインのみに一致し、example.ac.jp自体には一致しない)
+    Subdomain(String),
+    // guest@example.com
+    Address(String),
+}
+
+impl EmailAllowlistEntry {
+    fn matches(&self, email: &Email) -> bool {
+        let domain = email.domain().to_ascii_lowercase();
+        match self {
+            Self::Domain(allowed) => domain == *allowed,
+            Self::Subdomain(allowed) => domain
+                .strip_suffix(allowed.as_str())
+                .is_some_and(|sub| sub.len() > 1 && sub.ends_with('.')),
+            Self::Address(allowed) => email.0.to_ascii_lowercase() == *allowed,
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum EmailAllowlistError {
+    #[error("Invalid email allowlist entry: {0}")]
+    InvalidEntry(String),
+}
+
+impl TryFrom<&str> for EmailAllowlistEntry {
+    type Error = EmailAllowlistError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let entry = value.trim().to_ascii_lowercase();
+        let invalid = || EmailAllowlistError::InvalidEntry(value.to_string());
+
+        if entry.contains('@') {
+            Email::try_from(entry.clone()).map_err(|_| invalid())?;
+            return Ok(Self::Address(entry));
+        }
+
+        let (entry, subdomain) = match entry.strip_prefix("*.") {
+            Some(domain) => (domain.to_string(), true),
+            None => (entry, false),
+        };
+        // ドメインとして妥当かは、ダミーのアドレスを組み立てて検証する
+        if !is_valid_email_format(&format!("user@{entry}")) {
+            return Err(invalid());
+        }
+        Ok(if subdomain {
+            Self::Subdomain(entry)
+        } else {
+            Self::Domain(entry)
+        })
+    }
+}
+
+// 登録を許可するメールアドレスの一覧
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailAllowlist(Vec<EmailAllowlistEntry>);
+
+// カンマ区切りの規則の一覧 (例: "tsukuba.ac.jp,*.tsukuba.ac.jp,guest@example.com")
+impl TryFrom<&str> for EmailAllowlist {
+    type Error = EmailAllowlistError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let entries = value
+            .split(',')
+            .filter(|entry| !entry.trim().is_empty())
+            .map(EmailAllowlistEntry::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self(entries))
+    }
+}
+
+// これまで固定で許可していた筑波大学のドメインを既定値とする
+impl Default for EmailAllowlist {
+    fn default() -> Self {
+        Self(vec![
+            EmailAllowlistEntry::Domain("tsukuba.ac.jp".to_string()),
+            EmailAllowlistEntry::Subdomain("tsukuba.ac.jp".to_string()),
+        ])
+    }
+}
+
+impl EmailAllowlist {
+    pub fn allows(&self, email: &Email) -> bool {
+        self.0.iter().any(|entry| entry.matches(email))
+    }
+
+    // 一覧に含まれないアドレスは、管理者が自分以下のロールのユーザーを登録する場合にのみ許可する
+    // roleは登録するユーザーのロール
+    pub fn ensure_registrable(
+        &self,
+        email: &Email,
+        actor: Option<&Actor>,
+        role: &UserRole,
+    ) -> Result<(), EmailError> {
+        if self.allows(email) {
+            return Ok(());
+        }
+        match actor {
+            Some(actor)
+                if actor.has_permission(Permissions::REGISTER_UNLISTED_EMAIL)
+                    && role <= actor.role() =>
+            {
+                Ok(())
+            }
+            _ => Err(EmailError::InvalidDomain),
+        }
+    }
+}
+
 fn is_valid_email_format(email: &str) -> bool {
     // https://html.spec.whatwg.org/multipage/input.html#valid-e-mail-address
     let email_re = regex::Regex::new(r"^[a-zA-Z0-9.!#$%&'*+\/=?^_`{|}~-]+@[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?(?:\.[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?)*$").unwrap();
@@ -34,6 +156,20 @@ fn is_valid_email_format(email: &str) -> bool {
 
 #[cfg(test)]
 mod test {
+    use crate::{
+        entity::{
+            common::email::{Email, EmailAllowlist, EmailAllowlistError, EmailError},
+            custom_role::CustomRole,
+            permission::Permissions,
+            user::UserRole,
+        },
+        test::fixture,
+    };
+
+    fn email(value: &str) -> Email {
+        Email::try_from(value.to_string()).unwrap()
+    }
+
     #[test]
     fn valid_email_format() {
         assert!(super::is_valid_email_format("s0000000@u.tsukuba.ac.jp"));
@@ -46,4 +182,85 @@ mod test {
         assert!(!super::is_valid_email_format("s@u."));
         assert!(!super::is_valid_email_format("example.com"));
     }
+
+    #[test]
+    fn default_allowlist() {
+        let allowlist = EmailAllowlist::default();
+        assert!(allowlist.allows(&email("s0000000@u.tsukuba.ac.jp")));
+        assert!(allowlist.allows(&email("taro@tsukuba.ac.jp")));
+        assert!(!allowlist.allows(&email("john.doe@example.jp")));
+        assert!(!allowlist.allows(&email("john.doe@nottsukuba.ac.jp")));
+    }
+
+    #[test]
+    fn configured_allowlist() {
+        let allowlist =
+            EmailAllowlist::try_from("example.ac.jp, *.affiliate.jp, Guest@Example.com").unwrap();
+        assert!(allowlist.allows(&email("taro@example.ac.jp")));
+        assert!(!allowlist.allows(&email("taro@sub.example.ac.jp")));
+        assert!(allowlist.allows(&email("taro@lab.affiliate.jp")));
+        assert!(!allowlist.allows(&email("taro@affiliate.jp")));
+        assert!(allowlist.allows(&email("guest@example.com")));
+        assert!(!allowlist.allows(&email("other@example.com")));
+        assert!(!allowlist.allows(&email("s0000000@u.tsukuba.ac.jp")));
+    }
+
+    #[test]
+    fn invalid_allowlist_entry() {
+        assert!(matches!(
+            EmailAllowlist::try_from("example.ac.jp,*."),
+            Err(EmailAllowlistError::InvalidEntry(_))
+        ));
+        assert!(matches!(
+            EmailAllowlist::try_from("@example.com"),
+            Err(EmailAllowlistError::InvalidEntry(_))
+        ));
+    }
+
+    #[test]
+    fn unlisted_email_is_registrable_only_by_administrator() {
+        let allowlist = EmailAllowlist::default();
+        let guest = email("john.doe@example.jp");
+        assert!(matches!(
+            allowlist.ensure_registrable(&guest, None, &UserRole::General),
+            Err(EmailError::InvalidDomain)
+        ));
+        assert!(matches!(
+            allowlist.ensure_registrable(
+                &guest,
+                Some(&fixture::actor::actor1(UserRole::CommitteeOperator)),
+                &UserRole::General
+            ),
+            Err(EmailError::InvalidDomain)
+        ));
+        assert!(allowlist
+            .ensure_registrable(
+                &guest,
+                Some(&fixture::actor::actor1(UserRole::Administrator)),
+                &UserRole::General
+            )
+            .is_ok());
+    }
+
+    #[test]
+    fn unlisted_email_is_not_registrable_for_role_above_actor() {
+        let allowlist = EmailAllowlist::default();
+        let guest = email("john.doe@example.jp");
+        let role = CustomRole::new(
+            fixture::custom_role::id1(),
+            fixture::custom_role::name1(),
+            Permissions::REGISTER_UNLISTED_EMAIL,
+            None,
+            fixture::datetime::now(),
+            fixture::datetime::now(),
+        );
+        let actor = fixture::actor::actor1(UserRole::CommitteeOperator).with_custom_roles(&[role]);
+        assert!(allowlist
+            .ensure_registrable(&guest, Some(&actor), &UserRole::CommitteeEditor)
+            .is_ok());
+        assert!(matches!(
+            allowlist.ensure_registrable(&guest, Some(&actor), &UserRole::Administrator),
+            Err(EmailError::InvalidDomain)
+        ));
+    }
 }
@@ -0,0 +1,123 @@
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+use thiserror::Error;
+
+const MAX_LENGTH: usize = 20000;
+
+#[derive(Debug, Error)]
+pub enum MarkdownError {
+    #[error("Markdown is too long (max: {0})")]
+    TooLong(usize),
+    #[error("Markdown contains invalid character: {0:?}")]
+    InvalidCharacter(char),
+}
+
+pub fn validate(value: &str) -> Result<(), MarkdownError> {
+    if value.chars().count() > MAX_LENGTH {
+        return Err(MarkdownError::TooLong(MAX_LENGTH));
+    }
+    if let Some(c) = value
+        .chars()
+        .find(|c| c.is_control() && !matches!(c, '\n' | '\r' | '\t'))
+    {
+        return Err(MarkdownError::InvalidCharacter(c));
+    }
+    Ok(())
+}
+
+fn parser(value: &str) -> Parser<'_> {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_TASKLISTS);
+    Parser::new_ext(value, options)
+}
+
+/// MarkdownをHTMLに変換し、スクリプトなどの危険な要素を取り除く
+pub fn to_html(value: &str) -> String {
+    let mut unsafe_html = String::new();
+    pulldown_cmark::html::push_html(&mut unsafe_html, parser(value));
+    ammonia::Builder::default()
+        .link_rel(Some("noopener noreferrer"))
+        .clean(&unsafe_html)
+        .to_string()
+}
+
+/// Markdownをメール本文などで使うプレーンテキストに変換する
+pub fn to_plain_text(value: &str) -> String {
+    let mut text = String::new();
+    let mut link_urls = vec![];
+    for event in parser(value) {
+        match event {
+            Event::Text(s) | Event::Code(s) => text.push_str(&s),
+            Event::SoftBreak | Event::HardBreak => text.push('\n'),
+            Event::Rule => text.push_str("――――――――\n\n"),
+            Event::TaskListMarker(checked) => text.push_str(if checked { "[x] " } else { "[ ] " }),
+            Event::Start(Tag::Item) => text.push('・'),
+            Event::Start(Tag::Link { dest_url, .. })
+            | Event::Start(Tag::Image { dest_url, .. }) => link_urls.push(dest_url),
+            Event::End(TagEnd::Link) | Event::End(TagEnd::Image) => {
+                if let Some(url) = link_urls.pop() {
+                    text.push_str(&format!(" ({url})"));
+                }
+            }
+            Event::End(TagEnd::TableCell) => text.push('\t'),
+            Event::End(TagEnd::Item)
+            | Event::End(TagEnd::TableHead)
+            | Event::End(TagEnd::TableRow) => text.push('\n'),
+            Event::End(TagEnd::Paragraph)
+            | Event::End(TagEnd::Heading(_))
+            | Event::End(TagEnd::CodeBlock)
+            | Event::End(TagEnd::List(_))
+            | Event::End(TagEnd::Table)
+                if !text.ends_with("\n\n") =>
+            {
+                text.push_str(if text.ends_with('\n') { "\n" } else { "\n\n" });
+            }
+            _ => {}
+        }
+    }
+    text.trim_end().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_html, to_plain_text, validate, MarkdownError, MAX_LENGTH};
+
+    #[test]
+    fn markdownをhtmlに変換できる() {
+        assert_eq!(
+            to_html("# 見出し\n\n**太字**"),
+            "<h1>見出し</h1>\n<p><strong>太字</strong></p>\n"
+        );
+    }
+
+    #[test]
+    fn html変換時にスクリプトが除去される() {
+        let html = to_html("<script>alert(1)</script>\n\n[link](javascript:alert(1))");
+        assert!(!html.contains("<script>"));
+        assert!(!html.contains("javascript:"));
+    }
+
+    #[test]
+    fn markdownをプレーンテキストに変換できる() {
+        assert_eq!(
+            to_plain_text("# 見出し\n\n- 項目1\n- [リンク](https://example.com)\n\n本文"),
+            "見出し\n\n・項目1\n・リンク (https://example.com)\n\n本文"
+        );
+    }
+
+    #[test]
+    fn 長すぎるmarkdownは不正() {
+        let value = "あ".repeat(MAX_LENGTH + 1);
+        assert!(matches!(validate(&value), Err(MarkdownError::TooLong(_))));
+    }
+
+    #[test]
+    fn 制御文字を含むmarkdownは不正() {
+        assert!(matches!(
+            validate("本文\u{0}"),
+            Err(MarkdownError::InvalidCharacter('\u{0}'))
+        ));
+        assert!(validate("本文\n\t本文").is_ok());
+    }
+}
@@ -0,0 +1,240 @@
+use getset::Getters;
+use thiserror::Error;
+
+use crate::{ensure, impl_value_object};
+
+use super::{
+    actor::Actor,
+    common::datetime::DateTime,
+    permission::{PermissionDeniedError, Permissions},
+    project::ProjectCategories,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+pub struct CustomRole {
+    #[getset(get = "pub")]
+    id: CustomRoleId,
+    #[getset(get = "pub")]
+    name: CustomRoleName,
+    #[getset(get = "pub")]
+    permissions: Permissions,
+    // 権限を適用する企画区分 (Noneの場合はすべての企画区分)
+    #[getset(get = "pub")]
+    project_categories: Option<ProjectCategories>,
+    #[getset(get = "pub")]
+    created_at: DateTime,
+    #[getset(get = "pub")]
+    updated_at: DateTime,
+}
+
+impl CustomRole {
+    pub fn new(
+        id: CustomRoleId,
+        name: CustomRoleName,
+        permissions: Permissions,
+        project_categories: Option<ProjectCategories>,
+        created_at: DateTime,
+        updated_at: DateTime,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            permissions,
+            project_categories,
+            created_at,
+            updated_at,
+        }
+    }
+
+    pub fn create(
+        actor: &Actor,
+        name: CustomRoleName,
+        permissions: Permissions,
+        project_categories: Option<ProjectCategories>,
+    ) -> Result<Self, PermissionDeniedError> {
+        ensure!(actor.has_permission(Permissions::CREATE_ROLE));
+        // 自分が持っていない権限を含むロールは作れない
+        ensure!(actor.has_permission(permissions.clone()));
+
+        let now = DateTime::now();
+        Ok(Self {
+            id: CustomRoleId::new(uuid::Uuid::new_v4()),
+            name,
+            permissions,
+            project_categories,
+            created_at: now.clone(),
+            updated_at: now,
+        })
+    }
+
+    pub fn destruct(self) -> DestructedCustomRole {
+        DestructedCustomRole {
+            id: self.id,
+            name: self.name,
+            permissions: self.permissions,
+            project_categories: self.project_categories,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DestructedCustomRole {
+    pub id: CustomRoleId,
+    pub name: CustomRoleName,
+    pub permissions: Permissions,
+    pub project_categories: Option<ProjectCategories>,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
+impl CustomRole {
+    pub fn is_visible_to(&self, actor: &Actor) -> bool {
+        actor.has_permission(Permissions::READ_ROLE_ALL)
+    }
+
+    // 自分より強い権限を持つロールは更新・削除できない
+    pub fn is_updatable_by(&self, actor: &Actor) -> bool {
+        actor.has_permission(Permissions::UPDATE_ROLE_ALL)
+            && actor.has_permission(self.permissions.clone())
+    }
+
+    pub fn is_deletable_by(&self, actor: &Actor) -> bool {
+        actor.has_permission(Permissions::DELETE_ROLE_ALL)
+            && actor.has_permission(self.permissions.clone())
+    }
+
+    // ロールの付与・剥奪は、ユーザーを更新でき、かつロールの権限をすべて持っている場合にのみ行える
+    pub fn is_assignable_by(&self, actor: &Actor) -> bool {
+        actor.has_permission(Permissions::UPDATE_USER_ALL)
+            && actor.has_permission(self.permissions.clone())
+    }
+
+    pub fn set_name(
+        &mut self,
+        actor: &Actor,
+        name: CustomRoleName,
+    ) -> Result<(), PermissionDeniedError> {
+        ensure!(self.is_updatable_by(actor));
+        self.name = name;
+        Ok(())
+    }
+
+    pub fn set_permissions(
+        &mut self,
+        actor: &Actor,
+        permissions: Permissions,
+    ) -> Result<(), PermissionDeniedError> {
+        ensure!(self.is_updatable_by(actor));
+        ensure!(actor.has_permission(permissions.clone()));
+        self.permissions = permissions;
+        Ok(())
+    }
+
+    pub fn set_project_categories(
+        &mut self,
+        actor: &Actor,
+        project_categories: Option<ProjectCategories>,
+    ) -> Result<(), PermissionDeniedError> {
+        ensure!(self.is_updatable_by(actor));
+        self.project_categories = project_categories;
+        Ok(())
+    }
+}
+
+impl_value_object!(CustomRoleId(uuid::Uuid));
+#[derive(Debug, Error)]
+pub enum CustomRoleIdError {
+    #[error("Invalid UUID")]
+    InvalidUuid,
+}
+
+impl TryFrom<String> for CustomRoleId {
+    type Error = CustomRoleIdError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let uuid = uuid::Uuid::parse_str(&value).map_err(|_| CustomRoleIdError::InvalidUuid)?;
+        Ok(Self(uuid))
+    }
+}
+
+impl_value_object!(CustomRoleName(String));
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        entity::{
+            custom_role::{CustomRole, CustomRoleName},
+            permission::{PermissionDeniedError, Permissions},
+            project::ProjectCategory,
+            user::UserRole,
+        },
+        test::fixture,
+    };
+
+    #[test]
+    fn 管理者は自分の権限の範囲内でロールを作成できる() {
+        let actor = fixture::actor::actor1(UserRole::Administrator);
+        let role = CustomRole::create(
+            &actor,
+            CustomRoleName::new("広報".to_string()),
+            Permissions::CREATE_NEWS | Permissions::READ_NEWS_ALL,
+            None,
+        );
+        assert!(role.is_ok());
+    }
+
+    #[test]
+    fn 実委人管理者以外はロールを作成できない() {
+        let actor = fixture::actor::actor1(UserRole::CommitteeOperator);
+        let role = CustomRole::create(
+            &actor,
+            CustomRoleName::new("広報".to_string()),
+            Permissions::READ_NEWS_ALL,
+            None,
+        );
+        assert!(matches!(role, Err(PermissionDeniedError)));
+    }
+
+    #[test]
+    fn 自分が持たない権限を含むロールは付与できない() {
+        let role = fixture::custom_role::custom_role1();
+        let editor = fixture::actor::actor1(UserRole::CommitteeEditor);
+        assert!(role.is_assignable_by(&editor));
+
+        let mut role = role;
+        role.set_permissions(
+            &fixture::actor::actor1(UserRole::Administrator),
+            Permissions::DELETE_USER_ALL,
+        )
+        .unwrap();
+        assert!(!role.is_assignable_by(&editor));
+    }
+
+    #[test]
+    fn カスタムロールの権限は組み込みのロールの権限に加算される() {
+        let role = fixture::custom_role::custom_role1();
+        let actor = fixture::actor::actor1(UserRole::General);
+        assert!(!actor.has_permission(Permissions::READ_PROJECT_ALL));
+
+        let actor = actor.with_custom_roles(&[role]);
+        assert!(actor.has_permission(Permissions::READ_PROJECT_ALL));
+        assert!(!actor.has_permission(Permissions::UPDATE_PROJECT_ALL));
+    }
+
+    #[test]
+    fn 企画区分を限定したロールの権限は対象の企画区分にのみ有効() {
+        let role = fixture::custom_role::stage_role1();
+        let actor = fixture::actor::actor1(UserRole::General).with_custom_roles(&[role]);
+        assert!(!actor.has_permission(Permissions::UPDATE_PROJECT_ALL));
+        assert!(actor.has_permission_in_any_category(Permissions::UPDATE_PROJECT_ALL));
+        assert!(actor.has_project_permission(
+            Permissions::UPDATE_PROJECT_ALL,
+            ProjectCategory::StageUnited
+        ));
+        assert!(!actor.has_project_permission(
+            Permissions::UPDATE_PROJECT_ALL,
+            ProjectCategory::FoodsWithKitchen
+        ));
+    }
+}
@@ -6,7 +6,12 @@ use thiserror::Error;
 
 use crate::impl_value_object;
 
-use super::{common::datetime::DateTime, file_object::FileObjectKey, project::ProjectId};
+use super::{
+    common::datetime::DateTime,
+    file_object::{FileObjectKey, FileObjectMetadata},
+    project::ProjectId,
+    user::UserId,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, Getters, Setters)]
 pub struct FileData {
@@ -19,17 +24,41 @@ pub struct FileData {
     #[getset(get = "pub", set = "pub")]
     owner: Option<ProjectId>,
     #[getset(get = "pub")]
+    size: Option<FileSize>,
+    #[getset(get = "pub")]
+    content_type: Option<FileContentType>,
+    #[getset(get = "pub")]
+    checksum: Option<FileChecksum>,
+    #[getset(get = "pub")]
+    uploader: Option<UserId>,
+    // 企画への配布などのために単独でアップロードされたファイル
+    // どこからも参照されていなくても、参照されなくなったファイルとして回収しない
+    #[getset(get = "pub")]
+    is_standalone: FileIsStandalone,
+    #[getset(get = "pub")]
+    scan_status: FileScanStatus,
+    #[getset(get = "pub")]
+    thumbnail_status: FileThumbnailStatus,
+    #[getset(get = "pub")]
     created_at: DateTime,
     #[getset(get = "pub")]
     updated_at: DateTime,
 }
 
 impl FileData {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         id: FileId,
         name: FileName,
         url: FileObjectKey,
         owner: Option<ProjectId>,
+        size: Option<FileSize>,
+        content_type: Option<FileContentType>,
+        checksum: Option<FileChecksum>,
+        uploader: Option<UserId>,
+        is_standalone: FileIsStandalone,
+        scan_status: FileScanStatus,
+        thumbnail_status: FileThumbnailStatus,
         created_at: DateTime,
         updated_at: DateTime,
     ) -> Self {
@@ -38,18 +67,61 @@ impl FileData {
             filename: name,
             url,
             owner,
+            size,
+            content_type,
+            checksum,
+            uploader,
+            is_standalone,
+            scan_status,
+            thumbnail_status,
             created_at,
             updated_at,
         }
     }
 
-    pub fn create(filename: FileName, url: FileObjectKey, owner: Option<ProjectId>) -> Self {
+    pub fn create(
+        filename: FileName,
+        url: FileObjectKey,
+        owner: Option<ProjectId>,
+        uploader: UserId,
+        is_standalone: FileIsStandalone,
+        metadata: FileObjectMetadata,
+    ) -> Self {
+        Self::create_with_id(
+            FileId::new(uuid::Uuid::new_v4()),
+            filename,
+            url,
+            owner,
+            uploader,
+            is_standalone,
+            metadata,
+        )
+    }
+
+    // 署名付きURLでアップロードされたファイルは、発行時のIDをそのまま使う
+    pub fn create_with_id(
+        id: FileId,
+        filename: FileName,
+        url: FileObjectKey,
+        owner: Option<ProjectId>,
+        uploader: UserId,
+        is_standalone: FileIsStandalone,
+        metadata: FileObjectMetadata,
+    ) -> Self {
+        let metadata = metadata.destruct();
         let now = DateTime::now();
         Self {
-            id: FileId::new(uuid::Uuid::new_v4()),
+            id,
             filename,
             url,
             owner,
+            size: Some(metadata.size),
+            content_type: metadata.content_type,
+            checksum: Some(metadata.checksum),
+            uploader: Some(uploader),
+            is_standalone,
+            scan_status: FileScanStatus::Pending,
+            thumbnail_status: FileThumbnailStatus::Pending,
             created_at: now.clone(),
             updated_at: now,
         }
@@ -61,6 +133,13 @@ impl FileData {
             name: self.filename,
             url: self.url,
             owner: self.owner,
+            size: self.size,
+            content_type: self.content_type,
+            checksum: self.checksum,
+            uploader: self.uploader,
+            is_standalone: self.is_standalone,
+            scan_status: self.scan_status,
+            thumbnail_status: self.thumbnail_status,
             created_at: self.created_at,
             updated_at: self.updated_at,
         }
@@ -73,11 +152,121 @@ pub struct DestructedFileData {
     pub name: FileName,
     pub url: FileObjectKey,
     pub owner: Option<ProjectId>,
+    pub size: Option<FileSize>,
+    pub content_type: Option<FileContentType>,
+    pub checksum: Option<FileChecksum>,
+    pub uploader: Option<UserId>,
+    pub is_standalone: FileIsStandalone,
+    pub scan_status: FileScanStatus,
+    pub thumbnail_status: FileThumbnailStatus,
     pub created_at: DateTime,
     pub updated_at: DateTime,
 }
 
+// ウイルススキャンの結果
+// スキャンが終わるまではPendingとし、ダウンロードさせない
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileScanStatus {
+    Pending,
+    Clean,
+    Infected,
+    // clamdの上限を超える大きさなどの理由でスキャンできなかった
+    Unscannable,
+    // スキャナが設定されていなかったため、スキャンせずにダウンロードを許可した
+    // スキャナが設定されたら改めてスキャンする
+    NotScanned,
+}
+
+// サムネイル(PDFの場合は1ページ目のプレビュー)の生成状況
+// 対応していない種類のファイルや生成に失敗したファイルはUnavailableとする
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileThumbnailStatus {
+    Pending,
+    Ready,
+    Unavailable,
+}
+
+// サムネイルを生成できるファイルの種類
+const THUMBNAIL_CONTENT_TYPES: [&str; 5] = [
+    "image/png",
+    "image/jpeg",
+    "image/gif",
+    "image/webp",
+    "application/pdf",
+];
+
 impl_value_object!(FileId(uuid::Uuid));
+impl_value_object!(FileSize(i64));
+impl_value_object!(FileContentType(String));
+// SHA-256のハッシュ値を16進数で表したもの
+impl_value_object!(FileChecksum(String));
+impl_value_object!(FileIsStandalone(bool));
+
+impl FileData {
+    // 同じ企画が同じ内容のファイルを既にアップロードしているか
+    pub fn is_duplicate_of(&self, other: &FileData) -> bool {
+        self.id != other.id
+            && self.owner.is_some()
+            && self.owner == other.owner
+            && self.checksum.is_some()
+            && self.checksum == other.checksum
+    }
+
+    // スキャンの結果、問題がないと確認できたファイルだけをダウンロードさせる
+    pub fn is_downloadable(&self) -> bool {
+        matches!(
+            self.scan_status,
+            FileScanStatus::Clean | FileScanStatus::NotScanned
+        )
+    }
+
+    // まだスキャンしていないファイル
+    pub fn is_scan_pending(&self) -> bool {
+        matches!(
+            self.scan_status,
+            FileScanStatus::Pending | FileScanStatus::NotScanned
+        )
+    }
+
+    pub fn supports_thumbnail(&self) -> bool {
+        self.content_type
+            .as_ref()
+            .is_some_and(|it| THUMBNAIL_CONTENT_TYPES.contains(&it.clone().value().as_str()))
+    }
+
+    // サムネイルは元のファイルと同じ階層に置く
+    pub fn thumbnail_key(&self) -> Option<FileObjectKey> {
+        match self.thumbnail_status {
+            FileThumbnailStatus::Ready => Some(self.url.thumbnail()),
+            _ => None,
+        }
+    }
+
+    pub fn set_thumbnail_result(&mut self, is_generated: bool) {
+        self.thumbnail_status = if is_generated {
+            FileThumbnailStatus::Ready
+        } else {
+            FileThumbnailStatus::Unavailable
+        };
+    }
+
+    pub fn set_scan_result(&mut self, is_infected: bool) {
+        self.scan_status = if is_infected {
+            FileScanStatus::Infected
+        } else {
+            FileScanStatus::Clean
+        };
+    }
+
+    // スキャンできなかったファイルは、実委が確認するまでダウンロードさせない
+    pub fn set_unscannable(&mut self) {
+        self.scan_status = FileScanStatus::Unscannable;
+    }
+
+    pub fn set_not_scanned(&mut self) {
+        self.scan_status = FileScanStatus::NotScanned;
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FileName(String);
@@ -131,7 +320,119 @@ impl TryFrom<String> for FileId {
 
 #[cfg(test)]
 mod tests {
-    use crate::entity::file_data::FileName;
+    use crate::entity::file_data::{
+        FileChecksum, FileContentType, FileData, FileId, FileIsStandalone, FileName, FileScanStatus,
+    };
+    use crate::entity::file_object::FileObjectMetadata;
+    use crate::entity::project::ProjectId;
+    use crate::test::fixture;
+
+    fn file_data_with_checksum(
+        id: u128,
+        owner: Option<ProjectId>,
+        checksum: Option<&str>,
+    ) -> FileData {
+        let file_data = fixture::file_data::file_data(owner).destruct();
+        FileData::new(
+            FileId::new(uuid::Uuid::from_u128(id)),
+            file_data.name,
+            file_data.url,
+            file_data.owner,
+            file_data.size,
+            file_data.content_type,
+            checksum.map(|it| FileChecksum::new(it.to_string())),
+            file_data.uploader,
+            file_data.is_standalone,
+            file_data.scan_status,
+            file_data.thumbnail_status,
+            file_data.created_at,
+            file_data.updated_at,
+        )
+    }
+
+    #[test]
+    fn 同じ企画の同じ内容のファイルは重複とみなす() {
+        let file = file_data_with_checksum(1, Some(fixture::project::id1()), Some("abc"));
+        let other = file_data_with_checksum(2, Some(fixture::project::id1()), Some("abc"));
+        assert!(file.is_duplicate_of(&other));
+    }
+
+    #[test]
+    fn 別の企画や別の内容のファイルは重複とみなさない() {
+        let file = file_data_with_checksum(1, Some(fixture::project::id1()), Some("abc"));
+        let other_project = file_data_with_checksum(2, Some(fixture::project::id2()), Some("abc"));
+        let other_content = file_data_with_checksum(3, Some(fixture::project::id1()), Some("def"));
+        assert!(!file.is_duplicate_of(&other_project));
+        assert!(!file.is_duplicate_of(&other_content));
+        assert!(!file.is_duplicate_of(&file));
+    }
+
+    #[test]
+    fn チェックサムのないファイルは重複とみなさない() {
+        let file = file_data_with_checksum(1, Some(fixture::project::id1()), None);
+        let other = file_data_with_checksum(2, Some(fixture::project::id1()), None);
+        assert!(!file.is_duplicate_of(&other));
+    }
+
+    #[test]
+    fn スキャン済みで問題のないファイルだけをダウンロードできる() {
+        let mut file = FileData::create(
+            fixture::file_data::filename(),
+            fixture::file_object::key(),
+            None,
+            fixture::user::id1(),
+            FileIsStandalone::new(false),
+            fixture::file_object::metadata(),
+        );
+        assert_eq!(file.scan_status(), &FileScanStatus::Pending);
+        assert!(!file.is_downloadable());
+
+        file.set_not_scanned();
+        assert!(file.is_downloadable());
+        assert!(file.is_scan_pending());
+
+        file.set_scan_result(false);
+        assert!(file.is_downloadable());
+        assert!(!file.is_scan_pending());
+
+        file.set_scan_result(true);
+        assert_eq!(file.scan_status(), &FileScanStatus::Infected);
+        assert!(!file.is_downloadable());
+    }
+
+    #[test]
+    fn 画像とpdfのファイルだけサムネイルを生成できる() {
+        let file_with_content_type = |content_type: &str| {
+            FileData::create(
+                fixture::file_data::filename(),
+                fixture::file_object::key(),
+                None,
+                fixture::user::id1(),
+                FileIsStandalone::new(false),
+                FileObjectMetadata::new(
+                    fixture::file_data::size(),
+                    Some(FileContentType::new(content_type.to_string())),
+                    fixture::file_data::checksum(),
+                ),
+            )
+        };
+        assert!(file_with_content_type("image/png").supports_thumbnail());
+        assert!(file_with_content_type("application/pdf").supports_thumbnail());
+        assert!(!file_with_content_type("text/plain").supports_thumbnail());
+        assert!(!file_with_content_type("image/svg+xml").supports_thumbnail());
+    }
+
+    #[test]
+    fn 生成済みのサムネイルだけキーを返す() {
+        let mut file = fixture::file_data::file_data(None);
+        assert_eq!(file.thumbnail_key(), None);
+
+        file.set_thumbnail_result(true);
+        assert_eq!(
+            file.thumbnail_key(),
+            Some(fixture::file_object::key().thumbnail())
+        );
+    }
 
     #[test]
     fn filename_sanitized() {
@@ -0,0 +1,233 @@
+use getset::Getters;
+use thiserror::Error;
+
+use crate::impl_value_object;
+
+use super::{common::datetime::DateTime, file_object::FileObjectKey, form::FormId, user::UserId};
+
+// 処理中のまま進捗が更新されなくなったエクスポートは、サーバーが途中で止まったものとみなしてやり直す
+const STALE_AFTER_MINUTES: i64 = 10;
+
+// 申請のファイルをまとめたZIPファイルを、バックグラウンドで作成するエクスポート
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+pub struct FileExport {
+    #[getset(get = "pub")]
+    id: FileExportId,
+    #[getset(get = "pub")]
+    form_id: FormId,
+    #[getset(get = "pub")]
+    requested_by: UserId,
+    #[getset(get = "pub")]
+    status: FileExportStatus,
+    // 差分のエクスポートでは、この時刻より後に更新された回答のファイルだけを含める
+    #[getset(get = "pub")]
+    since: Option<DateTime>,
+    #[getset(get = "pub")]
+    total: FileExportCount,
+    #[getset(get = "pub")]
+    processed: FileExportCount,
+    #[getset(get = "pub")]
+    key: Option<FileObjectKey>,
+    #[getset(get = "pub")]
+    error: Option<FileExportError>,
+    #[getset(get = "pub")]
+    created_at: DateTime,
+    #[getset(get = "pub")]
+    updated_at: DateTime,
+}
+
+impl FileExport {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: FileExportId,
+        form_id: FormId,
+        requested_by: UserId,
+        status: FileExportStatus,
+        since: Option<DateTime>,
+        total: FileExportCount,
+        processed: FileExportCount,
+        key: Option<FileObjectKey>,
+        error: Option<FileExportError>,
+        created_at: DateTime,
+        updated_at: DateTime,
+    ) -> Self {
+        Self {
+            id,
+            form_id,
+            requested_by,
+            status,
+            since,
+            total,
+            processed,
+            key,
+            error,
+            created_at,
+            updated_at,
+        }
+    }
+
+    // previousには同じ申請の前回完了したエクスポートを渡すと、それ以降の差分だけをエクスポートする
+    pub fn create(form_id: FormId, requested_by: UserId, previous: Option<&FileExport>) -> Self {
+        let now = DateTime::now();
+        Self {
+            id: FileExportId::new(uuid::Uuid::new_v4()),
+            form_id,
+            requested_by,
+            status: FileExportStatus::Pending,
+            // 前回のエクスポートが作られている間に更新された回答も含まれるよう、完了時刻ではなく作成時刻を基準にする
+            since: previous.map(|it| it.created_at.clone()),
+            total: FileExportCount::new(0),
+            processed: FileExportCount::new(0),
+            key: None,
+            error: None,
+            created_at: now.clone(),
+            updated_at: now,
+        }
+    }
+
+    pub fn destruct(self) -> DestructedFileExport {
+        DestructedFileExport {
+            id: self.id,
+            form_id: self.form_id,
+            requested_by: self.requested_by,
+            status: self.status,
+            since: self.since,
+            total: self.total,
+            processed: self.processed,
+            key: self.key,
+            error: self.error,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DestructedFileExport {
+    pub id: FileExportId,
+    pub form_id: FormId,
+    pub requested_by: UserId,
+    pub status: FileExportStatus,
+    pub since: Option<DateTime>,
+    pub total: FileExportCount,
+    pub processed: FileExportCount,
+    pub key: Option<FileObjectKey>,
+    pub error: Option<FileExportError>,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
+impl FileExport {
+    pub fn is_incremental(&self) -> bool {
+        self.since.is_some()
+    }
+
+    pub fn is_runnable(&self, now: &chrono::DateTime<chrono::Utc>) -> bool {
+        match self.status {
+            FileExportStatus::Pending => true,
+            FileExportStatus::Running => {
+                self.updated_at.clone().value() < Self::stale_before(now).value()
+            }
+            FileExportStatus::Completed | FileExportStatus::Failed => false,
+        }
+    }
+
+    // これより前から進捗が更新されていない処理中のエクスポートはやり直す
+    pub fn stale_before(now: &chrono::DateTime<chrono::Utc>) -> DateTime {
+        DateTime::new(*now - chrono::Duration::minutes(STALE_AFTER_MINUTES))
+    }
+
+    pub fn start(&mut self, total: i32, now: &chrono::DateTime<chrono::Utc>) {
+        self.status = FileExportStatus::Running;
+        self.total = FileExportCount::new(total);
+        self.processed = FileExportCount::new(0);
+        self.error = None;
+        self.updated_at = DateTime::new(*now);
+    }
+
+    pub fn set_processed(&mut self, processed: i32, now: &chrono::DateTime<chrono::Utc>) {
+        self.processed = FileExportCount::new(processed);
+        self.updated_at = DateTime::new(*now);
+    }
+
+    pub fn complete(&mut self, key: FileObjectKey, now: &chrono::DateTime<chrono::Utc>) {
+        self.status = FileExportStatus::Completed;
+        self.processed = self.total.clone();
+        self.key = Some(key);
+        self.updated_at = DateTime::new(*now);
+    }
+
+    pub fn fail(&mut self, error: FileExportError, now: &chrono::DateTime<chrono::Utc>) {
+        self.status = FileExportStatus::Failed;
+        self.error = Some(error);
+        self.updated_at = DateTime::new(*now);
+    }
+}
+
+impl_value_object!(FileExportId(uuid::Uuid));
+#[derive(Debug, Error)]
+pub enum FileExportIdError {
+    #[error("Invalid UUID")]
+    InvalidUuid,
+}
+
+impl TryFrom<String> for FileExportId {
+    type Error = FileExportIdError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let uuid = uuid::Uuid::parse_str(&value).map_err(|_| FileExportIdError::InvalidUuid)?;
+        Ok(Self(uuid))
+    }
+}
+
+impl_value_object!(FileExportCount(i32));
+impl_value_object!(FileExportError(String));
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileExportStatus {
+    Pending,
+    Running,
+    Completed,
+    Failed,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        entity::{
+            file_export::{FileExport, FileExportStatus, STALE_AFTER_MINUTES},
+            file_object::FileObjectKey,
+        },
+        test::fixture,
+    };
+
+    #[test]
+    fn 前回のエクスポートの作成時刻以降の差分をエクスポートする() {
+        let previous = fixture::file_export::file_export1();
+        let export =
+            FileExport::create(fixture::form::id1(), fixture::user::id1(), Some(&previous));
+        assert!(export.is_incremental());
+        assert_eq!(export.since(), &Some(previous.created_at().clone()));
+    }
+
+    #[test]
+    fn 進捗が更新されなくなったエクスポートはやり直す() {
+        let mut export = fixture::file_export::file_export1();
+        let now = chrono::Utc::now();
+        assert!(export.is_runnable(&now));
+
+        export.start(3, &now);
+        assert_eq!(export.status(), &FileExportStatus::Running);
+        assert!(!export.is_runnable(&now));
+        assert!(export.is_runnable(&(now + chrono::Duration::minutes(STALE_AFTER_MINUTES + 1))));
+    }
+
+    #[test]
+    fn 完了したエクスポートは再実行しない() {
+        let mut export = fixture::file_export::file_export1();
+        let now = chrono::Utc::now();
+        export.start(3, &now);
+        export.complete(FileObjectKey::new("exports/1".to_string()), &now);
+        assert_eq!(export.processed().clone().value(), 3);
+        assert!(!export.is_runnable(&(now + chrono::Duration::days(1))));
+    }
+}
@@ -4,7 +4,10 @@ use thiserror::Error;
 
 use crate::impl_value_object;
 
-use super::{common::datetime::DateTime, file_data::FileName};
+use super::{
+    common::datetime::DateTime,
+    file_data::{FileChecksum, FileContentType, FileName, FileSize},
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, Getters, Setters)]
 pub struct FileObject {
@@ -58,17 +61,117 @@ impl TryFrom<&str> for FileSignedUrl {
 
 impl From<FileName> for ContentDisposition {
     fn from(value: FileName) -> Self {
-        Self::new(generate_content_disposition(value.value().as_bytes()))
+        Self::attachment(value)
     }
 }
 
-fn generate_content_disposition(value: &[u8]) -> String {
+// ブラウザ上で表示させるか、ダウンロードさせるか
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DispositionType {
+    Inline,
+    #[default]
+    Attachment,
+}
+
+impl ContentDisposition {
+    pub fn attachment(filename: FileName) -> Self {
+        Self::new(generate_content_disposition(
+            DispositionType::Attachment,
+            filename.value().as_bytes(),
+        ))
+    }
+
+    // スクリプトを含みうるファイルがブラウザ上で開かれないよう、
+    // プレビューできる種類のファイル以外は常にダウンロードさせる
+    pub fn for_file(
+        disposition_type: DispositionType,
+        filename: FileName,
+        content_type: Option<&FileContentType>,
+    ) -> Self {
+        let is_previewable = content_type
+            .is_some_and(|it| PREVIEWABLE_CONTENT_TYPES.contains(&it.clone().value().as_str()));
+        let disposition_type = match disposition_type {
+            DispositionType::Inline if is_previewable => DispositionType::Inline,
+            _ => DispositionType::Attachment,
+        };
+        Self::new(generate_content_disposition(
+            disposition_type,
+            filename.value().as_bytes(),
+        ))
+    }
+
+    pub fn is_inline(&self) -> bool {
+        self.0.starts_with("inline;")
+    }
+}
+
+const PREVIEWABLE_CONTENT_TYPES: [&str; 5] = [
+    "image/png",
+    "image/jpeg",
+    "image/gif",
+    "image/webp",
+    "application/pdf",
+];
+
+fn generate_content_disposition(disposition_type: DispositionType, value: &[u8]) -> String {
+    let disposition_type = match disposition_type {
+        DispositionType::Inline => "inline",
+        DispositionType::Attachment => "attachment",
+    };
     format!(
-        "attachment; filename*=UTF-8''{}",
+        "{disposition_type}; filename*=UTF-8''{}",
         percent_encoding::percent_encode(value, NON_ALPHANUMERIC)
     )
 }
 
+// 署名付きURLの有効期間(秒)
+impl_value_object!(FileSignedUrlExpiry(u64));
+
+// これまで固定で使っていた値を既定値とする
+pub const DEFAULT_SIGNED_URL_EXPIRES_IN_SECONDS: u64 = 3000;
+pub const MIN_SIGNED_URL_EXPIRES_IN_SECONDS: u64 = 60;
+// S3の署名付きURLは最長で7日間しか有効にできない
+pub const MAX_SIGNED_URL_EXPIRES_IN_SECONDS: u64 = 7 * 24 * 60 * 60;
+
+#[derive(Debug, Error)]
+pub enum FileSignedUrlExpiryError {
+    #[error(
+        "Expiry must be between {MIN_SIGNED_URL_EXPIRES_IN_SECONDS} and {MAX_SIGNED_URL_EXPIRES_IN_SECONDS} seconds"
+    )]
+    OutOfRange(u64),
+}
+
+impl TryFrom<u64> for FileSignedUrlExpiry {
+    type Error = FileSignedUrlExpiryError;
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        if !(MIN_SIGNED_URL_EXPIRES_IN_SECONDS..=MAX_SIGNED_URL_EXPIRES_IN_SECONDS).contains(&value)
+        {
+            return Err(FileSignedUrlExpiryError::OutOfRange(value));
+        }
+        Ok(Self(value))
+    }
+}
+
+impl Default for FileSignedUrlExpiry {
+    fn default() -> Self {
+        Self(DEFAULT_SIGNED_URL_EXPIRES_IN_SECONDS)
+    }
+}
+
+impl FileSignedUrlExpiry {
+    pub fn duration(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.0)
+    }
+
+    // 設定された有効期間より長くすることはできない
+    pub fn shorten(self, requested: Option<FileSignedUrlExpiry>) -> Self {
+        match requested {
+            Some(requested) if requested.0 < self.0 => requested,
+            _ => self,
+        }
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum FileObjectError {
     #[error("Invalid UUID")]
@@ -86,6 +189,10 @@ impl FileObjectKey {
     pub fn copy(&self) -> Self {
         Self(self.0.clone())
     }
+
+    pub fn thumbnail(&self) -> Self {
+        Self(format!("{}.thumbnail.png", self.0))
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -119,19 +226,72 @@ pub struct DestructedArchiveEntry {
     pub updated_at: DateTime,
 }
 
+// S3上に置かれたオブジェクトの情報
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+pub struct FileObjectMetadata {
+    #[getset(get = "pub")]
+    size: FileSize,
+    #[getset(get = "pub")]
+    content_type: Option<FileContentType>,
+    #[getset(get = "pub")]
+    checksum: FileChecksum,
+}
+
+impl FileObjectMetadata {
+    pub fn new(
+        size: FileSize,
+        content_type: Option<FileContentType>,
+        checksum: FileChecksum,
+    ) -> Self {
+        Self {
+            size,
+            content_type,
+            checksum,
+        }
+    }
+
+    // 内容から種類を判定できなかった場合は、申告された種類を使う
+    pub fn with_fallback_content_type(self, content_type: Option<FileContentType>) -> Self {
+        Self {
+            content_type: self.content_type.or(content_type),
+            ..self
+        }
+    }
+
+    pub fn destruct(self) -> DestructedFileObjectMetadata {
+        DestructedFileObjectMetadata {
+            size: self.size,
+            content_type: self.content_type,
+            checksum: self.checksum,
+        }
+    }
+}
+
+pub struct DestructedFileObjectMetadata {
+    pub size: FileSize,
+    pub content_type: Option<FileContentType>,
+    pub checksum: FileChecksum,
+}
+
 #[cfg(test)]
 mod test {
-    use crate::entity::file_object::generate_content_disposition;
+    use crate::entity::{
+        file_data::{FileContentType, FileName},
+        file_object::{
+            generate_content_disposition, ContentDisposition, DispositionType, FileSignedUrlExpiry,
+            MAX_SIGNED_URL_EXPIRES_IN_SECONDS, MIN_SIGNED_URL_EXPIRES_IN_SECONDS,
+        },
+    };
 
     #[test]
     fn encode_non_ascii_file_name() {
         assert_eq!(
             "attachment; filename*=UTF-8''%E3%83%86%E3%82%B9%E3%83%88%2Etxt",
-            generate_content_disposition("テスト.txt".as_bytes())
+            generate_content_disposition(DispositionType::Attachment, "テスト.txt".as_bytes())
         );
         assert_eq!(
             "attachment; filename*=UTF-8''%E3%83%86%20%E3%82%B9%E3%83%88%2Etxt",
-            generate_content_disposition("テ スト.txt".as_bytes())
+            generate_content_disposition(DispositionType::Attachment, "テ スト.txt".as_bytes())
         )
     }
 
@@ -139,7 +299,69 @@ mod test {
     fn encode_injecting_file_name() {
         assert_eq!(
             "attachment; filename*=UTF-8''example%22%3B%27%3B%2Etxt",
-            generate_content_disposition("example\";';.txt".as_bytes())
+            generate_content_disposition(
+                DispositionType::Attachment,
+                "example\";';.txt".as_bytes()
+            )
+        );
+    }
+
+    #[test]
+    fn プレビューできるファイルはインラインで表示できる() {
+        let content_type = FileContentType::new("application/pdf".to_string());
+        assert_eq!(
+            ContentDisposition::for_file(
+                DispositionType::Inline,
+                FileName::new("test.pdf".to_string()),
+                Some(&content_type),
+            )
+            .value(),
+            "inline; filename*=UTF-8''test%2Epdf"
+        );
+    }
+
+    #[test]
+    fn プレビューできないファイルはインラインを指定してもダウンロードさせる() {
+        let content_type = FileContentType::new("text/html".to_string());
+        assert_eq!(
+            ContentDisposition::for_file(
+                DispositionType::Inline,
+                FileName::new("test.html".to_string()),
+                Some(&content_type),
+            )
+            .value(),
+            "attachment; filename*=UTF-8''test%2Ehtml"
+        );
+        assert_eq!(
+            ContentDisposition::for_file(
+                DispositionType::Inline,
+                FileName::new("test".to_string()),
+                None,
+            )
+            .value(),
+            "attachment; filename*=UTF-8''test"
+        );
+    }
+
+    #[test]
+    fn 署名付きurlの有効期間は範囲内でなければならない() {
+        assert!(FileSignedUrlExpiry::try_from(MIN_SIGNED_URL_EXPIRES_IN_SECONDS - 1).is_err());
+        assert!(FileSignedUrlExpiry::try_from(MIN_SIGNED_URL_EXPIRES_IN_SECONDS).is_ok());
+        assert!(FileSignedUrlExpiry::try_from(MAX_SIGNED_URL_EXPIRES_IN_SECONDS).is_ok());
+        assert!(FileSignedUrlExpiry::try_from(MAX_SIGNED_URL_EXPIRES_IN_SECONDS + 1).is_err());
+    }
+
+    #[test]
+    fn 署名付きurlの有効期間は設定より長くできない() {
+        let expiry = FileSignedUrlExpiry::new(3000);
+        assert_eq!(
+            expiry.clone().shorten(Some(FileSignedUrlExpiry::new(300))),
+            FileSignedUrlExpiry::new(300)
+        );
+        assert_eq!(
+            expiry.clone().shorten(Some(FileSignedUrlExpiry::new(6000))),
+            FileSignedUrlExpiry::new(3000)
         );
+        assert_eq!(expiry.shorten(None), FileSignedUrlExpiry::new(3000));
     }
 }
@@ -0,0 +1,144 @@
+use getset::Getters;
+
+use super::{
+    actor::Actor,
+    common::datetime::DateTime,
+    file_data::{FileId, FileIsStandalone, FileName},
+    file_object::FileObjectKey,
+    project::ProjectId,
+    user::UserId,
+};
+
+// 署名付きURLを発行してから、アップロードの完了を報告するまでの猶予
+const UPLOAD_EXPIRES_IN_MINUTES: i64 = 60;
+
+// 署名付きURLを発行したが、まだ完了の報告を受けていないアップロード
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+pub struct FileUpload {
+    #[getset(get = "pub")]
+    id: FileId,
+    #[getset(get = "pub")]
+    filename: FileName,
+    #[getset(get = "pub")]
+    key: FileObjectKey,
+    #[getset(get = "pub")]
+    owner: Option<ProjectId>,
+    #[getset(get = "pub")]
+    uploader: UserId,
+    #[getset(get = "pub")]
+    is_standalone: FileIsStandalone,
+    #[getset(get = "pub")]
+    expires_at: DateTime,
+    #[getset(get = "pub")]
+    created_at: DateTime,
+}
+
+impl FileUpload {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: FileId,
+        filename: FileName,
+        key: FileObjectKey,
+        owner: Option<ProjectId>,
+        uploader: UserId,
+        is_standalone: FileIsStandalone,
+        expires_at: DateTime,
+        created_at: DateTime,
+    ) -> Self {
+        Self {
+            id,
+            filename,
+            key,
+            owner,
+            uploader,
+            is_standalone,
+            expires_at,
+            created_at,
+        }
+    }
+
+    pub fn create(
+        filename: FileName,
+        key: FileObjectKey,
+        owner: Option<ProjectId>,
+        uploader: UserId,
+        is_standalone: FileIsStandalone,
+    ) -> Self {
+        let now = chrono::Utc::now();
+        Self {
+            id: FileId::new(uuid::Uuid::new_v4()),
+            filename,
+            key,
+            owner,
+            uploader,
+            is_standalone,
+            expires_at: DateTime::new(now + chrono::Duration::minutes(UPLOAD_EXPIRES_IN_MINUTES)),
+            created_at: DateTime::new(now),
+        }
+    }
+
+    pub fn destruct(self) -> DestructedFileUpload {
+        DestructedFileUpload {
+            id: self.id,
+            filename: self.filename,
+            key: self.key,
+            owner: self.owner,
+            uploader: self.uploader,
+            is_standalone: self.is_standalone,
+            expires_at: self.expires_at,
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DestructedFileUpload {
+    pub id: FileId,
+    pub filename: FileName,
+    pub key: FileObjectKey,
+    pub owner: Option<ProjectId>,
+    pub uploader: UserId,
+    pub is_standalone: FileIsStandalone,
+    pub expires_at: DateTime,
+    pub created_at: DateTime,
+}
+
+impl FileUpload {
+    pub fn is_completable_by(&self, actor: &Actor) -> bool {
+        self.uploader == *actor.user_id()
+    }
+
+    pub fn is_expired(&self, now: &chrono::DateTime<chrono::Utc>) -> bool {
+        &self.expires_at.clone().value() <= now
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        entity::{actor::Actor, user::UserRole},
+        test::fixture,
+    };
+
+    #[test]
+    fn アップロードした本人は完了できる() {
+        let upload = fixture::file_upload::file_upload(fixture::user::id1());
+        let actor = Actor::new(fixture::user::id1(), UserRole::General);
+        assert!(upload.is_completable_by(&actor));
+    }
+
+    #[test]
+    fn アップロードした本人以外は完了できない() {
+        let upload = fixture::file_upload::file_upload(fixture::user::id1());
+        let actor = Actor::new(fixture::user::id2(), UserRole::Administrator);
+        assert!(!upload.is_completable_by(&actor));
+    }
+
+    #[test]
+    fn 期限を過ぎたアップロードは期限切れ() {
+        let upload = fixture::file_upload::file_upload(fixture::user::id1());
+        let expires_at = upload.expires_at().clone().value();
+        assert!(!upload.is_expired(&(expires_at - chrono::Duration::seconds(1))));
+        assert!(upload.is_expired(&expires_at));
+    }
+}
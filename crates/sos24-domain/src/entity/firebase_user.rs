@@ -64,3 +64,11 @@ impl From<UserEmail> for FirebaseUserEmail {
 }
 
 impl_value_object!(FirebaseUserPassword(String));
+
+impl FirebaseUserPassword {
+    // 一括登録したユーザーの初期パスワード
+    // ユーザーにはパスワード設定用のリンクを送るので、この値を知らせることはない
+    pub fn generate() -> Self {
+        Self(uuid::Uuid::new_v4().simple().to_string())
+    }
+}
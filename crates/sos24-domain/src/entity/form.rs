@@ -1,14 +1,17 @@
+use std::ffi::OsStr;
+use std::path::Path;
 use std::str::FromStr;
 
 use getset::Getters;
 use thiserror::Error;
 
-use crate::entity::file_data::FileId;
-use crate::entity::project::{Project, ProjectAttributes, ProjectCategories};
+use crate::entity::file_data::{FileData, FileId};
+use crate::entity::project::{Project, ProjectAttributes, ProjectCategories, ProjectId};
 use crate::{ensure, impl_value_object};
 
 use super::actor::Actor;
 use super::common::datetime::DateTime;
+use super::common::markdown::{self, MarkdownError};
 use super::permission::{PermissionDeniedError, Permissions};
 
 #[derive(Debug, Error)]
@@ -46,6 +49,10 @@ pub struct Form {
     #[getset(get = "pub")]
     attributes: ProjectAttributes,
     #[getset(get = "pub")]
+    included_projects: Vec<ProjectId>,
+    #[getset(get = "pub")]
+    excluded_projects: Vec<ProjectId>,
+    #[getset(get = "pub")]
     is_notified: FormIsNotified,
     #[getset(get = "pub")]
     items: Vec<FormItem>,
@@ -67,6 +74,8 @@ impl Form {
         ends_at: DateTime,
         categories: ProjectCategories,
         attributes: ProjectAttributes,
+        included_projects: Vec<ProjectId>,
+        excluded_projects: Vec<ProjectId>,
         items: Vec<FormItem>,
         attachments: Vec<FileId>,
     ) -> Result<Self, FormError> {
@@ -84,6 +93,8 @@ impl Form {
             ends_at,
             categories,
             attributes,
+            included_projects,
+            excluded_projects,
             is_notified: FormIsNotified::new(false),
             items,
             attachments,
@@ -102,6 +113,8 @@ impl Form {
         ends_at: DateTime,
         categories: ProjectCategories,
         attributes: ProjectAttributes,
+        included_projects: Vec<ProjectId>,
+        excluded_projects: Vec<ProjectId>,
         is_notified: FormIsNotified,
         items: Vec<FormItem>,
         attachments: Vec<FileId>,
@@ -117,6 +130,8 @@ impl Form {
             ends_at,
             categories,
             attributes,
+            included_projects,
+            excluded_projects,
             is_notified,
             items,
             attachments,
@@ -135,6 +150,8 @@ impl Form {
             ends_at: self.ends_at,
             categories: self.categories,
             attributes: self.attributes,
+            included_projects: self.included_projects,
+            excluded_projects: self.excluded_projects,
             is_notified: self.is_notified,
             items: self.items,
             attachments: self.attachments,
@@ -154,6 +171,8 @@ pub struct DestructedForm {
     pub ends_at: DateTime,
     pub categories: ProjectCategories,
     pub attributes: ProjectAttributes,
+    pub included_projects: Vec<ProjectId>,
+    pub excluded_projects: Vec<ProjectId>,
     pub is_notified: FormIsNotified,
     pub items: Vec<FormItem>,
     pub attachments: Vec<FileId>,
@@ -305,6 +324,26 @@ impl Form {
         Ok(())
     }
 
+    pub fn set_included_projects(
+        &mut self,
+        actor: &Actor,
+        included_projects: Vec<ProjectId>,
+    ) -> Result<(), PermissionDeniedError> {
+        ensure!(self.is_updatable_by(actor));
+        self.included_projects = included_projects;
+        Ok(())
+    }
+
+    pub fn set_excluded_projects(
+        &mut self,
+        actor: &Actor,
+        excluded_projects: Vec<ProjectId>,
+    ) -> Result<(), PermissionDeniedError> {
+        ensure!(self.is_updatable_by(actor));
+        self.excluded_projects = excluded_projects;
+        Ok(())
+    }
+
     pub fn set_items(
         &mut self,
         actor: &Actor,
@@ -332,6 +371,13 @@ impl Form {
 
     // この申請が引数に与えられた企画を対象にしたものであるかを返す
     pub fn is_sent_to(&self, project: &Project) -> bool {
+        // 除外リストが最優先で、次に個別指定、最後に企画区分・企画属性で判定する
+        if self.excluded_projects.contains(project.id()) {
+            return false;
+        }
+        if self.included_projects.contains(project.id()) {
+            return true;
+        }
         self.categories.matches(*project.category())
             && (self.attributes.matches(*project.attributes()) || project.attributes().is_empty())
     }
@@ -383,6 +429,24 @@ impl TryFrom<String> for FormId {
 impl_value_object!(FormIsDraft(bool));
 impl_value_object!(FormTitle(String));
 impl_value_object!(FormDescription(String));
+
+impl TryFrom<String> for FormDescription {
+    type Error = MarkdownError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        markdown::validate(&value)?;
+        Ok(Self(value))
+    }
+}
+
+impl FormDescription {
+    pub fn to_html(&self) -> String {
+        markdown::to_html(&self.0)
+    }
+
+    pub fn to_plain_text(&self) -> String {
+        markdown::to_plain_text(&self.0)
+    }
+}
 impl_value_object!(FormIsNotified(bool));
 
 #[derive(Debug, Clone, PartialEq, Eq, Getters)]
@@ -547,8 +611,13 @@ impl FormItemKind {
     pub fn new_file(
         extensions: Option<Vec<FormItemExtension>>,
         limit: Option<FormItemLimit>,
+        max_size: Option<FormItemMaxSize>,
     ) -> Self {
-        Self::File(FormItemFile { extensions, limit })
+        Self::File(FormItemFile {
+            extensions,
+            limit,
+            max_size,
+        })
     }
 }
 
@@ -654,6 +723,8 @@ pub struct FormItemFile {
     extensions: Option<Vec<FormItemExtension>>,
     #[getset(get = "pub")]
     limit: Option<FormItemLimit>,
+    #[getset(get = "pub")]
+    max_size: Option<FormItemMaxSize>,
 }
 
 impl FormItemFile {
@@ -661,6 +732,47 @@ impl FormItemFile {
         DestructedFormItemFile {
             extensions: self.extensions,
             limit: self.limit,
+            max_size: self.max_size,
+        }
+    }
+
+    // extensionsには拡張子（"pdf" や ".pdf"）かMIMEタイプ（"application/pdf" や "image/*"）を指定できる
+    pub fn accepts(&self, file_data: &FileData) -> bool {
+        let Some(extensions) = &self.extensions else {
+            return true;
+        };
+        if extensions.is_empty() {
+            return true;
+        }
+
+        let filename = file_data.filename().clone().value();
+        let file_extension = Path::new(&filename)
+            .extension()
+            .and_then(OsStr::to_str)
+            .map(str::to_lowercase);
+        let content_type = file_data
+            .content_type()
+            .clone()
+            .map(|it| it.value().to_lowercase());
+
+        extensions.iter().any(|extension| {
+            let extension = extension.clone().value().to_lowercase();
+            match extension.split_once('/') {
+                Some((kind, "*")) => content_type
+                    .as_deref()
+                    .and_then(|it| it.split_once('/'))
+                    .is_some_and(|(it, _)| it == kind),
+                Some(_) => content_type.as_deref() == Some(extension.as_str()),
+                None => file_extension.as_deref() == Some(extension.trim_start_matches('.')),
+            }
+        })
+    }
+
+    pub fn exceeds_max_size(&self, file_data: &FileData) -> bool {
+        match (&self.max_size, file_data.size()) {
+            (Some(max_size), Some(size)) => size.clone().value() as u64 > max_size.clone().value(),
+            // サイズが記録されていない古いファイルは判定できないので通す
+            _ => false,
         }
     }
 }
@@ -669,6 +781,7 @@ impl FormItemFile {
 pub struct DestructedFormItemFile {
     pub extensions: Option<Vec<FormItemExtension>>,
     pub limit: Option<FormItemLimit>,
+    pub max_size: Option<FormItemMaxSize>,
 }
 
 impl_value_object!(FormItemMinLength(u32));
@@ -681,20 +794,57 @@ impl_value_object!(FormItemMinSelection(u32));
 impl_value_object!(FormItemMaxSelection(u32));
 impl_value_object!(FormItemExtension(String));
 impl_value_object!(FormItemLimit(u32));
+impl_value_object!(FormItemMaxSize(u64));
 
 #[cfg(test)]
 mod tests {
     use crate::{
         entity::form::{
-            FormError, FormItemAllowNewline, FormItemKind, FormItemMax, FormItemMaxLength,
-            FormItemMaxSelection, FormItemMin, FormItemMinLength, FormItemMinSelection,
-            FormItemOption,
+            FormError, FormItemAllowNewline, FormItemExtension, FormItemFile, FormItemKind,
+            FormItemMax, FormItemMaxLength, FormItemMaxSelection, FormItemMaxSize, FormItemMin,
+            FormItemMinLength, FormItemMinSelection, FormItemOption,
+        },
+        entity::{
+            file_data::{FileContentType, FileData, FileName, FileSize},
+            user::UserRole,
         },
         test::fixture,
     };
 
     use super::Form;
 
+    fn form_item_file(extensions: &[&str], max_size: Option<u64>) -> FormItemFile {
+        FormItemFile {
+            extensions: Some(
+                extensions
+                    .iter()
+                    .map(|it| FormItemExtension::new(it.to_string()))
+                    .collect(),
+            ),
+            limit: None,
+            max_size: max_size.map(FormItemMaxSize::new),
+        }
+    }
+
+    fn file_data(filename: &str, content_type: Option<&str>, size: i64) -> FileData {
+        let file_data = fixture::file_data::file_data(None).destruct();
+        FileData::new(
+            file_data.id,
+            FileName::sanitized(filename.to_string()),
+            file_data.url,
+            file_data.owner,
+            Some(FileSize::new(size)),
+            content_type.map(|it| FileContentType::new(it.to_string())),
+            file_data.checksum,
+            file_data.uploader,
+            file_data.is_standalone,
+            file_data.scan_status,
+            file_data.thumbnail_status,
+            file_data.created_at,
+            file_data.updated_at,
+        )
+    }
+
     #[test]
     fn 申請の開始時間が終了時間より前ならばエラーを返さない() {
         let form = Form::create(
@@ -705,6 +855,8 @@ mod tests {
             fixture::form::ends_at1_opened(),
             fixture::form::categories1(),
             fixture::form::attributes1(),
+            fixture::form::included_projects1(),
+            fixture::form::excluded_projects1(),
             fixture::form::items1(),
             fixture::form::attachments1(),
         );
@@ -721,6 +873,8 @@ mod tests {
             fixture::form::starts_at1_opened(),
             fixture::form::categories1(),
             fixture::form::attributes1(),
+            fixture::form::included_projects1(),
+            fixture::form::excluded_projects1(),
             fixture::form::items1(),
             fixture::form::attachments1(),
         );
@@ -831,4 +985,52 @@ mod tests {
             Err(FormError::MinSelectionGreaterThanMaxSelection)
         ));
     }
+
+    #[test]
+    fn 個別指定された企画は企画区分と企画属性に関わらず対象になる() {
+        let actor = fixture::actor::actor1(UserRole::Administrator);
+        let project = fixture::project::project1(fixture::user::id1());
+        let mut form = fixture::form::form1_opened();
+        assert!(!form.is_sent_to(&project));
+
+        form.set_included_projects(&actor, vec![project.id().clone()])
+            .unwrap();
+        assert!(form.is_sent_to(&project));
+    }
+
+    #[test]
+    fn 除外された企画は個別指定されていても対象にならない() {
+        let actor = fixture::actor::actor1(UserRole::Administrator);
+        let project = fixture::project::project1(fixture::user::id1());
+        let mut form = fixture::form::form1_opened();
+        form.set_included_projects(&actor, vec![project.id().clone()])
+            .unwrap();
+        form.set_excluded_projects(&actor, vec![project.id().clone()])
+            .unwrap();
+        assert!(!form.is_sent_to(&project));
+    }
+
+    #[test]
+    fn 拡張子が一致するファイルは受け付ける() {
+        let item = form_item_file(&["pdf", ".PNG"], None);
+        assert!(item.accepts(&file_data("a.pdf", None, 1)));
+        assert!(item.accepts(&file_data("a.png", None, 1)));
+        assert!(!item.accepts(&file_data("a.txt", None, 1)));
+    }
+
+    #[test]
+    fn mimeタイプが一致するファイルは受け付ける() {
+        let item = form_item_file(&["application/pdf", "image/*"], None);
+        assert!(item.accepts(&file_data("a.bin", Some("application/pdf"), 1)));
+        assert!(item.accepts(&file_data("a.bin", Some("image/jpeg"), 1)));
+        assert!(!item.accepts(&file_data("a.pdf", Some("text/plain"), 1)));
+        assert!(!item.accepts(&file_data("a.pdf", None, 1)));
+    }
+
+    #[test]
+    fn 上限を超えるサイズのファイルは超過とみなす() {
+        let item = form_item_file(&[], Some(10));
+        assert!(!item.exceeds_max_size(&file_data("a.pdf", None, 10)));
+        assert!(item.exceeds_max_size(&file_data("a.pdf", None, 11)));
+    }
 }
@@ -5,7 +5,7 @@ use thiserror::Error;
 
 use crate::entity::file_data::FileId;
 use crate::entity::form::{FormId, FormItemId};
-use crate::entity::project::ProjectId;
+use crate::entity::project::{ProjectCategory, ProjectId};
 use crate::{ensure, impl_value_object};
 
 use super::actor::Actor;
@@ -82,20 +82,40 @@ pub struct DestructedFormAnswer {
 }
 
 impl FormAnswer {
-    pub fn is_updatable_by(&self, actor: &Actor, owned_project_id: Option<ProjectId>) -> bool {
+    // project_categoryは回答した企画の企画区分
+    pub fn is_visible_to(
+        &self,
+        actor: &Actor,
+        owned_project_id: Option<ProjectId>,
+        project_category: ProjectCategory,
+    ) -> bool {
+        (owned_project_id
+            .map(|project_id| self.project_id == project_id)
+            .unwrap_or(false)
+            && actor.is_in_scope(Permissions::READ_FORM_ANSWER_ALL))
+            || actor.has_project_permission(Permissions::READ_FORM_ANSWER_ALL, project_category)
+    }
+
+    pub fn is_updatable_by(
+        &self,
+        actor: &Actor,
+        owned_project_id: Option<ProjectId>,
+        project_category: ProjectCategory,
+    ) -> bool {
         owned_project_id
             .map(|project_id| self.project_id == project_id)
             .unwrap_or(false)
-            || actor.has_permission(Permissions::UPDATE_FORM_ANSWER_ALL)
+            || actor.has_project_permission(Permissions::UPDATE_FORM_ANSWER_ALL, project_category)
     }
 
     pub fn set_items(
         &mut self,
         actor: &Actor,
         owned_project_id: Option<ProjectId>,
+        project_category: ProjectCategory,
         items: Vec<FormAnswerItem>,
     ) -> Result<(), PermissionDeniedError> {
-        ensure!(self.is_updatable_by(actor, owned_project_id));
+        ensure!(self.is_updatable_by(actor, owned_project_id, project_category));
         self.items = items;
         Ok(())
     }
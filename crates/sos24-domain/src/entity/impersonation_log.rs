@@ -0,0 +1,171 @@
+use getset::Getters;
+use thiserror::Error;
+
+use crate::{ensure, impl_value_object};
+
+use super::{
+    actor::Actor,
+    common::datetime::DateTime,
+    permission::{PermissionDeniedError, Permissions},
+    user::UserId,
+};
+
+// 管理者が他のユーザーとして閲覧した記録
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+pub struct ImpersonationLog {
+    #[getset(get = "pub")]
+    id: ImpersonationLogId,
+    #[getset(get = "pub")]
+    impersonator_id: UserId,
+    #[getset(get = "pub")]
+    user_id: UserId,
+    #[getset(get = "pub")]
+    request: ImpersonationLogRequest,
+    #[getset(get = "pub")]
+    created_at: DateTime,
+}
+
+#[derive(Debug, Error)]
+pub enum ImpersonationLogError {
+    #[error("Cannot impersonate yourself")]
+    CannotImpersonateSelf,
+    #[error("Cannot impersonate a user with equal or higher privileges")]
+    CannotImpersonatePrivilegedUser,
+    #[error(transparent)]
+    PermissionDenied(#[from] PermissionDeniedError),
+}
+
+impl ImpersonationLog {
+    pub fn new(
+        id: ImpersonationLogId,
+        impersonator_id: UserId,
+        user_id: UserId,
+        request: ImpersonationLogRequest,
+        created_at: DateTime,
+    ) -> Self {
+        Self {
+            id,
+            impersonator_id,
+            user_id,
+            request,
+            created_at,
+        }
+    }
+
+    // userはなりすます対象のユーザーの、カスタムロールを含む権限を持つActor
+    pub fn create(
+        actor: &Actor,
+        user: &Actor,
+        request: ImpersonationLogRequest,
+    ) -> Result<Self, ImpersonationLogError> {
+        ensure!(actor.has_permission(Permissions::IMPERSONATE_USER));
+        if actor.user_id() == user.user_id() {
+            return Err(ImpersonationLogError::CannotImpersonateSelf);
+        }
+        // 自分と同等以上のロールを持つユーザーや、自分にない権限を持つユーザーにはなりすませない
+        if user.role() >= actor.role() || !actor.has_all_permissions_of(user) {
+            return Err(ImpersonationLogError::CannotImpersonatePrivilegedUser);
+        }
+
+        Ok(Self {
+            id: ImpersonationLogId::new(uuid::Uuid::new_v4()),
+            impersonator_id: actor.user_id().clone(),
+            user_id: user.user_id().clone(),
+            request,
+            created_at: DateTime::now(),
+        })
+    }
+
+    pub fn destruct(self) -> DestructedImpersonationLog {
+        DestructedImpersonationLog {
+            id: self.id,
+            impersonator_id: self.impersonator_id,
+            user_id: self.user_id,
+            request: self.request,
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DestructedImpersonationLog {
+    pub id: ImpersonationLogId,
+    pub impersonator_id: UserId,
+    pub user_id: UserId,
+    pub request: ImpersonationLogRequest,
+    pub created_at: DateTime,
+}
+
+impl ImpersonationLog {
+    pub fn is_visible_to(&self, actor: &Actor) -> bool {
+        actor.has_permission(Permissions::READ_IMPERSONATION_LOG_ALL)
+    }
+}
+
+impl_value_object!(ImpersonationLogId(uuid::Uuid));
+
+// なりすまし中に行ったリクエスト (例: "GET /projects/me")
+impl_value_object!(ImpersonationLogRequest(String));
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        entity::{
+            impersonation_log::{ImpersonationLog, ImpersonationLogError},
+            user::UserRole,
+        },
+        test::fixture,
+    };
+
+    #[test]
+    fn 管理者は一般ユーザーになりすませる() {
+        let actor = fixture::actor::actor1(UserRole::Administrator);
+        let user = fixture::actor::actor2(UserRole::General);
+        let log = ImpersonationLog::create(&actor, &user, fixture::impersonation_log::request1());
+        assert!(log.is_ok());
+    }
+
+    #[test]
+    fn 実委人管理者はなりすませない() {
+        let actor = fixture::actor::actor1(UserRole::CommitteeOperator);
+        let user = fixture::actor::actor2(UserRole::General);
+        let log = ImpersonationLog::create(&actor, &user, fixture::impersonation_log::request1());
+        assert!(matches!(
+            log,
+            Err(ImpersonationLogError::PermissionDenied(_))
+        ));
+    }
+
+    #[test]
+    fn 管理者は他の管理者になりすませない() {
+        let actor = fixture::actor::actor1(UserRole::Administrator);
+        let user = fixture::actor::actor2(UserRole::Administrator);
+        let log = ImpersonationLog::create(&actor, &user, fixture::impersonation_log::request1());
+        assert!(matches!(
+            log,
+            Err(ImpersonationLogError::CannotImpersonatePrivilegedUser)
+        ));
+    }
+
+    #[test]
+    fn 自分にない権限を持つユーザーにはなりすませない() {
+        let actor = fixture::actor::actor1(UserRole::CommitteeViewer)
+            .with_custom_roles(&[fixture::custom_role::impersonator_role1()]);
+        let user = fixture::actor::actor2(UserRole::General)
+            .with_custom_roles(&[fixture::custom_role::stage_role1()]);
+        let log = ImpersonationLog::create(&actor, &user, fixture::impersonation_log::request1());
+        assert!(matches!(
+            log,
+            Err(ImpersonationLogError::CannotImpersonatePrivilegedUser)
+        ));
+    }
+
+    #[test]
+    fn 自分の権限の範囲内のユーザーにはなりすませる() {
+        let actor = fixture::actor::actor1(UserRole::CommitteeViewer)
+            .with_custom_roles(&[fixture::custom_role::impersonator_role1()]);
+        let user = fixture::actor::actor2(UserRole::General);
+        let log = ImpersonationLog::create(&actor, &user, fixture::impersonation_log::request1());
+        assert!(log.is_ok());
+    }
+}
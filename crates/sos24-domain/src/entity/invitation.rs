@@ -3,9 +3,12 @@ use thiserror::Error;
 
 use crate::impl_value_object;
 
-use super::{common::datetime::DateTime, project::ProjectId, user::UserId};
+use super::{
+    actor::Actor, common::datetime::DateTime, permission::Permissions, project::ProjectId,
+    user::UserId,
+};
 
-#[derive(Debug, PartialEq, Eq, Getters)]
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
 pub struct Invitation {
     #[getset(get = "pub")]
     id: InvitationId,
@@ -105,6 +108,11 @@ impl Invitation {
     pub fn is_used(&self) -> bool {
         self.used_by.is_some()
     }
+
+    pub fn is_visible_to(&self, actor: &Actor) -> bool {
+        (self.inviter() == actor.user_id() && actor.is_in_scope(Permissions::READ_INVITATION_ALL))
+            || actor.has_permission(Permissions::READ_INVITATION_ALL)
+    }
 }
 
 impl_value_object!(InvitationId(uuid::Uuid));
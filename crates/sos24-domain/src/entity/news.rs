@@ -2,10 +2,11 @@ use getset::Getters;
 use thiserror::Error;
 
 use crate::entity::file_data::FileId;
-use crate::entity::project::{ProjectAttributes, ProjectCategories};
+use crate::entity::project::{ProjectAttributes, ProjectCategories, ProjectId};
 use crate::{ensure, impl_value_object};
 
 use super::common::datetime::DateTime;
+use super::common::markdown::{self, MarkdownError};
 use super::project::Project;
 use super::{
     actor::Actor,
@@ -29,6 +30,10 @@ pub struct News {
     #[getset(get = "pub")]
     attributes: ProjectAttributes,
     #[getset(get = "pub")]
+    included_projects: Vec<ProjectId>,
+    #[getset(get = "pub")]
+    excluded_projects: Vec<ProjectId>,
+    #[getset(get = "pub")]
     created_at: DateTime,
     #[getset(get = "pub")]
     updated_at: DateTime,
@@ -44,6 +49,8 @@ impl News {
         attachments: Vec<FileId>,
         categories: ProjectCategories,
         attributes: ProjectAttributes,
+        included_projects: Vec<ProjectId>,
+        excluded_projects: Vec<ProjectId>,
         created_at: DateTime,
         updated_at: DateTime,
     ) -> Self {
@@ -55,11 +62,14 @@ impl News {
             attachments,
             categories,
             attributes,
+            included_projects,
+            excluded_projects,
             created_at,
             updated_at,
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn create(
         state: NewsState,
         title: NewsTitle,
@@ -67,6 +77,8 @@ impl News {
         attachments: Vec<FileId>,
         categories: ProjectCategories,
         attributes: ProjectAttributes,
+        included_projects: Vec<ProjectId>,
+        excluded_projects: Vec<ProjectId>,
     ) -> Self {
         let now = DateTime::now();
         Self {
@@ -77,6 +89,8 @@ impl News {
             attachments,
             categories,
             attributes,
+            included_projects,
+            excluded_projects,
             created_at: now.clone(),
             updated_at: now,
         }
@@ -91,6 +105,8 @@ impl News {
             attachments: self.attachments,
             categories: self.categories,
             attributes: self.attributes,
+            included_projects: self.included_projects,
+            excluded_projects: self.excluded_projects,
             created_at: self.created_at,
             updated_at: self.updated_at,
         }
@@ -106,6 +122,8 @@ pub struct DestructedNews {
     pub attachments: Vec<FileId>,
     pub categories: ProjectCategories,
     pub attributes: ProjectAttributes,
+    pub included_projects: Vec<ProjectId>,
+    pub excluded_projects: Vec<ProjectId>,
     pub created_at: DateTime,
     pub updated_at: DateTime,
 }
@@ -213,8 +231,35 @@ impl News {
         Ok(())
     }
 
+    pub fn set_included_projects(
+        &mut self,
+        actor: &Actor,
+        included_projects: Vec<ProjectId>,
+    ) -> Result<(), PermissionDeniedError> {
+        ensure!(self.is_updatable_by_without_changing_state(actor));
+        self.included_projects = included_projects;
+        Ok(())
+    }
+
+    pub fn set_excluded_projects(
+        &mut self,
+        actor: &Actor,
+        excluded_projects: Vec<ProjectId>,
+    ) -> Result<(), PermissionDeniedError> {
+        ensure!(self.is_updatable_by_without_changing_state(actor));
+        self.excluded_projects = excluded_projects;
+        Ok(())
+    }
+
     // このお知らせが引数に与えられた企画を対象にしたものであるかを返す
     pub fn is_sent_to(&self, project: &Project) -> bool {
+        // 除外リストが最優先で、次に個別指定、最後に企画区分・企画属性で判定する
+        if self.excluded_projects.contains(project.id()) {
+            return false;
+        }
+        if self.included_projects.contains(project.id()) {
+            return true;
+        }
         self.categories.matches(*project.category())
             && (self.attributes.matches(*project.attributes()) || project.attributes().is_empty())
         // 企画属性が1つもない場合、企画区分が一致していれば対象であるとする
@@ -239,6 +284,24 @@ impl TryFrom<String> for NewsId {
 impl_value_object!(NewsTitle(String));
 impl_value_object!(NewsBody(String));
 
+impl TryFrom<String> for NewsBody {
+    type Error = MarkdownError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        markdown::validate(&value)?;
+        Ok(Self(value))
+    }
+}
+
+impl NewsBody {
+    pub fn to_html(&self) -> String {
+        markdown::to_html(&self.0)
+    }
+
+    pub fn to_plain_text(&self) -> String {
+        markdown::to_plain_text(&self.0)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum NewsState {
     Draft,
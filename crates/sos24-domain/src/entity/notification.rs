@@ -0,0 +1,184 @@
+use getset::Getters;
+use thiserror::Error;
+
+use crate::{ensure, impl_value_object};
+
+use super::{
+    actor::Actor, common::datetime::DateTime, permission::PermissionDeniedError, user::UserId,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+pub struct Notification {
+    #[getset(get = "pub")]
+    id: NotificationId,
+    #[getset(get = "pub")]
+    user_id: UserId,
+    #[getset(get = "pub")]
+    kind: NotificationKind,
+    #[getset(get = "pub")]
+    title: NotificationTitle,
+    #[getset(get = "pub")]
+    body: NotificationBody,
+    #[getset(get = "pub")]
+    url: NotificationUrl,
+    #[getset(get = "pub")]
+    read_at: Option<DateTime>,
+    #[getset(get = "pub")]
+    created_at: DateTime,
+}
+
+impl Notification {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: NotificationId,
+        user_id: UserId,
+        kind: NotificationKind,
+        title: NotificationTitle,
+        body: NotificationBody,
+        url: NotificationUrl,
+        read_at: Option<DateTime>,
+        created_at: DateTime,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            kind,
+            title,
+            body,
+            url,
+            read_at,
+            created_at,
+        }
+    }
+
+    pub fn create(
+        user_id: UserId,
+        kind: NotificationKind,
+        title: NotificationTitle,
+        body: NotificationBody,
+        url: NotificationUrl,
+    ) -> Self {
+        Self {
+            id: NotificationId::new(uuid::Uuid::new_v4()),
+            user_id,
+            kind,
+            title,
+            body,
+            url,
+            read_at: None,
+            created_at: DateTime::now(),
+        }
+    }
+
+    pub fn destruct(self) -> DestructedNotification {
+        DestructedNotification {
+            id: self.id,
+            user_id: self.user_id,
+            kind: self.kind,
+            title: self.title,
+            body: self.body,
+            url: self.url,
+            read_at: self.read_at,
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DestructedNotification {
+    pub id: NotificationId,
+    pub user_id: UserId,
+    pub kind: NotificationKind,
+    pub title: NotificationTitle,
+    pub body: NotificationBody,
+    pub url: NotificationUrl,
+    pub read_at: Option<DateTime>,
+    pub created_at: DateTime,
+}
+
+impl Notification {
+    // 通知は宛先のユーザー本人しか閲覧できない
+    pub fn is_visible_to(&self, actor: &Actor) -> bool {
+        self.user_id() == actor.user_id()
+    }
+
+    pub fn is_read(&self) -> bool {
+        self.read_at.is_some()
+    }
+
+    pub fn read(&mut self, actor: &Actor, read_at: DateTime) -> Result<(), PermissionDeniedError> {
+        ensure!(self.is_visible_to(actor));
+        // 既読の場合は最初に既読にした時刻を保持する
+        if self.read_at.is_none() {
+            self.read_at.replace(read_at);
+        }
+        Ok(())
+    }
+}
+
+impl_value_object!(NotificationId(uuid::Uuid));
+#[derive(Debug, Error)]
+pub enum NotificationIdError {
+    #[error("Invalid UUID")]
+    InvalidUuid,
+}
+
+impl TryFrom<String> for NotificationId {
+    type Error = NotificationIdError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let uuid = uuid::Uuid::parse_str(&value).map_err(|_| NotificationIdError::InvalidUuid)?;
+        Ok(Self(uuid))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NotificationKind {
+    NewsPublished,
+    FormStarted,
+    InvitationReceived,
+}
+
+impl_value_object!(NotificationTitle(String));
+impl_value_object!(NotificationBody(String));
+impl_value_object!(NotificationUrl(String));
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        entity::{permission::PermissionDeniedError, user::UserRole},
+        test::fixture,
+    };
+
+    #[test]
+    fn 宛先のユーザーは通知を閲覧できる() {
+        let notification = fixture::notification::notification1(fixture::user::id1());
+        let actor = fixture::actor::actor1(UserRole::General);
+        assert!(notification.is_visible_to(&actor));
+    }
+
+    #[test]
+    fn 宛先以外のユーザーは通知を閲覧できない() {
+        let notification = fixture::notification::notification1(fixture::user::id2());
+        let actor = fixture::actor::actor1(UserRole::Administrator);
+        assert!(!notification.is_visible_to(&actor));
+    }
+
+    #[test]
+    fn 宛先のユーザーは通知を既読にできる() {
+        let mut notification = fixture::notification::notification1(fixture::user::id1());
+        let actor = fixture::actor::actor1(UserRole::General);
+        assert!(notification.read(&actor, fixture::datetime::now()).is_ok());
+        assert!(notification.is_read());
+    }
+
+    #[test]
+    fn 宛先以外のユーザーは通知を既読にできない() {
+        let mut notification = fixture::notification::notification1(fixture::user::id2());
+        let actor = fixture::actor::actor1(UserRole::General);
+        assert!(matches!(
+            notification.read(&actor, fixture::datetime::now()),
+            Err(PermissionDeniedError)
+        ));
+        assert!(!notification.is_read());
+    }
+}
@@ -72,6 +72,29 @@ bitflags! {
       const READ_FILE_ALL = 1 << 44;
       const DELETE_FILE_ALL = 1 << 45;
 
+      // webhooks
+      const CREATE_WEBHOOK = 1 << 46;
+      const READ_WEBHOOK_ALL = 1 << 47;
+      const UPDATE_WEBHOOK_ALL = 1 << 48;
+      const DELETE_WEBHOOK_ALL = 1 << 49;
+
+      // custom roles
+      const CREATE_ROLE = 1 << 50;
+      const READ_ROLE_ALL = 1 << 51;
+      const UPDATE_ROLE_ALL = 1 << 52;
+      const DELETE_ROLE_ALL = 1 << 53;
+
+      // impersonation
+      const IMPERSONATE_USER = 1 << 54;
+      const READ_IMPERSONATION_LOG_ALL = 1 << 55;
+
+      // email
+      // 許可されたドメイン以外のメールアドレスでユーザーを登録する
+      const REGISTER_UNLISTED_EMAIL = 1 << 56;
+
+      // user import
+      const IMPORT_USER = 1 << 57;
+
     }
 }
 
@@ -98,6 +121,7 @@ impl UserRole {
                     | Permissions::UPDATE_PROJECT_ALL
                     | Permissions::READ_USER_ALL
                     | Permissions::UPDATE_USER_ALL
+                    | Permissions::READ_ROLE_ALL
                     | Permissions::CREATE_NEWS
                     | Permissions::UPDATE_NEWS_ALL
                     | Permissions::CREATE_SCHEDULED_NEWS
@@ -143,6 +167,29 @@ impl UserRole {
     }
 }
 
+impl Permissions {
+    // 権限の名前(定数名)の一覧から権限を組み立てる
+    pub fn from_names(names: &[String]) -> Result<Self, PermissionNameError> {
+        names.iter().try_fold(Permissions::empty(), |acc, name| {
+            Permissions::from_name(name)
+                .map(|permission| acc | permission)
+                .ok_or_else(|| PermissionNameError::Unknown(name.clone()))
+        })
+    }
+
+    pub fn names(&self) -> Vec<String> {
+        self.iter_names()
+            .map(|(name, _)| name.to_string())
+            .collect()
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum PermissionNameError {
+    #[error("Unknown permission: {0}")]
+    Unknown(String),
+}
+
 #[derive(Debug, Error)]
 #[error("Permission denied")]
 pub struct PermissionDeniedError;
@@ -164,11 +164,13 @@ impl Project {
     }
 
     pub fn is_visible_to(&self, actor: &Actor) -> bool {
-        self.is_owned_by(actor.user_id()) || actor.has_permission(Permissions::READ_PROJECT_ALL)
+        (self.is_owned_by(actor.user_id()) && actor.is_in_scope(Permissions::READ_PROJECT_ALL))
+            || actor.has_project_permission(Permissions::READ_PROJECT_ALL, self.category)
     }
 
     pub fn is_updatable_by(&self, actor: &Actor) -> bool {
-        self.is_owned_by(actor.user_id()) || actor.has_permission(Permissions::UPDATE_PROJECT_ALL)
+        self.is_owned_by(actor.user_id())
+            || actor.has_project_permission(Permissions::UPDATE_PROJECT_ALL, self.category)
     }
 
     pub fn set_title(
@@ -217,6 +219,11 @@ impl Project {
         category: ProjectCategory,
     ) -> Result<(), PermissionDeniedError> {
         ensure!(self.is_updatable_by(actor));
+        // 担当外の企画区分へ移すことはできない
+        ensure!(
+            self.is_owned_by(actor.user_id())
+                || actor.has_project_permission(Permissions::UPDATE_PROJECT_ALL, category)
+        );
         self.category = category;
         Ok(())
     }
@@ -236,7 +243,7 @@ impl Project {
         actor: &Actor,
         remarks: ProjectRemarks,
     ) -> Result<(), PermissionDeniedError> {
-        ensure!(actor.has_permission(Permissions::UPDATE_PROJECT_ALL));
+        ensure!(actor.has_project_permission(Permissions::UPDATE_PROJECT_ALL, self.category));
         self.remarks.replace(remarks);
         Ok(())
     }
@@ -276,7 +283,7 @@ impl Project {
         actor: &Actor,
         location_id: ProjectLocationId,
     ) -> Result<(), PermissionDeniedError> {
-        ensure!(actor.has_permission(Permissions::UPDATE_PROJECT_ALL));
+        ensure!(actor.has_project_permission(Permissions::UPDATE_PROJECT_ALL, self.category));
         self.location_id.replace(location_id);
         Ok(())
     }
@@ -409,6 +416,18 @@ pub enum ProjectCategory {
     StageUnited,
 }
 
+impl ProjectCategory {
+    pub const ALL: [ProjectCategory; 7] = [
+        ProjectCategory::General,
+        ProjectCategory::FoodsWithKitchen,
+        ProjectCategory::FoodsWithoutKitchen,
+        ProjectCategory::FoodsWithoutCooking,
+        ProjectCategory::Stage1A,
+        ProjectCategory::StageUniversityHall,
+        ProjectCategory::StageUnited,
+    ];
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ProjectAttributes(u32);
 
@@ -0,0 +1,97 @@
+use getset::Getters;
+
+use crate::impl_value_object;
+
+use super::{file_data::FileData, project::ProjectId};
+
+// 企画ごとにアップロードできるファイルの合計サイズ(バイト)
+impl_value_object!(ProjectStorageQuota(u64));
+
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+pub struct ProjectStorage {
+    #[getset(get = "pub")]
+    project_id: ProjectId,
+    #[getset(get = "pub")]
+    used: u64,
+    #[getset(get = "pub")]
+    quota: ProjectStorageQuota,
+}
+
+impl ProjectStorage {
+    pub fn new(project_id: ProjectId, used: u64, quota: ProjectStorageQuota) -> Self {
+        Self {
+            project_id,
+            used,
+            quota,
+        }
+    }
+
+    // 企画のファイルのサイズの合計を使用量とする
+    // サイズが記録されていない古いファイルは数えない
+    pub fn calculate(
+        project_id: ProjectId,
+        file_data_list: &[FileData],
+        quota: ProjectStorageQuota,
+    ) -> Self {
+        let used = file_data_list
+            .iter()
+            .filter_map(|it| it.size().clone())
+            .map(|it| it.value().max(0) as u64)
+            .sum();
+        Self::new(project_id, used, quota)
+    }
+
+    pub fn remaining(&self) -> u64 {
+        self.quota.clone().value().saturating_sub(self.used)
+    }
+
+    pub fn can_store(&self, size: u64) -> bool {
+        size <= self.remaining()
+    }
+
+    pub fn destruct(self) -> DestructedProjectStorage {
+        DestructedProjectStorage {
+            project_id: self.project_id,
+            used: self.used,
+            quota: self.quota,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DestructedProjectStorage {
+    pub project_id: ProjectId,
+    pub used: u64,
+    pub quota: ProjectStorageQuota,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::entity::project_storage::{ProjectStorage, ProjectStorageQuota};
+    use crate::test::fixture;
+
+    #[test]
+    fn 企画のファイルのサイズの合計を使用量とする() {
+        let file_data_list = vec![
+            fixture::file_data::file_data(Some(fixture::project::id1())),
+            fixture::file_data::file_data(Some(fixture::project::id1())),
+        ];
+        let storage = ProjectStorage::calculate(
+            fixture::project::id1(),
+            &file_data_list,
+            ProjectStorageQuota::new(30),
+        );
+        assert_eq!(storage.used(), &22);
+        assert_eq!(storage.remaining(), 8);
+        assert!(storage.can_store(8));
+        assert!(!storage.can_store(9));
+    }
+
+    #[test]
+    fn 上限を超えている場合は残りを0とする() {
+        let storage =
+            ProjectStorage::new(fixture::project::id1(), 40, ProjectStorageQuota::new(30));
+        assert_eq!(storage.remaining(), 0);
+        assert!(!storage.can_store(1));
+    }
+}
@@ -1,4 +1,5 @@
 use getset::Getters;
+use thiserror::Error;
 
 use crate::{ensure, impl_value_object};
 
@@ -76,6 +77,33 @@ impl User {
         }
     }
 
+    // 管理者がCSVなどから一括でユーザーを登録する
+    // 自分より強いロールのユーザーは登録できない
+    pub fn import(
+        actor: &Actor,
+        id: UserId,
+        name: UserName,
+        kana_name: UserKanaName,
+        email: UserEmail,
+        phone_number: UserPhoneNumber,
+        role: UserRole,
+    ) -> Result<Self, PermissionDeniedError> {
+        ensure!(actor.has_permission(Permissions::IMPORT_USER));
+        ensure!(actor.role() >= &role);
+
+        let now = DateTime::now();
+        Ok(Self {
+            id,
+            name,
+            kana_name,
+            email,
+            phone_number,
+            role,
+            created_at: now.clone(),
+            updated_at: now,
+        })
+    }
+
     pub fn destruct(self) -> DestructuredUser {
         DestructuredUser {
             id: self.id,
@@ -108,7 +136,8 @@ impl User {
     }
 
     pub fn is_visible_to(&self, actor: &Actor) -> bool {
-        actor.user_id() == self.id() || actor.has_permission(Permissions::READ_USER_ALL)
+        (actor.user_id() == self.id() && actor.is_in_scope(Permissions::READ_USER_ALL))
+            || actor.has_permission(Permissions::READ_USER_ALL)
     }
 
     pub fn is_updatable_by(&self, actor: &Actor) -> bool {
@@ -159,6 +188,20 @@ impl User {
         }
         Ok(())
     }
+
+    // 退会したユーザーの個人情報を消去する
+    // メールアドレスと電話番号は一意制約があるため、ユーザーごとに異なる値で置き換える
+    pub fn anonymize(&mut self, actor: &Actor) -> Result<(), PermissionDeniedError> {
+        ensure!(actor.user_id() == self.id() || actor.has_permission(Permissions::DELETE_USER_ALL));
+
+        let key = uuid::Uuid::new_v4().simple().to_string();
+        self.name = UserName::new("退会済みユーザー".to_string());
+        self.kana_name = UserKanaName::new("たいかいずみゆーざー".to_string());
+        self.email = UserEmail(Email::anonymous(&key));
+        self.phone_number = UserPhoneNumber::new(format!("deleted-{key}"));
+        self.role = UserRole::General;
+        Ok(())
+    }
 }
 
 impl_value_object!(UserId(String));
@@ -171,7 +214,87 @@ impl From<FirebaseUserId> for UserId {
 
 impl_value_object!(UserName(String));
 impl_value_object!(UserKanaName(String));
-impl_value_object!(UserPhoneNumber(String)); // ガバガバだが、電話番号が弾かれる事によってjsysの作業が増えることを鑑みて許容する
+// 国内形式の数字のみに正規化した電話番号 (例: 09012345678)
+// DBから復元する場合は、正規化以前に登録された値もあるため new を用いる
+impl_value_object!(UserPhoneNumber(String));
+
+#[derive(Debug, Error)]
+pub enum UserPhoneNumberError {
+    #[error("Invalid phone number: {0}")]
+    InvalidFormat(String),
+}
+
+// 携帯電話・IP電話などの11桁の番号の先頭
+const ELEVEN_DIGIT_PREFIXES: [&str; 6] = ["020", "050", "060", "070", "080", "090"];
+
+impl TryFrom<String> for UserPhoneNumber {
+    type Error = UserPhoneNumberError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let normalized = normalize_phone_number(&value);
+        let is_valid = normalized.starts_with('0')
+            && match normalized.len() {
+                10 => !normalized.starts_with("00"),
+                11 => {
+                    ELEVEN_DIGIT_PREFIXES
+                        .iter()
+                        .any(|prefix| normalized.starts_with(prefix))
+                        || normalized.starts_with("0800")
+                }
+                _ => false,
+            };
+        if !is_valid {
+            return Err(UserPhoneNumberError::InvalidFormat(value));
+        }
+        Ok(Self(normalized))
+    }
+}
+
+// 全角数字やハイフン・空白などの区切りを受け付け、+81で始まる番号は国内形式に直す
+fn normalize_phone_number(value: &str) -> String {
+    let mut digits = value
+        .chars()
+        .filter_map(|c| match c {
+            '0'..='9' | '+' => Some(c),
+            '０'..='９' => char::from_u32(c as u32 - '０' as u32 + '0' as u32),
+            '＋' => Some('+'),
+            _ => None,
+        })
+        .collect::<String>();
+
+    if let Some(rest) = digits.strip_prefix("+81") {
+        // +81 (0)90... のように国内の0を残した書き方も受け付ける
+        digits = format!("0{}", rest.strip_prefix('0').unwrap_or(rest));
+    }
+    digits.retain(|c| c.is_ascii_digit());
+    digits
+}
+
+impl UserPhoneNumber {
+    // 名簿などに載せるためのハイフン区切りの表記
+    pub fn display(&self) -> String {
+        let number = &self.0;
+        let groups: &[usize] = match number.len() {
+            11 if number.starts_with("0800") => &[4, 3, 4],
+            11 => &[3, 4, 4],
+            10 if number.starts_with("0120") => &[4, 3, 3],
+            10 if number.starts_with("03") || number.starts_with("06") => &[2, 4, 4],
+            10 => &[3, 3, 4],
+            // 正規化以前に登録された値はそのまま表示する
+            _ => return number.clone(),
+        };
+        if !number.chars().all(|c| c.is_ascii_digit()) {
+            return number.clone();
+        }
+
+        let mut parts = Vec::with_capacity(groups.len());
+        let mut start = 0;
+        for len in groups {
+            parts.push(&number[start..start + len]);
+            start += len;
+        }
+        parts.join("-")
+    }
+}
 
 // 権限の弱い順に定義
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -206,7 +329,9 @@ impl TryFrom<String> for UserEmail {
 
 #[cfg(test)]
 mod tests {
-    use crate::entity::user::UserRole;
+    use crate::entity::permission::Permissions;
+    use crate::entity::user::{UserPhoneNumber, UserPhoneNumberError, UserRole};
+    use crate::test::fixture;
 
     #[test]
     fn user_role_ordering() {
@@ -216,4 +341,87 @@ mod tests {
         assert!(UserRole::CommitteeDrafter > UserRole::CommitteeViewer);
         assert!(UserRole::CommitteeViewer > UserRole::General);
     }
+
+    #[test]
+    fn phone_number_normalization() {
+        let expected = UserPhoneNumber::new("09012345678".to_string());
+        for raw in [
+            "09012345678",
+            "090-1234-5678",
+            "090 1234 5678",
+            "０９０－１２３４－５６７８",
+            "+81-90-1234-5678",
+            "+81 (0)90 1234 5678",
+        ] {
+            assert_eq!(
+                UserPhoneNumber::try_from(raw.to_string()).unwrap(),
+                expected
+            );
+        }
+        assert_eq!(
+            UserPhoneNumber::try_from("029-853-2111".to_string()).unwrap(),
+            UserPhoneNumber::new("0298532111".to_string())
+        );
+    }
+
+    #[test]
+    fn invalid_phone_number() {
+        for raw in [
+            "",
+            "090123456",
+            "090123456789",
+            "19012345678",
+            "03123456789",
+        ] {
+            assert!(matches!(
+                UserPhoneNumber::try_from(raw.to_string()),
+                Err(UserPhoneNumberError::InvalidFormat(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn phone_number_display() {
+        let display = |raw: &str| {
+            UserPhoneNumber::try_from(raw.to_string())
+                .unwrap()
+                .display()
+        };
+        assert_eq!(display("09012345678"), "090-1234-5678");
+        assert_eq!(display("0312345678"), "03-1234-5678");
+        assert_eq!(display("0298532111"), "029-853-2111");
+        assert_eq!(display("0120123456"), "0120-123-456");
+        assert_eq!(
+            UserPhoneNumber::new("ガバガバ".to_string()).display(),
+            "ガバガバ"
+        );
+    }
+
+    #[test]
+    fn anonymize_user() {
+        let mut user = fixture::user::user1(UserRole::CommitteeEditor);
+        let actor = fixture::actor::actor1(UserRole::CommitteeEditor);
+        user.anonymize(&actor).unwrap();
+
+        assert_eq!(user.id(), &fixture::user::id1());
+        assert_ne!(user.name(), &fixture::user::name1());
+        assert_ne!(user.email(), &fixture::user::email1());
+        assert_ne!(user.phone_number(), &fixture::user::phone_number1());
+        assert_eq!(user.role(), &UserRole::General);
+    }
+
+    #[test]
+    fn anonymize_other_user() {
+        let mut user = fixture::user::user2(UserRole::General);
+        let actor = fixture::actor::actor1(UserRole::CommitteeEditor);
+        assert!(user.anonymize(&actor).is_err());
+    }
+
+    #[test]
+    fn api_token_needs_user_scope_to_view_self() {
+        let user = fixture::user::user1(UserRole::General);
+        let actor = fixture::actor::actor1(UserRole::General);
+        assert!(user.is_visible_to(&actor.clone().with_scopes(Permissions::READ_USER_ALL)));
+        assert!(!user.is_visible_to(&actor.with_scopes(Permissions::READ_PROJECT_ALL)));
+    }
 }
@@ -0,0 +1,274 @@
+use getset::Getters;
+use thiserror::Error;
+
+use crate::{ensure, impl_value_object};
+
+use super::{
+    actor::Actor,
+    common::datetime::DateTime,
+    permission::{PermissionDeniedError, Permissions},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+pub struct Webhook {
+    #[getset(get = "pub")]
+    id: WebhookId,
+    #[getset(get = "pub")]
+    name: WebhookName,
+    #[getset(get = "pub")]
+    url: WebhookUrl,
+    #[getset(get = "pub")]
+    secret: WebhookSecret,
+    #[getset(get = "pub")]
+    format: WebhookFormat,
+    #[getset(get = "pub")]
+    event_types: Vec<WebhookEventType>,
+    #[getset(get = "pub")]
+    created_at: DateTime,
+    #[getset(get = "pub")]
+    updated_at: DateTime,
+}
+
+impl Webhook {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: WebhookId,
+        name: WebhookName,
+        url: WebhookUrl,
+        secret: WebhookSecret,
+        format: WebhookFormat,
+        event_types: Vec<WebhookEventType>,
+        created_at: DateTime,
+        updated_at: DateTime,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            url,
+            secret,
+            format,
+            event_types,
+            created_at,
+            updated_at,
+        }
+    }
+
+    pub fn create(
+        name: WebhookName,
+        url: WebhookUrl,
+        format: WebhookFormat,
+        event_types: Vec<WebhookEventType>,
+    ) -> Self {
+        let now = DateTime::now();
+        Self {
+            id: WebhookId::new(uuid::Uuid::new_v4()),
+            name,
+            url,
+            secret: WebhookSecret::generate(),
+            format,
+            event_types,
+            created_at: now.clone(),
+            updated_at: now,
+        }
+    }
+
+    pub fn destruct(self) -> DestructedWebhook {
+        DestructedWebhook {
+            id: self.id,
+            name: self.name,
+            url: self.url,
+            secret: self.secret,
+            format: self.format,
+            event_types: self.event_types,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DestructedWebhook {
+    pub id: WebhookId,
+    pub name: WebhookName,
+    pub url: WebhookUrl,
+    pub secret: WebhookSecret,
+    pub format: WebhookFormat,
+    pub event_types: Vec<WebhookEventType>,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
+impl Webhook {
+    pub fn is_visible_to(&self, actor: &Actor) -> bool {
+        actor.has_permission(Permissions::READ_WEBHOOK_ALL)
+    }
+
+    pub fn is_updatable_by(&self, actor: &Actor) -> bool {
+        actor.has_permission(Permissions::UPDATE_WEBHOOK_ALL)
+    }
+
+    pub fn is_subscribed_to(&self, event_type: &WebhookEventType) -> bool {
+        self.event_types.contains(event_type)
+    }
+
+    pub fn set_name(
+        &mut self,
+        actor: &Actor,
+        name: WebhookName,
+    ) -> Result<(), PermissionDeniedError> {
+        ensure!(self.is_updatable_by(actor));
+        self.name = name;
+        Ok(())
+    }
+
+    pub fn set_url(&mut self, actor: &Actor, url: WebhookUrl) -> Result<(), PermissionDeniedError> {
+        ensure!(self.is_updatable_by(actor));
+        self.url = url;
+        Ok(())
+    }
+
+    pub fn set_format(
+        &mut self,
+        actor: &Actor,
+        format: WebhookFormat,
+    ) -> Result<(), PermissionDeniedError> {
+        ensure!(self.is_updatable_by(actor));
+        self.format = format;
+        Ok(())
+    }
+
+    pub fn set_event_types(
+        &mut self,
+        actor: &Actor,
+        event_types: Vec<WebhookEventType>,
+    ) -> Result<(), PermissionDeniedError> {
+        ensure!(self.is_updatable_by(actor));
+        self.event_types = event_types;
+        Ok(())
+    }
+}
+
+impl_value_object!(WebhookId(uuid::Uuid));
+#[derive(Debug, Error)]
+pub enum WebhookIdError {
+    #[error("Invalid UUID")]
+    InvalidUuid,
+}
+
+impl TryFrom<String> for WebhookId {
+    type Error = WebhookIdError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let uuid = uuid::Uuid::parse_str(&value).map_err(|_| WebhookIdError::InvalidUuid)?;
+        Ok(Self(uuid))
+    }
+}
+
+impl_value_object!(WebhookName(String));
+
+impl_value_object!(WebhookUrl(String));
+#[derive(Debug, Error)]
+pub enum WebhookUrlError {
+    #[error("Invalid URL")]
+    InvalidUrl,
+    #[error("URL scheme must be http or https")]
+    InvalidScheme,
+}
+
+impl TryFrom<String> for WebhookUrl {
+    type Error = WebhookUrlError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let url = url::Url::parse(&value).map_err(|_| WebhookUrlError::InvalidUrl)?;
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err(WebhookUrlError::InvalidScheme);
+        }
+        Ok(Self(value))
+    }
+}
+
+impl_value_object!(WebhookSecret(String));
+
+impl WebhookSecret {
+    fn generate() -> Self {
+        // UUIDv4を2つ繋げて256bit相当の乱数列にする
+        Self(format!(
+            "{}{}",
+            uuid::Uuid::new_v4().simple(),
+            uuid::Uuid::new_v4().simple()
+        ))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WebhookFormat {
+    // 署名付きのJSONを送信する
+    Json,
+    // SlackのIncoming Webhook向けにメッセージを送信する
+    Slack,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WebhookEventType {
+    ProjectCreated,
+    ProjectUpdated,
+    ProjectDeleted,
+    FormAnswerSubmitted,
+    NewsPublished,
+    // 実委向けの通知
+    CommitteeNotification,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        entity::{
+            permission::PermissionDeniedError,
+            user::UserRole,
+            webhook::{WebhookEventType, WebhookUrl, WebhookUrlError},
+        },
+        test::fixture,
+    };
+
+    #[test]
+    fn 管理者はwebhookを閲覧できる() {
+        let webhook = fixture::webhook::webhook1();
+        let actor = fixture::actor::actor1(UserRole::Administrator);
+        assert!(webhook.is_visible_to(&actor));
+    }
+
+    #[test]
+    fn 実委人管理者以外はwebhookを閲覧できない() {
+        let webhook = fixture::webhook::webhook1();
+        let actor = fixture::actor::actor1(UserRole::CommitteeOperator);
+        assert!(!webhook.is_visible_to(&actor));
+    }
+
+    #[test]
+    fn 実委人管理者以外はwebhookを更新できない() {
+        let mut webhook = fixture::webhook::webhook1();
+        let actor = fixture::actor::actor1(UserRole::CommitteeOperator);
+        assert!(matches!(
+            webhook.set_name(&actor, fixture::webhook::name2()),
+            Err(PermissionDeniedError)
+        ));
+    }
+
+    #[test]
+    fn 購読しているイベントのみ受け取る() {
+        let webhook = fixture::webhook::webhook1();
+        assert!(webhook.is_subscribed_to(&WebhookEventType::ProjectCreated));
+        assert!(!webhook.is_subscribed_to(&WebhookEventType::NewsPublished));
+    }
+
+    #[test]
+    fn httpとhttps以外のurlは不正() {
+        assert!(WebhookUrl::try_from("https://example.com/hook".to_string()).is_ok());
+        assert!(matches!(
+            WebhookUrl::try_from("ftp://example.com/hook".to_string()),
+            Err(WebhookUrlError::InvalidScheme)
+        ));
+        assert!(matches!(
+            WebhookUrl::try_from("example".to_string()),
+            Err(WebhookUrlError::InvalidUrl)
+        ));
+    }
+}
@@ -0,0 +1,268 @@
+use getset::Getters;
+use thiserror::Error;
+
+use crate::impl_value_object;
+
+use super::{
+    common::datetime::DateTime,
+    webhook::{WebhookEventType, WebhookId},
+};
+
+// 初回を含めた最大の送信回数
+const MAX_ATTEMPTS: i32 = 6;
+// 再送までの待機時間の基準（失敗するたびに倍になる）
+const BASE_BACKOFF_SECONDS: i64 = 60;
+
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+pub struct WebhookDelivery {
+    #[getset(get = "pub")]
+    id: WebhookDeliveryId,
+    #[getset(get = "pub")]
+    webhook_id: WebhookId,
+    #[getset(get = "pub")]
+    event_type: WebhookEventType,
+    #[getset(get = "pub")]
+    payload: WebhookPayload,
+    #[getset(get = "pub")]
+    status: WebhookDeliveryStatus,
+    #[getset(get = "pub")]
+    attempts: WebhookDeliveryAttempts,
+    #[getset(get = "pub")]
+    response_status: Option<WebhookResponseStatus>,
+    #[getset(get = "pub")]
+    last_error: Option<WebhookDeliveryError>,
+    #[getset(get = "pub")]
+    next_attempt_at: Option<DateTime>,
+    #[getset(get = "pub")]
+    created_at: DateTime,
+    #[getset(get = "pub")]
+    updated_at: DateTime,
+}
+
+impl WebhookDelivery {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: WebhookDeliveryId,
+        webhook_id: WebhookId,
+        event_type: WebhookEventType,
+        payload: WebhookPayload,
+        status: WebhookDeliveryStatus,
+        attempts: WebhookDeliveryAttempts,
+        response_status: Option<WebhookResponseStatus>,
+        last_error: Option<WebhookDeliveryError>,
+        next_attempt_at: Option<DateTime>,
+        created_at: DateTime,
+        updated_at: DateTime,
+    ) -> Self {
+        Self {
+            id,
+            webhook_id,
+            event_type,
+            payload,
+            status,
+            attempts,
+            response_status,
+            last_error,
+            next_attempt_at,
+            created_at,
+            updated_at,
+        }
+    }
+
+    pub fn create(
+        webhook_id: WebhookId,
+        event_type: WebhookEventType,
+        payload: WebhookPayload,
+    ) -> Self {
+        let now = DateTime::now();
+        Self {
+            id: WebhookDeliveryId::new(uuid::Uuid::new_v4()),
+            webhook_id,
+            event_type,
+            payload,
+            status: WebhookDeliveryStatus::Pending,
+            attempts: WebhookDeliveryAttempts::new(0),
+            response_status: None,
+            last_error: None,
+            next_attempt_at: Some(now.clone()),
+            created_at: now.clone(),
+            updated_at: now,
+        }
+    }
+
+    pub fn destruct(self) -> DestructedWebhookDelivery {
+        DestructedWebhookDelivery {
+            id: self.id,
+            webhook_id: self.webhook_id,
+            event_type: self.event_type,
+            payload: self.payload,
+            status: self.status,
+            attempts: self.attempts,
+            response_status: self.response_status,
+            last_error: self.last_error,
+            next_attempt_at: self.next_attempt_at,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DestructedWebhookDelivery {
+    pub id: WebhookDeliveryId,
+    pub webhook_id: WebhookId,
+    pub event_type: WebhookEventType,
+    pub payload: WebhookPayload,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: WebhookDeliveryAttempts,
+    pub response_status: Option<WebhookResponseStatus>,
+    pub last_error: Option<WebhookDeliveryError>,
+    pub next_attempt_at: Option<DateTime>,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
+impl WebhookDelivery {
+    pub fn is_due(&self, now: &chrono::DateTime<chrono::Utc>) -> bool {
+        self.status == WebhookDeliveryStatus::Pending
+            && self
+                .next_attempt_at
+                .as_ref()
+                .is_some_and(|it| &it.clone().value() <= now)
+    }
+
+    pub fn succeed(
+        &mut self,
+        response_status: WebhookResponseStatus,
+        now: &chrono::DateTime<chrono::Utc>,
+    ) {
+        self.attempts = WebhookDeliveryAttempts::new(self.attempts.clone().value() + 1);
+        self.status = WebhookDeliveryStatus::Succeeded;
+        self.response_status = Some(response_status);
+        self.last_error = None;
+        self.next_attempt_at = None;
+        self.updated_at = DateTime::new(*now);
+    }
+
+    // 失敗した場合は指数的に間隔を空けて再送し、上限に達したら諦める
+    pub fn fail(
+        &mut self,
+        response_status: Option<WebhookResponseStatus>,
+        error: WebhookDeliveryError,
+        now: &chrono::DateTime<chrono::Utc>,
+    ) {
+        let attempts = self.attempts.clone().value() + 1;
+        self.attempts = WebhookDeliveryAttempts::new(attempts);
+        self.response_status = response_status;
+        self.last_error = Some(error);
+        self.updated_at = DateTime::new(*now);
+
+        if attempts >= MAX_ATTEMPTS {
+            self.status = WebhookDeliveryStatus::Failed;
+            self.next_attempt_at = None;
+        } else {
+            let backoff = BASE_BACKOFF_SECONDS * 2_i64.pow((attempts - 1) as u32);
+            self.next_attempt_at = Some(DateTime::new(*now + chrono::Duration::seconds(backoff)));
+        }
+    }
+
+    // 送信先のWebhookが削除されたなど、再送しても届かない場合は送信せずに諦める
+    pub fn abandon(&mut self, error: WebhookDeliveryError, now: &chrono::DateTime<chrono::Utc>) {
+        self.status = WebhookDeliveryStatus::Failed;
+        self.last_error = Some(error);
+        self.next_attempt_at = None;
+        self.updated_at = DateTime::new(*now);
+    }
+}
+
+impl_value_object!(WebhookDeliveryId(uuid::Uuid));
+#[derive(Debug, Error)]
+pub enum WebhookDeliveryIdError {
+    #[error("Invalid UUID")]
+    InvalidUuid,
+}
+
+impl TryFrom<String> for WebhookDeliveryId {
+    type Error = WebhookDeliveryIdError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let uuid =
+            uuid::Uuid::parse_str(&value).map_err(|_| WebhookDeliveryIdError::InvalidUuid)?;
+        Ok(Self(uuid))
+    }
+}
+
+impl_value_object!(WebhookPayload(String));
+impl_value_object!(WebhookDeliveryAttempts(i32));
+impl_value_object!(WebhookResponseStatus(i32));
+impl_value_object!(WebhookDeliveryError(String));
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WebhookDeliveryStatus {
+    Pending,
+    Succeeded,
+    Failed,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        entity::webhook_delivery::{
+            WebhookDeliveryError, WebhookDeliveryStatus, WebhookResponseStatus, MAX_ATTEMPTS,
+        },
+        test::fixture,
+    };
+
+    #[test]
+    fn 作成直後の配信は送信対象() {
+        let delivery = fixture::webhook_delivery::webhook_delivery1();
+        assert!(delivery.is_due(&chrono::Utc::now()));
+    }
+
+    #[test]
+    fn 送信に成功すると完了になる() {
+        let mut delivery = fixture::webhook_delivery::webhook_delivery1();
+        let now = chrono::Utc::now();
+        delivery.succeed(WebhookResponseStatus::new(200), &now);
+        assert_eq!(delivery.status(), &WebhookDeliveryStatus::Succeeded);
+        assert!(!delivery.is_due(&now));
+    }
+
+    #[test]
+    fn 送信に失敗すると間隔を空けて再送する() {
+        let mut delivery = fixture::webhook_delivery::webhook_delivery1();
+        let now = chrono::Utc::now();
+
+        delivery.fail(
+            Some(WebhookResponseStatus::new(500)),
+            WebhookDeliveryError::new("Internal Server Error".to_string()),
+            &now,
+        );
+        assert_eq!(delivery.status(), &WebhookDeliveryStatus::Pending);
+        assert!(!delivery.is_due(&now));
+        assert!(delivery.is_due(&(now + chrono::Duration::seconds(60))));
+
+        delivery.fail(None, WebhookDeliveryError::new("timeout".to_string()), &now);
+        assert!(!delivery.is_due(&(now + chrono::Duration::seconds(60))));
+        assert!(delivery.is_due(&(now + chrono::Duration::seconds(120))));
+    }
+
+    #[test]
+    fn 送信回数の上限に達すると失敗になる() {
+        let mut delivery = fixture::webhook_delivery::webhook_delivery1();
+        let now = chrono::Utc::now();
+        for _ in 0..MAX_ATTEMPTS {
+            delivery.fail(None, WebhookDeliveryError::new("timeout".to_string()), &now);
+        }
+        assert_eq!(delivery.status(), &WebhookDeliveryStatus::Failed);
+        assert_eq!(delivery.next_attempt_at(), &None);
+    }
+
+    #[test]
+    fn 諦めた配信は再送しない() {
+        let mut delivery = fixture::webhook_delivery::webhook_delivery1();
+        let now = chrono::Utc::now();
+        delivery.abandon(WebhookDeliveryError::new("deleted".to_string()), &now);
+        assert_eq!(delivery.status(), &WebhookDeliveryStatus::Failed);
+        assert!(!delivery.is_due(&(now + chrono::Duration::days(1))));
+    }
+}
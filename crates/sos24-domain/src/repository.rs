@@ -1,12 +1,21 @@
+pub mod api_token;
+pub mod custom_role;
 pub mod file_data;
+pub mod file_export;
 pub mod file_object;
+pub mod file_upload;
 pub mod firebase_user;
 pub mod form;
 pub mod form_answer;
+pub mod impersonation_log;
 pub mod invitation;
 pub mod news;
+pub mod notification;
 pub mod project;
+pub mod project_storage_quota;
 pub mod user;
+pub mod webhook;
+pub mod webhook_delivery;
 
 pub trait Repositories: Send + Sync + 'static {
     type FirebaseUserRepositoryImpl: firebase_user::FirebaseUserRepository;
@@ -14,18 +23,36 @@ pub trait Repositories: Send + Sync + 'static {
     type FormAnswerRepositoryImpl: form_answer::FormAnswerRepository;
     type InvitationRepositoryImpl: invitation::InvitationRepository;
     type NewsRepositoryImpl: news::NewsRepository;
+    type NotificationRepositoryImpl: notification::NotificationRepository;
     type ProjectRepositoryImpl: project::ProjectRepository;
     type FileDataRepositoryImpl: file_data::FileDataRepository;
     type FileObjectRepositoryImpl: file_object::FileObjectRepository;
     type UserRepositoryImpl: user::UserRepository;
+    type WebhookRepositoryImpl: webhook::WebhookRepository;
+    type WebhookDeliveryRepositoryImpl: webhook_delivery::WebhookDeliveryRepository;
+    type FileUploadRepositoryImpl: file_upload::FileUploadRepository;
+    type ProjectStorageQuotaRepositoryImpl: project_storage_quota::ProjectStorageQuotaRepository;
+    type FileExportRepositoryImpl: file_export::FileExportRepository;
+    type ApiTokenRepositoryImpl: api_token::ApiTokenRepository;
+    type CustomRoleRepositoryImpl: custom_role::CustomRoleRepository;
+    type ImpersonationLogRepositoryImpl: impersonation_log::ImpersonationLogRepository;
 
     fn firebase_user_repository(&self) -> &Self::FirebaseUserRepositoryImpl;
     fn form_repository(&self) -> &Self::FormRepositoryImpl;
     fn form_answer_repository(&self) -> &Self::FormAnswerRepositoryImpl;
     fn invitation_repository(&self) -> &Self::InvitationRepositoryImpl;
     fn news_repository(&self) -> &Self::NewsRepositoryImpl;
+    fn notification_repository(&self) -> &Self::NotificationRepositoryImpl;
     fn project_repository(&self) -> &Self::ProjectRepositoryImpl;
     fn file_data_repository(&self) -> &Self::FileDataRepositoryImpl;
     fn file_object_repository(&self) -> &Self::FileObjectRepositoryImpl;
     fn user_repository(&self) -> &Self::UserRepositoryImpl;
+    fn webhook_repository(&self) -> &Self::WebhookRepositoryImpl;
+    fn webhook_delivery_repository(&self) -> &Self::WebhookDeliveryRepositoryImpl;
+    fn file_upload_repository(&self) -> &Self::FileUploadRepositoryImpl;
+    fn project_storage_quota_repository(&self) -> &Self::ProjectStorageQuotaRepositoryImpl;
+    fn file_export_repository(&self) -> &Self::FileExportRepositoryImpl;
+    fn api_token_repository(&self) -> &Self::ApiTokenRepositoryImpl;
+    fn custom_role_repository(&self) -> &Self::CustomRoleRepositoryImpl;
+    fn impersonation_log_repository(&self) -> &Self::ImpersonationLogRepositoryImpl;
 }
@@ -0,0 +1,36 @@
+use mockall::automock;
+use thiserror::Error;
+
+use crate::entity::{
+    api_token::{ApiToken, ApiTokenHash, ApiTokenId},
+    common::datetime::DateTime,
+    user::UserId,
+};
+
+#[derive(Debug, Error)]
+pub enum ApiTokenRepositoryError {
+    #[error(transparent)]
+    InternalError(#[from] anyhow::Error),
+}
+
+#[automock]
+#[allow(async_fn_in_trait)]
+pub trait ApiTokenRepository: Send + Sync + 'static {
+    async fn create(&self, token: ApiToken) -> Result<(), ApiTokenRepositoryError>;
+    async fn find_by_id(&self, id: ApiTokenId)
+        -> Result<Option<ApiToken>, ApiTokenRepositoryError>;
+    async fn find_by_user_id(
+        &self,
+        user_id: UserId,
+    ) -> Result<Vec<ApiToken>, ApiTokenRepositoryError>;
+    async fn find_by_token_hash(
+        &self,
+        token_hash: ApiTokenHash,
+    ) -> Result<Option<ApiToken>, ApiTokenRepositoryError>;
+    async fn update_last_used_at(
+        &self,
+        id: ApiTokenId,
+        last_used_at: DateTime,
+    ) -> Result<(), ApiTokenRepositoryError>;
+    async fn delete_by_id(&self, id: ApiTokenId) -> Result<(), ApiTokenRepositoryError>;
+}
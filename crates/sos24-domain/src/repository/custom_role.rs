@@ -0,0 +1,36 @@
+use mockall::automock;
+use thiserror::Error;
+
+use crate::entity::{
+    custom_role::{CustomRole, CustomRoleId},
+    user::UserId,
+};
+
+#[derive(Debug, Error)]
+pub enum CustomRoleRepositoryError {
+    #[error(transparent)]
+    InternalError(#[from] anyhow::Error),
+}
+
+#[automock]
+#[allow(async_fn_in_trait)]
+pub trait CustomRoleRepository: Send + Sync + 'static {
+    async fn list(&self) -> Result<Vec<CustomRole>, CustomRoleRepositoryError>;
+    async fn create(&self, role: CustomRole) -> Result<(), CustomRoleRepositoryError>;
+    async fn find_by_id(
+        &self,
+        id: CustomRoleId,
+    ) -> Result<Option<CustomRole>, CustomRoleRepositoryError>;
+    async fn find_by_user_id(
+        &self,
+        user_id: UserId,
+    ) -> Result<Vec<CustomRole>, CustomRoleRepositoryError>;
+    async fn update(&self, role: CustomRole) -> Result<(), CustomRoleRepositoryError>;
+    async fn delete_by_id(&self, id: CustomRoleId) -> Result<(), CustomRoleRepositoryError>;
+    // ユーザーに割り当てるカスタムロールを置き換える
+    async fn set_user_roles(
+        &self,
+        user_id: UserId,
+        role_ids: Vec<CustomRoleId>,
+    ) -> Result<(), CustomRoleRepositoryError>;
+}
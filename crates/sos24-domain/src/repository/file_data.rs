@@ -1,7 +1,8 @@
 use mockall::automock;
 use thiserror::Error;
 
-use crate::entity::file_data::{FileData, FileId};
+use crate::entity::common::datetime::DateTime;
+use crate::entity::file_data::{FileData, FileId, FileScanStatus, FileThumbnailStatus};
 use crate::entity::project::ProjectId;
 
 #[derive(Debug, Error)]
@@ -20,9 +21,36 @@ pub trait FileDataRepository: Send + Sync + 'static {
         &self,
         owner_project: ProjectId,
     ) -> Result<Vec<FileData>, FileDataRepositoryError>;
+    async fn find_by_scan_status(
+        &self,
+        scan_status: FileScanStatus,
+    ) -> Result<Vec<FileData>, FileDataRepositoryError>;
+    async fn update_scan_status(
+        &self,
+        id: FileId,
+        scan_status: FileScanStatus,
+    ) -> Result<(), FileDataRepositoryError>;
+    async fn find_by_thumbnail_status(
+        &self,
+        thumbnail_status: FileThumbnailStatus,
+    ) -> Result<Vec<FileData>, FileDataRepositoryError>;
+    async fn update_thumbnail_status(
+        &self,
+        id: FileId,
+        thumbnail_status: FileThumbnailStatus,
+    ) -> Result<(), FileDataRepositoryError>;
     async fn delete_by_id(&self, id: FileId) -> Result<(), FileDataRepositoryError>;
     async fn delete_by_owner_project(
         &self,
         owner_project: ProjectId,
     ) -> Result<(), FileDataRepositoryError>;
+    // どこからも参照されていないファイルとしてソフトデリートする
+    async fn delete_as_garbage_by_id(&self, id: FileId) -> Result<(), FileDataRepositoryError>;
+    // 参照されていないとしてソフトデリートされたファイルのうち、deleted_beforeより前のものを返す
+    async fn find_garbage_deleted_before(
+        &self,
+        deleted_before: DateTime,
+    ) -> Result<Vec<FileData>, FileDataRepositoryError>;
+    // レコードを完全に削除する
+    async fn purge_by_id(&self, id: FileId) -> Result<(), FileDataRepositoryError>;
 }
@@ -0,0 +1,44 @@
+use mockall::automock;
+use thiserror::Error;
+
+use crate::entity::{
+    common::datetime::DateTime,
+    file_export::{FileExport, FileExportId},
+    form::FormId,
+    user::UserId,
+};
+
+#[derive(Debug, Error)]
+pub enum FileExportRepositoryError {
+    #[error(transparent)]
+    InternalError(#[from] anyhow::Error),
+}
+
+#[automock]
+#[allow(async_fn_in_trait)]
+pub trait FileExportRepository: Send + Sync + 'static {
+    async fn create(&self, export: FileExport) -> Result<(), FileExportRepositoryError>;
+    async fn find_by_id(
+        &self,
+        id: FileExportId,
+    ) -> Result<Option<FileExport>, FileExportRepositoryError>;
+    // 差分のエクスポートの基準にするため、同じユーザーが要求したものに限る
+    async fn find_latest_completed_by_form_id_and_requester(
+        &self,
+        form_id: FormId,
+        requested_by: UserId,
+    ) -> Result<Option<FileExport>, FileExportRepositoryError>;
+    // 未着手のものと、stale_beforeより前から進捗が更新されていない処理中のものを取得する
+    async fn find_runnable(
+        &self,
+        stale_before: DateTime,
+    ) -> Result<Vec<FileExport>, FileExportRepositoryError>;
+    // 同じエクスポートを複数のタスクが同時に処理しないよう、実行可能な場合に限り処理中にする
+    // 処理中にできた場合はtrueを返す
+    async fn acquire(
+        &self,
+        id: FileExportId,
+        stale_before: DateTime,
+    ) -> Result<bool, FileExportRepositoryError>;
+    async fn update(&self, export: FileExport) -> Result<(), FileExportRepositoryError>;
+}
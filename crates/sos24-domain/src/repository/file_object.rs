@@ -1,13 +1,20 @@
 use mockall::automock;
 use thiserror::Error;
 use tokio::io::DuplexStream;
+use tokio::sync::mpsc::UnboundedSender;
 
-use crate::entity::file_object::{
-    ArchiveEntry, ContentDisposition, FileObject, FileObjectKey, FileSignedUrl,
+use crate::entity::{
+    file_data::FileContentType,
+    file_object::{
+        ArchiveEntry, ContentDisposition, FileObject, FileObjectKey, FileObjectMetadata,
+        FileSignedUrl, FileSignedUrlExpiry,
+    },
 };
 
 #[derive(Debug, Error)]
 pub enum FileObjectRepositoryError {
+    #[error("File size exceeds the limit of {0} bytes")]
+    SizeLimitExceeded(u64),
     #[error(transparent)]
     InternalError(#[from] anyhow::Error),
 }
@@ -20,17 +27,64 @@ pub trait FileObjectRepository: Send + Sync + 'static {
         bucket: String,
         file_object: FileObject,
     ) -> Result<(), FileObjectRepositoryError>;
+    // readerから読み出しながらアップロードし、サイズ・内容から判定した種類・チェックサムを返す
+    // size_limitを超えた時点でアップロードを中断する
+    async fn create_stream(
+        &self,
+        bucket: String,
+        key: FileObjectKey,
+        reader: DuplexStream,
+        size_limit: u64,
+    ) -> Result<FileObjectMetadata, FileObjectRepositoryError>;
+    // content_typeを指定した場合は、レスポンスのContent-Typeをその値で上書きする
     async fn generate_url(
         &self,
         bucket: String,
         key: FileObjectKey,
         content_disposition: Option<ContentDisposition>,
+        content_type: Option<FileContentType>,
+        expires_in: FileSignedUrlExpiry,
     ) -> Result<FileSignedUrl, FileObjectRepositoryError>;
+    // クライアントが直接アップロードするための署名付きURLを生成する
+    async fn generate_upload_url(
+        &self,
+        bucket: String,
+        key: FileObjectKey,
+        expires_in: std::time::Duration,
+    ) -> Result<FileSignedUrl, FileObjectRepositoryError>;
+    // オブジェクトが存在しない場合はNoneを返す
+    async fn find_metadata(
+        &self,
+        bucket: String,
+        key: FileObjectKey,
+    ) -> Result<Option<FileObjectMetadata>, FileObjectRepositoryError>;
+    // 同じバケット内でオブジェクトをコピーする
+    // コピー元が存在しない場合はfalseを返す
+    async fn copy(
+        &self,
+        bucket: String,
+        from: FileObjectKey,
+        to: FileObjectKey,
+    ) -> Result<bool, FileObjectRepositoryError>;
+    // オブジェクトの内容をwriterに書き出す
+    async fn download(
+        &self,
+        bucket: String,
+        key: FileObjectKey,
+        writer: DuplexStream,
+    ) -> Result<(), FileObjectRepositoryError>;
+    async fn delete(
+        &self,
+        bucket: String,
+        key: FileObjectKey,
+    ) -> Result<(), FileObjectRepositoryError>;
     // TODO: 返り値をラッピングしておくと内部仕様が露出しなくてよい
+    // progressを渡すと、ファイルをアーカイブに1つ追加するたびに通知する
     fn create_archive(
         &self,
         bucket: String,
         entry_list: Vec<ArchiveEntry>,
         writer: DuplexStream,
+        progress: Option<UnboundedSender<()>>,
     ) -> impl std::future::Future<Output = Result<(), FileObjectRepositoryError>> + Send;
 }
@@ -0,0 +1,24 @@
+use mockall::automock;
+use thiserror::Error;
+
+use crate::entity::{common::datetime::DateTime, file_data::FileId, file_upload::FileUpload};
+
+#[derive(Debug, Error)]
+pub enum FileUploadRepositoryError {
+    #[error(transparent)]
+    InternalError(#[from] anyhow::Error),
+}
+
+#[automock]
+#[allow(async_fn_in_trait)]
+pub trait FileUploadRepository: Send + Sync + 'static {
+    async fn create(&self, file_upload: FileUpload) -> Result<(), FileUploadRepositoryError>;
+    async fn find_by_id(&self, id: FileId)
+        -> Result<Option<FileUpload>, FileUploadRepositoryError>;
+    // 完了の報告を受けないまま期限を過ぎたものを取得する
+    async fn find_expired(
+        &self,
+        now: DateTime,
+    ) -> Result<Vec<FileUpload>, FileUploadRepositoryError>;
+    async fn delete_by_id(&self, id: FileId) -> Result<(), FileUploadRepositoryError>;
+}
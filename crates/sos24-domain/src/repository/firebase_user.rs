@@ -1,7 +1,9 @@
 use mockall::automock;
 use thiserror::Error;
 
-use crate::entity::firebase_user::{FirebaseUserEmail, FirebaseUserId, NewFirebaseUser};
+use crate::entity::firebase_user::{
+    FirebaseUserEmail, FirebaseUserId, FirebaseUserPassword, NewFirebaseUser,
+};
 
 #[derive(Debug, Error)]
 pub enum FirebaseUserRepositoryError {
@@ -28,4 +30,18 @@ pub trait FirebaseUserRepository: Send + Sync + 'static {
     ) -> Result<(), FirebaseUserRepositoryError>;
 
     async fn delete_by_id(&self, id: FirebaseUserId) -> Result<(), FirebaseUserRepositoryError>;
+
+    /// パスワードを設定(再設定)するためのリンクを発行する
+    async fn generate_password_reset_link(
+        &self,
+        email: FirebaseUserEmail,
+    ) -> Result<String, FirebaseUserRepositoryError>;
+
+    /// メールアドレスとパスワードを検証し、一致したユーザーのIDを返す
+    /// 認証基盤側でサインインを行う実装ではサポートされない
+    async fn verify_password(
+        &self,
+        email: FirebaseUserEmail,
+        password: FirebaseUserPassword,
+    ) -> Result<Option<FirebaseUserId>, FirebaseUserRepositoryError>;
 }
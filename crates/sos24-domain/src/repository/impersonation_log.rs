@@ -0,0 +1,17 @@
+use mockall::automock;
+use thiserror::Error;
+
+use crate::entity::impersonation_log::ImpersonationLog;
+
+#[derive(Debug, Error)]
+pub enum ImpersonationLogRepositoryError {
+    #[error(transparent)]
+    InternalError(#[from] anyhow::Error),
+}
+
+#[automock]
+#[allow(async_fn_in_trait)]
+pub trait ImpersonationLogRepository: Send + Sync + 'static {
+    async fn list(&self) -> Result<Vec<ImpersonationLog>, ImpersonationLogRepositoryError>;
+    async fn create(&self, log: ImpersonationLog) -> Result<(), ImpersonationLogRepositoryError>;
+}
@@ -0,0 +1,38 @@
+use mockall::automock;
+use thiserror::Error;
+
+use crate::entity::{
+    common::datetime::DateTime,
+    notification::{Notification, NotificationId},
+    user::UserId,
+};
+
+#[derive(Debug, Error)]
+pub enum NotificationRepositoryError {
+    #[error(transparent)]
+    InternalError(#[from] anyhow::Error),
+}
+
+#[automock]
+#[allow(async_fn_in_trait)]
+pub trait NotificationRepository: Send + Sync + 'static {
+    async fn create(&self, notification: Notification) -> Result<(), NotificationRepositoryError>;
+    async fn find_by_id(
+        &self,
+        id: NotificationId,
+    ) -> Result<Option<Notification>, NotificationRepositoryError>;
+    async fn find_by_user_id(
+        &self,
+        user_id: UserId,
+    ) -> Result<Vec<Notification>, NotificationRepositoryError>;
+    async fn count_unread_by_user_id(
+        &self,
+        user_id: UserId,
+    ) -> Result<i64, NotificationRepositoryError>;
+    async fn update(&self, notification: Notification) -> Result<(), NotificationRepositoryError>;
+    async fn read_all_by_user_id(
+        &self,
+        user_id: UserId,
+        read_at: DateTime,
+    ) -> Result<(), NotificationRepositoryError>;
+}
@@ -0,0 +1,30 @@
+use mockall::automock;
+use thiserror::Error;
+
+use crate::entity::project::ProjectId;
+use crate::entity::project_storage::ProjectStorageQuota;
+
+#[derive(Debug, Error)]
+pub enum ProjectStorageQuotaRepositoryError {
+    #[error(transparent)]
+    InternalError(#[from] anyhow::Error),
+}
+
+// 実委が企画ごとに個別に設定した容量の上限
+#[automock]
+#[allow(async_fn_in_trait)]
+pub trait ProjectStorageQuotaRepository: Send + Sync + 'static {
+    async fn find_by_project_id(
+        &self,
+        project_id: ProjectId,
+    ) -> Result<Option<ProjectStorageQuota>, ProjectStorageQuotaRepositoryError>;
+    async fn upsert(
+        &self,
+        project_id: ProjectId,
+        quota: ProjectStorageQuota,
+    ) -> Result<(), ProjectStorageQuotaRepositoryError>;
+    async fn delete_by_project_id(
+        &self,
+        project_id: ProjectId,
+    ) -> Result<(), ProjectStorageQuotaRepositoryError>;
+}
@@ -0,0 +1,29 @@
+use mockall::automock;
+use thiserror::Error;
+
+use crate::entity::webhook::{Webhook, WebhookEventType, WebhookId, WebhookUrl};
+
+#[derive(Debug, Error)]
+pub enum WebhookRepositoryError {
+    #[error(transparent)]
+    InternalError(#[from] anyhow::Error),
+}
+
+#[automock]
+#[allow(async_fn_in_trait)]
+pub trait WebhookRepository: Send + Sync + 'static {
+    async fn list(&self) -> Result<Vec<Webhook>, WebhookRepositoryError>;
+    async fn create(&self, webhook: Webhook) -> Result<(), WebhookRepositoryError>;
+    async fn find_by_id(&self, id: WebhookId) -> Result<Option<Webhook>, WebhookRepositoryError>;
+    async fn find_by_event_type(
+        &self,
+        event_type: WebhookEventType,
+    ) -> Result<Vec<Webhook>, WebhookRepositoryError>;
+    // 削除済みのものも含めて、同じURLのWebhookが作成されたことがあるか
+    async fn exists_by_url_including_deleted(
+        &self,
+        url: WebhookUrl,
+    ) -> Result<bool, WebhookRepositoryError>;
+    async fn update(&self, webhook: Webhook) -> Result<(), WebhookRepositoryError>;
+    async fn delete_by_id(&self, id: WebhookId) -> Result<(), WebhookRepositoryError>;
+}
@@ -0,0 +1,36 @@
+use mockall::automock;
+use thiserror::Error;
+
+use crate::entity::{
+    common::datetime::DateTime,
+    webhook::WebhookId,
+    webhook_delivery::{WebhookDelivery, WebhookDeliveryId},
+};
+
+#[derive(Debug, Error)]
+pub enum WebhookDeliveryRepositoryError {
+    #[error(transparent)]
+    InternalError(#[from] anyhow::Error),
+}
+
+#[automock]
+#[allow(async_fn_in_trait)]
+pub trait WebhookDeliveryRepository: Send + Sync + 'static {
+    async fn create(&self, delivery: WebhookDelivery)
+        -> Result<(), WebhookDeliveryRepositoryError>;
+    async fn find_by_id(
+        &self,
+        id: WebhookDeliveryId,
+    ) -> Result<Option<WebhookDelivery>, WebhookDeliveryRepositoryError>;
+    async fn find_by_webhook_id(
+        &self,
+        webhook_id: WebhookId,
+    ) -> Result<Vec<WebhookDelivery>, WebhookDeliveryRepositoryError>;
+    // 送信待ちで、再送時刻を過ぎたものを取得する
+    async fn find_due(
+        &self,
+        now: DateTime,
+    ) -> Result<Vec<WebhookDelivery>, WebhookDeliveryRepositoryError>;
+    async fn update(&self, delivery: WebhookDelivery)
+        -> Result<(), WebhookDeliveryRepositoryError>;
+}
@@ -1 +1,2 @@
+pub mod file_garbage;
 pub mod verify_form_answer;
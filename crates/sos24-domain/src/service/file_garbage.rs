@@ -0,0 +1,139 @@
+use std::collections::HashSet;
+
+use crate::entity::{
+    common::datetime::DateTime, file_data::FileData, form::Form, form_answer::FormAnswer,
+    news::News,
+};
+
+// 回答などに紐づけられる前のファイルを消さないよう、アップロードから猶予を設ける
+pub const GRACE_PERIOD_DAYS: i64 = 7;
+// 誤って消した場合に戻せるよう、ソフトデリートしてからしばらくはオブジェクトを残す
+pub const PURGE_DELAY_DAYS: i64 = 30;
+
+// フォームの添付ファイル・申請の回答・お知らせの添付ファイルのいずれからも参照されておらず、
+// 猶予を過ぎたファイルを返す
+// 配布などのために単独でアップロードされたファイルは対象にしない
+pub fn find_unreferenced(
+    file_data_list: Vec<FileData>,
+    form_list: &[Form],
+    form_answer_list: &[FormAnswer],
+    news_list: &[News],
+    now: &DateTime,
+) -> Vec<FileData> {
+    let referenced = form_list
+        .iter()
+        .flat_map(|form| form.attachments().clone())
+        .chain(
+            form_answer_list
+                .iter()
+                .flat_map(|answer| answer.list_file_items())
+                .flat_map(|(_, file_ids)| file_ids),
+        )
+        .chain(news_list.iter().flat_map(|news| news.attachments().clone()))
+        .map(|file_id| file_id.value())
+        .collect::<HashSet<_>>();
+
+    let uploaded_before = now.clone().value() - chrono::Duration::days(GRACE_PERIOD_DAYS);
+    file_data_list
+        .into_iter()
+        .filter(|file_data| !file_data.is_standalone().clone().value())
+        .filter(|file_data| file_data.created_at().clone().value() < uploaded_before)
+        .filter(|file_data| !referenced.contains(&file_data.id().clone().value()))
+        .collect()
+}
+
+// これより前にソフトデリートされたファイルのオブジェクトを削除する
+pub fn purge_threshold(now: &DateTime) -> DateTime {
+    DateTime::new(now.clone().value() - chrono::Duration::days(PURGE_DELAY_DAYS))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        entity::{
+            common::datetime::DateTime,
+            file_data::{FileData, FileIsStandalone},
+            form_answer::{FormAnswer, FormAnswerItem, FormAnswerItemFile, FormAnswerItemKind},
+        },
+        test::fixture,
+    };
+
+    use super::{find_unreferenced, GRACE_PERIOD_DAYS};
+
+    fn after_grace_period() -> DateTime {
+        DateTime::new(chrono::Utc::now() + chrono::Duration::days(GRACE_PERIOD_DAYS + 1))
+    }
+
+    #[test]
+    fn 回答から参照されているファイルは対象にならない() {
+        let answer = FormAnswer::new(
+            fixture::form_answer::id1(),
+            fixture::project::id1(),
+            fixture::form::id1(),
+            vec![FormAnswerItem::new(
+                fixture::form::formitem_id1(),
+                FormAnswerItemKind::File(FormAnswerItemFile::new(vec![fixture::file_data::id()])),
+            )],
+            fixture::datetime::now(),
+            fixture::datetime::now(),
+        );
+        let res = find_unreferenced(
+            vec![fixture::file_data::file_data(Some(fixture::project::id1()))],
+            &[],
+            &[answer],
+            &[],
+            &after_grace_period(),
+        );
+        assert!(res.is_empty());
+    }
+
+    #[test]
+    fn どこからも参照されていないファイルは猶予を過ぎると対象になる() {
+        let file_data_list = vec![fixture::file_data::file_data(Some(fixture::project::id1()))];
+        let res = find_unreferenced(
+            file_data_list.clone(),
+            &[fixture::form::form1_opened()],
+            &[],
+            &[fixture::news::news1()],
+            &fixture::datetime::now(),
+        );
+        assert!(res.is_empty());
+
+        let res = find_unreferenced(
+            file_data_list,
+            &[fixture::form::form1_opened()],
+            &[],
+            &[fixture::news::news1()],
+            &after_grace_period(),
+        );
+        assert_eq!(res.len(), 1);
+    }
+
+    #[test]
+    fn 単独でアップロードされたファイルは参照されていなくても対象にならない() {
+        let file_data = fixture::file_data::file_data(None).destruct();
+        let standalone = FileData::new(
+            file_data.id,
+            file_data.name,
+            file_data.url,
+            file_data.owner,
+            file_data.size,
+            file_data.content_type,
+            file_data.checksum,
+            file_data.uploader,
+            FileIsStandalone::new(true),
+            file_data.scan_status,
+            file_data.thumbnail_status,
+            file_data.created_at,
+            file_data.updated_at,
+        );
+        let res = find_unreferenced(
+            vec![standalone],
+            &[fixture::form::form1_opened()],
+            &[],
+            &[fixture::news::news1()],
+            &after_grace_period(),
+        );
+        assert!(res.is_empty());
+    }
+}
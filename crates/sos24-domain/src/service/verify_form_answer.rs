@@ -2,6 +2,7 @@ use thiserror::Error;
 use unicode_segmentation::UnicodeSegmentation;
 
 use crate::entity::{
+    file_data::{FileData, FileId},
     form::{
         Form, FormItem, FormItemChooseMany, FormItemChooseOne, FormItemFile, FormItemId,
         FormItemInt, FormItemKind, FormItemOption, FormItemString,
@@ -38,9 +39,22 @@ pub enum VerifyFormAnswerError {
     TooManyOptionsChooseMany(FormItemId, u32),
     #[error("File answer item {0:?} has too many files (max: {1})")]
     TooManyFiles(FormItemId, u32),
+    #[error("File answer item {0:?} references unknown file {1:?}")]
+    FileNotFound(FormItemId, FileId),
+    #[error("File answer item {0:?} references file {1:?} owned by another project")]
+    FileNotOwnedByProject(FormItemId, FileId),
+    #[error("File answer item {0:?} has file {1:?} with disallowed extension")]
+    InvalidFileExtension(FormItemId, FileId),
+    #[error("File answer item {0:?} has file {1:?} which is too large (max: {2} bytes)")]
+    TooLargeFile(FormItemId, FileId, u64),
 }
 
-pub fn verify(form: &Form, answer: &FormAnswer) -> Result<(), VerifyFormAnswerError> {
+// file_data_listには回答が参照しているファイルを渡す
+pub fn verify(
+    form: &Form,
+    answer: &FormAnswer,
+    file_data_list: &[FileData],
+) -> Result<(), VerifyFormAnswerError> {
     for form_item in form.items() {
         let answer_item = answer
             .items()
@@ -55,7 +69,7 @@ pub fn verify(form: &Form, answer: &FormAnswer) -> Result<(), VerifyFormAnswerEr
         }
 
         match answer_item {
-            Some(answer_item) => verify_item(form_item, answer_item)?,
+            Some(answer_item) => verify_item(form_item, answer, answer_item, file_data_list)?,
             None => continue,
         }
     }
@@ -75,7 +89,9 @@ fn verify_is_none(answer_item: Option<&FormAnswerItem>) -> bool {
 
 fn verify_item(
     form_item: &FormItem,
+    answer: &FormAnswer,
     answer_item: &FormAnswerItem,
+    file_data_list: &[FileData],
 ) -> Result<(), VerifyFormAnswerError> {
     let item_id = form_item.id().clone();
     match (form_item.kind().clone(), answer_item.kind().clone()) {
@@ -92,7 +108,7 @@ fn verify_item(
             verify_item_choose_many(item_id, form_item, answer_item)
         }
         (FormItemKind::File(form_item), FormAnswerItemKind::File(answer_item)) => {
-            verify_item_file(item_id, form_item, answer_item)
+            verify_item_file(item_id, form_item, answer, answer_item, file_data_list)
         }
         _ => Err(VerifyFormAnswerError::InvalidAnswerItemKind(
             form_item.id().clone(),
@@ -212,17 +228,163 @@ fn verify_item_choose_many(
 fn verify_item_file(
     item_id: FormItemId,
     form_file: FormItemFile,
+    answer: &FormAnswer,
     answer_file: FormAnswerItemFile,
+    file_data_list: &[FileData],
 ) -> Result<(), VerifyFormAnswerError> {
     let files = answer_file.clone().value();
 
-    // extensionsはフロントエンドでチェックするためここでは何もしない
-
     if let Some(limit) = form_file.limit() {
         let limit = limit.clone().value();
         if files.len() > limit as usize {
             return Err(VerifyFormAnswerError::TooManyFiles(item_id, limit));
         }
     }
+
+    for file_id in files {
+        let Some(file_data) = file_data_list.iter().find(|it| it.id() == &file_id) else {
+            return Err(VerifyFormAnswerError::FileNotFound(item_id, file_id));
+        };
+
+        if file_data.owner().as_ref() != Some(answer.project_id()) {
+            return Err(VerifyFormAnswerError::FileNotOwnedByProject(
+                item_id, file_id,
+            ));
+        }
+
+        if !form_file.accepts(file_data) {
+            return Err(VerifyFormAnswerError::InvalidFileExtension(
+                item_id, file_id,
+            ));
+        }
+
+        if form_file.exceeds_max_size(file_data) {
+            let max_size = form_file.max_size().clone().map_or(0, |it| it.value());
+            return Err(VerifyFormAnswerError::TooLargeFile(
+                item_id, file_id, max_size,
+            ));
+        }
+    }
+
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        entity::{
+            file_data::FileData,
+            form::{Form, FormItem, FormItemExtension, FormItemKind, FormItemMaxSize},
+            form_answer::{FormAnswer, FormAnswerItem, FormAnswerItemFile, FormAnswerItemKind},
+            project::ProjectId,
+            user::UserRole,
+        },
+        test::fixture,
+    };
+
+    use super::{verify, VerifyFormAnswerError};
+
+    fn form_with_file_item(max_size: Option<u64>) -> Form {
+        form_with_file_item_accepting("txt", max_size)
+    }
+
+    fn form_with_file_item_accepting(extension: &str, max_size: Option<u64>) -> Form {
+        let mut form = fixture::form::form1_opened();
+        form.set_items(
+            &fixture::actor::actor1(UserRole::Administrator),
+            vec![FormItem::new(
+                fixture::form::formitem_id1(),
+                fixture::form::formitem_name1(),
+                None,
+                fixture::form::formitem_required1(),
+                FormItemKind::new_file(
+                    Some(vec![FormItemExtension::new(extension.to_string())]),
+                    None,
+                    max_size.map(FormItemMaxSize::new),
+                ),
+            )],
+        )
+        .unwrap();
+        form
+    }
+
+    fn answer_with_file() -> FormAnswer {
+        let mut answer = fixture::form_answer::form_answer1(fixture::project::id1());
+        answer
+            .set_items(
+                &fixture::actor::actor1(UserRole::Administrator),
+                None,
+                fixture::project::category1(),
+                vec![FormAnswerItem::new(
+                    fixture::form::formitem_id1(),
+                    FormAnswerItemKind::File(FormAnswerItemFile::new(vec![
+                        fixture::file_data::id(),
+                    ])),
+                )],
+            )
+            .unwrap();
+        answer
+    }
+
+    fn file_data_list(owner: ProjectId) -> Vec<FileData> {
+        vec![fixture::file_data::file_data(Some(owner))]
+    }
+
+    #[test]
+    fn 自分の企画の条件を満たすファイルは回答できる() {
+        let res = verify(
+            &form_with_file_item(None),
+            &answer_with_file(),
+            &file_data_list(fixture::project::id1()),
+        );
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn 他の企画のファイルは回答できない() {
+        let res = verify(
+            &form_with_file_item(None),
+            &answer_with_file(),
+            &file_data_list(fixture::project::id2()),
+        );
+        assert!(matches!(
+            res,
+            Err(VerifyFormAnswerError::FileNotOwnedByProject(_, _))
+        ));
+    }
+
+    #[test]
+    fn 上限を超えるサイズのファイルは回答できない() {
+        let res = verify(
+            &form_with_file_item(Some(10)),
+            &answer_with_file(),
+            &file_data_list(fixture::project::id1()),
+        );
+        assert!(matches!(
+            res,
+            Err(VerifyFormAnswerError::TooLargeFile(_, _, 10))
+        ));
+    }
+
+    #[test]
+    fn 許可されていない拡張子のファイルは回答できない() {
+        let res = verify(
+            &form_with_file_item_accepting("pdf", None),
+            &answer_with_file(),
+            &file_data_list(fixture::project::id1()),
+        );
+        assert!(matches!(
+            res,
+            Err(VerifyFormAnswerError::InvalidFileExtension(_, _))
+        ));
+    }
+
+    #[test]
+    fn 存在しないファイルは回答できない() {
+        let res = verify(&form_with_file_item(None), &answer_with_file(), &[]);
+        assert!(matches!(
+            res,
+            Err(VerifyFormAnswerError::FileNotFound(_, _))
+        ));
+    }
+}
@@ -1,12 +1,22 @@
 pub mod actor;
+pub mod api_token;
+pub mod custom_role;
 pub mod datetime;
+pub mod email;
 pub mod file_data;
+pub mod file_export;
 pub mod file_object;
+pub mod file_upload;
 pub mod firebase_user;
 pub mod form;
 pub mod form_answer;
+pub mod impersonation_log;
 pub mod invitation;
 pub mod news;
+pub mod notification;
 pub mod project;
 pub mod project_application_period;
+pub mod project_storage;
 pub mod user;
+pub mod webhook;
+pub mod webhook_delivery;
@@ -0,0 +1,31 @@
+use crate::entity::{
+    api_token::{ApiToken, ApiTokenId, ApiTokenName, ApiTokenScope},
+    user::UserRole,
+};
+
+use super::actor;
+
+pub fn id1() -> ApiTokenId {
+    ApiTokenId::new(uuid::Uuid::from_u128(1))
+}
+
+pub fn api_token1() -> ApiToken {
+    let (token, _) = ApiToken::create(
+        &actor::actor1(UserRole::CommitteeViewer),
+        ApiTokenName::new("export".to_string()),
+        vec![ApiTokenScope::ReadProjects],
+        None,
+    )
+    .unwrap();
+    let token = token.destruct();
+    ApiToken::new(
+        id1(),
+        token.user_id,
+        token.name,
+        token.scopes,
+        token.token_hash,
+        token.expires_at,
+        token.last_used_at,
+        token.created_at,
+    )
+}
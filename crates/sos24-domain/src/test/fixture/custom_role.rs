@@ -0,0 +1,68 @@
+use crate::entity::{
+    custom_role::{CustomRole, CustomRoleId, CustomRoleName},
+    permission::Permissions,
+    project::ProjectCategories,
+};
+
+use super::datetime;
+
+pub fn id1() -> CustomRoleId {
+    CustomRoleId::new(uuid::Uuid::from_u128(1))
+}
+
+pub fn name1() -> CustomRoleName {
+    CustomRoleName::new("企画閲覧".to_string())
+}
+
+pub fn name2() -> CustomRoleName {
+    CustomRoleName::new("企画編集".to_string())
+}
+
+pub fn custom_role1() -> CustomRole {
+    CustomRole::new(
+        id1(),
+        name1(),
+        Permissions::READ_PROJECT_ALL,
+        None,
+        datetime::now(),
+        datetime::now(),
+    )
+}
+
+pub fn id2() -> CustomRoleId {
+    CustomRoleId::new(uuid::Uuid::from_u128(2))
+}
+
+// ステージ企画のみを担当するロール
+pub fn stage_role1() -> CustomRole {
+    CustomRole::new(
+        id2(),
+        CustomRoleName::new("ステージ局".to_string()),
+        Permissions::READ_PROJECT_ALL
+            | Permissions::UPDATE_PROJECT_ALL
+            | Permissions::READ_FORM_ANSWER_ALL,
+        Some(
+            ProjectCategories::STAGE_1A
+                | ProjectCategories::STAGE_UNIVERSITY_HALL
+                | ProjectCategories::STAGE_UNITED,
+        ),
+        datetime::now(),
+        datetime::now(),
+    )
+}
+
+pub fn id3() -> CustomRoleId {
+    CustomRoleId::new(uuid::Uuid::from_u128(3))
+}
+
+// なりすましの権限だけを与えるロール
+pub fn impersonator_role1() -> CustomRole {
+    CustomRole::new(
+        id3(),
+        CustomRoleName::new("なりすまし".to_string()),
+        Permissions::IMPERSONATE_USER,
+        None,
+        datetime::now(),
+        datetime::now(),
+    )
+}
@@ -0,0 +1,5 @@
+use crate::entity::common::email::EmailAllowlist;
+
+pub fn allowlist() -> EmailAllowlist {
+    EmailAllowlist::default()
+}
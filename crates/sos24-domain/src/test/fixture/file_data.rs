@@ -1,8 +1,11 @@
-use crate::entity::file_data::{FileData, FileId, FileName};
+use crate::entity::file_data::{
+    FileChecksum, FileContentType, FileData, FileId, FileIsStandalone, FileName, FileScanStatus,
+    FileSize, FileThumbnailStatus,
+};
 use crate::entity::project::ProjectId;
 use crate::test::fixture::file_object::key;
 
-use super::datetime;
+use super::{datetime, user};
 
 pub fn id() -> FileId {
     FileId::new(uuid::Uuid::from_u128(1))
@@ -12,12 +15,34 @@ pub fn filename() -> FileName {
     FileName::sanitized("test.txt".to_string())
 }
 
+pub fn size() -> FileSize {
+    FileSize::new(11)
+}
+
+pub fn content_type() -> FileContentType {
+    FileContentType::new("text/plain".to_string())
+}
+
+pub fn checksum() -> FileChecksum {
+    // "hello world" のSHA-256
+    FileChecksum::new(
+        "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9".to_string(),
+    )
+}
+
 pub fn file_data(owner: Option<ProjectId>) -> FileData {
     FileData::new(
         id(),
         filename(),
         key(),
         owner,
+        Some(size()),
+        Some(content_type()),
+        Some(checksum()),
+        Some(user::id1()),
+        FileIsStandalone::new(false),
+        FileScanStatus::Clean,
+        FileThumbnailStatus::Unavailable,
         datetime::now(),
         datetime::now(),
     )
@@ -0,0 +1,22 @@
+use crate::entity::file_export::{FileExport, FileExportId};
+
+pub fn id1() -> FileExportId {
+    FileExportId::new(uuid::Uuid::from_u128(1))
+}
+
+pub fn file_export1() -> FileExport {
+    let export = FileExport::create(super::form::id1(), super::user::id1(), None).destruct();
+    FileExport::new(
+        id1(),
+        export.form_id,
+        export.requested_by,
+        export.status,
+        export.since,
+        export.total,
+        export.processed,
+        export.key,
+        export.error,
+        export.created_at,
+        export.updated_at,
+    )
+}
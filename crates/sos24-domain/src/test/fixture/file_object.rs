@@ -1,4 +1,6 @@
-use crate::entity::file_object::{FileObject, FileObjectKey, FileSignedUrl};
+use crate::entity::file_object::{FileObject, FileObjectKey, FileObjectMetadata, FileSignedUrl};
+
+use super::file_data;
 
 pub fn data() -> Vec<u8> {
     b"hello world".to_vec()
@@ -15,3 +17,11 @@ pub fn file_object() -> FileObject {
 pub fn signed_url() -> FileSignedUrl {
     FileSignedUrl::new(url::Url::parse("https://example.com").unwrap())
 }
+
+pub fn metadata() -> FileObjectMetadata {
+    FileObjectMetadata::new(
+        file_data::size(),
+        Some(file_data::content_type()),
+        file_data::checksum(),
+    )
+}
@@ -0,0 +1,21 @@
+use crate::entity::common::datetime::DateTime;
+use crate::entity::file_data::FileIsStandalone;
+use crate::entity::file_upload::FileUpload;
+use crate::entity::user::UserId;
+use crate::test::fixture::file_data::{filename, id};
+use crate::test::fixture::file_object::key;
+
+use super::datetime;
+
+pub fn file_upload(uploader: UserId) -> FileUpload {
+    FileUpload::new(
+        id(),
+        filename(),
+        key(),
+        None,
+        uploader,
+        FileIsStandalone::new(false),
+        DateTime::new(chrono::Utc::now() + chrono::Duration::minutes(60)),
+        datetime::now(),
+    )
+}
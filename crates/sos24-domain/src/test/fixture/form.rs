@@ -7,7 +7,7 @@ use crate::entity::{
         FormItemId, FormItemKind, FormItemMax, FormItemMaxLength, FormItemMin, FormItemMinLength,
         FormItemName, FormItemRequired, FormTitle,
     },
-    project::{ProjectAttributes, ProjectCategories},
+    project::{ProjectAttributes, ProjectCategories, ProjectId},
 };
 
 use super::datetime;
@@ -123,6 +123,14 @@ pub fn items1() -> Vec<FormItem> {
     )]
 }
 
+pub fn included_projects1() -> Vec<ProjectId> {
+    vec![]
+}
+
+pub fn excluded_projects1() -> Vec<ProjectId> {
+    vec![]
+}
+
 pub fn attachments1() -> Vec<FileId> {
     vec![]
 }
@@ -137,6 +145,8 @@ pub fn form1_draft() -> Form {
         ends_at1_opened(),
         categories1(),
         attributes1(),
+        included_projects1(),
+        excluded_projects1(),
         is_notified1(),
         items1(),
         attachments1(),
@@ -155,6 +165,8 @@ pub fn form1_scheduled() -> Form {
         ends_at1_scheduled(),
         categories1(),
         attributes1(),
+        included_projects1(),
+        excluded_projects1(),
         is_notified1(),
         items1(),
         attachments1(),
@@ -173,6 +185,8 @@ pub fn form1_opened() -> Form {
         ends_at1_opened(),
         categories1(),
         attributes1(),
+        included_projects1(),
+        excluded_projects1(),
         is_notified1(),
         items1(),
         attachments1(),
@@ -191,6 +205,8 @@ pub fn form1_closed() -> Form {
         ends_at1_closed(),
         categories1(),
         attributes1(),
+        included_projects1(),
+        excluded_projects1(),
         is_notified1(),
         items1(),
         attachments1(),
@@ -265,6 +281,14 @@ pub fn items2() -> Vec<FormItem> {
     )]
 }
 
+pub fn included_projects2() -> Vec<ProjectId> {
+    vec![]
+}
+
+pub fn excluded_projects2() -> Vec<ProjectId> {
+    vec![]
+}
+
 pub fn attachments2() -> Vec<FileId> {
     vec![]
 }
@@ -279,6 +303,8 @@ pub fn form2() -> Form {
         ends_at2(),
         categories2(),
         attributes2(),
+        included_projects2(),
+        excluded_projects2(),
         is_notified1(),
         items2(),
         attachments2(),
@@ -0,0 +1,18 @@
+use crate::entity::{
+    impersonation_log::{ImpersonationLog, ImpersonationLogId, ImpersonationLogRequest},
+    user::UserId,
+};
+
+use super::datetime;
+
+pub fn id1() -> ImpersonationLogId {
+    ImpersonationLogId::new(uuid::Uuid::from_u128(1))
+}
+
+pub fn request1() -> ImpersonationLogRequest {
+    ImpersonationLogRequest::new("GET /projects/me".to_string())
+}
+
+pub fn impersonation_log1(impersonator_id: UserId, user_id: UserId) -> ImpersonationLog {
+    ImpersonationLog::new(id1(), impersonator_id, user_id, request1(), datetime::now())
+}
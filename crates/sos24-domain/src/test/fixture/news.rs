@@ -2,7 +2,7 @@ use chrono::TimeZone;
 
 use crate::entity::file_data::FileId;
 use crate::entity::news::{News, NewsBody, NewsId, NewsState, NewsTitle};
-use crate::entity::project::{ProjectAttributes, ProjectCategories};
+use crate::entity::project::{ProjectAttributes, ProjectCategories, ProjectId};
 
 use super::datetime;
 
@@ -34,6 +34,14 @@ pub fn attributes1() -> ProjectAttributes {
     ProjectAttributes::ACADEMIC
 }
 
+pub fn included_projects1() -> Vec<ProjectId> {
+    vec![]
+}
+
+pub fn excluded_projects1() -> Vec<ProjectId> {
+    vec![]
+}
+
 pub fn news1() -> News {
     News::new(
         id1(),
@@ -43,6 +51,8 @@ pub fn news1() -> News {
         attachments1(),
         categories1(),
         attributes1(),
+        included_projects1(),
+        excluded_projects1(),
         datetime::now(),
         datetime::now(),
     )
@@ -80,6 +90,14 @@ pub fn attributes2() -> ProjectAttributes {
     ProjectAttributes::OFFICIAL
 }
 
+pub fn included_projects2() -> Vec<ProjectId> {
+    vec![]
+}
+
+pub fn excluded_projects2() -> Vec<ProjectId> {
+    vec![]
+}
+
 pub fn news2() -> News {
     News::new(
         id2(),
@@ -89,6 +107,8 @@ pub fn news2() -> News {
         attachments2(),
         categories2(),
         attributes2(),
+        included_projects2(),
+        excluded_projects2(),
         datetime::now(),
         datetime::now(),
     )
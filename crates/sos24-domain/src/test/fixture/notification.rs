@@ -0,0 +1,42 @@
+use crate::entity::{
+    notification::{
+        Notification, NotificationBody, NotificationId, NotificationKind, NotificationTitle,
+        NotificationUrl,
+    },
+    user::UserId,
+};
+
+use super::datetime;
+
+pub fn id1() -> NotificationId {
+    NotificationId::new(uuid::Uuid::from_u128(1))
+}
+
+pub fn kind1() -> NotificationKind {
+    NotificationKind::NewsPublished
+}
+
+pub fn title1() -> NotificationTitle {
+    NotificationTitle::new("タイトル1".to_string())
+}
+
+pub fn body1() -> NotificationBody {
+    NotificationBody::new("本文1".to_string())
+}
+
+pub fn url1() -> NotificationUrl {
+    NotificationUrl::new("https://example.com/news/1".to_string())
+}
+
+pub fn notification1(user_id: UserId) -> Notification {
+    Notification::new(
+        id1(),
+        user_id,
+        kind1(),
+        title1(),
+        body1(),
+        url1(),
+        None,
+        datetime::now(),
+    )
+}
@@ -0,0 +1,10 @@
+use crate::entity::project_storage::ProjectStorageQuota;
+
+pub fn default_quota() -> ProjectStorageQuota {
+    // 1GB
+    ProjectStorageQuota::new(1_000_000_000)
+}
+
+pub fn quota() -> ProjectStorageQuota {
+    ProjectStorageQuota::new(100)
+}
@@ -21,7 +21,7 @@ pub fn email1() -> UserEmail {
 }
 
 pub fn phone_number1() -> UserPhoneNumber {
-    UserPhoneNumber::new("0900-000-0001".to_string())
+    UserPhoneNumber::new("09000000001".to_string())
 }
 
 pub fn id2() -> UserId {
@@ -41,7 +41,7 @@ pub fn email2() -> UserEmail {
 }
 
 pub fn phone_number2() -> UserPhoneNumber {
-    UserPhoneNumber::new("0900-000-0002".to_string())
+    UserPhoneNumber::new("09000000002".to_string())
 }
 
 pub fn user1(role: UserRole) -> User {
@@ -0,0 +1,54 @@
+use crate::entity::webhook::{
+    Webhook, WebhookEventType, WebhookFormat, WebhookId, WebhookName, WebhookSecret, WebhookUrl,
+};
+
+use super::datetime;
+
+pub fn id1() -> WebhookId {
+    WebhookId::new(uuid::Uuid::from_u128(1))
+}
+
+pub fn name1() -> WebhookName {
+    WebhookName::new("備品管理".to_string())
+}
+
+pub fn name2() -> WebhookName {
+    WebhookName::new("ステージ管理".to_string())
+}
+
+pub fn url1() -> WebhookUrl {
+    WebhookUrl::new("https://example.com/webhook".to_string())
+}
+
+pub fn secret1() -> WebhookSecret {
+    WebhookSecret::new("secret".to_string())
+}
+
+pub fn webhook1() -> Webhook {
+    Webhook::new(
+        id1(),
+        name1(),
+        url1(),
+        secret1(),
+        WebhookFormat::Json,
+        vec![
+            WebhookEventType::ProjectCreated,
+            WebhookEventType::ProjectUpdated,
+        ],
+        datetime::now(),
+        datetime::now(),
+    )
+}
+
+pub fn webhook_slack() -> Webhook {
+    Webhook::new(
+        id1(),
+        name2(),
+        WebhookUrl::new("https://hooks.slack.com/services/XXX".to_string()),
+        secret1(),
+        WebhookFormat::Slack,
+        vec![WebhookEventType::ProjectCreated],
+        datetime::now(),
+        datetime::now(),
+    )
+}
@@ -0,0 +1,20 @@
+use crate::entity::{
+    webhook::WebhookEventType,
+    webhook_delivery::{WebhookDelivery, WebhookDeliveryId, WebhookPayload},
+};
+
+pub fn id1() -> WebhookDeliveryId {
+    WebhookDeliveryId::new(uuid::Uuid::from_u128(1))
+}
+
+pub fn payload1() -> WebhookPayload {
+    WebhookPayload::new(r#"{"type":"project.created"}"#.to_string())
+}
+
+pub fn webhook_delivery1() -> WebhookDelivery {
+    WebhookDelivery::create(
+        super::webhook::id1(),
+        WebhookEventType::ProjectCreated,
+        payload1(),
+    )
+}
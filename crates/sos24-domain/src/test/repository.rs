@@ -1,9 +1,13 @@
 use crate::repository::{
-    file_data::MockFileDataRepository, file_object::MockFileObjectRepository,
+    api_token::MockApiTokenRepository, custom_role::MockCustomRoleRepository,
+    file_data::MockFileDataRepository, file_export::MockFileExportRepository,
+    file_object::MockFileObjectRepository, file_upload::MockFileUploadRepository,
     firebase_user::MockFirebaseUserRepository, form::MockFormRepository,
-    form_answer::MockFormAnswerRepository, invitation::MockInvitationRepository,
-    news::MockNewsRepository, project::MockProjectRepository, user::MockUserRepository,
-    Repositories,
+    form_answer::MockFormAnswerRepository, impersonation_log::MockImpersonationLogRepository,
+    invitation::MockInvitationRepository, news::MockNewsRepository,
+    notification::MockNotificationRepository, project::MockProjectRepository,
+    project_storage_quota::MockProjectStorageQuotaRepository, user::MockUserRepository,
+    webhook::MockWebhookRepository, webhook_delivery::MockWebhookDeliveryRepository, Repositories,
 };
 
 #[derive(Default)]
@@ -13,10 +17,19 @@ pub struct MockRepositories {
     form_answer_repository: MockFormAnswerRepository,
     invitation_repository: MockInvitationRepository,
     news_repository: MockNewsRepository,
+    notification_repository: MockNotificationRepository,
     file_data_repository: MockFileDataRepository,
     file_object_repository: MockFileObjectRepository,
     project_repository: MockProjectRepository,
     user_repository: MockUserRepository,
+    webhook_repository: MockWebhookRepository,
+    webhook_delivery_repository: MockWebhookDeliveryRepository,
+    file_upload_repository: MockFileUploadRepository,
+    project_storage_quota_repository: MockProjectStorageQuotaRepository,
+    file_export_repository: MockFileExportRepository,
+    api_token_repository: MockApiTokenRepository,
+    custom_role_repository: MockCustomRoleRepository,
+    impersonation_log_repository: MockImpersonationLogRepository,
 }
 
 impl MockRepositories {
@@ -40,6 +53,10 @@ impl MockRepositories {
         &mut self.news_repository
     }
 
+    pub fn notification_repository_mut(&mut self) -> &mut MockNotificationRepository {
+        &mut self.notification_repository
+    }
+
     pub fn file_data_repository_mut(&mut self) -> &mut MockFileDataRepository {
         &mut self.file_data_repository
     }
@@ -55,6 +72,40 @@ impl MockRepositories {
     pub fn user_repository_mut(&mut self) -> &mut MockUserRepository {
         &mut self.user_repository
     }
+
+    pub fn webhook_repository_mut(&mut self) -> &mut MockWebhookRepository {
+        &mut self.webhook_repository
+    }
+
+    pub fn webhook_delivery_repository_mut(&mut self) -> &mut MockWebhookDeliveryRepository {
+        &mut self.webhook_delivery_repository
+    }
+
+    pub fn file_upload_repository_mut(&mut self) -> &mut MockFileUploadRepository {
+        &mut self.file_upload_repository
+    }
+
+    pub fn file_export_repository_mut(&mut self) -> &mut MockFileExportRepository {
+        &mut self.file_export_repository
+    }
+
+    pub fn api_token_repository_mut(&mut self) -> &mut MockApiTokenRepository {
+        &mut self.api_token_repository
+    }
+
+    pub fn custom_role_repository_mut(&mut self) -> &mut MockCustomRoleRepository {
+        &mut self.custom_role_repository
+    }
+
+    pub fn impersonation_log_repository_mut(&mut self) -> &mut MockImpersonationLogRepository {
+        &mut self.impersonation_log_repository
+    }
+
+    pub fn project_storage_quota_repository_mut(
+        &mut self,
+    ) -> &mut MockProjectStorageQuotaRepository {
+        &mut self.project_storage_quota_repository
+    }
 }
 
 impl Repositories for MockRepositories {
@@ -63,10 +114,19 @@ impl Repositories for MockRepositories {
     type FormAnswerRepositoryImpl = MockFormAnswerRepository;
     type InvitationRepositoryImpl = MockInvitationRepository;
     type NewsRepositoryImpl = MockNewsRepository;
+    type NotificationRepositoryImpl = MockNotificationRepository;
     type ProjectRepositoryImpl = MockProjectRepository;
     type FileDataRepositoryImpl = MockFileDataRepository;
     type FileObjectRepositoryImpl = MockFileObjectRepository;
     type UserRepositoryImpl = MockUserRepository;
+    type WebhookRepositoryImpl = MockWebhookRepository;
+    type WebhookDeliveryRepositoryImpl = MockWebhookDeliveryRepository;
+    type FileUploadRepositoryImpl = MockFileUploadRepository;
+    type ProjectStorageQuotaRepositoryImpl = MockProjectStorageQuotaRepository;
+    type FileExportRepositoryImpl = MockFileExportRepository;
+    type ApiTokenRepositoryImpl = MockApiTokenRepository;
+    type CustomRoleRepositoryImpl = MockCustomRoleRepository;
+    type ImpersonationLogRepositoryImpl = MockImpersonationLogRepository;
 
     fn firebase_user_repository(&self) -> &Self::FirebaseUserRepositoryImpl {
         &self.firebase_user_repository
@@ -88,6 +148,10 @@ impl Repositories for MockRepositories {
         &self.news_repository
     }
 
+    fn notification_repository(&self) -> &Self::NotificationRepositoryImpl {
+        &self.notification_repository
+    }
+
     fn project_repository(&self) -> &Self::ProjectRepositoryImpl {
         &self.project_repository
     }
@@ -103,4 +167,36 @@ impl Repositories for MockRepositories {
     fn user_repository(&self) -> &Self::UserRepositoryImpl {
         &self.user_repository
     }
+
+    fn webhook_repository(&self) -> &Self::WebhookRepositoryImpl {
+        &self.webhook_repository
+    }
+
+    fn webhook_delivery_repository(&self) -> &Self::WebhookDeliveryRepositoryImpl {
+        &self.webhook_delivery_repository
+    }
+
+    fn file_upload_repository(&self) -> &Self::FileUploadRepositoryImpl {
+        &self.file_upload_repository
+    }
+
+    fn project_storage_quota_repository(&self) -> &Self::ProjectStorageQuotaRepositoryImpl {
+        &self.project_storage_quota_repository
+    }
+
+    fn file_export_repository(&self) -> &Self::FileExportRepositoryImpl {
+        &self.file_export_repository
+    }
+
+    fn api_token_repository(&self) -> &Self::ApiTokenRepositoryImpl {
+        &self.api_token_repository
+    }
+
+    fn custom_role_repository(&self) -> &Self::CustomRoleRepositoryImpl {
+        &self.custom_role_repository
+    }
+
+    fn impersonation_log_repository(&self) -> &Self::ImpersonationLogRepositoryImpl {
+        &self.impersonation_log_repository
+    }
 }
@@ -0,0 +1,173 @@
+use anyhow::Context;
+use futures_util::{StreamExt, TryStreamExt};
+use sqlx::prelude::*;
+
+use sos24_domain::{
+    entity::{
+        api_token::{ApiToken, ApiTokenHash, ApiTokenId, ApiTokenName, ApiTokenScope},
+        common::datetime::DateTime,
+        permission::Permissions,
+        user::UserId,
+    },
+    repository::api_token::{ApiTokenRepository, ApiTokenRepositoryError},
+};
+
+use crate::shared::postgresql::Postgresql;
+
+#[derive(FromRow)]
+pub struct ApiTokenRow {
+    id: uuid::Uuid,
+    user_id: String,
+    name: String,
+    permissions: i64,
+    token_hash: String,
+    expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    last_used_at: Option<chrono::DateTime<chrono::Utc>>,
+    created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<ApiTokenRow> for ApiToken {
+    fn from(value: ApiTokenRow) -> Self {
+        let permissions = Permissions::from_bits_truncate(value.permissions as u64);
+        ApiToken::new(
+            ApiTokenId::new(value.id),
+            UserId::new(value.user_id),
+            ApiTokenName::new(value.name),
+            ApiTokenScope::from_permissions(&permissions),
+            ApiTokenHash::new(value.token_hash),
+            value.expires_at.map(DateTime::new),
+            value.last_used_at.map(DateTime::new),
+            DateTime::new(value.created_at),
+        )
+    }
+}
+
+pub struct PgApiTokenRepository {
+    db: Postgresql,
+}
+
+impl PgApiTokenRepository {
+    pub fn new(db: Postgresql) -> Self {
+        Self { db }
+    }
+}
+
+impl ApiTokenRepository for PgApiTokenRepository {
+    async fn create(&self, token: ApiToken) -> Result<(), ApiTokenRepositoryError> {
+        tracing::info!("APIトークンを作成します");
+
+        let permissions =
+            i64::try_from(token.permissions().bits()).context("Permissions are too large")?;
+        let token = token.destruct();
+        sqlx::query!(
+            r#"INSERT INTO api_tokens (id, user_id, name, permissions, token_hash, expires_at) VALUES ($1, $2, $3, $4, $5, $6)"#,
+            token.id.value(),
+            token.user_id.value(),
+            token.name.value(),
+            permissions,
+            token.token_hash.value(),
+            token.expires_at.map(|it| it.value()),
+        )
+        .execute(&*self.db)
+        .await
+        .context("Failed to create api token")?;
+
+        tracing::info!("APIトークンの作成が完了しました");
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        id: ApiTokenId,
+    ) -> Result<Option<ApiToken>, ApiTokenRepositoryError> {
+        tracing::info!("APIトークンを取得します: {id:?}");
+
+        let token_row = sqlx::query_as!(
+            ApiTokenRow,
+            r#"SELECT id, user_id, name, permissions, token_hash, expires_at, last_used_at, created_at FROM api_tokens WHERE id = $1"#,
+            id.clone().value()
+        )
+        .fetch_optional(&*self.db)
+        .await
+        .context("Failed to fetch api token")?;
+
+        tracing::info!("APIトークンの取得が完了しました: {id:?}");
+        Ok(token_row.map(ApiToken::from))
+    }
+
+    async fn find_by_user_id(
+        &self,
+        user_id: UserId,
+    ) -> Result<Vec<ApiToken>, ApiTokenRepositoryError> {
+        tracing::info!("ユーザーのAPIトークン一覧を取得します: {user_id:?}");
+
+        let token_list = sqlx::query_as!(
+            ApiTokenRow,
+            r#"SELECT id, user_id, name, permissions, token_hash, expires_at, last_used_at, created_at FROM api_tokens WHERE user_id = $1 ORDER BY created_at"#,
+            user_id.clone().value()
+        )
+        .fetch(&*self.db)
+        .map(|row| Ok::<_, anyhow::Error>(ApiToken::from(row?)))
+        .try_collect()
+        .await
+        .context("Failed to fetch api token list")?;
+
+        tracing::info!("ユーザーのAPIトークン一覧の取得が完了しました: {user_id:?}");
+        Ok(token_list)
+    }
+
+    async fn find_by_token_hash(
+        &self,
+        token_hash: ApiTokenHash,
+    ) -> Result<Option<ApiToken>, ApiTokenRepositoryError> {
+        tracing::info!("ハッシュ値からAPIトークンを取得します");
+
+        // 削除されたユーザーのトークンでは認証できないようにする
+        let token_row = sqlx::query_as!(
+            ApiTokenRow,
+            r#"SELECT api_tokens.id, api_tokens.user_id, api_tokens.name, api_tokens.permissions, api_tokens.token_hash, api_tokens.expires_at, api_tokens.last_used_at, api_tokens.created_at FROM api_tokens INNER JOIN users ON api_tokens.user_id = users.id WHERE api_tokens.token_hash = $1 AND users.deleted_at IS NULL"#,
+            token_hash.value()
+        )
+        .fetch_optional(&*self.db)
+        .await
+        .context("Failed to fetch api token")?;
+
+        tracing::info!("ハッシュ値からのAPIトークンの取得が完了しました");
+        Ok(token_row.map(ApiToken::from))
+    }
+
+    async fn update_last_used_at(
+        &self,
+        id: ApiTokenId,
+        last_used_at: DateTime,
+    ) -> Result<(), ApiTokenRepositoryError> {
+        tracing::info!("APIトークンの最終利用日時を更新します: {id:?}");
+
+        sqlx::query!(
+            r#"UPDATE api_tokens SET last_used_at = $2 WHERE id = $1"#,
+            id.clone().value(),
+            last_used_at.value(),
+        )
+        .execute(&*self.db)
+        .await
+        .context("Failed to update api token")?;
+
+        tracing::info!("APIトークンの最終利用日時の更新が完了しました: {id:?}");
+        Ok(())
+    }
+
+    async fn delete_by_id(&self, id: ApiTokenId) -> Result<(), ApiTokenRepositoryError> {
+        tracing::info!("APIトークンを削除します: {id:?}");
+
+        sqlx::query!(
+            r#"DELETE FROM api_tokens WHERE id = $1"#,
+            id.clone().value()
+        )
+        .execute(&*self.db)
+        .await
+        .context("Failed to delete api token")?;
+
+        tracing::info!("APIトークンの削除が完了しました: {id:?}");
+        Ok(())
+    }
+}
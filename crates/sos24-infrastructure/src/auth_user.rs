@@ -0,0 +1,86 @@
+use sos24_domain::{
+    entity::firebase_user::{
+        FirebaseUserEmail, FirebaseUserId, FirebaseUserPassword, NewFirebaseUser,
+    },
+    repository::firebase_user::{FirebaseUserRepository, FirebaseUserRepositoryError},
+};
+
+use crate::{
+    firebase_user::FirebaseUserRepositoryImpl,
+    local_user::PgLocalUserRepository,
+    shared::{firebase::FirebaseAuth, postgresql::Postgresql},
+};
+
+/// ユーザーの認証情報を管理する認証基盤
+pub enum AuthBackend {
+    Firebase(Box<FirebaseAuth>),
+    Local,
+}
+
+/// 設定された認証基盤に処理を委譲する
+pub enum AuthUserRepository {
+    Firebase(Box<FirebaseUserRepositoryImpl>),
+    Local(PgLocalUserRepository),
+}
+
+impl AuthUserRepository {
+    pub fn new(backend: AuthBackend, postgresql: Postgresql) -> Self {
+        match backend {
+            AuthBackend::Firebase(auth) => {
+                Self::Firebase(Box::new(FirebaseUserRepositoryImpl::new(*auth)))
+            }
+            AuthBackend::Local => Self::Local(PgLocalUserRepository::new(postgresql)),
+        }
+    }
+}
+
+impl FirebaseUserRepository for AuthUserRepository {
+    async fn create(
+        &self,
+        new_firebase_user: NewFirebaseUser,
+    ) -> Result<FirebaseUserId, FirebaseUserRepositoryError> {
+        match self {
+            Self::Firebase(repository) => repository.create(new_firebase_user).await,
+            Self::Local(repository) => repository.create(new_firebase_user).await,
+        }
+    }
+
+    async fn update_email_by_id(
+        &self,
+        id: FirebaseUserId,
+        email: FirebaseUserEmail,
+    ) -> Result<(), FirebaseUserRepositoryError> {
+        match self {
+            Self::Firebase(repository) => repository.update_email_by_id(id, email).await,
+            Self::Local(repository) => repository.update_email_by_id(id, email).await,
+        }
+    }
+
+    async fn delete_by_id(&self, id: FirebaseUserId) -> Result<(), FirebaseUserRepositoryError> {
+        match self {
+            Self::Firebase(repository) => repository.delete_by_id(id).await,
+            Self::Local(repository) => repository.delete_by_id(id).await,
+        }
+    }
+
+    async fn generate_password_reset_link(
+        &self,
+        email: FirebaseUserEmail,
+    ) -> Result<String, FirebaseUserRepositoryError> {
+        match self {
+            Self::Firebase(repository) => repository.generate_password_reset_link(email).await,
+            Self::Local(repository) => repository.generate_password_reset_link(email).await,
+        }
+    }
+
+    async fn verify_password(
+        &self,
+        email: FirebaseUserEmail,
+        password: FirebaseUserPassword,
+    ) -> Result<Option<FirebaseUserId>, FirebaseUserRepositoryError> {
+        match self {
+            Self::Firebase(repository) => repository.verify_password(email, password).await,
+            Self::Local(repository) => repository.verify_password(email, password).await,
+        }
+    }
+}
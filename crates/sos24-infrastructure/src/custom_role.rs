@@ -0,0 +1,213 @@
+use anyhow::{anyhow, Context};
+use futures_util::{StreamExt, TryStreamExt};
+use sqlx::prelude::*;
+
+use sos24_domain::{
+    entity::{
+        common::datetime::DateTime,
+        custom_role::{CustomRole, CustomRoleId, CustomRoleName},
+        permission::Permissions,
+        project::ProjectCategories,
+        user::UserId,
+    },
+    repository::custom_role::{CustomRoleRepository, CustomRoleRepositoryError},
+};
+
+use crate::shared::postgresql::Postgresql;
+
+#[derive(FromRow)]
+pub struct CustomRoleRow {
+    id: uuid::Uuid,
+    name: String,
+    permissions: i64,
+    project_categories: Option<i32>,
+    created_at: chrono::DateTime<chrono::Utc>,
+    updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl TryFrom<CustomRoleRow> for CustomRole {
+    type Error = anyhow::Error;
+    fn try_from(value: CustomRoleRow) -> Result<Self, Self::Error> {
+        let project_categories = value
+            .project_categories
+            .map(|categories| {
+                ProjectCategories::from_bits(categories as u32)
+                    .ok_or(anyhow!("cannot convert project categories"))
+            })
+            .transpose()?;
+        Ok(CustomRole::new(
+            CustomRoleId::new(value.id),
+            CustomRoleName::new(value.name),
+            Permissions::from_bits_truncate(value.permissions as u64),
+            project_categories,
+            DateTime::new(value.created_at),
+            DateTime::new(value.updated_at),
+        ))
+    }
+}
+
+pub struct PgCustomRoleRepository {
+    db: Postgresql,
+}
+
+impl PgCustomRoleRepository {
+    pub fn new(db: Postgresql) -> Self {
+        Self { db }
+    }
+}
+
+impl CustomRoleRepository for PgCustomRoleRepository {
+    async fn list(&self) -> Result<Vec<CustomRole>, CustomRoleRepositoryError> {
+        tracing::info!("カスタムロール一覧を取得します");
+
+        let role_list = sqlx::query_as!(
+            CustomRoleRow,
+            r#"SELECT id, name, permissions, project_categories, created_at, updated_at FROM custom_roles ORDER BY created_at ASC"#
+        )
+        .fetch(&*self.db)
+        .map(|row| CustomRole::try_from(row?))
+        .try_collect()
+        .await
+        .context("Failed to fetch custom role list")?;
+
+        tracing::info!("カスタムロール一覧を取得しました");
+        Ok(role_list)
+    }
+
+    async fn create(&self, role: CustomRole) -> Result<(), CustomRoleRepositoryError> {
+        tracing::info!("カスタムロールを作成します");
+
+        let role = role.destruct();
+        let permissions =
+            i64::try_from(role.permissions.bits()).context("Permissions are too large")?;
+        sqlx::query!(
+            r#"INSERT INTO custom_roles (id, name, permissions, project_categories) VALUES ($1, $2, $3, $4)"#,
+            role.id.value(),
+            role.name.value(),
+            permissions,
+            role.project_categories.map(|categories| categories.bits() as i32),
+        )
+        .execute(&*self.db)
+        .await
+        .context("Failed to create custom role")?;
+
+        tracing::info!("カスタムロールを作成しました");
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        id: CustomRoleId,
+    ) -> Result<Option<CustomRole>, CustomRoleRepositoryError> {
+        tracing::info!("カスタムロールを取得します: {id:?}");
+
+        let role_row = sqlx::query_as!(
+            CustomRoleRow,
+            r#"SELECT id, name, permissions, project_categories, created_at, updated_at FROM custom_roles WHERE id = $1"#,
+            id.clone().value()
+        )
+        .fetch_optional(&*self.db)
+        .await
+        .context("Failed to fetch custom role")?;
+
+        tracing::info!("カスタムロールを取得しました: {id:?}");
+        Ok(role_row.map(CustomRole::try_from).transpose()?)
+    }
+
+    async fn find_by_user_id(
+        &self,
+        user_id: UserId,
+    ) -> Result<Vec<CustomRole>, CustomRoleRepositoryError> {
+        tracing::info!("ユーザーのカスタムロール一覧を取得します: {user_id:?}");
+
+        let role_list = sqlx::query_as!(
+            CustomRoleRow,
+            r#"SELECT custom_roles.id, custom_roles.name, custom_roles.permissions, custom_roles.project_categories, custom_roles.created_at, custom_roles.updated_at FROM custom_roles INNER JOIN user_custom_roles ON custom_roles.id = user_custom_roles.role_id WHERE user_custom_roles.user_id = $1 ORDER BY custom_roles.created_at ASC"#,
+            user_id.clone().value()
+        )
+        .fetch(&*self.db)
+        .map(|row| CustomRole::try_from(row?))
+        .try_collect()
+        .await
+        .context("Failed to fetch custom role list")?;
+
+        tracing::info!("ユーザーのカスタムロール一覧を取得しました: {user_id:?}");
+        Ok(role_list)
+    }
+
+    async fn update(&self, role: CustomRole) -> Result<(), CustomRoleRepositoryError> {
+        tracing::info!("カスタムロールを更新します");
+
+        let role = role.destruct();
+        let permissions =
+            i64::try_from(role.permissions.bits()).context("Permissions are too large")?;
+        sqlx::query!(
+            r#"UPDATE custom_roles SET name = $2, permissions = $3, project_categories = $4 WHERE id = $1"#,
+            role.id.value(),
+            role.name.value(),
+            permissions,
+            role.project_categories.map(|categories| categories.bits() as i32),
+        )
+        .execute(&*self.db)
+        .await
+        .context("Failed to update custom role")?;
+
+        tracing::info!("カスタムロールを更新しました");
+        Ok(())
+    }
+
+    async fn delete_by_id(&self, id: CustomRoleId) -> Result<(), CustomRoleRepositoryError> {
+        tracing::info!("カスタムロールを削除します: {id:?}");
+
+        // ユーザーへの割り当てはON DELETE CASCADEで削除される
+        sqlx::query!(
+            r#"DELETE FROM custom_roles WHERE id = $1"#,
+            id.clone().value()
+        )
+        .execute(&*self.db)
+        .await
+        .context("Failed to delete custom role")?;
+
+        tracing::info!("カスタムロールを削除しました: {id:?}");
+        Ok(())
+    }
+
+    async fn set_user_roles(
+        &self,
+        user_id: UserId,
+        role_ids: Vec<CustomRoleId>,
+    ) -> Result<(), CustomRoleRepositoryError> {
+        tracing::info!("ユーザーのカスタムロールを設定します: {user_id:?}");
+
+        let mut tx = self
+            .db
+            .begin()
+            .await
+            .context("Failed to begin transaction")?;
+
+        sqlx::query!(
+            r#"DELETE FROM user_custom_roles WHERE user_id = $1"#,
+            user_id.clone().value()
+        )
+        .execute(&mut *tx)
+        .await
+        .context("Failed to delete user custom roles")?;
+
+        sqlx::query!(
+            r#"INSERT INTO user_custom_roles (user_id, role_id) SELECT $1, UNNEST($2::uuid[])"#,
+            user_id.clone().value(),
+            &role_ids
+                .into_iter()
+                .map(|id| id.value())
+                .collect::<Vec<_>>(),
+        )
+        .execute(&mut *tx)
+        .await
+        .context("Failed to insert user custom roles")?;
+
+        tx.commit().await.context("Failed to commit transaction")?;
+
+        tracing::info!("ユーザーのカスタムロールを設定しました: {user_id:?}");
+        Ok(())
+    }
+}
@@ -50,6 +50,14 @@ impl EmailSender for SendGridEmailSender {
                     .set_value(&command.body),
             )
             .add_category("sos");
+        // text/plainはtext/htmlより前に追加する必要がある
+        if let Some(ref html_body) = command.html_body {
+            message = message.add_content(
+                Content::new()
+                    .set_content_type("text/html")
+                    .set_value(html_body),
+            );
+        }
         if let Some(ref reply_to) = command.reply_to {
             message = message.set_reply_to(reply_to.to_email());
         }
@@ -0,0 +1,34 @@
+use sos24_use_case::shared::adapter::event::{Event, EventBus};
+use tokio::sync::broadcast;
+
+// 購読者の処理が追いつかない場合に保持しておくイベントの数
+const CAPACITY: usize = 1024;
+
+#[derive(Clone)]
+pub struct BroadcastEventBus {
+    sender: broadcast::Sender<Event>,
+}
+
+impl BroadcastEventBus {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(CAPACITY);
+        Self { sender }
+    }
+}
+
+impl Default for BroadcastEventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventBus for BroadcastEventBus {
+    fn publish(&self, event: Event) {
+        // 購読者がいない場合は送信に失敗するが、問題ないので無視する
+        let _ = self.sender.send(event);
+    }
+
+    fn subscribe(&self) -> broadcast::Receiver<Event> {
+        self.sender.subscribe()
+    }
+}
@@ -5,9 +5,13 @@ use sqlx::prelude::*;
 use sos24_domain::{
     entity::{
         common::datetime::DateTime,
-        file_data::{FileData, FileId, FileName},
+        file_data::{
+            FileChecksum, FileContentType, FileData, FileId, FileIsStandalone, FileName,
+            FileScanStatus, FileSize, FileThumbnailStatus,
+        },
         file_object::FileObjectKey,
         project::ProjectId,
+        user::UserId,
     },
     repository::file_data::{FileDataRepository, FileDataRepositoryError},
 };
@@ -20,6 +24,13 @@ pub struct FileDataRow {
     name: String,
     url: String,
     owner_project: Option<uuid::Uuid>,
+    size: Option<i64>,
+    content_type: Option<String>,
+    checksum: Option<String>,
+    uploader: Option<String>,
+    is_standalone: bool,
+    scan_status: FileScanStatusRow,
+    thumbnail_status: FileThumbnailStatusRow,
     created_at: chrono::DateTime<chrono::Utc>,
     updated_at: chrono::DateTime<chrono::Utc>,
 }
@@ -33,12 +44,81 @@ impl TryFrom<FileDataRow> for FileData {
             FileName::sanitized(value.name),
             FileObjectKey::new(value.url),
             value.owner_project.map(ProjectId::new),
+            value.size.map(FileSize::new),
+            value.content_type.map(FileContentType::new),
+            value.checksum.map(FileChecksum::new),
+            value.uploader.map(UserId::new),
+            FileIsStandalone::new(value.is_standalone),
+            FileScanStatus::from(value.scan_status),
+            FileThumbnailStatus::from(value.thumbnail_status),
             DateTime::new(value.created_at),
             DateTime::new(value.updated_at),
         ))
     }
 }
 
+#[derive(Type)]
+#[sqlx(type_name = "file_scan_status", rename_all = "snake_case")]
+pub enum FileScanStatusRow {
+    Pending,
+    Clean,
+    Infected,
+    Unscannable,
+    NotScanned,
+}
+
+impl From<FileScanStatus> for FileScanStatusRow {
+    fn from(status: FileScanStatus) -> Self {
+        match status {
+            FileScanStatus::Pending => Self::Pending,
+            FileScanStatus::Clean => Self::Clean,
+            FileScanStatus::Infected => Self::Infected,
+            FileScanStatus::Unscannable => Self::Unscannable,
+            FileScanStatus::NotScanned => Self::NotScanned,
+        }
+    }
+}
+
+impl From<FileScanStatusRow> for FileScanStatus {
+    fn from(status: FileScanStatusRow) -> Self {
+        match status {
+            FileScanStatusRow::Pending => Self::Pending,
+            FileScanStatusRow::Clean => Self::Clean,
+            FileScanStatusRow::Infected => Self::Infected,
+            FileScanStatusRow::Unscannable => Self::Unscannable,
+            FileScanStatusRow::NotScanned => Self::NotScanned,
+        }
+    }
+}
+
+#[derive(Type)]
+#[sqlx(type_name = "file_thumbnail_status", rename_all = "snake_case")]
+pub enum FileThumbnailStatusRow {
+    Pending,
+    Ready,
+    Unavailable,
+}
+
+impl From<FileThumbnailStatus> for FileThumbnailStatusRow {
+    fn from(status: FileThumbnailStatus) -> Self {
+        match status {
+            FileThumbnailStatus::Pending => Self::Pending,
+            FileThumbnailStatus::Ready => Self::Ready,
+            FileThumbnailStatus::Unavailable => Self::Unavailable,
+        }
+    }
+}
+
+impl From<FileThumbnailStatusRow> for FileThumbnailStatus {
+    fn from(status: FileThumbnailStatusRow) -> Self {
+        match status {
+            FileThumbnailStatusRow::Pending => Self::Pending,
+            FileThumbnailStatusRow::Ready => Self::Ready,
+            FileThumbnailStatusRow::Unavailable => Self::Unavailable,
+        }
+    }
+}
+
 pub struct PgFileDataRepository {
     db: Postgresql,
 }
@@ -55,7 +135,7 @@ impl FileDataRepository for PgFileDataRepository {
 
         let file_data_list = sqlx::query_as!(
             FileDataRow,
-            r#"SELECT id, name, url, owner_project, created_at, updated_at FROM files WHERE deleted_at IS NULL"#
+            r#"SELECT id, name, url, owner_project, size, content_type, checksum, uploader, is_standalone, scan_status AS "scan_status: FileScanStatusRow", thumbnail_status AS "thumbnail_status: FileThumbnailStatusRow", created_at, updated_at FROM files WHERE deleted_at IS NULL"#
         )
         .fetch(&*self.db)
         .map(|row| FileData::try_from(row?))
@@ -72,11 +152,18 @@ impl FileDataRepository for PgFileDataRepository {
 
         let file_data = file_data.destruct();
         sqlx::query!(
-            r#"INSERT INTO files (id, name, url, owner_project) VALUES ($1, $2, $3, $4)"#,
+            r#"INSERT INTO files (id, name, url, owner_project, size, content_type, checksum, uploader, is_standalone, scan_status, thumbnail_status) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)"#,
             file_data.id.value(),
             file_data.name.value(),
             file_data.url.value().to_string(),
-            file_data.owner.map(|it| it.value())
+            file_data.owner.map(|it| it.value()),
+            file_data.size.map(|it| it.value()),
+            file_data.content_type.map(|it| it.value()),
+            file_data.checksum.map(|it| it.value()),
+            file_data.uploader.map(|it| it.value()),
+            file_data.is_standalone.value(),
+            FileScanStatusRow::from(file_data.scan_status) as FileScanStatusRow,
+            FileThumbnailStatusRow::from(file_data.thumbnail_status) as FileThumbnailStatusRow,
         )
         .execute(&*self.db)
         .await
@@ -91,7 +178,7 @@ impl FileDataRepository for PgFileDataRepository {
 
         let file_data_row = sqlx::query_as!(
             FileDataRow,
-            r#"SELECT id, name, url, owner_project, created_at, updated_at FROM files WHERE id = $1 AND deleted_at IS NULL"#,
+            r#"SELECT id, name, url, owner_project, size, content_type, checksum, uploader, is_standalone, scan_status AS "scan_status: FileScanStatusRow", thumbnail_status AS "thumbnail_status: FileThumbnailStatusRow", created_at, updated_at FROM files WHERE id = $1 AND deleted_at IS NULL"#,
             id.clone().value()
         )
         .fetch_optional(&*self.db)
@@ -110,7 +197,7 @@ impl FileDataRepository for PgFileDataRepository {
 
         let file_data_list = sqlx::query_as!(
             FileDataRow,
-            r#"SELECT id, name, url, owner_project, created_at, updated_at FROM files WHERE owner_project = $1 AND deleted_at IS NULL"#,
+            r#"SELECT id, name, url, owner_project, size, content_type, checksum, uploader, is_standalone, scan_status AS "scan_status: FileScanStatusRow", thumbnail_status AS "thumbnail_status: FileThumbnailStatusRow", created_at, updated_at FROM files WHERE owner_project = $1 AND deleted_at IS NULL"#,
             owner_project.clone().value()
         )
         .fetch(&*self.db)
@@ -123,6 +210,90 @@ impl FileDataRepository for PgFileDataRepository {
         Ok(file_data_list)
     }
 
+    async fn find_by_scan_status(
+        &self,
+        scan_status: FileScanStatus,
+    ) -> Result<Vec<FileData>, FileDataRepositoryError> {
+        tracing::info!("スキャン状態が{scan_status:?}のファイルデータを取得しています");
+
+        let file_data_list = sqlx::query_as!(
+            FileDataRow,
+            r#"SELECT id, name, url, owner_project, size, content_type, checksum, uploader, is_standalone, scan_status AS "scan_status: FileScanStatusRow", thumbnail_status AS "thumbnail_status: FileThumbnailStatusRow", created_at, updated_at FROM files WHERE scan_status = $1 AND deleted_at IS NULL ORDER BY created_at ASC"#,
+            FileScanStatusRow::from(scan_status) as FileScanStatusRow,
+        )
+        .fetch(&*self.db)
+        .map(|row| FileData::try_from(row?))
+        .try_collect()
+        .await
+        .context("Failed to fetch file data list by scan status")?;
+
+        tracing::info!("スキャン状態が{scan_status:?}のファイルデータの取得が完了しました");
+        Ok(file_data_list)
+    }
+
+    async fn update_scan_status(
+        &self,
+        id: FileId,
+        scan_status: FileScanStatus,
+    ) -> Result<(), FileDataRepositoryError> {
+        tracing::info!("ファイルデータのスキャン状態を更新しています: {id:?}");
+
+        sqlx::query!(
+            r#"UPDATE files SET scan_status = $2 WHERE id = $1 AND deleted_at IS NULL"#,
+            id.clone().value(),
+            FileScanStatusRow::from(scan_status) as FileScanStatusRow,
+        )
+        .execute(&*self.db)
+        .await
+        .context("Failed to update file scan status")?;
+
+        tracing::info!("ファイルデータのスキャン状態の更新が完了しました: {id:?}");
+        Ok(())
+    }
+
+    async fn find_by_thumbnail_status(
+        &self,
+        thumbnail_status: FileThumbnailStatus,
+    ) -> Result<Vec<FileData>, FileDataRepositoryError> {
+        tracing::info!("サムネイルの状態が{thumbnail_status:?}のファイルデータを取得しています");
+
+        let file_data_list = sqlx::query_as!(
+            FileDataRow,
+            r#"SELECT id, name, url, owner_project, size, content_type, checksum, uploader, is_standalone, scan_status AS "scan_status: FileScanStatusRow", thumbnail_status AS "thumbnail_status: FileThumbnailStatusRow", created_at, updated_at FROM files WHERE thumbnail_status = $1 AND deleted_at IS NULL ORDER BY created_at ASC"#,
+            FileThumbnailStatusRow::from(thumbnail_status) as FileThumbnailStatusRow,
+        )
+        .fetch(&*self.db)
+        .map(|row| FileData::try_from(row?))
+        .try_collect()
+        .await
+        .context("Failed to fetch file data list by thumbnail status")?;
+
+        tracing::info!(
+            "サムネイルの状態が{thumbnail_status:?}のファイルデータの取得が完了しました"
+        );
+        Ok(file_data_list)
+    }
+
+    async fn update_thumbnail_status(
+        &self,
+        id: FileId,
+        thumbnail_status: FileThumbnailStatus,
+    ) -> Result<(), FileDataRepositoryError> {
+        tracing::info!("ファイルデータのサムネイルの状態を更新しています: {id:?}");
+
+        sqlx::query!(
+            r#"UPDATE files SET thumbnail_status = $2 WHERE id = $1 AND deleted_at IS NULL"#,
+            id.clone().value(),
+            FileThumbnailStatusRow::from(thumbnail_status) as FileThumbnailStatusRow,
+        )
+        .execute(&*self.db)
+        .await
+        .context("Failed to update file thumbnail status")?;
+
+        tracing::info!("ファイルデータのサムネイルの状態の更新が完了しました: {id:?}");
+        Ok(())
+    }
+
     async fn delete_by_id(&self, id: FileId) -> Result<(), FileDataRepositoryError> {
         tracing::info!("ファイルデータを削除しています: {id:?}");
 
@@ -151,4 +322,52 @@ impl FileDataRepository for PgFileDataRepository {
             .context("Failed to delete file data by owner project")?;
         Ok(())
     }
+
+    async fn delete_as_garbage_by_id(&self, id: FileId) -> Result<(), FileDataRepositoryError> {
+        tracing::info!("参照されていないファイルデータを削除しています: {id:?}");
+
+        sqlx::query!(
+            r#"UPDATE files SET deleted_at = NOW(), garbage_collected_at = NOW() WHERE id = $1 AND deleted_at IS NULL"#,
+            id.clone().value()
+        )
+        .execute(&*self.db)
+        .await
+        .context("Failed to delete file data as garbage")?;
+
+        tracing::info!("参照されていないファイルデータの削除が完了しました: {id:?}");
+        Ok(())
+    }
+
+    async fn find_garbage_deleted_before(
+        &self,
+        deleted_before: DateTime,
+    ) -> Result<Vec<FileData>, FileDataRepositoryError> {
+        tracing::info!("参照されていないとして削除されたファイルデータを取得しています");
+
+        let file_data_list = sqlx::query_as!(
+            FileDataRow,
+            r#"SELECT id, name, url, owner_project, size, content_type, checksum, uploader, is_standalone, scan_status AS "scan_status: FileScanStatusRow", thumbnail_status AS "thumbnail_status: FileThumbnailStatusRow", created_at, updated_at FROM files WHERE garbage_collected_at IS NOT NULL AND deleted_at < $1 ORDER BY deleted_at ASC"#,
+            deleted_before.value()
+        )
+        .fetch(&*self.db)
+        .map(|row| FileData::try_from(row?))
+        .try_collect()
+        .await
+        .context("Failed to fetch garbage file data list")?;
+
+        tracing::info!("参照されていないとして削除されたファイルデータの取得が完了しました");
+        Ok(file_data_list)
+    }
+
+    async fn purge_by_id(&self, id: FileId) -> Result<(), FileDataRepositoryError> {
+        tracing::info!("ファイルデータを完全に削除しています: {id:?}");
+
+        sqlx::query!(r#"DELETE FROM files WHERE id = $1"#, id.clone().value())
+            .execute(&*self.db)
+            .await
+            .context("Failed to purge file data")?;
+
+        tracing::info!("ファイルデータの完全な削除が完了しました: {id:?}");
+        Ok(())
+    }
 }
@@ -0,0 +1,222 @@
+use anyhow::Context;
+use futures_util::{StreamExt, TryStreamExt};
+use sqlx::prelude::*;
+
+use sos24_domain::{
+    entity::{
+        common::datetime::DateTime,
+        file_export::{
+            FileExport, FileExportCount, FileExportError, FileExportId, FileExportStatus,
+        },
+        file_object::FileObjectKey,
+        form::FormId,
+        user::UserId,
+    },
+    repository::file_export::{FileExportRepository, FileExportRepositoryError},
+};
+
+use crate::shared::postgresql::Postgresql;
+
+#[derive(FromRow)]
+pub struct FileExportRow {
+    id: uuid::Uuid,
+    form_id: uuid::Uuid,
+    requested_by: String,
+    status: FileExportStatusRow,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    total: i32,
+    processed: i32,
+    key: Option<String>,
+    error: Option<String>,
+    created_at: chrono::DateTime<chrono::Utc>,
+    updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<FileExportRow> for FileExport {
+    fn from(value: FileExportRow) -> Self {
+        FileExport::new(
+            FileExportId::new(value.id),
+            FormId::new(value.form_id),
+            UserId::new(value.requested_by),
+            FileExportStatus::from(value.status),
+            value.since.map(DateTime::new),
+            FileExportCount::new(value.total),
+            FileExportCount::new(value.processed),
+            value.key.map(FileObjectKey::new),
+            value.error.map(FileExportError::new),
+            DateTime::new(value.created_at),
+            DateTime::new(value.updated_at),
+        )
+    }
+}
+
+#[derive(Type)]
+#[sqlx(type_name = "file_export_status", rename_all = "snake_case")]
+pub enum FileExportStatusRow {
+    Pending,
+    Running,
+    Completed,
+    Failed,
+}
+
+impl From<FileExportStatus> for FileExportStatusRow {
+    fn from(status: FileExportStatus) -> Self {
+        match status {
+            FileExportStatus::Pending => Self::Pending,
+            FileExportStatus::Running => Self::Running,
+            FileExportStatus::Completed => Self::Completed,
+            FileExportStatus::Failed => Self::Failed,
+        }
+    }
+}
+
+impl From<FileExportStatusRow> for FileExportStatus {
+    fn from(status: FileExportStatusRow) -> Self {
+        match status {
+            FileExportStatusRow::Pending => Self::Pending,
+            FileExportStatusRow::Running => Self::Running,
+            FileExportStatusRow::Completed => Self::Completed,
+            FileExportStatusRow::Failed => Self::Failed,
+        }
+    }
+}
+
+pub struct PgFileExportRepository {
+    db: Postgresql,
+}
+
+impl PgFileExportRepository {
+    pub fn new(db: Postgresql) -> Self {
+        Self { db }
+    }
+}
+
+impl FileExportRepository for PgFileExportRepository {
+    async fn create(&self, export: FileExport) -> Result<(), FileExportRepositoryError> {
+        tracing::info!("エクスポートを作成します");
+
+        let export = export.destruct();
+        sqlx::query!(
+            r#"INSERT INTO file_exports (id, form_id, requested_by, status, since, total, processed) VALUES ($1, $2, $3, $4, $5, $6, $7)"#,
+            export.id.value(),
+            export.form_id.value(),
+            export.requested_by.value(),
+            FileExportStatusRow::from(export.status) as FileExportStatusRow,
+            export.since.map(|it| it.value()),
+            export.total.value(),
+            export.processed.value(),
+        )
+        .execute(&*self.db)
+        .await
+        .context("Failed to create file export")?;
+
+        tracing::info!("エクスポートの作成が完了しました");
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        id: FileExportId,
+    ) -> Result<Option<FileExport>, FileExportRepositoryError> {
+        tracing::info!("エクスポートを取得します: {id:?}");
+
+        let export_row = sqlx::query_as!(
+            FileExportRow,
+            r#"SELECT id, form_id, requested_by, status AS "status: FileExportStatusRow", since, total, processed, key, error, created_at, updated_at FROM file_exports WHERE id = $1"#,
+            id.clone().value()
+        )
+        .fetch_optional(&*self.db)
+        .await
+        .context("Failed to fetch file export")?;
+
+        tracing::info!("エクスポートの取得が完了しました: {id:?}");
+        Ok(export_row.map(FileExport::from))
+    }
+
+    async fn find_latest_completed_by_form_id_and_requester(
+        &self,
+        form_id: FormId,
+        requested_by: UserId,
+    ) -> Result<Option<FileExport>, FileExportRepositoryError> {
+        tracing::info!("申請の最新のエクスポートを取得します: {form_id:?}, {requested_by:?}");
+
+        let export_row = sqlx::query_as!(
+            FileExportRow,
+            r#"SELECT id, form_id, requested_by, status AS "status: FileExportStatusRow", since, total, processed, key, error, created_at, updated_at FROM file_exports WHERE form_id = $1 AND requested_by = $2 AND status = 'completed' ORDER BY created_at DESC LIMIT 1"#,
+            form_id.clone().value(),
+            requested_by.clone().value()
+        )
+        .fetch_optional(&*self.db)
+        .await
+        .context("Failed to fetch file export")?;
+
+        tracing::info!(
+            "申請の最新のエクスポートの取得が完了しました: {form_id:?}, {requested_by:?}"
+        );
+        Ok(export_row.map(FileExport::from))
+    }
+
+    async fn find_runnable(
+        &self,
+        stale_before: DateTime,
+    ) -> Result<Vec<FileExport>, FileExportRepositoryError> {
+        tracing::info!("実行可能なエクスポート一覧を取得します");
+
+        let export_list = sqlx::query_as!(
+            FileExportRow,
+            r#"SELECT id, form_id, requested_by, status AS "status: FileExportStatusRow", since, total, processed, key, error, created_at, updated_at FROM file_exports WHERE status = 'pending' OR (status = 'running' AND updated_at < $1) ORDER BY created_at"#,
+            stale_before.value()
+        )
+        .fetch(&*self.db)
+        .map(|row| Ok::<_, anyhow::Error>(FileExport::from(row?)))
+        .try_collect()
+        .await
+        .context("Failed to fetch file export list")?;
+
+        tracing::info!("実行可能なエクスポート一覧の取得が完了しました");
+        Ok(export_list)
+    }
+
+    async fn acquire(
+        &self,
+        id: FileExportId,
+        stale_before: DateTime,
+    ) -> Result<bool, FileExportRepositoryError> {
+        tracing::info!("エクスポートを処理中にします: {id:?}");
+
+        let result = sqlx::query!(
+            r#"UPDATE file_exports SET status = 'running', updated_at = CURRENT_TIMESTAMP WHERE id = $1 AND (status = 'pending' OR (status = 'running' AND updated_at < $2))"#,
+            id.clone().value(),
+            stale_before.value()
+        )
+        .execute(&*self.db)
+        .await
+        .context("Failed to acquire file export")?;
+
+        let acquired = result.rows_affected() > 0;
+        tracing::info!("エクスポートを処理中にしました: {id:?}: {acquired}");
+        Ok(acquired)
+    }
+
+    async fn update(&self, export: FileExport) -> Result<(), FileExportRepositoryError> {
+        tracing::info!("エクスポートを更新します: {:?}", export.id());
+
+        let export = export.destruct();
+        sqlx::query!(
+            r#"UPDATE file_exports SET status = $2, total = $3, processed = $4, key = $5, error = $6, updated_at = $7 WHERE id = $1"#,
+            export.id.clone().value(),
+            FileExportStatusRow::from(export.status) as FileExportStatusRow,
+            export.total.value(),
+            export.processed.value(),
+            export.key.map(|it| it.value()),
+            export.error.map(|it| it.value()),
+            export.updated_at.value(),
+        )
+        .execute(&*self.db)
+        .await
+        .context("Failed to update file export")?;
+
+        tracing::info!("エクスポートの更新が完了しました: {:?}", export.id);
+        Ok(())
+    }
+}
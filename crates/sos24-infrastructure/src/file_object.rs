@@ -4,19 +4,135 @@ use std::time::Duration;
 use anyhow::Context;
 use async_zip::tokio::write::ZipFileWriter;
 use async_zip::{Compression, ZipEntryBuilder};
-use aws_sdk_s3::{presigning::PresigningConfig, primitives::SdkBody};
+use aws_sdk_s3::{
+    error::ProvideErrorMetadata,
+    presigning::PresigningConfig,
+    primitives::SdkBody,
+    types::{CompletedMultipartUpload, CompletedPart},
+};
 use futures_util::future;
-use tokio::io::DuplexStream;
+use sha2::{Digest, Sha256};
+use tokio::io::{AsyncReadExt, AsyncWriteExt, DuplexStream};
+use tokio::sync::mpsc::UnboundedSender;
 use tokio_util::compat::FuturesAsyncWriteCompatExt;
 
 use sos24_domain::entity::file_object::ArchiveEntry;
 use sos24_domain::{
-    entity::file_object::{ContentDisposition, FileObject, FileObjectKey, FileSignedUrl},
+    entity::{
+        file_data::{FileChecksum, FileContentType, FileSize},
+        file_object::{
+            ContentDisposition, FileObject, FileObjectKey, FileObjectMetadata, FileSignedUrl,
+            FileSignedUrlExpiry,
+        },
+    },
     repository::file_object::{FileObjectRepository, FileObjectRepositoryError},
 };
 
 use crate::shared::s3::S3;
 
+// マルチパートアップロードの1パートあたりのサイズ（S3の下限は5MiB）
+const PART_SIZE: u64 = 8 * 1024 * 1024;
+// ファイルの種類を判定するために先頭から読むバイト数
+const SNIFF_SIZE: usize = 8 * 1024;
+// メタデータを求めるためにオブジェクトを読むときのバッファのサイズ
+const READ_BUFFER_SIZE: usize = 64 * 1024;
+
+// 読み出したバイト列から、サイズ・内容から判定した種類・チェックサムを求める
+#[derive(Default)]
+struct ObjectDigest {
+    size: u64,
+    head: Vec<u8>,
+    hasher: Sha256,
+}
+
+impl ObjectDigest {
+    fn update(&mut self, bytes: &[u8]) {
+        self.size += bytes.len() as u64;
+        if self.head.len() < SNIFF_SIZE {
+            let len = (SNIFF_SIZE - self.head.len()).min(bytes.len());
+            self.head.extend_from_slice(&bytes[..len]);
+        }
+        self.hasher.update(bytes);
+    }
+
+    fn finish(self) -> FileObjectMetadata {
+        FileObjectMetadata::new(
+            FileSize::new(self.size as i64),
+            infer::get(&self.head).map(|it| FileContentType::new(it.mime_type().to_string())),
+            FileChecksum::new(hex::encode(self.hasher.finalize())),
+        )
+    }
+}
+
+// 進行中のマルチパートアップロードの宛先
+struct MultipartUpload<'a> {
+    bucket: &'a str,
+    key: &'a str,
+    upload_id: &'a str,
+}
+
+// 完了する前に破棄されたマルチパートアップロードを中断する
+// クライアントの切断などでアップロードのFutureごと破棄された場合も、途中まで送ったパートが残らないようにする
+struct AbortOnDrop {
+    s3: S3,
+    bucket: String,
+    key: String,
+    upload_id: Option<String>,
+}
+
+impl AbortOnDrop {
+    fn new(s3: S3, bucket: String, key: String, upload_id: String) -> Self {
+        Self {
+            s3,
+            bucket,
+            key,
+            upload_id: Some(upload_id),
+        }
+    }
+
+    // アップロードが完了したので中断しない
+    fn disarm(mut self) {
+        self.upload_id = None;
+    }
+
+    async fn abort(mut self) {
+        if let Some(upload_id) = self.upload_id.take() {
+            abort_multipart_upload(&self.s3, &self.bucket, &self.key, &upload_id).await;
+        }
+    }
+}
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        let Some(upload_id) = self.upload_id.take() else {
+            return;
+        };
+        let Ok(handle) = tokio::runtime::Handle::try_current() else {
+            tracing::warn!("Failed to abort multipart upload: no runtime");
+            return;
+        };
+        let s3 = self.s3.clone();
+        let bucket = std::mem::take(&mut self.bucket);
+        let key = std::mem::take(&mut self.key);
+        handle.spawn(async move {
+            abort_multipart_upload(&s3, &bucket, &key, &upload_id).await;
+        });
+    }
+}
+
+async fn abort_multipart_upload(s3: &S3, bucket: &str, key: &str, upload_id: &str) {
+    if let Err(err) = s3
+        .abort_multipart_upload()
+        .bucket(bucket)
+        .key(key)
+        .upload_id(upload_id)
+        .send()
+        .await
+    {
+        tracing::warn!("Failed to abort multipart upload: {err:?}");
+    }
+}
+
 pub struct S3FileObjectRepository {
     s3: S3,
 }
@@ -25,6 +141,58 @@ impl S3FileObjectRepository {
     pub fn new(s3: S3) -> Self {
         Self { s3 }
     }
+
+    async fn upload_parts(
+        &self,
+        upload: &MultipartUpload<'_>,
+        first_part: Vec<u8>,
+        reader: &mut DuplexStream,
+        digest: &mut ObjectDigest,
+        size_limit: u64,
+    ) -> Result<Vec<CompletedPart>, FileObjectRepositoryError> {
+        let mut part = first_part;
+        let mut completed_parts = Vec::new();
+        loop {
+            let part_number = completed_parts.len() as i32 + 1;
+            let output = self
+                .s3
+                .upload_part()
+                .bucket(upload.bucket)
+                .key(upload.key)
+                .upload_id(upload.upload_id)
+                .part_number(part_number)
+                .body(SdkBody::from(part).into())
+                .send()
+                .await
+                .context("Failed to upload part")?;
+            completed_parts.push(
+                CompletedPart::builder()
+                    .set_e_tag(output.e_tag().map(str::to_string))
+                    .part_number(part_number)
+                    .build(),
+            );
+
+            part = read_part(reader).await?;
+            if part.is_empty() {
+                break;
+            }
+            digest.update(&part);
+            if digest.size > size_limit {
+                return Err(FileObjectRepositoryError::SizeLimitExceeded(size_limit));
+            }
+        }
+        Ok(completed_parts)
+    }
+}
+
+async fn read_part(reader: &mut DuplexStream) -> anyhow::Result<Vec<u8>> {
+    let mut part = Vec::new();
+    reader
+        .take(PART_SIZE)
+        .read_to_end(&mut part)
+        .await
+        .context("Failed to read file")?;
+    Ok(part)
 }
 
 impl FileObjectRepository for S3FileObjectRepository {
@@ -49,16 +217,109 @@ impl FileObjectRepository for S3FileObjectRepository {
         Ok(())
     }
 
+    async fn create_stream(
+        &self,
+        bucket: String,
+        key: FileObjectKey,
+        mut reader: DuplexStream,
+        size_limit: u64,
+    ) -> Result<FileObjectMetadata, FileObjectRepositoryError> {
+        tracing::info!("ファイルをS3にストリーミングでアップロードします: {key:?}");
+
+        let mut digest = ObjectDigest::default();
+        let first_part = read_part(&mut reader).await?;
+        digest.update(&first_part);
+        if digest.size > size_limit {
+            return Err(FileObjectRepositoryError::SizeLimitExceeded(size_limit));
+        }
+
+        // 1パートに収まる小さなファイルはそのままアップロードする
+        if digest.size < PART_SIZE {
+            self.s3
+                .put_object()
+                .body(SdkBody::from(first_part).into())
+                .key(key.clone().value())
+                .bucket(bucket)
+                .send()
+                .await
+                .context("failed to create object")?;
+
+            tracing::info!("ファイルのアップロードが完了しました: {key:?}");
+            return Ok(digest.finish());
+        }
+
+        let upload = self
+            .s3
+            .create_multipart_upload()
+            .bucket(&bucket)
+            .key(key.clone().value())
+            .send()
+            .await
+            .context("Failed to create multipart upload")?;
+        let upload_id = upload
+            .upload_id()
+            .context("Upload ID was not returned")?
+            .to_string();
+        let guard = AbortOnDrop::new(
+            self.s3.clone(),
+            bucket.clone(),
+            key.clone().value(),
+            upload_id.clone(),
+        );
+
+        let result = self
+            .upload_parts(
+                &MultipartUpload {
+                    bucket: &bucket,
+                    key: &key.clone().value(),
+                    upload_id: &upload_id,
+                },
+                first_part,
+                &mut reader,
+                &mut digest,
+                size_limit,
+            )
+            .await;
+        let completed_parts = match result {
+            Ok(it) => it,
+            Err(err) => {
+                // 途中まで送ったパートが残らないように破棄する
+                guard.abort().await;
+                return Err(err);
+            }
+        };
+
+        self.s3
+            .complete_multipart_upload()
+            .bucket(&bucket)
+            .key(key.clone().value())
+            .upload_id(&upload_id)
+            .multipart_upload(
+                CompletedMultipartUpload::builder()
+                    .set_parts(Some(completed_parts))
+                    .build(),
+            )
+            .send()
+            .await
+            .context("Failed to complete multipart upload")?;
+        guard.disarm();
+
+        tracing::info!("ファイルのアップロードが完了しました: {key:?}");
+        Ok(digest.finish())
+    }
+
     async fn generate_url(
         &self,
         bucket: String,
         key: FileObjectKey,
         content_disposition: Option<ContentDisposition>,
+        content_type: Option<FileContentType>,
+        expires_in: FileSignedUrlExpiry,
     ) -> Result<FileSignedUrl, FileObjectRepositoryError> {
         tracing::info!("ファイルの署名付きURLを生成します: {key:?}");
 
         let presign_config = PresigningConfig::builder()
-            .expires_in(Duration::new(3000, 0))
+            .expires_in(expires_in.duration())
             .build()
             .context("Failed to build presigning config")?;
         let request = self
@@ -67,6 +328,7 @@ impl FileObjectRepository for S3FileObjectRepository {
             .bucket(bucket)
             .key(key.clone().value())
             .set_response_content_disposition(content_disposition.map(|value| value.value()))
+            .set_response_content_type(content_type.map(|value| value.value()))
             .presigned(presign_config)
             .await
             .context("Failed to generate presign url")?;
@@ -75,11 +337,164 @@ impl FileObjectRepository for S3FileObjectRepository {
         Ok(FileSignedUrl::try_from(request.uri()).context("Failed to parse")?)
     }
 
+    async fn generate_upload_url(
+        &self,
+        bucket: String,
+        key: FileObjectKey,
+        expires_in: Duration,
+    ) -> Result<FileSignedUrl, FileObjectRepositoryError> {
+        tracing::info!("アップロード用の署名付きURLを生成します: {key:?}");
+
+        let presign_config = PresigningConfig::builder()
+            .expires_in(expires_in)
+            .build()
+            .context("Failed to build presigning config")?;
+        let request = self
+            .s3
+            .put_object()
+            .bucket(bucket)
+            .key(key.clone().value())
+            .presigned(presign_config)
+            .await
+            .context("Failed to generate presign url")?;
+
+        tracing::info!("アップロード用の署名付きURLを生成しました: {key:?}");
+        Ok(FileSignedUrl::try_from(request.uri()).context("Failed to parse")?)
+    }
+
+    async fn find_metadata(
+        &self,
+        bucket: String,
+        key: FileObjectKey,
+    ) -> Result<Option<FileObjectMetadata>, FileObjectRepositoryError> {
+        tracing::info!("ファイルのメタデータを取得します: {key:?}");
+
+        let output = match self
+            .s3
+            .get_object()
+            .bucket(bucket)
+            .key(key.clone().value())
+            .send()
+            .await
+        {
+            Ok(output) => output,
+            Err(err) => {
+                let err = err.into_service_error();
+                if err.is_no_such_key() {
+                    tracing::info!("ファイルが存在しませんでした: {key:?}");
+                    return Ok(None);
+                }
+                return Err(anyhow::Error::new(err)
+                    .context("Failed to get object")
+                    .into());
+            }
+        };
+
+        // チェックサムを求めるため、オブジェクトを最後まで読む
+        let content_type = output
+            .content_type()
+            .map(|it| FileContentType::new(it.to_string()));
+        let mut body = output.body.into_async_read();
+        let mut digest = ObjectDigest::default();
+        let mut buf = vec![0; READ_BUFFER_SIZE];
+        loop {
+            let len = body.read(&mut buf).await.context("Failed to read object")?;
+            if len == 0 {
+                break;
+            }
+            digest.update(&buf[..len]);
+        }
+        let metadata = digest.finish().with_fallback_content_type(content_type);
+
+        tracing::info!("ファイルのメタデータを取得しました: {key:?}");
+        Ok(Some(metadata))
+    }
+
+    async fn copy(
+        &self,
+        bucket: String,
+        from: FileObjectKey,
+        to: FileObjectKey,
+    ) -> Result<bool, FileObjectRepositoryError> {
+        tracing::info!("ファイルをコピーします: {from:?} -> {to:?}");
+
+        let result = self
+            .s3
+            .copy_object()
+            .bucket(&bucket)
+            .copy_source(format!("{bucket}/{}", from.clone().value()))
+            .key(to.clone().value())
+            .send()
+            .await;
+        if let Err(err) = result {
+            let err = err.into_service_error();
+            if err.code() == Some("NoSuchKey") {
+                tracing::info!("コピー元のファイルが存在しませんでした: {from:?}");
+                return Ok(false);
+            }
+            return Err(anyhow::Error::new(err)
+                .context("Failed to copy object")
+                .into());
+        }
+
+        tracing::info!("ファイルをコピーしました: {from:?} -> {to:?}");
+        Ok(true)
+    }
+
+    async fn download(
+        &self,
+        bucket: String,
+        key: FileObjectKey,
+        mut writer: DuplexStream,
+    ) -> Result<(), FileObjectRepositoryError> {
+        tracing::info!("ファイルをダウンロードします: {key:?}");
+
+        let output = self
+            .s3
+            .get_object()
+            .bucket(bucket)
+            .key(key.clone().value())
+            .send()
+            .await
+            .context("Failed to get object")?;
+        let mut body = output.body.into_async_read();
+        tokio::io::copy(&mut body, &mut writer)
+            .await
+            .context("Failed to copy object")?;
+        writer
+            .shutdown()
+            .await
+            .context("Failed to shutdown writer")?;
+
+        tracing::info!("ファイルをダウンロードしました: {key:?}");
+        Ok(())
+    }
+
+    async fn delete(
+        &self,
+        bucket: String,
+        key: FileObjectKey,
+    ) -> Result<(), FileObjectRepositoryError> {
+        tracing::info!("ファイルを削除します: {key:?}");
+
+        self.s3
+            .delete_object()
+            .bucket(bucket)
+            .key(key.clone().value())
+            .send()
+            .await
+            .context("Failed to delete object")?;
+
+        tracing::info!("ファイルを削除しました: {key:?}");
+        Ok(())
+    }
+
     async fn create_archive(
         &self,
         bucket: String,
         entry_list: Vec<ArchiveEntry>,
         writer: DuplexStream,
+        progress: Option<UnboundedSender<()>>,
     ) -> Result<(), FileObjectRepositoryError> {
         tracing::info!("ファイルのアーカイブを作成します");
 
@@ -162,6 +577,10 @@ impl FileObjectRepository for S3FileObjectRepository {
                 .context("Failed to close")?;
 
             tracing::info!("ファイルをアーカイブに追加しました: {:?}", temp_file_path);
+            if let Some(ref progress) = progress {
+                // 受信側が先に終了していても、アーカイブの作成は続ける
+                let _ = progress.send(());
+            }
         }
 
         zip_writer.close().await.context("Failed to close")?;
@@ -0,0 +1,108 @@
+use std::time::Duration;
+
+use anyhow::{bail, Context};
+use tokio::io::{AsyncReadExt, AsyncWriteExt, DuplexStream};
+use tokio::net::TcpStream;
+
+use sos24_use_case::shared::adapter::file_scanner::{FileScanner, ScanResult};
+
+// clamdに送る1チャンクあたりのサイズ
+const CHUNK_SIZE: usize = 64 * 1024;
+const TIMEOUT: Duration = Duration::from_secs(300);
+
+// clamdのINSTREAMコマンドでファイルをスキャンする
+// ref: https://docs.clamav.net/manual/Usage/Scanning.html#clamd
+pub struct ClamAvFileScanner {
+    address: Option<String>,
+}
+
+impl ClamAvFileScanner {
+    pub fn new(address: Option<String>) -> Self {
+        Self { address }
+    }
+
+    async fn scan_with_clamd(
+        address: &str,
+        mut reader: DuplexStream,
+    ) -> anyhow::Result<ScanResult> {
+        let mut stream = TcpStream::connect(address)
+            .await
+            .context("Failed to connect to clamd")?;
+        stream
+            .write_all(b"zINSTREAM\0")
+            .await
+            .context("Failed to send command to clamd")?;
+
+        let mut buf = vec![0; CHUNK_SIZE];
+        loop {
+            let len = reader.read(&mut buf).await.context("Failed to read file")?;
+            if len == 0 {
+                break;
+            }
+            stream
+                .write_all(&(len as u32).to_be_bytes())
+                .await
+                .context("Failed to send chunk to clamd")?;
+            stream
+                .write_all(&buf[..len])
+                .await
+                .context("Failed to send chunk to clamd")?;
+        }
+        stream
+            .write_all(&0u32.to_be_bytes())
+            .await
+            .context("Failed to send chunk to clamd")?;
+
+        let mut response = Vec::new();
+        stream
+            .read_to_end(&mut response)
+            .await
+            .context("Failed to read response from clamd")?;
+        let response = String::from_utf8_lossy(&response);
+        parse_response(response.trim_end_matches(['\0', '\n']))
+    }
+}
+
+// "stream: OK" もしくは "stream: {マルウェア名} FOUND" が返ってくる
+// clamdのStreamMaxLength(既定は25MB)を超えると "INSTREAM size limit exceeded. ERROR" が返ってくる
+fn parse_response(response: &str) -> anyhow::Result<ScanResult> {
+    let result = response.strip_prefix("stream: ").unwrap_or(response);
+    if result == "OK" {
+        return Ok(ScanResult::Clean);
+    }
+    if let Some(signature) = result.strip_suffix(" FOUND") {
+        return Ok(ScanResult::Infected(signature.to_string()));
+    }
+    if result.starts_with("INSTREAM size limit exceeded") {
+        return Ok(ScanResult::TooLarge);
+    }
+    bail!("Unexpected response from clamd: {response}")
+}
+
+impl FileScanner for ClamAvFileScanner {
+    fn is_enabled(&self) -> bool {
+        self.address.is_some()
+    }
+
+    async fn scan(&self, mut reader: DuplexStream) -> anyhow::Result<ScanResult> {
+        tracing::info!("ファイルをスキャンします");
+
+        let Some(ref address) = self.address else {
+            // 書き込み側が詰まらないよう、最後まで読み捨てる
+            tokio::io::copy(&mut reader, &mut tokio::io::sink())
+                .await
+                .context("Failed to read file")?;
+            tracing::warn!(
+                "clamdのアドレスが設定されていないため、スキャンせずに問題なしとしました"
+            );
+            return Ok(ScanResult::Clean);
+        };
+
+        let result = tokio::time::timeout(TIMEOUT, Self::scan_with_clamd(address, reader))
+            .await
+            .context("Timed out scanning file")??;
+
+        tracing::info!("ファイルをスキャンしました: {result:?}");
+        Ok(result)
+    }
+}
@@ -0,0 +1,133 @@
+use anyhow::Context;
+use futures_util::{StreamExt, TryStreamExt};
+use sqlx::prelude::*;
+
+use sos24_domain::{
+    entity::{
+        common::datetime::DateTime,
+        file_data::{FileId, FileIsStandalone, FileName},
+        file_object::FileObjectKey,
+        file_upload::FileUpload,
+        project::ProjectId,
+        user::UserId,
+    },
+    repository::file_upload::{FileUploadRepository, FileUploadRepositoryError},
+};
+
+use crate::shared::postgresql::Postgresql;
+
+#[derive(FromRow)]
+pub struct FileUploadRow {
+    id: uuid::Uuid,
+    name: String,
+    url: String,
+    owner_project: Option<uuid::Uuid>,
+    uploader: String,
+    is_standalone: bool,
+    expires_at: chrono::DateTime<chrono::Utc>,
+    created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<FileUploadRow> for FileUpload {
+    fn from(value: FileUploadRow) -> Self {
+        FileUpload::new(
+            FileId::new(value.id),
+            FileName::sanitized(value.name),
+            FileObjectKey::new(value.url),
+            value.owner_project.map(ProjectId::new),
+            UserId::new(value.uploader),
+            FileIsStandalone::new(value.is_standalone),
+            DateTime::new(value.expires_at),
+            DateTime::new(value.created_at),
+        )
+    }
+}
+
+pub struct PgFileUploadRepository {
+    db: Postgresql,
+}
+
+impl PgFileUploadRepository {
+    pub fn new(db: Postgresql) -> Self {
+        Self { db }
+    }
+}
+
+impl FileUploadRepository for PgFileUploadRepository {
+    async fn create(&self, file_upload: FileUpload) -> Result<(), FileUploadRepositoryError> {
+        tracing::info!("アップロードを作成しています");
+
+        let file_upload = file_upload.destruct();
+        sqlx::query!(
+            r#"INSERT INTO file_uploads (id, name, url, owner_project, uploader, is_standalone, expires_at) VALUES ($1, $2, $3, $4, $5, $6, $7)"#,
+            file_upload.id.value(),
+            file_upload.filename.value(),
+            file_upload.key.value(),
+            file_upload.owner.map(|it| it.value()),
+            file_upload.uploader.value(),
+            file_upload.is_standalone.value(),
+            file_upload.expires_at.value()
+        )
+        .execute(&*self.db)
+        .await
+        .context("Failed to create file upload")?;
+
+        tracing::info!("アップロードの作成が完了しました");
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        id: FileId,
+    ) -> Result<Option<FileUpload>, FileUploadRepositoryError> {
+        tracing::info!("アップロードを取得しています: {id:?}");
+
+        let file_upload_row = sqlx::query_as!(
+            FileUploadRow,
+            r#"SELECT id, name, url, owner_project, uploader, is_standalone, expires_at, created_at FROM file_uploads WHERE id = $1"#,
+            id.clone().value()
+        )
+        .fetch_optional(&*self.db)
+        .await
+        .context("Failed to fetch file upload")?;
+
+        tracing::info!("アップロードの取得が完了しました: {id:?}");
+        Ok(file_upload_row.map(FileUpload::from))
+    }
+
+    async fn find_expired(
+        &self,
+        now: DateTime,
+    ) -> Result<Vec<FileUpload>, FileUploadRepositoryError> {
+        tracing::info!("期限切れのアップロード一覧を取得しています");
+
+        let file_upload_list = sqlx::query_as!(
+            FileUploadRow,
+            r#"SELECT id, name, url, owner_project, uploader, is_standalone, expires_at, created_at FROM file_uploads WHERE expires_at <= $1"#,
+            now.value()
+        )
+        .fetch(&*self.db)
+        .map(|row| Ok::<_, anyhow::Error>(FileUpload::from(row?)))
+        .try_collect()
+        .await
+        .context("Failed to fetch expired file upload list")?;
+
+        tracing::info!("期限切れのアップロード一覧の取得が完了しました");
+        Ok(file_upload_list)
+    }
+
+    async fn delete_by_id(&self, id: FileId) -> Result<(), FileUploadRepositoryError> {
+        tracing::info!("アップロードを削除しています: {id:?}");
+
+        sqlx::query!(
+            r#"DELETE FROM file_uploads WHERE id = $1"#,
+            id.clone().value()
+        )
+        .execute(&*self.db)
+        .await
+        .context("Failed to delete file upload")?;
+
+        tracing::info!("アップロードの削除が完了しました: {id:?}");
+        Ok(())
+    }
+}
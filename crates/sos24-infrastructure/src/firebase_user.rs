@@ -1,10 +1,11 @@
+use rs_firebase_admin_sdk::auth::oob_code::{OobCodeAction, OobCodeActionType};
 use rs_firebase_admin_sdk::auth::UserUpdate;
 use rs_firebase_admin_sdk::{
     auth::{FirebaseAuthService, NewUser},
     client::error::ApiClientError,
 };
 
-use sos24_domain::entity::firebase_user::FirebaseUserEmail;
+use sos24_domain::entity::firebase_user::{FirebaseUserEmail, FirebaseUserPassword};
 use sos24_domain::{
     entity::firebase_user::{FirebaseUserId, NewFirebaseUser},
     repository::firebase_user::{FirebaseUserRepository, FirebaseUserRepositoryError},
@@ -88,4 +89,34 @@ impl FirebaseUserRepository for FirebaseUserRepositoryImpl {
             Err(err) => Err(anyhow::anyhow!("Failed to delete firebase user: {err}").into()),
         }
     }
+
+    async fn generate_password_reset_link(
+        &self,
+        email: FirebaseUserEmail,
+    ) -> Result<String, FirebaseUserRepositoryError> {
+        tracing::info!("Firebaseのユーザーのパスワード再設定リンクを発行します");
+
+        let action =
+            OobCodeAction::builder(OobCodeActionType::PasswordReset, email.value()).build();
+        let res = self.auth.generate_email_action_link(action).await;
+
+        match res {
+            Ok(link) => {
+                tracing::info!("Firebaseのユーザーのパスワード再設定リンクの発行が完了しました");
+                Ok(link)
+            }
+            Err(err) => {
+                Err(anyhow::anyhow!("Failed to generate password reset link: {err}").into())
+            }
+        }
+    }
+
+    async fn verify_password(
+        &self,
+        _email: FirebaseUserEmail,
+        _password: FirebaseUserPassword,
+    ) -> Result<Option<FirebaseUserId>, FirebaseUserRepositoryError> {
+        // Firebaseではクライアントが直接サインインしてIDトークンを取得する
+        Err(anyhow::anyhow!("Password sign-in is not supported with Firebase").into())
+    }
 }
@@ -7,7 +7,7 @@ use mongodb::{
 use serde::{Deserialize, Serialize};
 
 use sos24_domain::entity::form::{FormIsDraft, FormItemExtension, FormItemId};
-use sos24_domain::entity::project::{ProjectAttributes, ProjectCategories};
+use sos24_domain::entity::project::{ProjectAttributes, ProjectCategories, ProjectId};
 use sos24_domain::entity::{file_data::FileId, form::FormIsNotified};
 use sos24_domain::{
     entity::{
@@ -15,8 +15,8 @@ use sos24_domain::{
         form::{
             Form, FormDescription, FormId, FormItem, FormItemAllowNewline, FormItemDescription,
             FormItemKind, FormItemLimit, FormItemMax, FormItemMaxLength, FormItemMaxSelection,
-            FormItemMin, FormItemMinLength, FormItemMinSelection, FormItemName, FormItemOption,
-            FormItemRequired, FormTitle,
+            FormItemMaxSize, FormItemMin, FormItemMinLength, FormItemMinSelection, FormItemName,
+            FormItemOption, FormItemRequired, FormTitle,
         },
     },
     repository::form::{FormRepository, FormRepositoryError},
@@ -34,6 +34,11 @@ pub struct FormDoc {
     ends_at: chrono::DateTime<chrono::Utc>,
     categories: i32,
     attributes: i32,
+    // 個別指定・除外機能の追加前に作成されたドキュメントにはフィールドが存在しない
+    #[serde(default)]
+    included_projects: Vec<String>,
+    #[serde(default)]
+    excluded_projects: Vec<String>,
     is_notified: bool,
     items: Vec<FormItemDoc>,
     attachments: Vec<String>,
@@ -53,6 +58,16 @@ impl From<Form> for FormDoc {
             ends_at: form.ends_at.value(),
             categories: form.categories.bits() as i32,
             attributes: form.attributes.bits() as i32,
+            included_projects: form
+                .included_projects
+                .into_iter()
+                .map(|it| it.value().to_string())
+                .collect(),
+            excluded_projects: form
+                .excluded_projects
+                .into_iter()
+                .map(|it| it.value().to_string())
+                .collect(),
             items: form.items.into_iter().map(FormItemDoc::from).collect(),
             attachments: form
                 .attachments
@@ -80,6 +95,16 @@ impl TryFrom<FormDoc> for Form {
                 .ok_or(anyhow!("cannot convert project categories"))?,
             ProjectAttributes::from_bits(value.attributes as u32)
                 .ok_or(anyhow!("cannot convert project attributes"))?,
+            value
+                .included_projects
+                .into_iter()
+                .map(ProjectId::try_from)
+                .collect::<Result<_, _>>()?,
+            value
+                .excluded_projects
+                .into_iter()
+                .map(ProjectId::try_from)
+                .collect::<Result<_, _>>()?,
             FormIsNotified::new(value.is_notified),
             value
                 .items
@@ -154,6 +179,7 @@ pub enum FormItemKindDoc {
     File {
         extensions: Option<Vec<String>>,
         limit: Option<u32>,
+        max_size: Option<u64>,
     },
 }
 
@@ -196,6 +222,7 @@ impl From<FormItemKind> for FormItemKindDoc {
                         .extensions
                         .map(|it| it.into_iter().map(|it| it.value()).collect()),
                     limit: item.limit.map(|it| it.value()),
+                    max_size: item.max_size.map(|it| it.value()),
                 }
             }
         }
@@ -231,9 +258,14 @@ impl TryFrom<FormItemKindDoc> for FormItemKind {
                 min_selection.map(FormItemMinSelection::new),
                 max_selection.map(FormItemMaxSelection::new),
             )?),
-            FormItemKindDoc::File { extensions, limit } => Ok(FormItemKind::new_file(
+            FormItemKindDoc::File {
+                extensions,
+                limit,
+                max_size,
+            } => Ok(FormItemKind::new_file(
                 extensions.map(|it| it.into_iter().map(FormItemExtension::new).collect()),
                 limit.map(FormItemLimit::new),
+                max_size.map(FormItemMaxSize::new),
             )),
         }
     }
@@ -316,6 +348,8 @@ impl FormRepository for MongoFormRepository {
                         "ends_at":bson::to_bson(&form_doc.ends_at).unwrap(),
                         "categories": bson::to_bson(&form_doc.categories).unwrap(),
                         "attributes": bson::to_bson(&form_doc.attributes).unwrap(),
+                        "included_projects": bson::to_bson(&form_doc.included_projects).unwrap(),
+                        "excluded_projects": bson::to_bson(&form_doc.excluded_projects).unwrap(),
                         "attachments": bson::to_bson(&form_doc.attachments).unwrap(),
                         "is_notified": bson::to_bson(&form_doc.is_notified).unwrap(),
                         "items": bson::to_bson(&form_doc.items).unwrap(),
@@ -0,0 +1,83 @@
+use anyhow::Context;
+use futures_util::{StreamExt, TryStreamExt};
+use sqlx::prelude::*;
+
+use sos24_domain::{
+    entity::{
+        common::datetime::DateTime,
+        impersonation_log::{ImpersonationLog, ImpersonationLogId, ImpersonationLogRequest},
+        user::UserId,
+    },
+    repository::impersonation_log::{ImpersonationLogRepository, ImpersonationLogRepositoryError},
+};
+
+use crate::shared::postgresql::Postgresql;
+
+#[derive(FromRow)]
+pub struct ImpersonationLogRow {
+    id: uuid::Uuid,
+    impersonator_id: String,
+    user_id: String,
+    request: String,
+    created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<ImpersonationLogRow> for ImpersonationLog {
+    fn from(value: ImpersonationLogRow) -> Self {
+        ImpersonationLog::new(
+            ImpersonationLogId::new(value.id),
+            UserId::new(value.impersonator_id),
+            UserId::new(value.user_id),
+            ImpersonationLogRequest::new(value.request),
+            DateTime::new(value.created_at),
+        )
+    }
+}
+
+pub struct PgImpersonationLogRepository {
+    db: Postgresql,
+}
+
+impl PgImpersonationLogRepository {
+    pub fn new(db: Postgresql) -> Self {
+        Self { db }
+    }
+}
+
+impl ImpersonationLogRepository for PgImpersonationLogRepository {
+    async fn list(&self) -> Result<Vec<ImpersonationLog>, ImpersonationLogRepositoryError> {
+        tracing::info!("なりすましの記録一覧を取得します");
+
+        let log_list = sqlx::query_as!(
+            ImpersonationLogRow,
+            r#"SELECT id, impersonator_id, user_id, request, created_at FROM impersonation_logs ORDER BY created_at DESC"#
+        )
+        .fetch(&*self.db)
+        .map(|row| Ok::<_, anyhow::Error>(ImpersonationLog::from(row?)))
+        .try_collect()
+        .await
+        .context("Failed to fetch impersonation log list")?;
+
+        tracing::info!("なりすましの記録一覧を取得しました");
+        Ok(log_list)
+    }
+
+    async fn create(&self, log: ImpersonationLog) -> Result<(), ImpersonationLogRepositoryError> {
+        tracing::info!("なりすましの記録を作成します");
+
+        let log = log.destruct();
+        sqlx::query!(
+            r#"INSERT INTO impersonation_logs (id, impersonator_id, user_id, request) VALUES ($1, $2, $3, $4)"#,
+            log.id.value(),
+            log.impersonator_id.value(),
+            log.user_id.value(),
+            log.request.value(),
+        )
+        .execute(&*self.db)
+        .await
+        .context("Failed to create impersonation log")?;
+
+        tracing::info!("なりすましの記録を作成しました");
+        Ok(())
+    }
+}
@@ -1,71 +1,122 @@
+use api_token::PgApiTokenRepository;
+use auth_user::{AuthBackend, AuthUserRepository};
+use custom_role::PgCustomRoleRepository;
 use email::SendGridEmailSender;
+use event::BroadcastEventBus;
 use file_data::PgFileDataRepository;
+use file_export::PgFileExportRepository;
 use file_object::S3FileObjectRepository;
-use firebase_user::FirebaseUserRepositoryImpl;
+use file_scanner::ClamAvFileScanner;
+use file_upload::PgFileUploadRepository;
 use form::MongoFormRepository;
 use form_answer::MongoFormAnswerRepository;
+use impersonation_log::PgImpersonationLogRepository;
 use invitation::PgInvitationRepository;
 use news::PgNewsRepository;
-use notification::SlackNotifier;
+use notification::{DefaultNotifier, PgNotificationRepository};
 use project::PgProjectRepository;
-use shared::{
-    firebase::FirebaseAuth, mongodb::MongoDb, postgresql::Postgresql, s3::S3, sendgrid::SendGrid,
-};
+use project_storage_quota::PgProjectStorageQuotaRepository;
+use shared::{mongodb::MongoDb, postgresql::Postgresql, s3::S3, sendgrid::SendGrid};
 use sos24_domain::repository::Repositories;
 use sos24_use_case::shared::adapter::Adapters;
+use thumbnail::VipsThumbnailGenerator;
 use user::PgUserRepository;
+use webhook::{HttpWebhookSender, PgWebhookRepository};
+use webhook_delivery::PgWebhookDeliveryRepository;
 
+pub mod api_token;
+pub mod auth_user;
+pub mod custom_role;
 pub mod email;
+pub mod event;
 pub mod file_data;
+pub mod file_export;
 pub mod file_object;
+pub mod file_scanner;
+pub mod file_upload;
 pub mod firebase_user;
 pub mod form;
 pub mod form_answer;
+pub mod impersonation_log;
 pub mod invitation;
+pub mod local_user;
 pub mod news;
 pub mod notification;
 pub mod project;
+pub mod project_storage_quota;
 pub mod shared;
+pub mod thumbnail;
 pub mod user;
+pub mod webhook;
+pub mod webhook_delivery;
 
 pub struct DefaultRepositories {
-    firebase_user_repository: FirebaseUserRepositoryImpl,
+    firebase_user_repository: AuthUserRepository,
     form_repository: MongoFormRepository,
     form_answer_repository: MongoFormAnswerRepository,
     invitation_repository: PgInvitationRepository,
     news_repository: PgNewsRepository,
+    notification_repository: PgNotificationRepository,
     project_repository: PgProjectRepository,
     file_data_repository: PgFileDataRepository,
     user_repository: PgUserRepository,
     file_object_repository: S3FileObjectRepository,
+    webhook_repository: PgWebhookRepository,
+    webhook_delivery_repository: PgWebhookDeliveryRepository,
+    file_upload_repository: PgFileUploadRepository,
+    project_storage_quota_repository: PgProjectStorageQuotaRepository,
+    file_export_repository: PgFileExportRepository,
+    api_token_repository: PgApiTokenRepository,
+    custom_role_repository: PgCustomRoleRepository,
+    impersonation_log_repository: PgImpersonationLogRepository,
 }
 
 impl DefaultRepositories {
-    pub fn new(postgresql: Postgresql, mongodb: MongoDb, auth: FirebaseAuth, s3: S3) -> Self {
+    pub fn new(postgresql: Postgresql, mongodb: MongoDb, auth: AuthBackend, s3: S3) -> Self {
         Self {
-            firebase_user_repository: FirebaseUserRepositoryImpl::new(auth),
+            firebase_user_repository: AuthUserRepository::new(auth, postgresql.clone()),
             form_repository: MongoFormRepository::new(mongodb.clone()),
             form_answer_repository: MongoFormAnswerRepository::new(mongodb.clone()),
             invitation_repository: PgInvitationRepository::new(postgresql.clone()),
             news_repository: PgNewsRepository::new(postgresql.clone()),
+            notification_repository: PgNotificationRepository::new(postgresql.clone()),
             project_repository: PgProjectRepository::new(postgresql.clone()),
             file_data_repository: PgFileDataRepository::new(postgresql.clone()),
             user_repository: PgUserRepository::new(postgresql.clone()),
             file_object_repository: S3FileObjectRepository::new(s3.clone()),
+            webhook_repository: PgWebhookRepository::new(postgresql.clone()),
+            webhook_delivery_repository: PgWebhookDeliveryRepository::new(postgresql.clone()),
+            file_upload_repository: PgFileUploadRepository::new(postgresql.clone()),
+            file_export_repository: PgFileExportRepository::new(postgresql.clone()),
+            api_token_repository: PgApiTokenRepository::new(postgresql.clone()),
+            custom_role_repository: PgCustomRoleRepository::new(postgresql.clone()),
+            impersonation_log_repository: PgImpersonationLogRepository::new(postgresql.clone()),
+            project_storage_quota_repository: PgProjectStorageQuotaRepository::new(
+                postgresql.clone(),
+            ),
         }
     }
 }
 
 impl Repositories for DefaultRepositories {
-    type FirebaseUserRepositoryImpl = FirebaseUserRepositoryImpl;
+    type FirebaseUserRepositoryImpl = AuthUserRepository;
     type FormRepositoryImpl = MongoFormRepository;
     type FormAnswerRepositoryImpl = MongoFormAnswerRepository;
     type InvitationRepositoryImpl = PgInvitationRepository;
     type NewsRepositoryImpl = PgNewsRepository;
+    type NotificationRepositoryImpl = PgNotificationRepository;
     type ProjectRepositoryImpl = PgProjectRepository;
     type FileDataRepositoryImpl = PgFileDataRepository;
     type FileObjectRepositoryImpl = S3FileObjectRepository;
     type UserRepositoryImpl = PgUserRepository;
+    type WebhookRepositoryImpl = PgWebhookRepository;
+    type WebhookDeliveryRepositoryImpl = PgWebhookDeliveryRepository;
+    type FileUploadRepositoryImpl = PgFileUploadRepository;
+    type ProjectStorageQuotaRepositoryImpl = PgProjectStorageQuotaRepository;
+    type FileExportRepositoryImpl = PgFileExportRepository;
+    type ApiTokenRepositoryImpl = PgApiTokenRepository;
+    type CustomRoleRepositoryImpl = PgCustomRoleRepository;
+    type ImpersonationLogRepositoryImpl = PgImpersonationLogRepository;
 
     fn firebase_user_repository(&self) -> &Self::FirebaseUserRepositoryImpl {
         &self.firebase_user_repository
@@ -87,6 +138,10 @@ impl Repositories for DefaultRepositories {
         &self.news_repository
     }
 
+    fn notification_repository(&self) -> &Self::NotificationRepositoryImpl {
+        &self.notification_repository
+    }
+
     fn project_repository(&self) -> &Self::ProjectRepositoryImpl {
         &self.project_repository
     }
@@ -102,31 +157,96 @@ impl Repositories for DefaultRepositories {
     fn user_repository(&self) -> &Self::UserRepositoryImpl {
         &self.user_repository
     }
+
+    fn webhook_repository(&self) -> &Self::WebhookRepositoryImpl {
+        &self.webhook_repository
+    }
+
+    fn webhook_delivery_repository(&self) -> &Self::WebhookDeliveryRepositoryImpl {
+        &self.webhook_delivery_repository
+    }
+
+    fn file_upload_repository(&self) -> &Self::FileUploadRepositoryImpl {
+        &self.file_upload_repository
+    }
+
+    fn project_storage_quota_repository(&self) -> &Self::ProjectStorageQuotaRepositoryImpl {
+        &self.project_storage_quota_repository
+    }
+
+    fn file_export_repository(&self) -> &Self::FileExportRepositoryImpl {
+        &self.file_export_repository
+    }
+
+    fn api_token_repository(&self) -> &Self::ApiTokenRepositoryImpl {
+        &self.api_token_repository
+    }
+
+    fn custom_role_repository(&self) -> &Self::CustomRoleRepositoryImpl {
+        &self.custom_role_repository
+    }
+
+    fn impersonation_log_repository(&self) -> &Self::ImpersonationLogRepositoryImpl {
+        &self.impersonation_log_repository
+    }
 }
 
 pub struct DefaultAdapters {
     email_sender: SendGridEmailSender,
-    notifier: SlackNotifier,
+    event_bus: BroadcastEventBus,
+    file_scanner: ClamAvFileScanner,
+    notifier: DefaultNotifier,
+    thumbnail_generator: VipsThumbnailGenerator,
+    webhook_sender: HttpWebhookSender,
 }
 
 impl DefaultAdapters {
-    pub fn new(send_grid: SendGrid, slack_webhook_url: Option<String>) -> Self {
+    pub fn new(
+        send_grid: SendGrid,
+        clamav_address: Option<String>,
+        postgresql: Postgresql,
+    ) -> Self {
+        let event_bus = BroadcastEventBus::new();
         Self {
             email_sender: SendGridEmailSender::new(send_grid),
-            notifier: SlackNotifier::new(slack_webhook_url),
+            event_bus: event_bus.clone(),
+            file_scanner: ClamAvFileScanner::new(clamav_address),
+            notifier: DefaultNotifier::new(event_bus, postgresql),
+            thumbnail_generator: VipsThumbnailGenerator::new(),
+            webhook_sender: HttpWebhookSender::new(),
         }
     }
 }
 
 impl Adapters for DefaultAdapters {
     type EmailSenderImpl = SendGridEmailSender;
-    type NotifierImpl = SlackNotifier;
+    type EventBusImpl = BroadcastEventBus;
+    type FileScannerImpl = ClamAvFileScanner;
+    type NotifierImpl = DefaultNotifier;
+    type ThumbnailGeneratorImpl = VipsThumbnailGenerator;
+    type WebhookSenderImpl = HttpWebhookSender;
 
     fn email_sender(&self) -> &Self::EmailSenderImpl {
         &self.email_sender
     }
 
+    fn event_bus(&self) -> &Self::EventBusImpl {
+        &self.event_bus
+    }
+
+    fn file_scanner(&self) -> &Self::FileScannerImpl {
+        &self.file_scanner
+    }
+
     fn notifier(&self) -> &Self::NotifierImpl {
         &self.notifier
     }
+
+    fn thumbnail_generator(&self) -> &Self::ThumbnailGeneratorImpl {
+        &self.thumbnail_generator
+    }
+
+    fn webhook_sender(&self) -> &Self::WebhookSenderImpl {
+        &self.webhook_sender
+    }
 }
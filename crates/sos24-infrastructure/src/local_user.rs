@@ -0,0 +1,196 @@
+use anyhow::Context;
+use hmac::Hmac;
+use sha2::Sha256;
+
+use sos24_domain::{
+    entity::firebase_user::{
+        FirebaseUserEmail, FirebaseUserId, FirebaseUserPassword, NewFirebaseUser,
+    },
+    repository::firebase_user::{FirebaseUserRepository, FirebaseUserRepositoryError},
+};
+
+use crate::shared::postgresql::Postgresql;
+
+const HASH_SCHEME: &str = "pbkdf2-sha256";
+const HASH_ROUNDS: u32 = 100_000;
+const HASH_LENGTH: usize = 32;
+// Firebase Authentication と同じ基準にする
+const MIN_PASSWORD_LENGTH: usize = 6;
+
+/// Firebaseを利用せずにPostgreSQLで認証情報を管理する実装
+/// 開発環境やテストでオフラインにサーバーを起動するために用いる
+pub struct PgLocalUserRepository {
+    db: Postgresql,
+}
+
+impl PgLocalUserRepository {
+    pub fn new(db: Postgresql) -> Self {
+        Self { db }
+    }
+}
+
+impl FirebaseUserRepository for PgLocalUserRepository {
+    async fn create(
+        &self,
+        new_firebase_user: NewFirebaseUser,
+    ) -> Result<FirebaseUserId, FirebaseUserRepositoryError> {
+        tracing::info!("ローカルのユーザーを作成します");
+
+        let new_firebase_user = new_firebase_user.destruct();
+        let password = new_firebase_user.password.value();
+        if password.chars().count() < MIN_PASSWORD_LENGTH {
+            return Err(FirebaseUserRepositoryError::WeakPassword);
+        }
+
+        let password_hash = tokio::task::spawn_blocking(move || hash_password(&password))
+            .await
+            .context("Failed to hash password")?;
+
+        let id = uuid::Uuid::new_v4().simple().to_string();
+        let created = sqlx::query_scalar!(
+            r#"INSERT INTO local_users (id, email, password_hash) VALUES ($1, $2, $3) ON CONFLICT (email) DO NOTHING RETURNING id"#,
+            id,
+            new_firebase_user.email.clone().value(),
+            password_hash,
+        )
+        .fetch_optional(&*self.db)
+        .await
+        .context("Failed to create local user")?;
+
+        let Some(id) = created else {
+            return Err(FirebaseUserRepositoryError::EmailExists(
+                new_firebase_user.email,
+            ));
+        };
+
+        tracing::info!("ローカルのユーザー作成が完了しました");
+        Ok(FirebaseUserId::new(id))
+    }
+
+    async fn update_email_by_id(
+        &self,
+        id: FirebaseUserId,
+        email: FirebaseUserEmail,
+    ) -> Result<(), FirebaseUserRepositoryError> {
+        tracing::info!("ローカルのユーザーのメールアドレスを更新します: {id:?}");
+
+        let res = sqlx::query!(
+            r#"UPDATE local_users SET email = $2 WHERE id = $1"#,
+            id.clone().value(),
+            email.clone().value(),
+        )
+        .execute(&*self.db)
+        .await;
+
+        match res {
+            Ok(_) => {
+                tracing::info!("ローカルのユーザーのメールアドレスの更新が完了しました: {id:?}");
+                Ok(())
+            }
+            Err(e) => match e.as_database_error() {
+                Some(e) if e.constraint() == Some("local_users_email_key") => {
+                    Err(FirebaseUserRepositoryError::EmailExists(email))
+                }
+                _ => Err(anyhow::anyhow!("Failed to update local user: {e}").into()),
+            },
+        }
+    }
+
+    async fn delete_by_id(&self, id: FirebaseUserId) -> Result<(), FirebaseUserRepositoryError> {
+        tracing::info!("ローカルのユーザーを削除します: {id:?}");
+
+        sqlx::query!(
+            r#"DELETE FROM local_users WHERE id = $1"#,
+            id.clone().value()
+        )
+        .execute(&*self.db)
+        .await
+        .context("Failed to delete local user")?;
+
+        tracing::info!("ローカルのユーザーの削除が完了しました: {id:?}");
+        Ok(())
+    }
+
+    async fn generate_password_reset_link(
+        &self,
+        _email: FirebaseUserEmail,
+    ) -> Result<String, FirebaseUserRepositoryError> {
+        // ローカルの認証基盤にはパスワードを再設定する仕組みがない
+        Err(
+            anyhow::anyhow!("Password reset links are not supported with the local auth backend")
+                .into(),
+        )
+    }
+
+    async fn verify_password(
+        &self,
+        email: FirebaseUserEmail,
+        password: FirebaseUserPassword,
+    ) -> Result<Option<FirebaseUserId>, FirebaseUserRepositoryError> {
+        tracing::info!("ローカルのユーザーのパスワードを検証します");
+
+        let user = sqlx::query!(
+            r#"SELECT id, password_hash FROM local_users WHERE email = $1"#,
+            email.value()
+        )
+        .fetch_optional(&*self.db)
+        .await
+        .context("Failed to fetch local user")?;
+
+        let Some(user) = user else {
+            return Ok(None);
+        };
+
+        let password = password.value();
+        let password_hash = user.password_hash;
+        let verified =
+            tokio::task::spawn_blocking(move || verify_password(&password, &password_hash))
+                .await
+                .context("Failed to verify password")??;
+
+        tracing::info!("ローカルのユーザーのパスワードの検証が完了しました");
+        Ok(verified.then(|| FirebaseUserId::new(user.id)))
+    }
+}
+
+// `pbkdf2-sha256$<rounds>$<salt>$<hash>` の形式で保存する
+fn hash_password(password: &str) -> String {
+    let salt = uuid::Uuid::new_v4();
+    let hash = derive_key(password, salt.as_bytes(), HASH_ROUNDS);
+    format!(
+        "{HASH_SCHEME}${HASH_ROUNDS}${}${}",
+        hex::encode(salt.as_bytes()),
+        hex::encode(hash)
+    )
+}
+
+fn verify_password(password: &str, password_hash: &str) -> anyhow::Result<bool> {
+    let mut parts = password_hash.split('$');
+    let (Some(HASH_SCHEME), Some(rounds), Some(salt), Some(hash), None) = (
+        parts.next(),
+        parts.next(),
+        parts.next(),
+        parts.next(),
+        parts.next(),
+    ) else {
+        anyhow::bail!("Unsupported password hash format");
+    };
+
+    let rounds = rounds.parse().context("Invalid password hash rounds")?;
+    let salt = hex::decode(salt).context("Invalid password hash salt")?;
+    let hash = hex::decode(hash).context("Invalid password hash")?;
+
+    let derived = derive_key(password, &salt, rounds);
+    // 比較にかかる時間から一致した長さを推測されないようにする
+    let diff = derived
+        .iter()
+        .zip(hash.iter())
+        .fold(0u8, |acc, (a, b)| acc | (a ^ b));
+    Ok(derived.len() == hash.len() && diff == 0)
+}
+
+fn derive_key(password: &str, salt: &[u8], rounds: u32) -> [u8; HASH_LENGTH] {
+    let mut key = [0u8; HASH_LENGTH];
+    pbkdf2::pbkdf2::<Hmac<Sha256>>(password.as_bytes(), salt, rounds, &mut key);
+    key
+}
@@ -5,7 +5,7 @@ use sqlx::prelude::*;
 
 use sos24_domain::entity::file_data::FileId;
 use sos24_domain::entity::news::{News, NewsBody, NewsId, NewsState, NewsTitle};
-use sos24_domain::entity::project::{ProjectAttributes, ProjectCategories};
+use sos24_domain::entity::project::{ProjectAttributes, ProjectCategories, ProjectId};
 use sos24_domain::repository::news::{NewsRepository, NewsRepositoryError};
 use sqlx::types::chrono;
 
@@ -20,6 +20,8 @@ pub struct NewsRow {
     attachments: Vec<uuid::Uuid>,
     categories: i32,
     attributes: i32,
+    included_projects: Vec<uuid::Uuid>,
+    excluded_projects: Vec<uuid::Uuid>,
     created_at: chrono::DateTime<chrono::Utc>,
     updated_at: chrono::DateTime<chrono::Utc>,
     scheduled_at: Option<chrono::DateTime<chrono::Utc>>, // SQL NULL maps Rust Option::None,
@@ -66,6 +68,16 @@ impl TryFrom<NewsRow> for News {
                 .ok_or(anyhow!("cannot convert project categories"))?,
             ProjectAttributes::from_bits(value.attributes as u32)
                 .ok_or(anyhow!("cannot convert project attributes"))?,
+            value
+                .included_projects
+                .into_iter()
+                .map(ProjectId::new)
+                .collect(),
+            value
+                .excluded_projects
+                .into_iter()
+                .map(ProjectId::new)
+                .collect(),
             DateTime::new(value.created_at),
             DateTime::new(value.updated_at),
         ))
@@ -88,7 +100,7 @@ impl NewsRepository for PgNewsRepository {
 
         let news_list = sqlx::query_as!(
             NewsRow,
-            r#"SELECT id, state AS "state: NewsStateRow", title, body, attachments, categories, attributes, created_at, updated_at, scheduled_at FROM news WHERE deleted_at IS NULL ORDER BY created_at DESC"#
+            r#"SELECT id, state AS "state: NewsStateRow", title, body, attachments, categories, attributes, included_projects, excluded_projects, created_at, updated_at, scheduled_at FROM news WHERE deleted_at IS NULL ORDER BY created_at DESC"#
         )
         .fetch(&*self.db)
         .map(|row| News::try_from(row?))
@@ -105,7 +117,7 @@ impl NewsRepository for PgNewsRepository {
 
         let news = news.destruct();
         sqlx::query!(
-            r#"INSERT INTO news (id, state, title, body, attachments, categories, attributes, scheduled_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)"#,
+            r#"INSERT INTO news (id, state, title, body, attachments, categories, attributes, included_projects, excluded_projects, scheduled_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)"#,
             news.id.value(),
             NewsStateRow::from(news.state.clone()) as NewsStateRow,
             news.title.value(),
@@ -113,6 +125,8 @@ impl NewsRepository for PgNewsRepository {
             &news.attachments.into_iter().map(|id| id.value()).collect::<Vec<_>>(),
             news.categories.bits() as i32,
             news.attributes.bits() as i32,
+            &news.included_projects.into_iter().map(|id| id.value()).collect::<Vec<_>>(),
+            &news.excluded_projects.into_iter().map(|id| id.value()).collect::<Vec<_>>(),
             match news.state {
                 NewsState::Scheduled(date) => Some(date.value()),
                 _ => None
@@ -131,7 +145,7 @@ impl NewsRepository for PgNewsRepository {
 
         let news_row = sqlx::query_as!(
             NewsRow,
-            r#"SELECT id,  state AS "state: NewsStateRow", title, body, attachments, categories, attributes, created_at, updated_at, scheduled_at FROM news WHERE id = $1 AND deleted_at IS NULL"#,
+            r#"SELECT id,  state AS "state: NewsStateRow", title, body, attachments, categories, attributes, included_projects, excluded_projects, created_at, updated_at, scheduled_at FROM news WHERE id = $1 AND deleted_at IS NULL"#,
             id.clone().value()
         )
         .fetch_optional(&*self.db)
@@ -147,7 +161,7 @@ impl NewsRepository for PgNewsRepository {
 
         let news = news.destruct();
         sqlx::query!(
-            r#"UPDATE news SET state = $2, title = $3, body = $4, attachments = $5, categories = $6, attributes = $7, scheduled_at = $8 WHERE id = $1 and deleted_at IS NULL"#,
+            r#"UPDATE news SET state = $2, title = $3, body = $4, attachments = $5, categories = $6, attributes = $7, included_projects = $8, excluded_projects = $9, scheduled_at = $10 WHERE id = $1 and deleted_at IS NULL"#,
             news.id.value(),
             NewsStateRow::from(news.state.clone()) as NewsStateRow,
             news.title.value(),
@@ -155,6 +169,8 @@ impl NewsRepository for PgNewsRepository {
             &news.attachments.into_iter().map(|id| id.value()).collect::<Vec<_>>(),
             news.categories.bits() as i32,
             news.attributes.bits() as i32,
+            &news.included_projects.into_iter().map(|id| id.value()).collect::<Vec<_>>(),
+            &news.excluded_projects.into_iter().map(|id| id.value()).collect::<Vec<_>>(),
             match news.state {
                 NewsState::Scheduled(date) => Some(date.value()),
                 _ => None
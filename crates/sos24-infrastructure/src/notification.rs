@@ -1,47 +1,263 @@
-use std::time::Duration;
-
 use anyhow::Context;
-use reqwest::ClientBuilder;
-use serde_json::json;
-use sos24_use_case::shared::adapter::notification::Notifier;
+use futures_util::{StreamExt, TryStreamExt};
+use sqlx::prelude::{FromRow, Type};
 
-pub struct SlackNotifier {
-    webhook_url: Option<String>,
-}
+use sos24_domain::{
+    entity::{
+        common::datetime::DateTime,
+        notification::{
+            Notification, NotificationBody, NotificationId, NotificationKind, NotificationTitle,
+            NotificationUrl,
+        },
+        user::UserId,
+    },
+    repository::{
+        notification::{NotificationRepository, NotificationRepositoryError},
+        webhook::WebhookRepository,
+        webhook_delivery::WebhookDeliveryRepository,
+    },
+};
+use sos24_use_case::{
+    shared::adapter::{
+        event::{Event, EventBus},
+        notification::Notifier,
+    },
+    webhook::content::WebhookContent,
+};
 
-impl SlackNotifier {
-    pub fn new(webhook_url: Option<String>) -> Self {
-        let webhook_url = match webhook_url {
-            Some(url) => {
-                // 有効なURLかどうかをチェックする
-                let _ = reqwest::Url::parse(&url).expect("Invalid URL");
-                Some(url)
-            }
-            None => None,
-        };
+use crate::{
+    event::BroadcastEventBus, shared::postgresql::Postgresql, webhook::PgWebhookRepository,
+    webhook_delivery::PgWebhookDeliveryRepository,
+};
+
+// 実委向けの通知はWebhook(Slackなど)への配信として保存し、
+// ユーザー向けの通知は各ユーザーの受信箱に送る
+pub struct DefaultNotifier {
+    event_bus: BroadcastEventBus,
+    inbox: PgNotificationRepository,
+    webhooks: PgWebhookRepository,
+    webhook_deliveries: PgWebhookDeliveryRepository,
+}
 
-        Self { webhook_url }
+impl DefaultNotifier {
+    pub fn new(event_bus: BroadcastEventBus, postgresql: Postgresql) -> Self {
+        Self {
+            event_bus,
+            inbox: PgNotificationRepository::new(postgresql.clone()),
+            webhooks: PgWebhookRepository::new(postgresql.clone()),
+            webhook_deliveries: PgWebhookDeliveryRepository::new(postgresql),
+        }
     }
 }
 
-impl Notifier for SlackNotifier {
+impl Notifier for DefaultNotifier {
     async fn notify(&self, message: String) -> anyhow::Result<()> {
-        tracing::info!("Slack通知を送信します");
+        // イベントバスは購読が遅れると取りこぼし、再起動で失われるため、
+        // Webhookへの配信はここで直接作成する
+        let content = WebhookContent::committee_notification(message.clone());
+        let webhook_list = self
+            .webhooks
+            .find_by_event_type(content.event_type().clone())
+            .await?;
+        for delivery in content.deliveries(webhook_list) {
+            self.webhook_deliveries.create(delivery).await?;
+        }
+
+        self.event_bus.publish(Event::CommitteeNotified(message));
+        Ok(())
+    }
+
+    async fn notify_users(&self, notifications: Vec<Notification>) -> anyhow::Result<()> {
+        tracing::info!("{}件の通知を受信箱に送信します", notifications.len());
+
+        for notification in notifications {
+            self.inbox.create(notification).await?;
+        }
+
+        tracing::info!("通知を受信箱に送信しました");
+        Ok(())
+    }
+}
+
+#[derive(FromRow)]
+pub struct NotificationRow {
+    id: uuid::Uuid,
+    user_id: String,
+    kind: NotificationKindRow,
+    title: String,
+    body: String,
+    url: String,
+    read_at: Option<chrono::DateTime<chrono::Utc>>,
+    created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<NotificationRow> for Notification {
+    fn from(row: NotificationRow) -> Self {
+        Notification::new(
+            NotificationId::new(row.id),
+            UserId::new(row.user_id),
+            NotificationKind::from(row.kind),
+            NotificationTitle::new(row.title),
+            NotificationBody::new(row.body),
+            NotificationUrl::new(row.url),
+            row.read_at.map(DateTime::new),
+            DateTime::new(row.created_at),
+        )
+    }
+}
+
+#[derive(Type)]
+#[sqlx(type_name = "notification_kind", rename_all = "snake_case")]
+pub enum NotificationKindRow {
+    NewsPublished,
+    FormStarted,
+    InvitationReceived,
+}
+
+impl From<NotificationKind> for NotificationKindRow {
+    fn from(kind: NotificationKind) -> Self {
+        match kind {
+            NotificationKind::NewsPublished => Self::NewsPublished,
+            NotificationKind::FormStarted => Self::FormStarted,
+            NotificationKind::InvitationReceived => Self::InvitationReceived,
+        }
+    }
+}
+
+impl From<NotificationKindRow> for NotificationKind {
+    fn from(kind: NotificationKindRow) -> Self {
+        match kind {
+            NotificationKindRow::NewsPublished => Self::NewsPublished,
+            NotificationKindRow::FormStarted => Self::FormStarted,
+            NotificationKindRow::InvitationReceived => Self::InvitationReceived,
+        }
+    }
+}
 
-        let Some(ref webhook_url) = self.webhook_url else {
-            tracing::warn!("SlackのWebhook URLが設定されていないため、通知を送信しませんでした");
-            return Ok(());
-        };
+pub struct PgNotificationRepository {
+    db: Postgresql,
+}
+
+impl PgNotificationRepository {
+    pub fn new(db: Postgresql) -> Self {
+        Self { db }
+    }
+}
+
+impl NotificationRepository for PgNotificationRepository {
+    async fn create(&self, notification: Notification) -> Result<(), NotificationRepositoryError> {
+        tracing::info!("通知を作成します");
+
+        let notification = notification.destruct();
+        sqlx::query!(
+            r#"INSERT INTO notifications (id, user_id, kind, title, body, url) VALUES ($1, $2, $3, $4, $5, $6)"#,
+            notification.id.value(),
+            notification.user_id.value(),
+            NotificationKindRow::from(notification.kind) as NotificationKindRow,
+            notification.title.value(),
+            notification.body.value(),
+            notification.url.value(),
+        )
+            .execute(&*self.db)
+            .await
+            .context("Failed to create notification")?;
+
+        tracing::info!("通知を作成しました");
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        id: NotificationId,
+    ) -> Result<Option<Notification>, NotificationRepositoryError> {
+        tracing::info!("通知を取得します: {id:?}");
+
+        let notification_row = sqlx::query_as!(
+            NotificationRow,
+            r#"SELECT id, user_id, kind AS "kind: NotificationKindRow", title, body, url, read_at, created_at FROM notifications WHERE id = $1 AND deleted_at IS NULL"#,
+            id.clone().value()
+        )
+            .fetch_optional(&*self.db)
+            .await
+            .context("Failed to fetch notification")?;
+
+        tracing::info!("通知を取得しました: {id:?}");
+        Ok(notification_row.map(Notification::from))
+    }
+
+    async fn find_by_user_id(
+        &self,
+        user_id: UserId,
+    ) -> Result<Vec<Notification>, NotificationRepositoryError> {
+        tracing::info!("通知一覧を取得します: {user_id:?}");
+
+        let notification_list = sqlx::query_as!(
+            NotificationRow,
+            r#"SELECT id, user_id, kind AS "kind: NotificationKindRow", title, body, url, read_at, created_at FROM notifications WHERE user_id = $1 AND deleted_at IS NULL ORDER BY created_at DESC"#,
+            user_id.clone().value()
+        )
+            .fetch(&*self.db)
+            .map(|row| Ok::<_, anyhow::Error>(Notification::from(row?)))
+            .try_collect()
+            .await
+            .context("Failed to fetch notification list")?;
+
+        tracing::info!("通知一覧を取得しました: {user_id:?}");
+        Ok(notification_list)
+    }
+
+    async fn count_unread_by_user_id(
+        &self,
+        user_id: UserId,
+    ) -> Result<i64, NotificationRepositoryError> {
+        tracing::info!("未読の通知数を取得します: {user_id:?}");
+
+        let count = sqlx::query_scalar!(
+            r#"SELECT COUNT(*) AS "count!" FROM notifications WHERE user_id = $1 AND read_at IS NULL AND deleted_at IS NULL"#,
+            user_id.clone().value()
+        )
+            .fetch_one(&*self.db)
+            .await
+            .context("Failed to count unread notifications")?;
+
+        tracing::info!("未読の通知数を取得しました: {user_id:?}");
+        Ok(count)
+    }
+
+    async fn update(&self, notification: Notification) -> Result<(), NotificationRepositoryError> {
+        tracing::info!("通知を更新します");
+
+        let notification = notification.destruct();
+        sqlx::query!(
+            r#"UPDATE notifications SET read_at = $2 WHERE id = $1 AND deleted_at IS NULL"#,
+            notification.id.value(),
+            notification.read_at.map(|it| it.value()),
+        )
+        .execute(&*self.db)
+        .await
+        .context("Failed to update notification")?;
+
+        tracing::info!("通知を更新しました");
+        Ok(())
+    }
 
-        let client = ClientBuilder::new()
-            .timeout(Duration::from_secs(60))
-            .build()
-            .context("Failed to create HTTP client")?;
+    async fn read_all_by_user_id(
+        &self,
+        user_id: UserId,
+        read_at: DateTime,
+    ) -> Result<(), NotificationRepositoryError> {
+        tracing::info!("通知を全て既読にします: {user_id:?}");
 
-        let body = json!({"text": message});
-        client.post(webhook_url).json(&body).send().await?;
+        sqlx::query!(
+            r#"UPDATE notifications SET read_at = $2 WHERE user_id = $1 AND read_at IS NULL AND deleted_at IS NULL"#,
+            user_id.clone().value(),
+            read_at.value(),
+        )
+            .execute(&*self.db)
+            .await
+            .context("Failed to read all notifications")?;
 
-        tracing::info!("Slack通知を送信しました");
+        tracing::info!("通知を全て既読にしました: {user_id:?}");
         Ok(())
     }
 }
@@ -0,0 +1,79 @@
+use anyhow::Context;
+
+use sos24_domain::{
+    entity::{project::ProjectId, project_storage::ProjectStorageQuota},
+    repository::project_storage_quota::{
+        ProjectStorageQuotaRepository, ProjectStorageQuotaRepositoryError,
+    },
+};
+
+use crate::shared::postgresql::Postgresql;
+
+pub struct PgProjectStorageQuotaRepository {
+    db: Postgresql,
+}
+
+impl PgProjectStorageQuotaRepository {
+    pub fn new(db: Postgresql) -> Self {
+        Self { db }
+    }
+}
+
+impl ProjectStorageQuotaRepository for PgProjectStorageQuotaRepository {
+    async fn find_by_project_id(
+        &self,
+        project_id: ProjectId,
+    ) -> Result<Option<ProjectStorageQuota>, ProjectStorageQuotaRepositoryError> {
+        tracing::info!("企画の容量の上限を取得しています: {project_id:?}");
+
+        let quota = sqlx::query_scalar!(
+            r#"SELECT quota FROM project_storage_quotas WHERE project_id = $1"#,
+            project_id.clone().value()
+        )
+        .fetch_optional(&*self.db)
+        .await
+        .context("Failed to fetch project storage quota")?;
+
+        tracing::info!("企画の容量の上限の取得が完了しました: {project_id:?}");
+        Ok(quota.map(|it| ProjectStorageQuota::new(it.max(0) as u64)))
+    }
+
+    async fn upsert(
+        &self,
+        project_id: ProjectId,
+        quota: ProjectStorageQuota,
+    ) -> Result<(), ProjectStorageQuotaRepositoryError> {
+        tracing::info!("企画の容量の上限を設定しています: {project_id:?}");
+
+        let quota = i64::try_from(quota.value()).context("Quota is too large")?;
+        sqlx::query!(
+            r#"INSERT INTO project_storage_quotas (project_id, quota) VALUES ($1, $2) ON CONFLICT (project_id) DO UPDATE SET quota = $2"#,
+            project_id.clone().value(),
+            quota
+        )
+        .execute(&*self.db)
+        .await
+        .context("Failed to upsert project storage quota")?;
+
+        tracing::info!("企画の容量の上限の設定が完了しました: {project_id:?}");
+        Ok(())
+    }
+
+    async fn delete_by_project_id(
+        &self,
+        project_id: ProjectId,
+    ) -> Result<(), ProjectStorageQuotaRepositoryError> {
+        tracing::info!("企画の容量の上限を削除しています: {project_id:?}");
+
+        sqlx::query!(
+            r#"DELETE FROM project_storage_quotas WHERE project_id = $1"#,
+            project_id.clone().value()
+        )
+        .execute(&*self.db)
+        .await
+        .context("Failed to delete project storage quota")?;
+
+        tracing::info!("企画の容量の上限の削除が完了しました: {project_id:?}");
+        Ok(())
+    }
+}
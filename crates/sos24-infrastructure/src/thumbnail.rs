@@ -0,0 +1,84 @@
+use std::time::Duration;
+
+use anyhow::{bail, Context};
+use tokio::io::{AsyncReadExt, AsyncWriteExt, DuplexStream};
+use tokio::process::Command;
+
+use sos24_use_case::shared::adapter::thumbnail::ThumbnailGenerator;
+
+// サムネイルの縦横の最大サイズ(px)
+const THUMBNAIL_SIZE: &str = "320x320";
+const TIMEOUT: Duration = Duration::from_secs(60);
+// 一時ファイルに書き出す元のファイルの最大サイズ
+// これより大きいファイルはサムネイルを生成しない
+const MAX_INPUT_SIZE: u64 = 100 * 1024 * 1024;
+
+// libvipsのvipsthumbnailでサムネイルを生成する
+// PDFは1ページ目が読み込まれるため、そのままプレビューになる
+pub struct VipsThumbnailGenerator;
+
+impl VipsThumbnailGenerator {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for VipsThumbnailGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ThumbnailGenerator for VipsThumbnailGenerator {
+    async fn generate(
+        &self,
+        content_type: String,
+        mut reader: DuplexStream,
+    ) -> anyhow::Result<Vec<u8>> {
+        tracing::info!("サムネイルを生成します: {content_type}");
+
+        // vipsthumbnailは入力の種類を中身から判定するので、拡張子は付けなくてよい
+        let temp_dir = tempfile::tempdir().context("Failed to create temp dir")?;
+        let input = temp_dir.path().join("input");
+        let output = temp_dir.path().join("thumbnail.png");
+        let mut file = tokio::fs::File::create(&input)
+            .await
+            .context("Failed to create temp file")?;
+        let written = tokio::io::copy(&mut (&mut reader).take(MAX_INPUT_SIZE + 1), &mut file)
+            .await
+            .context("Failed to write temp file")?;
+        if written > MAX_INPUT_SIZE {
+            bail!("File is too large to generate thumbnail: over {MAX_INPUT_SIZE} bytes");
+        }
+        file.flush().await.context("Failed to write temp file")?;
+
+        let result = tokio::time::timeout(
+            TIMEOUT,
+            Command::new("vipsthumbnail")
+                .arg(&input)
+                .arg("--size")
+                .arg(THUMBNAIL_SIZE)
+                .arg("-o")
+                .arg(&output)
+                .kill_on_drop(true)
+                .output(),
+        )
+        .await
+        .context("Timed out generating thumbnail")?
+        .context("Failed to run vipsthumbnail")?;
+        if !result.status.success() {
+            bail!(
+                "vipsthumbnail exited with {}: {}",
+                result.status,
+                String::from_utf8_lossy(&result.stderr)
+            );
+        }
+
+        let thumbnail = tokio::fs::read(&output)
+            .await
+            .context("Failed to read thumbnail")?;
+
+        tracing::info!("サムネイルを生成しました");
+        Ok(thumbnail)
+    }
+}
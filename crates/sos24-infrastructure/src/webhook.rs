@@ -0,0 +1,316 @@
+use std::time::Duration;
+
+use anyhow::Context;
+use futures_util::{StreamExt, TryStreamExt};
+use hmac::{Hmac, Mac};
+use reqwest::ClientBuilder;
+use sha2::Sha256;
+use sqlx::prelude::{FromRow, Type};
+
+use sos24_domain::{
+    entity::{
+        common::datetime::DateTime,
+        webhook::{
+            Webhook, WebhookEventType, WebhookFormat, WebhookId, WebhookName, WebhookSecret,
+            WebhookUrl,
+        },
+    },
+    repository::webhook::{WebhookRepository, WebhookRepositoryError},
+};
+use sos24_use_case::shared::adapter::webhook::{WebhookRequest, WebhookSender};
+
+use crate::shared::postgresql::Postgresql;
+
+pub struct HttpWebhookSender;
+
+impl HttpWebhookSender {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for HttpWebhookSender {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WebhookSender for HttpWebhookSender {
+    async fn send(&self, request: WebhookRequest) -> anyhow::Result<u16> {
+        tracing::info!("Webhookを送信します: {}", request.delivery_id);
+
+        let client = ClientBuilder::new()
+            .timeout(Duration::from_secs(60))
+            .build()
+            .context("Failed to create HTTP client")?;
+
+        let mut builder = client
+            .post(&request.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header("X-Sos24-Event", &request.event_type)
+            .header("X-Sos24-Delivery", &request.delivery_id);
+
+        // 受信側で改ざんを検知できるよう、本文のHMAC-SHA256を付与する
+        if let Some(secret) = request.secret {
+            let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+                .context("Failed to create HMAC")?;
+            mac.update(request.body.as_bytes());
+            let signature = hex::encode(mac.finalize().into_bytes());
+            builder = builder.header("X-Sos24-Signature", format!("sha256={signature}"));
+        }
+
+        let response = builder
+            .body(request.body)
+            .send()
+            .await
+            .context("Failed to send webhook")?;
+
+        tracing::info!(
+            "Webhookを送信しました: {} ({})",
+            request.delivery_id,
+            response.status()
+        );
+        Ok(response.status().as_u16())
+    }
+}
+
+#[derive(FromRow)]
+pub struct WebhookRow {
+    id: uuid::Uuid,
+    name: String,
+    url: String,
+    secret: String,
+    format: WebhookFormatRow,
+    event_types: Vec<WebhookEventTypeRow>,
+    created_at: chrono::DateTime<chrono::Utc>,
+    updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<WebhookRow> for Webhook {
+    fn from(row: WebhookRow) -> Self {
+        Webhook::new(
+            WebhookId::new(row.id),
+            WebhookName::new(row.name),
+            WebhookUrl::new(row.url),
+            WebhookSecret::new(row.secret),
+            WebhookFormat::from(row.format),
+            row.event_types
+                .into_iter()
+                .map(WebhookEventType::from)
+                .collect(),
+            DateTime::new(row.created_at),
+            DateTime::new(row.updated_at),
+        )
+    }
+}
+
+#[derive(Type)]
+#[sqlx(type_name = "webhook_format", rename_all = "snake_case")]
+pub enum WebhookFormatRow {
+    Json,
+    Slack,
+}
+
+impl From<WebhookFormat> for WebhookFormatRow {
+    fn from(format: WebhookFormat) -> Self {
+        match format {
+            WebhookFormat::Json => Self::Json,
+            WebhookFormat::Slack => Self::Slack,
+        }
+    }
+}
+
+impl From<WebhookFormatRow> for WebhookFormat {
+    fn from(format: WebhookFormatRow) -> Self {
+        match format {
+            WebhookFormatRow::Json => Self::Json,
+            WebhookFormatRow::Slack => Self::Slack,
+        }
+    }
+}
+
+#[derive(Type)]
+#[sqlx(type_name = "webhook_event_type", rename_all = "snake_case")]
+pub enum WebhookEventTypeRow {
+    ProjectCreated,
+    ProjectUpdated,
+    ProjectDeleted,
+    FormAnswerSubmitted,
+    NewsPublished,
+    CommitteeNotification,
+}
+
+impl From<WebhookEventType> for WebhookEventTypeRow {
+    fn from(event_type: WebhookEventType) -> Self {
+        match event_type {
+            WebhookEventType::ProjectCreated => Self::ProjectCreated,
+            WebhookEventType::ProjectUpdated => Self::ProjectUpdated,
+            WebhookEventType::ProjectDeleted => Self::ProjectDeleted,
+            WebhookEventType::FormAnswerSubmitted => Self::FormAnswerSubmitted,
+            WebhookEventType::NewsPublished => Self::NewsPublished,
+            WebhookEventType::CommitteeNotification => Self::CommitteeNotification,
+        }
+    }
+}
+
+impl From<WebhookEventTypeRow> for WebhookEventType {
+    fn from(event_type: WebhookEventTypeRow) -> Self {
+        match event_type {
+            WebhookEventTypeRow::ProjectCreated => Self::ProjectCreated,
+            WebhookEventTypeRow::ProjectUpdated => Self::ProjectUpdated,
+            WebhookEventTypeRow::ProjectDeleted => Self::ProjectDeleted,
+            WebhookEventTypeRow::FormAnswerSubmitted => Self::FormAnswerSubmitted,
+            WebhookEventTypeRow::NewsPublished => Self::NewsPublished,
+            WebhookEventTypeRow::CommitteeNotification => Self::CommitteeNotification,
+        }
+    }
+}
+
+pub struct PgWebhookRepository {
+    db: Postgresql,
+}
+
+impl PgWebhookRepository {
+    pub fn new(db: Postgresql) -> Self {
+        Self { db }
+    }
+}
+
+impl WebhookRepository for PgWebhookRepository {
+    async fn list(&self) -> Result<Vec<Webhook>, WebhookRepositoryError> {
+        tracing::info!("Webhook一覧を取得します");
+
+        let webhook_list = sqlx::query_as!(
+            WebhookRow,
+            r#"SELECT id, name, url, secret, format AS "format: WebhookFormatRow", event_types AS "event_types: Vec<WebhookEventTypeRow>", created_at, updated_at FROM webhooks WHERE deleted_at IS NULL ORDER BY created_at ASC"#
+        )
+        .fetch(&*self.db)
+        .map(|row| Ok::<_, anyhow::Error>(Webhook::from(row?)))
+        .try_collect()
+        .await
+        .context("Failed to fetch webhook list")?;
+
+        tracing::info!("Webhook一覧を取得しました");
+        Ok(webhook_list)
+    }
+
+    async fn create(&self, webhook: Webhook) -> Result<(), WebhookRepositoryError> {
+        tracing::info!("Webhookを作成します");
+
+        let webhook = webhook.destruct();
+        sqlx::query!(
+            r#"INSERT INTO webhooks (id, name, url, secret, format, event_types) VALUES ($1, $2, $3, $4, $5, $6)"#,
+            webhook.id.value(),
+            webhook.name.value(),
+            webhook.url.value(),
+            webhook.secret.value(),
+            WebhookFormatRow::from(webhook.format) as WebhookFormatRow,
+            webhook
+                .event_types
+                .into_iter()
+                .map(WebhookEventTypeRow::from)
+                .collect::<Vec<_>>() as Vec<WebhookEventTypeRow>,
+        )
+        .execute(&*self.db)
+        .await
+        .context("Failed to create webhook")?;
+
+        tracing::info!("Webhookを作成しました");
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: WebhookId) -> Result<Option<Webhook>, WebhookRepositoryError> {
+        tracing::info!("Webhookを取得します: {id:?}");
+
+        let webhook_row = sqlx::query_as!(
+            WebhookRow,
+            r#"SELECT id, name, url, secret, format AS "format: WebhookFormatRow", event_types AS "event_types: Vec<WebhookEventTypeRow>", created_at, updated_at FROM webhooks WHERE id = $1 AND deleted_at IS NULL"#,
+            id.clone().value()
+        )
+        .fetch_optional(&*self.db)
+        .await
+        .context("Failed to fetch webhook")?;
+
+        tracing::info!("Webhookを取得しました: {id:?}");
+        Ok(webhook_row.map(Webhook::from))
+    }
+
+    async fn find_by_event_type(
+        &self,
+        event_type: WebhookEventType,
+    ) -> Result<Vec<Webhook>, WebhookRepositoryError> {
+        tracing::info!("イベントを購読しているWebhook一覧を取得します: {event_type:?}");
+
+        let webhook_list = sqlx::query_as!(
+            WebhookRow,
+            r#"SELECT id, name, url, secret, format AS "format: WebhookFormatRow", event_types AS "event_types: Vec<WebhookEventTypeRow>", created_at, updated_at FROM webhooks WHERE $1 = ANY(event_types) AND deleted_at IS NULL ORDER BY created_at ASC"#,
+            WebhookEventTypeRow::from(event_type.clone()) as WebhookEventTypeRow
+        )
+        .fetch(&*self.db)
+        .map(|row| Ok::<_, anyhow::Error>(Webhook::from(row?)))
+        .try_collect()
+        .await
+        .context("Failed to fetch webhook list")?;
+
+        tracing::info!("イベントを購読しているWebhook一覧を取得しました: {event_type:?}");
+        Ok(webhook_list)
+    }
+
+    async fn exists_by_url_including_deleted(
+        &self,
+        url: WebhookUrl,
+    ) -> Result<bool, WebhookRepositoryError> {
+        tracing::info!("同じURLのWebhookが作成されたことがあるか確認します");
+
+        let exists = sqlx::query_scalar!(
+            r#"SELECT EXISTS(SELECT 1 FROM webhooks WHERE url = $1) AS "exists!""#,
+            url.value()
+        )
+        .fetch_one(&*self.db)
+        .await
+        .context("Failed to check webhook url")?;
+
+        tracing::info!("同じURLのWebhookが作成されたことがあるか確認しました");
+        Ok(exists)
+    }
+
+    async fn update(&self, webhook: Webhook) -> Result<(), WebhookRepositoryError> {
+        tracing::info!("Webhookを更新します");
+
+        let webhook = webhook.destruct();
+        sqlx::query!(
+            r#"UPDATE webhooks SET name = $2, url = $3, secret = $4, format = $5, event_types = $6 WHERE id = $1 AND deleted_at IS NULL"#,
+            webhook.id.value(),
+            webhook.name.value(),
+            webhook.url.value(),
+            webhook.secret.value(),
+            WebhookFormatRow::from(webhook.format) as WebhookFormatRow,
+            webhook
+                .event_types
+                .into_iter()
+                .map(WebhookEventTypeRow::from)
+                .collect::<Vec<_>>() as Vec<WebhookEventTypeRow>,
+        )
+        .execute(&*self.db)
+        .await
+        .context("Failed to update webhook")?;
+
+        tracing::info!("Webhookを更新しました");
+        Ok(())
+    }
+
+    async fn delete_by_id(&self, id: WebhookId) -> Result<(), WebhookRepositoryError> {
+        tracing::info!("Webhookを削除します: {id:?}");
+
+        sqlx::query!(
+            r#"UPDATE webhooks SET deleted_at = NOW() WHERE id = $1 AND deleted_at IS NULL"#,
+            id.clone().value()
+        )
+        .execute(&*self.db)
+        .await
+        .context("Failed to delete webhook")?;
+
+        tracing::info!("Webhookを削除しました: {id:?}");
+        Ok(())
+    }
+}
@@ -0,0 +1,200 @@
+use anyhow::Context;
+use futures_util::{StreamExt, TryStreamExt};
+use sqlx::prelude::{FromRow, Type};
+
+use sos24_domain::{
+    entity::{
+        common::datetime::DateTime,
+        webhook::{WebhookEventType, WebhookId},
+        webhook_delivery::{
+            WebhookDelivery, WebhookDeliveryAttempts, WebhookDeliveryError, WebhookDeliveryId,
+            WebhookDeliveryStatus, WebhookPayload, WebhookResponseStatus,
+        },
+    },
+    repository::webhook_delivery::{WebhookDeliveryRepository, WebhookDeliveryRepositoryError},
+};
+
+use crate::{shared::postgresql::Postgresql, webhook::WebhookEventTypeRow};
+
+#[derive(FromRow)]
+pub struct WebhookDeliveryRow {
+    id: uuid::Uuid,
+    webhook_id: uuid::Uuid,
+    event_type: WebhookEventTypeRow,
+    payload: String,
+    status: WebhookDeliveryStatusRow,
+    attempts: i32,
+    response_status: Option<i32>,
+    last_error: Option<String>,
+    next_attempt_at: Option<chrono::DateTime<chrono::Utc>>,
+    created_at: chrono::DateTime<chrono::Utc>,
+    updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<WebhookDeliveryRow> for WebhookDelivery {
+    fn from(row: WebhookDeliveryRow) -> Self {
+        WebhookDelivery::new(
+            WebhookDeliveryId::new(row.id),
+            WebhookId::new(row.webhook_id),
+            WebhookEventType::from(row.event_type),
+            WebhookPayload::new(row.payload),
+            WebhookDeliveryStatus::from(row.status),
+            WebhookDeliveryAttempts::new(row.attempts),
+            row.response_status.map(WebhookResponseStatus::new),
+            row.last_error.map(WebhookDeliveryError::new),
+            row.next_attempt_at.map(DateTime::new),
+            DateTime::new(row.created_at),
+            DateTime::new(row.updated_at),
+        )
+    }
+}
+
+#[derive(Type)]
+#[sqlx(type_name = "webhook_delivery_status", rename_all = "snake_case")]
+pub enum WebhookDeliveryStatusRow {
+    Pending,
+    Succeeded,
+    Failed,
+}
+
+impl From<WebhookDeliveryStatus> for WebhookDeliveryStatusRow {
+    fn from(status: WebhookDeliveryStatus) -> Self {
+        match status {
+            WebhookDeliveryStatus::Pending => Self::Pending,
+            WebhookDeliveryStatus::Succeeded => Self::Succeeded,
+            WebhookDeliveryStatus::Failed => Self::Failed,
+        }
+    }
+}
+
+impl From<WebhookDeliveryStatusRow> for WebhookDeliveryStatus {
+    fn from(status: WebhookDeliveryStatusRow) -> Self {
+        match status {
+            WebhookDeliveryStatusRow::Pending => Self::Pending,
+            WebhookDeliveryStatusRow::Succeeded => Self::Succeeded,
+            WebhookDeliveryStatusRow::Failed => Self::Failed,
+        }
+    }
+}
+
+pub struct PgWebhookDeliveryRepository {
+    db: Postgresql,
+}
+
+impl PgWebhookDeliveryRepository {
+    pub fn new(db: Postgresql) -> Self {
+        Self { db }
+    }
+}
+
+impl WebhookDeliveryRepository for PgWebhookDeliveryRepository {
+    async fn create(
+        &self,
+        delivery: WebhookDelivery,
+    ) -> Result<(), WebhookDeliveryRepositoryError> {
+        tracing::info!("Webhookの配信を作成します");
+
+        let delivery = delivery.destruct();
+        sqlx::query!(
+            r#"INSERT INTO webhook_deliveries (id, webhook_id, event_type, payload, status, attempts, next_attempt_at) VALUES ($1, $2, $3, $4, $5, $6, $7)"#,
+            delivery.id.value(),
+            delivery.webhook_id.value(),
+            WebhookEventTypeRow::from(delivery.event_type) as WebhookEventTypeRow,
+            delivery.payload.value(),
+            WebhookDeliveryStatusRow::from(delivery.status) as WebhookDeliveryStatusRow,
+            delivery.attempts.value(),
+            delivery.next_attempt_at.map(|it| it.value()),
+        )
+        .execute(&*self.db)
+        .await
+        .context("Failed to create webhook delivery")?;
+
+        tracing::info!("Webhookの配信を作成しました");
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        id: WebhookDeliveryId,
+    ) -> Result<Option<WebhookDelivery>, WebhookDeliveryRepositoryError> {
+        tracing::info!("Webhookの配信を取得します: {id:?}");
+
+        let delivery_row = sqlx::query_as!(
+            WebhookDeliveryRow,
+            r#"SELECT id, webhook_id, event_type AS "event_type: WebhookEventTypeRow", payload, status AS "status: WebhookDeliveryStatusRow", attempts, response_status, last_error, next_attempt_at, created_at, updated_at FROM webhook_deliveries WHERE id = $1"#,
+            id.clone().value()
+        )
+        .fetch_optional(&*self.db)
+        .await
+        .context("Failed to fetch webhook delivery")?;
+
+        tracing::info!("Webhookの配信を取得しました: {id:?}");
+        Ok(delivery_row.map(WebhookDelivery::from))
+    }
+
+    async fn find_by_webhook_id(
+        &self,
+        webhook_id: WebhookId,
+    ) -> Result<Vec<WebhookDelivery>, WebhookDeliveryRepositoryError> {
+        tracing::info!("Webhookの配信一覧を取得します: {webhook_id:?}");
+
+        let delivery_list = sqlx::query_as!(
+            WebhookDeliveryRow,
+            r#"SELECT id, webhook_id, event_type AS "event_type: WebhookEventTypeRow", payload, status AS "status: WebhookDeliveryStatusRow", attempts, response_status, last_error, next_attempt_at, created_at, updated_at FROM webhook_deliveries WHERE webhook_id = $1 ORDER BY created_at DESC"#,
+            webhook_id.clone().value()
+        )
+        .fetch(&*self.db)
+        .map(|row| Ok::<_, anyhow::Error>(WebhookDelivery::from(row?)))
+        .try_collect()
+        .await
+        .context("Failed to fetch webhook delivery list")?;
+
+        tracing::info!("Webhookの配信一覧を取得しました: {webhook_id:?}");
+        Ok(delivery_list)
+    }
+
+    async fn find_due(
+        &self,
+        now: DateTime,
+    ) -> Result<Vec<WebhookDelivery>, WebhookDeliveryRepositoryError> {
+        tracing::info!("送信待ちのWebhookの配信一覧を取得します");
+
+        let delivery_list = sqlx::query_as!(
+            WebhookDeliveryRow,
+            r#"SELECT id, webhook_id, event_type AS "event_type: WebhookEventTypeRow", payload, status AS "status: WebhookDeliveryStatusRow", attempts, response_status, last_error, next_attempt_at, created_at, updated_at FROM webhook_deliveries WHERE status = 'pending' AND next_attempt_at <= $1 ORDER BY next_attempt_at ASC"#,
+            now.value()
+        )
+        .fetch(&*self.db)
+        .map(|row| Ok::<_, anyhow::Error>(WebhookDelivery::from(row?)))
+        .try_collect()
+        .await
+        .context("Failed to fetch due webhook delivery list")?;
+
+        tracing::info!("送信待ちのWebhookの配信一覧を取得しました");
+        Ok(delivery_list)
+    }
+
+    async fn update(
+        &self,
+        delivery: WebhookDelivery,
+    ) -> Result<(), WebhookDeliveryRepositoryError> {
+        tracing::info!("Webhookの配信を更新します");
+
+        let delivery = delivery.destruct();
+        sqlx::query!(
+            r#"UPDATE webhook_deliveries SET status = $2, attempts = $3, response_status = $4, last_error = $5, next_attempt_at = $6 WHERE id = $1"#,
+            delivery.id.value(),
+            WebhookDeliveryStatusRow::from(delivery.status) as WebhookDeliveryStatusRow,
+            delivery.attempts.value(),
+            delivery.response_status.map(|it| it.value()),
+            delivery.last_error.map(|it| it.value()),
+            delivery.next_attempt_at.map(|it| it.value()),
+        )
+        .execute(&*self.db)
+        .await
+        .context("Failed to update webhook delivery")?;
+
+        tracing::info!("Webhookの配信を更新しました");
+        Ok(())
+    }
+}
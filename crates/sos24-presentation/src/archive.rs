@@ -0,0 +1,30 @@
+use anyhow::Context;
+use async_zip::tokio::write::ZipFileWriter;
+use async_zip::{Compression, ZipEntryBuilder};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ArchiveError {
+    #[error(transparent)]
+    FailedToArchive(#[from] anyhow::Error),
+}
+
+// (ファイル名, 内容) の組をまとめてZIPにする
+pub async fn archive_to_zip(entries: Vec<(String, Vec<u8>)>) -> Result<Vec<u8>, ArchiveError> {
+    let mut zip_writer = ZipFileWriter::with_tokio(Vec::new());
+    for (file_name, data) in entries {
+        let zip_entry = ZipEntryBuilder::new(file_name.into(), Compression::Deflate)
+            .last_modification_date(chrono::Utc::now().into());
+        zip_writer
+            .write_entry_whole(zip_entry, &data)
+            .await
+            .context("Failed to write entry")?;
+    }
+
+    let data = zip_writer
+        .close()
+        .await
+        .context("Failed to close")?
+        .into_inner();
+    Ok(data)
+}
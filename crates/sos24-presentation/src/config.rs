@@ -1,11 +1,30 @@
+use sos24_domain::entity::{common::email::EmailAllowlist, file_object::FileSignedUrlExpiry};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AuthProvider {
+    #[default]
+    Firebase,
+    // 開発環境やテスト向けに、自前で署名したIDトークンを発行・検証する
+    Local,
+}
+
 #[derive(Default, Clone)]
 pub struct Config {
+    pub auth_provider: AuthProvider,
+    // AUTH_PROVIDER=local のときにIDトークンの署名に用いる鍵
+    pub local_auth_secret: String,
     pub firebase_project_id: String,
+    // IDトークンの署名を検証するための公開鍵の取得先
+    pub jwks_url: String,
     pub require_email_verification: bool,
     pub project_application_start_at: String,
     pub project_application_end_at: String,
     pub s3_bucket_name: String,
     pub file_upload_limit: usize,
+    pub project_storage_quota: u64,
+    pub file_url_expires_in: FileSignedUrlExpiry,
+    // ユーザー登録を許可するメールアドレスのドメイン・アドレス
+    pub email_allowlist: EmailAllowlist,
 
     pub email_sender_address: String,
     pub email_reply_to_address: String,
@@ -1,3 +1,4 @@
+use sos24_domain::entity::permission::Permissions;
 use sos24_use_case::shared::context::{self, ContextProvider};
 
 use crate::config::Config;
@@ -7,6 +8,8 @@ pub struct Context {
     user_id: String,
     requested_at: chrono::DateTime<chrono::Utc>,
     config: context::Config,
+    scopes: Option<Permissions>,
+    impersonator: Option<String>,
 }
 
 impl From<Config> for context::Config {
@@ -15,6 +18,8 @@ impl From<Config> for context::Config {
             email_sender_address: config.email_sender_address,
             email_reply_to_address: config.email_reply_to_address,
             app_url: config.app_url,
+            project_storage_quota: config.project_storage_quota,
+            file_url_expires_in: config.file_url_expires_in,
         }
     }
 }
@@ -25,6 +30,8 @@ impl Context {
             user_id,
             requested_at: chrono::Utc::now(),
             config,
+            scopes: None,
+            impersonator: None,
         }
     }
 
@@ -33,6 +40,24 @@ impl Context {
             user_id: String::from("system"), // FIXME
             requested_at: chrono::Utc::now(),
             config,
+            scopes: None,
+            impersonator: None,
+        }
+    }
+
+    // APIトークンで認証した場合は、トークンで許可された権限に制限する
+    pub fn with_scopes(self, scopes: Permissions) -> Self {
+        Self {
+            scopes: Some(scopes),
+            ..self
+        }
+    }
+
+    // 管理者が他のユーザーになりすましている場合は、なりすましている管理者を記録する
+    pub fn with_impersonator(self, impersonator: String) -> Self {
+        Self {
+            impersonator: Some(impersonator),
+            ..self
         }
     }
 }
@@ -49,4 +74,12 @@ impl ContextProvider for Context {
     fn config(&self) -> &context::Config {
         &self.config
     }
+
+    fn scopes(&self) -> Option<Permissions> {
+        self.scopes.clone()
+    }
+
+    fn impersonator(&self) -> Option<String> {
+        self.impersonator.clone()
+    }
 }
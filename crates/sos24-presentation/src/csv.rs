@@ -1,6 +1,6 @@
 use anyhow::Context;
-use csv::Writer;
-use serde::Serialize;
+use csv::{ReaderBuilder, Trim, Writer};
+use serde::{de::DeserializeOwned, Serialize};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -20,3 +20,24 @@ pub fn serialize_to_csv<S: Serialize>(records: Vec<S>) -> Result<String, CsvSeri
     let data = String::from_utf8(csv).context("Failed to convert csv to string")?;
     Ok(data)
 }
+
+#[derive(Debug, Error)]
+pub enum CsvDeserializationError {
+    #[error(transparent)]
+    FailedToDeserialize(#[from] anyhow::Error),
+}
+
+pub fn deserialize_from_csv<D: DeserializeOwned>(
+    data: &str,
+) -> Result<Vec<D>, CsvDeserializationError> {
+    // Excelなどで保存したCSVにはUTF-8 BOMが付いていることがある
+    let data = data.strip_prefix('\u{feff}').unwrap_or(data);
+    let mut rdr = ReaderBuilder::new()
+        .trim(Trim::All)
+        .from_reader(data.as_bytes());
+    let records = rdr
+        .deserialize()
+        .collect::<Result<Vec<D>, _>>()
+        .context("Failed to deserialize")?;
+    Ok(records)
+}
@@ -2,6 +2,9 @@ use std::env;
 
 use base64::prelude::BASE64_STANDARD;
 use base64::Engine;
+use sos24_domain::entity::{common::email::EmailAllowlist, file_object::FileSignedUrlExpiry};
+
+use crate::config::AuthProvider;
 
 pub fn host() -> String {
     env::var("HOST").unwrap_or({
@@ -25,6 +28,30 @@ pub fn postgres_db_url() -> String {
     env::var("POSTGRES_DB_URL").expect("Env `POSTGRES_DB_URL` must be set")
 }
 
+pub fn auth_provider() -> AuthProvider {
+    env::var("AUTH_PROVIDER")
+        .ok()
+        .filter(|it| !it.is_empty())
+        .map(|it| match it.as_str() {
+            "firebase" => AuthProvider::Firebase,
+            "local" => AuthProvider::Local,
+            _ => panic!("Env `AUTH_PROVIDER` must be either `firebase` or `local`"),
+        })
+        .unwrap_or_else(|| {
+            tracing::debug!(
+                "The AUTH_PROVIDER environment variable is not set. Using the default value instead."
+            );
+            AuthProvider::Firebase
+        })
+}
+
+pub fn local_auth_secret() -> String {
+    env::var("LOCAL_AUTH_SECRET")
+        .ok()
+        .filter(|it| !it.is_empty())
+        .expect("Env `LOCAL_AUTH_SECRET` must be set when `AUTH_PROVIDER` is `local`")
+}
+
 pub fn firebase_service_account_key() -> String {
     let encoded_key = env::var("FIREBASE_SERVICE_ACCOUNT_KEY")
         .expect("Env `FIREBASE_SERVICE_ACCOUNT_KEY` must be set");
@@ -38,6 +65,19 @@ pub fn firebase_project_id() -> String {
     env::var("FIREBASE_PROJECT_ID").expect("Env `FIREBASE_PROJECT_ID` must be set")
 }
 
+pub fn jwks_url() -> String {
+    env::var("JWKS_URL")
+        .ok()
+        .filter(|it| !it.is_empty())
+        .unwrap_or_else(|| {
+            tracing::debug!(
+                "The JWKS_URL environment variable is not set. Using the default value instead."
+            );
+            "https://www.googleapis.com/service_accounts/v1/jwk/securetoken@system.gserviceaccount.com"
+                .to_string()
+        })
+}
+
 pub fn project_application_start_at() -> String {
     env::var("PROJECT_APPLICATION_START_AT")
         .expect("Env `PROJECT_APPLICATION_START_AT` must be set")
@@ -99,7 +139,30 @@ pub fn app_url() -> String {
 }
 
 pub fn slack_webhook_url() -> Option<String> {
-    env::var("SLACK_WEBHOOK_URL").ok()
+    env::var("SLACK_WEBHOOK_URL")
+        .ok()
+        .filter(|it| !it.is_empty())
+}
+
+pub fn clamav_address() -> Option<String> {
+    env::var("CLAMAV_ADDRESS").ok().filter(|it| !it.is_empty())
+}
+
+pub fn project_storage_quota() -> u64 {
+    env::var("PROJECT_STORAGE_QUOTA")
+        .ok()
+        .filter(|it| !it.is_empty())
+        .map(|it| {
+            it.parse()
+                .expect("Env `PROJECT_STORAGE_QUOTA` must be a non-negative integer")
+        })
+        .unwrap_or({
+            tracing::debug!(
+                "The PROJECT_STORAGE_QUOTA environment variable is not set. Using the default value instead."
+            );
+            // 5GB
+            5_000_000_000
+        })
 }
 
 pub fn default_admin_email() -> String {
@@ -109,3 +172,37 @@ pub fn default_admin_email() -> String {
 pub fn default_admin_password() -> String {
     env::var("DEFAULT_ADMIN_PASSWORD").expect("Env `DEFAULT_ADMIN_PASSWORD` must be set")
 }
+
+pub fn file_url_expires_in() -> FileSignedUrlExpiry {
+    env::var("FILE_URL_EXPIRES_IN")
+        .ok()
+        .filter(|it| !it.is_empty())
+        .map(|it| {
+            let seconds: u64 = it
+                .parse()
+                .expect("Env `FILE_URL_EXPIRES_IN` must be a non-negative integer");
+            FileSignedUrlExpiry::try_from(seconds)
+                .expect("Env `FILE_URL_EXPIRES_IN` is out of range")
+        })
+        .unwrap_or_else(|| {
+            tracing::debug!(
+                "The FILE_URL_EXPIRES_IN environment variable is not set. Using the default value instead."
+            );
+            FileSignedUrlExpiry::default()
+        })
+}
+
+pub fn allowed_emails() -> EmailAllowlist {
+    env::var("ALLOWED_EMAILS")
+        .ok()
+        .filter(|it| !it.is_empty())
+        .map(|it| {
+            EmailAllowlist::try_from(it.as_str()).expect("Env `ALLOWED_EMAILS` is invalid")
+        })
+        .unwrap_or_else(|| {
+            tracing::debug!(
+                "The ALLOWED_EMAILS environment variable is not set. Using the default value instead."
+            );
+            EmailAllowlist::default()
+        })
+}
@@ -1,37 +1,58 @@
 use axum::http::StatusCode;
 
+use sos24_domain::entity::api_token::{ApiTokenError, ApiTokenIdError};
 use sos24_domain::entity::common::datetime::DateTimeError;
+use sos24_domain::entity::common::markdown::MarkdownError;
+use sos24_domain::entity::custom_role::CustomRoleIdError;
 use sos24_domain::entity::file_data::FileIdError;
+use sos24_domain::entity::file_export::FileExportIdError;
+use sos24_domain::entity::file_object::FileSignedUrlExpiryError;
 use sos24_domain::entity::form::{FormError, FormIdError, FormItemIdError};
 use sos24_domain::entity::form_answer::FormAnswerIdError;
+use sos24_domain::entity::impersonation_log::ImpersonationLogError;
 use sos24_domain::entity::project::BoundedStringError;
+use sos24_domain::repository::api_token::ApiTokenRepositoryError;
+use sos24_domain::repository::custom_role::CustomRoleRepositoryError;
 use sos24_domain::repository::file_data::FileDataRepositoryError;
+use sos24_domain::repository::file_export::FileExportRepositoryError;
 use sos24_domain::repository::file_object::FileObjectRepositoryError;
+use sos24_domain::repository::file_upload::FileUploadRepositoryError;
 use sos24_domain::repository::form::FormRepositoryError;
 use sos24_domain::repository::form_answer::FormAnswerRepositoryError;
+use sos24_domain::repository::impersonation_log::ImpersonationLogRepositoryError;
+use sos24_domain::repository::project_storage_quota::ProjectStorageQuotaRepositoryError;
 use sos24_domain::service::verify_form_answer::VerifyFormAnswerError;
 use sos24_domain::{
     entity::{
         common::email::EmailError,
         invitation::{InvitationError, InvitationIdError},
         news::NewsIdError,
-        permission::PermissionDeniedError,
+        notification::NotificationIdError,
+        permission::{PermissionDeniedError, PermissionNameError},
         project::{ProjectError, ProjectIdError},
+        user::UserPhoneNumberError,
+        webhook::{WebhookIdError, WebhookUrlError},
     },
     repository::{
         firebase_user::FirebaseUserRepositoryError, invitation::InvitationRepositoryError,
-        news::NewsRepositoryError, project::ProjectRepositoryError, user::UserRepositoryError,
+        news::NewsRepositoryError, notification::NotificationRepositoryError,
+        project::ProjectRepositoryError, user::UserRepositoryError,
+        webhook::WebhookRepositoryError, webhook_delivery::WebhookDeliveryRepositoryError,
     },
 };
+use sos24_use_case::event::EventUseCaseError;
 use sos24_use_case::file::FileUseCaseError;
 use sos24_use_case::form::FormUseCaseError;
 use sos24_use_case::form_answer::FormAnswerUseCaseError;
+use sos24_use_case::role::RoleUseCaseError;
 use sos24_use_case::{
-    invitation::InvitationUseCaseError, news::NewsUseCaseError, project::ProjectUseCaseError,
-    shared::context::ContextError, user::UserUseCaseError,
+    invitation::InvitationUseCaseError, news::NewsUseCaseError,
+    notification::NotificationUseCaseError, project::ProjectUseCaseError,
+    shared::context::ContextError, user::UserUseCaseError, webhook::WebhookUseCaseError,
 };
 
-use crate::csv::CsvSerializationError;
+use crate::archive::ArchiveError;
+use crate::csv::{CsvDeserializationError, CsvSerializationError};
 
 use super::AppError;
 
@@ -47,6 +68,30 @@ impl From<CsvSerializationError> for AppError {
     }
 }
 
+impl From<ArchiveError> for AppError {
+    fn from(error: ArchiveError) -> Self {
+        match error {
+            ArchiveError::FailedToArchive(err) => AppError::new(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "archive/failed-to-archive".to_string(),
+                err.to_string(),
+            ),
+        }
+    }
+}
+
+impl From<CsvDeserializationError> for AppError {
+    fn from(error: CsvDeserializationError) -> Self {
+        match error {
+            CsvDeserializationError::FailedToDeserialize(err) => AppError::new(
+                StatusCode::BAD_REQUEST,
+                "csv/failed-to-deserialize".to_string(),
+                format!("{err:#}"),
+            ),
+        }
+    }
+}
+
 impl From<FormUseCaseError> for AppError {
     fn from(error: FormUseCaseError) -> Self {
         let message = error.to_string();
@@ -71,6 +116,7 @@ impl From<FormUseCaseError> for AppError {
             ),
             FormUseCaseError::ProjectUseCaseError(e) => e.into(),
             FormUseCaseError::DateTimeError(e) => e.into(),
+            FormUseCaseError::MarkdownError(e) => e.into(),
             FormUseCaseError::FormRepositoryError(e) => e.into(),
             FormUseCaseError::ContextError(e) => e.into(),
             FormUseCaseError::PermissionDeniedError(e) => e.into(),
@@ -220,24 +266,79 @@ impl From<FileUseCaseError> for AppError {
                 "file/form-item-not-found".to_string(),
                 message,
             ),
+            FileUseCaseError::UploadNotFound(_) => AppError::new(
+                StatusCode::NOT_FOUND,
+                "file/upload-not-found".to_string(),
+                message,
+            ),
+            FileUseCaseError::UploadExpired(_) => AppError::new(
+                StatusCode::BAD_REQUEST,
+                "file/upload-expired".to_string(),
+                message,
+            ),
+            FileUseCaseError::ObjectNotUploaded(_) => AppError::new(
+                StatusCode::BAD_REQUEST,
+                "file/object-not-uploaded".to_string(),
+                message,
+            ),
+            FileUseCaseError::NotScanned(_) => AppError::new(
+                StatusCode::CONFLICT,
+                "file/not-scanned".to_string(),
+                message,
+            ),
+            FileUseCaseError::Infected(_) => {
+                AppError::new(StatusCode::FORBIDDEN, "file/infected".to_string(), message)
+            }
+            FileUseCaseError::Unscannable(_) => AppError::new(
+                StatusCode::FORBIDDEN,
+                "file/unscannable".to_string(),
+                message,
+            ),
+            FileUseCaseError::ThumbnailNotFound(_) => AppError::new(
+                StatusCode::NOT_FOUND,
+                "file/thumbnail-not-found".to_string(),
+                message,
+            ),
+            FileUseCaseError::StorageQuotaExceeded(_) => AppError::new(
+                StatusCode::PAYLOAD_TOO_LARGE,
+                "file/storage-quota-exceeded".to_string(),
+                message,
+            ),
+            FileUseCaseError::ExportNotFound(_) => AppError::new(
+                StatusCode::NOT_FOUND,
+                "file/export-not-found".to_string(),
+                message,
+            ),
             FileUseCaseError::FileDataRepositoryError(e) => e.into(),
+            FileUseCaseError::FileUploadRepositoryError(e) => e.into(),
             FileUseCaseError::FileIdError(e) => e.into(),
+            FileUseCaseError::FileSignedUrlExpiryError(e) => e.into(),
+            FileUseCaseError::FileExportRepositoryError(e) => e.into(),
+            FileUseCaseError::FileExportIdError(e) => e.into(),
             FileUseCaseError::PermissionDeniedError(e) => e.into(),
             FileUseCaseError::InternalError(e) => e.into(),
             FileUseCaseError::FileObjectRepositoryError(e) => e.into(),
             FileUseCaseError::ContextError(e) => e.into(),
+            FileUseCaseError::NewsRepositoryError(e) => e.into(),
             FileUseCaseError::ProjectRepositoryError(e) => e.into(),
             FileUseCaseError::ProjectIdError(e) => e.into(),
             FileUseCaseError::FormRepositoryError(e) => e.into(),
             FileUseCaseError::FormIdError(e) => e.into(),
             FileUseCaseError::FormAnswerRepositoryError(e) => e.into(),
+            FileUseCaseError::ProjectStorageQuotaRepositoryError(e) => e.into(),
         }
     }
 }
 
 impl From<FileObjectRepositoryError> for AppError {
     fn from(error: FileObjectRepositoryError) -> AppError {
+        let message = error.to_string();
         match error {
+            FileObjectRepositoryError::SizeLimitExceeded(_) => AppError::new(
+                StatusCode::PAYLOAD_TOO_LARGE,
+                "file/too-large".to_string(),
+                message,
+            ),
             FileObjectRepositoryError::InternalError(e) => e.into(),
         }
     }
@@ -259,6 +360,112 @@ impl From<FileDataRepositoryError> for AppError {
     }
 }
 
+impl From<ProjectStorageQuotaRepositoryError> for AppError {
+    fn from(value: ProjectStorageQuotaRepositoryError) -> Self {
+        match value {
+            ProjectStorageQuotaRepositoryError::InternalError(e) => e.into(),
+        }
+    }
+}
+
+impl From<FileUploadRepositoryError> for AppError {
+    fn from(value: FileUploadRepositoryError) -> Self {
+        match value {
+            FileUploadRepositoryError::InternalError(e) => e.into(),
+        }
+    }
+}
+
+impl From<FileExportRepositoryError> for AppError {
+    fn from(value: FileExportRepositoryError) -> Self {
+        match value {
+            FileExportRepositoryError::InternalError(e) => e.into(),
+        }
+    }
+}
+
+impl From<FileExportIdError> for AppError {
+    fn from(value: FileExportIdError) -> Self {
+        AppError::new(
+            StatusCode::BAD_REQUEST,
+            "file/export-id".to_string(),
+            value.to_string(),
+        )
+    }
+}
+
+impl From<ApiTokenRepositoryError> for AppError {
+    fn from(value: ApiTokenRepositoryError) -> Self {
+        match value {
+            ApiTokenRepositoryError::InternalError(e) => e.into(),
+        }
+    }
+}
+
+impl From<ApiTokenError> for AppError {
+    fn from(value: ApiTokenError) -> Self {
+        match value {
+            ApiTokenError::EmptyScopes => AppError::new(
+                StatusCode::BAD_REQUEST,
+                "user/api-token-empty-scopes".to_string(),
+                value.to_string(),
+            ),
+            ApiTokenError::AlreadyExpired => AppError::new(
+                StatusCode::BAD_REQUEST,
+                "user/api-token-already-expired".to_string(),
+                value.to_string(),
+            ),
+            ApiTokenError::PermissionDenied(e) => e.into(),
+        }
+    }
+}
+
+impl From<ImpersonationLogRepositoryError> for AppError {
+    fn from(value: ImpersonationLogRepositoryError) -> Self {
+        match value {
+            ImpersonationLogRepositoryError::InternalError(e) => e.into(),
+        }
+    }
+}
+
+impl From<ImpersonationLogError> for AppError {
+    fn from(value: ImpersonationLogError) -> Self {
+        match value {
+            ImpersonationLogError::CannotImpersonateSelf => AppError::new(
+                StatusCode::BAD_REQUEST,
+                "auth/cannot-impersonate-self".to_string(),
+                value.to_string(),
+            ),
+            ImpersonationLogError::CannotImpersonatePrivilegedUser => AppError::new(
+                StatusCode::FORBIDDEN,
+                "auth/cannot-impersonate-privileged-user".to_string(),
+                value.to_string(),
+            ),
+            ImpersonationLogError::PermissionDenied(e) => e.into(),
+        }
+    }
+}
+
+impl From<ApiTokenIdError> for AppError {
+    fn from(value: ApiTokenIdError) -> Self {
+        AppError::new(
+            StatusCode::BAD_REQUEST,
+            "user/api-token-id".to_string(),
+            value.to_string(),
+        )
+    }
+}
+
+impl From<FileSignedUrlExpiryError> for AppError {
+    fn from(value: FileSignedUrlExpiryError) -> Self {
+        AppError::new(
+            StatusCode::BAD_REQUEST,
+            "file/invalid-expires-in".to_string(),
+            value.to_string(),
+        )
+    }
+}
+
 impl From<FileIdError> for AppError {
     fn from(value: FileIdError) -> Self {
         AppError::new(
@@ -297,6 +504,86 @@ impl From<NewsUseCaseError> for AppError {
             NewsUseCaseError::ProjectRepositoryError(e) => e.into(),
             NewsUseCaseError::UserRepositoryError(e) => e.into(),
             NewsUseCaseError::DateTimeError(e) => e.into(),
+            NewsUseCaseError::MarkdownError(e) => e.into(),
+            NewsUseCaseError::ProjectIdError(e) => e.into(),
+        }
+    }
+}
+
+impl From<NotificationUseCaseError> for AppError {
+    fn from(error: NotificationUseCaseError) -> AppError {
+        let message = error.to_string();
+        match error {
+            NotificationUseCaseError::NotFound(_) => AppError::new(
+                StatusCode::NOT_FOUND,
+                "notification/not-found".to_string(),
+                message,
+            ),
+            NotificationUseCaseError::NotificationIdError(e) => e.into(),
+            NotificationUseCaseError::NotificationRepositoryError(e) => e.into(),
+            NotificationUseCaseError::ContextError(e) => e.into(),
+            NotificationUseCaseError::PermissionDeniedError(e) => e.into(),
+            NotificationUseCaseError::InternalError(e) => e.into(),
+        }
+    }
+}
+
+impl From<EventUseCaseError> for AppError {
+    fn from(error: EventUseCaseError) -> AppError {
+        match error {
+            EventUseCaseError::ContextError(e) => e.into(),
+            EventUseCaseError::InternalError(e) => e.into(),
+        }
+    }
+}
+
+impl From<WebhookUseCaseError> for AppError {
+    fn from(error: WebhookUseCaseError) -> AppError {
+        let message = error.to_string();
+        match error {
+            WebhookUseCaseError::NotFound(_) => AppError::new(
+                StatusCode::NOT_FOUND,
+                "webhook/not-found".to_string(),
+                message,
+            ),
+            WebhookUseCaseError::FormNotFound(_) => {
+                AppError::new(StatusCode::NOT_FOUND, "form/not-found".to_string(), message)
+            }
+            WebhookUseCaseError::ProjectNotFound(_) => AppError::new(
+                StatusCode::NOT_FOUND,
+                "project/not-found".to_string(),
+                message,
+            ),
+            WebhookUseCaseError::WebhookIdError(e) => e.into(),
+            WebhookUseCaseError::WebhookUrlError(e) => e.into(),
+            WebhookUseCaseError::WebhookRepositoryError(e) => e.into(),
+            WebhookUseCaseError::WebhookDeliveryRepositoryError(e) => e.into(),
+            WebhookUseCaseError::FormRepositoryError(e) => e.into(),
+            WebhookUseCaseError::ProjectRepositoryError(e) => e.into(),
+            WebhookUseCaseError::ContextError(e) => e.into(),
+            WebhookUseCaseError::PermissionDeniedError(e) => e.into(),
+            WebhookUseCaseError::InternalError(e) => e.into(),
+        }
+    }
+}
+
+impl From<RoleUseCaseError> for AppError {
+    fn from(error: RoleUseCaseError) -> AppError {
+        let message = error.to_string();
+        match error {
+            RoleUseCaseError::NotFound(_) => {
+                AppError::new(StatusCode::NOT_FOUND, "role/not-found".to_string(), message)
+            }
+            RoleUseCaseError::UserNotFound(_) => {
+                AppError::new(StatusCode::NOT_FOUND, "user/not-found".to_string(), message)
+            }
+            RoleUseCaseError::CustomRoleIdError(e) => e.into(),
+            RoleUseCaseError::PermissionNameError(e) => e.into(),
+            RoleUseCaseError::CustomRoleRepositoryError(e) => e.into(),
+            RoleUseCaseError::UserRepositoryError(e) => e.into(),
+            RoleUseCaseError::ContextError(e) => e.into(),
+            RoleUseCaseError::PermissionDeniedError(e) => e.into(),
+            RoleUseCaseError::InternalError(e) => e.into(),
         }
     }
 }
@@ -386,10 +673,40 @@ impl From<UserUseCaseError> for AppError {
                 "user/arleady-exist".to_string(),
                 error.to_string(),
             ),
+            UserUseCaseError::InvalidCredentials => AppError::new(
+                StatusCode::UNAUTHORIZED,
+                "auth/invalid-credentials".to_string(),
+                error.to_string(),
+            ),
+            UserUseCaseError::ApiTokenNotFound(_) => AppError::new(
+                StatusCode::NOT_FOUND,
+                "user/api-token-not-found".to_string(),
+                error.to_string(),
+            ),
+            UserUseCaseError::InvalidApiToken => AppError::new(
+                StatusCode::UNAUTHORIZED,
+                "auth/invalid-token".to_string(),
+                error.to_string(),
+            ),
+            UserUseCaseError::StillOwnsProject(_) => AppError::new(
+                StatusCode::CONFLICT,
+                "user/still-owns-project".to_string(),
+                error.to_string(),
+            ),
+            UserUseCaseError::InvitationRepositoryError(e) => e.into(),
+            UserUseCaseError::FormRepositoryError(e) => e.into(),
+            UserUseCaseError::FormAnswerRepositoryError(e) => e.into(),
+            UserUseCaseError::ApiTokenRepositoryError(e) => e.into(),
+            UserUseCaseError::ApiTokenError(e) => e.into(),
+            UserUseCaseError::ApiTokenIdError(e) => e.into(),
+            UserUseCaseError::DateTimeError(e) => e.into(),
+            UserUseCaseError::ImpersonationLogError(e) => e.into(),
+            UserUseCaseError::ImpersonationLogRepositoryError(e) => e.into(),
             UserUseCaseError::ContextError(e) => e.into(),
             UserUseCaseError::UserRepositoryError(e) => e.into(),
             UserUseCaseError::FirebaseUserRepositoryError(e) => e.into(),
             UserUseCaseError::EmailError(e) => e.into(),
+            UserUseCaseError::UserPhoneNumberError(e) => e.into(),
             UserUseCaseError::PermissionDeniedError(e) => e.into(),
             UserUseCaseError::InternalError(e) => e.into(),
             UserUseCaseError::ProjectRepositoryError(e) => e.into(),
@@ -407,6 +724,7 @@ impl From<ContextError> for AppError {
             ),
             ContextError::UserRepositoryError(e) => e.into(),
             ContextError::ProjectRepositoryError(e) => e.into(),
+            ContextError::CustomRoleRepositoryError(e) => e.into(),
         }
     }
 }
@@ -435,6 +753,38 @@ impl From<InvitationRepositoryError> for AppError {
     }
 }
 
+impl From<NotificationRepositoryError> for AppError {
+    fn from(error: NotificationRepositoryError) -> AppError {
+        match error {
+            NotificationRepositoryError::InternalError(e) => e.into(),
+        }
+    }
+}
+
+impl From<WebhookRepositoryError> for AppError {
+    fn from(error: WebhookRepositoryError) -> AppError {
+        match error {
+            WebhookRepositoryError::InternalError(e) => e.into(),
+        }
+    }
+}
+
+impl From<CustomRoleRepositoryError> for AppError {
+    fn from(error: CustomRoleRepositoryError) -> AppError {
+        match error {
+            CustomRoleRepositoryError::InternalError(e) => e.into(),
+        }
+    }
+}
+
+impl From<WebhookDeliveryRepositoryError> for AppError {
+    fn from(error: WebhookDeliveryRepositoryError) -> AppError {
+        match error {
+            WebhookDeliveryRepositoryError::InternalError(e) => e.into(),
+        }
+    }
+}
+
 impl From<ProjectRepositoryError> for AppError {
     fn from(error: ProjectRepositoryError) -> AppError {
         match error {
@@ -560,6 +910,72 @@ impl From<InvitationIdError> for AppError {
     }
 }
 
+impl From<NotificationIdError> for AppError {
+    fn from(error: NotificationIdError) -> AppError {
+        match error {
+            NotificationIdError::InvalidUuid => AppError::new(
+                StatusCode::BAD_REQUEST,
+                "notification/invalid-uuid".to_string(),
+                error.to_string(),
+            ),
+        }
+    }
+}
+
+impl From<WebhookIdError> for AppError {
+    fn from(error: WebhookIdError) -> AppError {
+        match error {
+            WebhookIdError::InvalidUuid => AppError::new(
+                StatusCode::BAD_REQUEST,
+                "webhook/invalid-uuid".to_string(),
+                error.to_string(),
+            ),
+        }
+    }
+}
+
+impl From<CustomRoleIdError> for AppError {
+    fn from(error: CustomRoleIdError) -> AppError {
+        match error {
+            CustomRoleIdError::InvalidUuid => AppError::new(
+                StatusCode::BAD_REQUEST,
+                "role/invalid-uuid".to_string(),
+                error.to_string(),
+            ),
+        }
+    }
+}
+
+impl From<PermissionNameError> for AppError {
+    fn from(error: PermissionNameError) -> AppError {
+        let message = error.to_string();
+        match error {
+            PermissionNameError::Unknown(_) => AppError::new(
+                StatusCode::BAD_REQUEST,
+                "role/unknown-permission".to_string(),
+                message,
+            ),
+        }
+    }
+}
+
+impl From<WebhookUrlError> for AppError {
+    fn from(error: WebhookUrlError) -> AppError {
+        match error {
+            WebhookUrlError::InvalidUrl => AppError::new(
+                StatusCode::BAD_REQUEST,
+                "webhook/invalid-url".to_string(),
+                error.to_string(),
+            ),
+            WebhookUrlError::InvalidScheme => AppError::new(
+                StatusCode::BAD_REQUEST,
+                "webhook/invalid-url-scheme".to_string(),
+                error.to_string(),
+            ),
+        }
+    }
+}
+
 impl From<FormIdError> for AppError {
     fn from(error: FormIdError) -> Self {
         match error {
@@ -659,6 +1075,26 @@ impl From<VerifyFormAnswerError> for AppError {
                 "form-answer/too-many-files".to_string(),
                 error.to_string(),
             ),
+            VerifyFormAnswerError::FileNotFound(_, _) => AppError::new(
+                StatusCode::BAD_REQUEST,
+                "form-answer/file-not-found".to_string(),
+                error.to_string(),
+            ),
+            VerifyFormAnswerError::FileNotOwnedByProject(_, _) => AppError::new(
+                StatusCode::BAD_REQUEST,
+                "form-answer/file-not-owned-by-project".to_string(),
+                error.to_string(),
+            ),
+            VerifyFormAnswerError::InvalidFileExtension(_, _) => AppError::new(
+                StatusCode::BAD_REQUEST,
+                "form-answer/invalid-file-extension".to_string(),
+                error.to_string(),
+            ),
+            VerifyFormAnswerError::TooLargeFile(_, _, _) => AppError::new(
+                StatusCode::BAD_REQUEST,
+                "form-answer/too-large-file".to_string(),
+                error.to_string(),
+            ),
         }
     }
 }
@@ -704,6 +1140,18 @@ impl From<EmailError> for AppError {
     }
 }
 
+impl From<UserPhoneNumberError> for AppError {
+    fn from(error: UserPhoneNumberError) -> AppError {
+        match error {
+            UserPhoneNumberError::InvalidFormat(_) => AppError::new(
+                StatusCode::BAD_REQUEST,
+                "user/invalid-phone-number".to_string(),
+                error.to_string(),
+            ),
+        }
+    }
+}
+
 impl From<DateTimeError> for AppError {
     fn from(error: DateTimeError) -> Self {
         match error {
@@ -716,6 +1164,23 @@ impl From<DateTimeError> for AppError {
     }
 }
 
+impl From<MarkdownError> for AppError {
+    fn from(error: MarkdownError) -> Self {
+        match error {
+            MarkdownError::TooLong(_) => AppError::new(
+                StatusCode::BAD_REQUEST,
+                "markdown/too-long".to_string(),
+                error.to_string(),
+            ),
+            MarkdownError::InvalidCharacter(_) => AppError::new(
+                StatusCode::BAD_REQUEST,
+                "markdown/invalid-character".to_string(),
+                error.to_string(),
+            ),
+        }
+    }
+}
+
 impl From<BoundedStringError> for AppError {
     fn from(error: BoundedStringError) -> AppError {
         match error {
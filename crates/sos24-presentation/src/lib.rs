@@ -1,3 +1,4 @@
+pub mod archive;
 pub mod config;
 pub mod context;
 pub mod csv;
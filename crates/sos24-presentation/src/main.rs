@@ -1,11 +1,19 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use sos24_use_case::user::interactor::create::CreateUserCommand;
 use tokio::net::TcpListener;
 
-use sos24_presentation::{config::Config, context::Context, env, module, route::create_app};
+use sos24_presentation::{
+    config::{AuthProvider, Config},
+    context::Context,
+    env, module,
+    route::create_app,
+};
 use tokio_cron_scheduler::{Job, JobScheduler};
 
+// 作成されたWebhookの配信を確認する間隔
+const WEBHOOK_DELIVERY_INTERVAL: Duration = Duration::from_secs(5);
+
 #[tokio::main]
 async fn main() {
     if let Err(e) = dotenvy::dotenv_override() {
@@ -16,14 +24,27 @@ async fn main() {
 
     tracing::info!("Initializing server");
 
+    let auth_provider = env::auth_provider();
     let config = Config {
-        firebase_project_id: env::firebase_project_id(),
+        auth_provider,
+        local_auth_secret: match auth_provider {
+            AuthProvider::Firebase => String::new(),
+            AuthProvider::Local => env::local_auth_secret(),
+        },
+        firebase_project_id: match auth_provider {
+            AuthProvider::Firebase => env::firebase_project_id(),
+            AuthProvider::Local => String::new(),
+        },
+        jwks_url: env::jwks_url(),
         require_email_verification: env::require_email_verification(),
         project_application_start_at: env::project_application_start_at(),
         project_application_end_at: env::project_application_end_at(),
         s3_bucket_name: env::s3_bucket_name(),
         // 1GB
         file_upload_limit: 1e+9 as usize,
+        project_storage_quota: env::project_storage_quota(),
+        file_url_expires_in: env::file_url_expires_in(),
+        email_allowlist: env::allowed_emails(),
 
         email_sender_address: env::email_sender_address(),
         email_reply_to_address: env::email_reply_to_address(),
@@ -45,8 +66,50 @@ async fn main() {
         })
         .await;
 
+    // 実委向けの通知はWebhookで配信するので、SlackのURLはWebhookとして登録しておく
+    if let Some(url) = env::slack_webhook_url() {
+        if let Err(err) = modules
+            .webhook_use_case()
+            .create_slack_notification(url)
+            .await
+        {
+            tracing::error!("Failed to register Slack webhook: {err:?}");
+        }
+    }
+
     let app = create_app(Arc::clone(&modules));
 
+    // イベントを購読してWebhookの配信を作成する
+    let dispatcher_modules = Arc::clone(&modules);
+    let dispatcher_ctx = Context::new_system(config.clone().into());
+    tokio::spawn(async move {
+        dispatcher_modules
+            .webhook_use_case()
+            .dispatch_events(&dispatcher_ctx)
+            .await;
+    });
+
+    // 作成されたWebhookの配信を送信する
+    // 同じ配信を重複して送らないよう、1つのタスクで順番に処理する
+    let deliverer_modules = Arc::clone(&modules);
+    let deliverer_config = config.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(WEBHOOK_DELIVERY_INTERVAL);
+        loop {
+            interval.tick().await;
+            let ctx = Context::new_system(deliverer_config.clone().into());
+            if let Err(err) = deliverer_modules
+                .webhook_use_case()
+                .deliver_pending(&ctx)
+                .await
+            {
+                tracing::error!("Failed to deliver pending webhooks: {err:?}");
+            }
+        }
+    });
+
+    let gc_modules = Arc::clone(&modules);
+    let gc_config = config.clone();
     let sched = JobScheduler::new()
         .await
         .expect("Failed to create job scheduler");
@@ -66,11 +129,58 @@ async fn main() {
                 .check_news_and_send_notify(&ctx)
                 .await
                 .expect("Failed to check news and send notify");
+            if let Err(err) = modules
+                .file_use_case()
+                .clean_up_uploads(&ctx, modules.config().s3_bucket_name.clone())
+                .await
+            {
+                tracing::error!("Failed to clean up expired uploads: {err:?}");
+            }
+            if let Err(err) = modules
+                .file_use_case()
+                .scan_pending(&ctx, modules.config().s3_bucket_name.clone())
+                .await
+            {
+                tracing::error!("Failed to scan pending files: {err:?}");
+            }
+            if let Err(err) = modules
+                .file_use_case()
+                .generate_pending_thumbnails(&ctx, modules.config().s3_bucket_name.clone())
+                .await
+            {
+                tracing::error!("Failed to generate pending thumbnails: {err:?}");
+            }
+            if let Err(err) = modules
+                .file_use_case()
+                .run_pending_exports(&ctx, modules.config().s3_bucket_name.clone())
+                .await
+            {
+                tracing::error!("Failed to run pending exports: {err:?}");
+            }
             tracing::info!("cronjobを実行しました");
         })
     })
     .expect("Failed to create job");
     sched.add(job).await.expect("Failed to add job");
+    // フォーム・回答・お知らせをすべて読み込むため、1日1回(日本時間の午前4時)だけ実行する
+    let gc_job = Job::new_async("0 0 19 * * *", move |_, _| {
+        let modules = Arc::clone(&gc_modules);
+        let config = gc_config.clone().into();
+        Box::pin(async move {
+            tracing::info!("参照されていないファイルを回収します");
+            let ctx = Context::new_system(config);
+            if let Err(err) = modules
+                .file_use_case()
+                .collect_garbage(&ctx, modules.config().s3_bucket_name.clone())
+                .await
+            {
+                tracing::error!("Failed to collect garbage files: {err:?}");
+            }
+            tracing::info!("参照されていないファイルを回収しました");
+        })
+    })
+    .expect("Failed to create job");
+    sched.add(gc_job).await.expect("Failed to add job");
     sched.start().await.expect("Failed to start job scheduler");
 
     tracing::info!("Server initialized");
@@ -1,20 +1,26 @@
 use std::sync::Arc;
-use std::time::Duration;
 
 use anyhow::Context as _;
 use axum::{
     extract::{Request, State},
-    http::StatusCode,
+    http::{HeaderValue, Method, StatusCode},
     middleware::Next,
-    response::IntoResponse,
+    response::{IntoResponse, Response},
 };
-use jsonwebtoken::{
-    decode, decode_header, jwk::JwkSet, Algorithm, DecodingKey, TokenData, Validation,
-};
-use reqwest::ClientBuilder;
+use jsonwebtoken::{decode, decode_header, Algorithm, TokenData, Validation};
 use serde::{Deserialize, Serialize};
+use sos24_domain::entity::{api_token::ApiTokenSecret, permission::Permissions};
+use sos24_use_case::{
+    shared::context::ContextProvider, user::interactor::impersonate::ImpersonateCommand,
+};
 
-use crate::{context::Context, error::AppError, module::Modules};
+use crate::{
+    config::{AuthProvider, Config},
+    context::Context,
+    error::AppError,
+    middleware::{jwks::JwksCache, local_auth::LocalTokenIssuer},
+    module::Modules,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct Claims {
@@ -26,9 +32,6 @@ pub(crate) struct Claims {
     pub email_verified: bool,
 }
 
-const JWK_URL: &str =
-    "https://www.googleapis.com/service_accounts/v1/jwk/securetoken@system.gserviceaccount.com";
-
 pub(crate) async fn jwt_auth(
     State(modules): State<Arc<Modules>>,
     mut request: Request,
@@ -59,7 +62,27 @@ pub(crate) async fn jwt_auth(
 
     let jwt_token = authorization.trim_start_matches("Bearer ");
 
-    let token = match verify_id_token(jwt_token, &modules.config().firebase_project_id).await {
+    if ApiTokenSecret::is_api_token(jwt_token) {
+        if request.headers().contains_key(IMPERSONATE_USER_HEADER) {
+            return Err(AppError::new(
+                StatusCode::FORBIDDEN,
+                "auth/impersonation-not-allowed".to_string(),
+                "Impersonation is not allowed with API tokens.".to_string(),
+            ));
+        }
+
+        let ctx = authenticate_api_token(&modules, jwt_token, request.method()).await?;
+        request.extensions_mut().insert(ctx);
+
+        tracing::info!("APIトークンによるユーザー認証が完了しました");
+        return Ok(next.run(request).await);
+    }
+
+    let token = match modules
+        .token_verifier()
+        .verify(jwt_token, modules.config())
+        .await
+    {
         Ok(v) => v,
         Err(e) => {
             tracing::error!("Failed to verify: {e}");
@@ -82,27 +105,154 @@ pub(crate) async fn jwt_auth(
 
     // もし user_id 以上のものを Extension に入れるなら、ここで渡す
     let ctx = Context::new(token.claims.sub.clone(), modules.config().clone().into());
+    let impersonate_target = request.headers().get(IMPERSONATE_USER_HEADER).cloned();
+    let ctx = match impersonate_target {
+        Some(target) => {
+            impersonate(
+                &modules,
+                ctx,
+                &target,
+                request.method(),
+                request.uri().path(),
+            )
+            .await?
+        }
+        None => ctx,
+    };
     request.extensions_mut().insert(ctx);
 
     tracing::info!("ユーザー認証が完了しました");
     Ok(next.run(request).await)
 }
 
+// 認証なしでも利用できるエンドポイントで、Authorizationヘッダーがある場合だけ認証する
+pub(crate) async fn optional_jwt_auth(
+    State(modules): State<Arc<Modules>>,
+    request: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    if !request.headers().contains_key("Authorization") {
+        return Ok(next.run(request).await);
+    }
+    jwt_auth(State(modules), request, next)
+        .await
+        .map(IntoResponse::into_response)
+}
+
+// 管理者がこのヘッダーにユーザーIDを指定すると、そのユーザーとしてリクエストを行える
+pub(crate) const IMPERSONATE_USER_HEADER: &str = "X-Impersonate-User";
+
+// なりすましはサポートのための閲覧にのみ使い、データを変更するリクエストは拒否する
+async fn impersonate(
+    modules: &Modules,
+    ctx: Context,
+    target: &HeaderValue,
+    method: &Method,
+    path: &str,
+) -> Result<Context, AppError> {
+    if method != Method::GET && method != Method::HEAD {
+        return Err(AppError::new(
+            StatusCode::FORBIDDEN,
+            "auth/impersonation-read-only".to_string(),
+            "Impersonation can only be used for read requests.".to_string(),
+        ));
+    }
+
+    let target = target.to_str().map_err(|e| {
+        AppError::new(
+            StatusCode::BAD_REQUEST,
+            "auth/invalid-impersonate-user-header".to_string(),
+            e.to_string(),
+        )
+    })?;
+
+    modules
+        .user_use_case()
+        .impersonate(
+            &ctx,
+            ImpersonateCommand {
+                user_id: target.to_string(),
+                request: format!("{method} {path}"),
+            },
+        )
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to impersonate: {e}");
+            AppError::from(e)
+        })?;
+
+    Ok(
+        Context::new(target.to_string(), modules.config().clone().into())
+            .with_impersonator(ctx.user_id()),
+    )
+}
+
+// APIトークンはスクリプトからデータを取得するためのものなので、読み取りのリクエストにのみ使える
+async fn authenticate_api_token(
+    modules: &Modules,
+    token: &str,
+    method: &Method,
+) -> Result<Context, AppError> {
+    if method != Method::GET && method != Method::HEAD {
+        return Err(AppError::new(
+            StatusCode::FORBIDDEN,
+            "auth/api-token-read-only".to_string(),
+            "API tokens can only be used for read requests.".to_string(),
+        ));
+    }
+
+    let credential = modules
+        .user_use_case()
+        .authenticate_api_token(token.to_string())
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to verify api token: {e}");
+            AppError::from(e)
+        })?;
+
+    Ok(
+        Context::new(credential.user_id, modules.config().clone().into())
+            .with_scopes(Permissions::from_bits_truncate(credential.permissions)),
+    )
+}
+
+// 設定された認証基盤に応じてIDトークンを検証する
+pub enum TokenVerifier {
+    Firebase(JwksCache),
+    Local(LocalTokenIssuer),
+}
+
+impl TokenVerifier {
+    pub fn new(config: &Config) -> anyhow::Result<Self> {
+        match config.auth_provider {
+            AuthProvider::Firebase => Ok(Self::Firebase(JwksCache::new(config.jwks_url.clone())?)),
+            AuthProvider::Local => Ok(Self::Local(LocalTokenIssuer::new(
+                &config.local_auth_secret,
+            )?)),
+        }
+    }
+
+    pub(crate) async fn verify(
+        &self,
+        token: &str,
+        config: &Config,
+    ) -> anyhow::Result<TokenData<Claims>> {
+        match self {
+            Self::Firebase(jwks) => verify_id_token(token, &config.firebase_project_id, jwks).await,
+            Self::Local(issuer) => issuer.verify(token),
+        }
+    }
+}
+
 pub(crate) async fn verify_id_token(
     token: &str,
     firebase_project_id: &str,
+    jwks: &JwksCache,
 ) -> anyhow::Result<TokenData<Claims>> {
     let header = decode_header(token)?;
     let kid = header.kid.context("No key ID found in JWT header")?;
 
-    let client = ClientBuilder::new()
-        .timeout(Duration::from_secs(60))
-        .build()
-        .context("Failed to create HTTP client")?;
-    let jwks: JwkSet = client.get(JWK_URL).send().await?.json().await?;
-
-    let jwk = jwks.find(&kid).context("Unknown key ID")?;
-    let key = DecodingKey::from_jwk(jwk)?;
+    let key = jwks.find(&kid).await?;
 
     let mut validation = Validation::new(Algorithm::RS256);
 
@@ -0,0 +1,175 @@
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Context as _};
+use jsonwebtoken::{
+    jwk::{Jwk, JwkSet},
+    DecodingKey,
+};
+use reqwest::{header::CACHE_CONTROL, Client, ClientBuilder};
+use tokio::sync::{Mutex, RwLock};
+
+// Cache-Controlにmax-ageが無い場合に保持する時間
+const DEFAULT_MAX_AGE: Duration = Duration::from_secs(60 * 60);
+// 未知のkidを含むトークンが続いても、この間隔より短くは取得し直さない
+const MIN_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+const TIMEOUT: Duration = Duration::from_secs(60);
+
+struct CachedJwks {
+    jwks: JwkSet,
+    fetched_at: Instant,
+    expires_at: Instant,
+}
+
+enum Lookup {
+    Found(Box<Jwk>),
+    // 取得し直したばかりなので、取得し直しても見つからない
+    Unknown,
+    // 期限切れか、鍵が更新されている可能性がある
+    Stale,
+}
+
+// IDトークンの署名を検証するための公開鍵(JWKS)をキャッシュする
+// 鍵のローテーションで未知のkidが来た場合は取得し直すが、同時に取得し直すのは1つのリクエストだけにする
+pub struct JwksCache {
+    url: String,
+    client: Client,
+    cache: RwLock<Option<CachedJwks>>,
+    refresh_lock: Mutex<()>,
+}
+
+impl JwksCache {
+    pub fn new(url: String) -> anyhow::Result<Self> {
+        let client = ClientBuilder::new()
+            .timeout(TIMEOUT)
+            .build()
+            .context("Failed to create HTTP client")?;
+        Ok(Self {
+            url,
+            client,
+            cache: RwLock::new(None),
+            refresh_lock: Mutex::new(()),
+        })
+    }
+
+    pub async fn find(&self, kid: &str) -> anyhow::Result<DecodingKey> {
+        match self.lookup(kid).await {
+            Lookup::Found(jwk) => return Ok(DecodingKey::from_jwk(&jwk)?),
+            Lookup::Unknown => bail!("Unknown key ID"),
+            Lookup::Stale => {}
+        }
+
+        let _guard = self.refresh_lock.lock().await;
+        // 待っている間に他のリクエストが取得し直しているかもしれない
+        match self.lookup(kid).await {
+            Lookup::Found(jwk) => return Ok(DecodingKey::from_jwk(&jwk)?),
+            Lookup::Unknown => bail!("Unknown key ID"),
+            Lookup::Stale => {}
+        }
+
+        let jwk = match self.fetch().await {
+            Ok(cached) => {
+                let jwk = cached.jwks.find(kid).cloned();
+                *self.cache.write().await = Some(cached);
+                jwk
+            }
+            Err(err) => {
+                // 取得できない間は、期限切れでも手元にある鍵で検証する
+                tracing::warn!("JWKSの取得に失敗しました: {err:?}");
+                let cache = self.cache.read().await;
+                let jwk = cache.as_ref().and_then(|it| it.jwks.find(kid)).cloned();
+                if jwk.is_none() {
+                    return Err(err);
+                }
+                jwk
+            }
+        };
+
+        let jwk = jwk.context("Unknown key ID")?;
+        Ok(DecodingKey::from_jwk(&jwk)?)
+    }
+
+    async fn lookup(&self, kid: &str) -> Lookup {
+        let now = Instant::now();
+        let cache = self.cache.read().await;
+        let Some(cached) = cache.as_ref().filter(|it| it.expires_at > now) else {
+            return Lookup::Stale;
+        };
+        match cached.jwks.find(kid) {
+            Some(jwk) => Lookup::Found(Box::new(jwk.clone())),
+            None if now.duration_since(cached.fetched_at) < MIN_REFRESH_INTERVAL => Lookup::Unknown,
+            None => Lookup::Stale,
+        }
+    }
+
+    async fn fetch(&self) -> anyhow::Result<CachedJwks> {
+        tracing::info!("JWKSを取得します");
+
+        let response = self
+            .client
+            .get(&self.url)
+            .send()
+            .await
+            .context("Failed to fetch JWKS")?
+            .error_for_status()
+            .context("Failed to fetch JWKS")?;
+        let max_age = response
+            .headers()
+            .get(CACHE_CONTROL)
+            .and_then(|it| it.to_str().ok())
+            .and_then(parse_max_age)
+            .unwrap_or(DEFAULT_MAX_AGE);
+        let jwks: JwkSet = response.json().await.context("Failed to parse JWKS")?;
+
+        tracing::info!(
+            "JWKSを取得しました: {}件, max-age: {max_age:?}",
+            jwks.keys.len()
+        );
+        let fetched_at = Instant::now();
+        Ok(CachedJwks {
+            jwks,
+            fetched_at,
+            expires_at: fetched_at + max_age,
+        })
+    }
+}
+
+// "public, max-age=19801, must-revalidate, no-transform" のような値からmax-ageを取り出す
+fn parse_max_age(cache_control: &str) -> Option<Duration> {
+    cache_control.split(',').find_map(|directive| {
+        let (name, value) = directive.split_once('=')?;
+        if !name.trim().eq_ignore_ascii_case("max-age") {
+            return None;
+        }
+        value
+            .trim()
+            .trim_matches('"')
+            .parse()
+            .ok()
+            .map(Duration::from_secs)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::parse_max_age;
+
+    #[test]
+    fn max_ageを取り出せる() {
+        assert_eq!(
+            parse_max_age("public, max-age=19801, must-revalidate, no-transform"),
+            Some(Duration::from_secs(19801))
+        );
+        assert_eq!(
+            parse_max_age("Max-Age=\"60\""),
+            Some(Duration::from_secs(60))
+        );
+    }
+
+    #[test]
+    fn max_ageが無い場合はnoneを返す() {
+        assert_eq!(parse_max_age("no-cache"), None);
+        assert_eq!(parse_max_age("max-age=abc"), None);
+    }
+}
@@ -1,8 +1,13 @@
+pub mod auth;
+pub mod event;
 pub mod file;
 pub mod form;
 pub mod form_answer;
 pub mod invitation;
 pub mod news;
+pub mod notification;
 pub mod project;
 pub mod project_application_period;
+pub mod role;
 pub mod user;
+pub mod webhook;
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use sos24_use_case::user::interactor::sign_in::SignInCommand;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SignIn {
+    pub email: String,
+    #[schema(format = "password")]
+    pub password: String,
+}
+
+impl From<SignIn> for SignInCommand {
+    fn from(value: SignIn) -> Self {
+        SignInCommand {
+            email: value.email,
+            password: value.password,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct IdToken {
+    pub id_token: String,
+    // IDトークンの有効期間 (秒)
+    pub expires_in: u64,
+}
@@ -0,0 +1,92 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use sos24_use_case::event::dto::EventDto;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Event {
+    ProjectCreated {
+        #[schema(format = "uuid")]
+        project_id: String,
+    },
+    ProjectUpdated {
+        #[schema(format = "uuid")]
+        project_id: String,
+    },
+    ProjectDeleted {
+        #[schema(format = "uuid")]
+        project_id: String,
+    },
+    NewsPublished {
+        #[schema(format = "uuid")]
+        news_id: String,
+    },
+    FormStarted {
+        #[schema(format = "uuid")]
+        form_id: String,
+    },
+    FormAnswerSubmitted {
+        #[schema(format = "uuid")]
+        form_answer_id: String,
+        #[schema(format = "uuid")]
+        form_id: String,
+        #[schema(format = "uuid")]
+        project_id: String,
+    },
+    FormAnswerUpdated {
+        #[schema(format = "uuid")]
+        form_answer_id: String,
+        #[schema(format = "uuid")]
+        form_id: String,
+        #[schema(format = "uuid")]
+        project_id: String,
+    },
+    InvitationReceived {
+        #[schema(format = "uuid")]
+        invitation_id: String,
+        #[schema(format = "uuid")]
+        project_id: String,
+    },
+    CommitteeNotified {
+        message: String,
+    },
+}
+
+impl From<EventDto> for Event {
+    fn from(dto: EventDto) -> Self {
+        match dto {
+            EventDto::ProjectCreated { project_id } => Self::ProjectCreated { project_id },
+            EventDto::ProjectUpdated { project_id } => Self::ProjectUpdated { project_id },
+            EventDto::ProjectDeleted { project_id } => Self::ProjectDeleted { project_id },
+            EventDto::NewsPublished { news_id } => Self::NewsPublished { news_id },
+            EventDto::FormStarted { form_id } => Self::FormStarted { form_id },
+            EventDto::FormAnswerSubmitted {
+                form_answer_id,
+                form_id,
+                project_id,
+            } => Self::FormAnswerSubmitted {
+                form_answer_id,
+                form_id,
+                project_id,
+            },
+            EventDto::FormAnswerUpdated {
+                form_answer_id,
+                form_id,
+                project_id,
+            } => Self::FormAnswerUpdated {
+                form_answer_id,
+                form_id,
+                project_id,
+            },
+            EventDto::InvitationReceived {
+                invitation_id,
+                project_id,
+            } => Self::InvitationReceived {
+                invitation_id,
+                project_id,
+            },
+            EventDto::CommitteeNotified { message } => Self::CommitteeNotified { message },
+        }
+    }
+}
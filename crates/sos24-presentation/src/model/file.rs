@@ -1,6 +1,13 @@
 use serde::{Deserialize, Serialize};
 
-use sos24_use_case::file::dto::{FileDto, FileInfoDto};
+use sos24_use_case::file::{
+    dto::{
+        CreatedFileExportDto, FileDispositionDto, FileDto, FileExportDto, FileExportStatusDto,
+        FileGarbageReportDto, FileInfoDto, FileScanStatusDto, FileThumbnailDto, ProjectStorageDto,
+        UploadUrlDto,
+    },
+    interactor::{create_export::CreateFileExportCommand, find_by_id::FindFileQuery},
+};
 use utoipa::{IntoParams, ToSchema};
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -9,6 +16,8 @@ pub struct File {
     pub id: String,
     #[schema(format = "uri")]
     pub url: String,
+    #[schema(format = "uri")]
+    pub thumbnail_url: Option<String>,
     pub name: String,
     #[schema(format = "uuid")]
     pub owner: Option<String>,
@@ -23,6 +32,7 @@ impl From<FileDto> for File {
         File {
             id: file.id,
             url: file.url,
+            thumbnail_url: file.thumbnail_url,
             name: file.filename,
             owner: file.owner,
             created_at: file.created_at.to_rfc3339(),
@@ -31,10 +41,61 @@ impl From<FileDto> for File {
     }
 }
 
+#[derive(Debug, Serialize, ToSchema)]
+pub struct FileThumbnail {
+    #[schema(format = "uri")]
+    pub url: String,
+}
+
+impl From<FileThumbnailDto> for FileThumbnail {
+    fn from(thumbnail: FileThumbnailDto) -> Self {
+        FileThumbnail { url: thumbnail.url }
+    }
+}
+
 #[derive(Debug, Deserialize, IntoParams)]
 pub struct CreateFileQuery {
     #[param(inline)]
     pub visibility: Visibility,
+    // 企画への配布などのために単独でアップロードするファイルは、どこからも参照されていなくても削除しない
+    #[serde(default)]
+    pub standalone: bool,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct FileUrlQuery {
+    // プレビューできない種類のファイルは、inlineを指定してもダウンロードさせる
+    #[serde(default)]
+    #[param(inline)]
+    pub disposition: Disposition,
+    // 設定された有効期間より短くしたい場合に指定する(秒)
+    pub expires_in: Option<u64>,
+}
+
+impl From<FileUrlQuery> for FindFileQuery {
+    fn from(query: FileUrlQuery) -> Self {
+        FindFileQuery {
+            disposition: FileDispositionDto::from(query.disposition),
+            expires_in: query.expires_in,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Disposition {
+    Inline,
+    #[default]
+    Attachment,
+}
+
+impl From<Disposition> for FileDispositionDto {
+    fn from(disposition: Disposition) -> Self {
+        match disposition {
+            Disposition::Inline => FileDispositionDto::Inline,
+            Disposition::Attachment => FileDispositionDto::Attachment,
+        }
+    }
 }
 
 #[derive(ToSchema)]
@@ -57,6 +118,11 @@ pub struct FileInfo {
     pub filename: String,
     #[schema(format = "uuid")]
     pub owner: Option<String>,
+    pub size: Option<i64>,
+    pub content_type: Option<String>,
+    pub checksum: Option<String>,
+    pub uploader: Option<String>,
+    pub scan_status: FileScanStatus,
     #[schema(format = "date-time")]
     pub created_at: String,
     #[schema(format = "date-time")]
@@ -69,16 +135,68 @@ impl From<FileInfoDto> for FileInfo {
             id: file.id,
             filename: file.filename,
             owner: file.owner,
+            size: file.size,
+            content_type: file.content_type,
+            checksum: file.checksum,
+            uploader: file.uploader,
+            scan_status: FileScanStatus::from(file.scan_status),
             created_at: file.created_at.to_rfc3339(),
             updated_at: file.updated_at.to_rfc3339(),
         }
     }
 }
 
+#[derive(Debug, Serialize, ToSchema)]
+pub struct FileGarbageReport {
+    pub to_be_deleted: Vec<FileInfo>,
+    pub to_be_purged: Vec<FileInfo>,
+}
+
+impl From<FileGarbageReportDto> for FileGarbageReport {
+    fn from(report: FileGarbageReportDto) -> Self {
+        FileGarbageReport {
+            to_be_deleted: report
+                .to_be_deleted
+                .into_iter()
+                .map(FileInfo::from)
+                .collect(),
+            to_be_purged: report
+                .to_be_purged
+                .into_iter()
+                .map(FileInfo::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FileScanStatus {
+    Pending,
+    Clean,
+    Infected,
+    Unscannable,
+    NotScanned,
+}
+
+impl From<FileScanStatusDto> for FileScanStatus {
+    fn from(status: FileScanStatusDto) -> Self {
+        match status {
+            FileScanStatusDto::Pending => FileScanStatus::Pending,
+            FileScanStatusDto::Clean => FileScanStatus::Clean,
+            FileScanStatusDto::Infected => FileScanStatus::Infected,
+            FileScanStatusDto::Unscannable => FileScanStatus::Unscannable,
+            FileScanStatusDto::NotScanned => FileScanStatus::NotScanned,
+        }
+    }
+}
+
 #[derive(Debug, Serialize, ToSchema)]
 pub struct CreatedFile {
     #[schema(format = "uuid")]
     pub ids: Vec<String>,
+    #[schema(format = "uuid")]
+    pub duplicate_ids: Vec<String>,
 }
 
 #[derive(Debug, Deserialize, IntoParams)]
@@ -88,3 +206,141 @@ pub struct ExportFileQuery {
     #[param(format = "uuid")]
     pub form_id: Option<String>,
 }
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateUploadUrl {
+    pub filename: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UploadUrl {
+    #[schema(format = "uuid")]
+    pub id: String,
+    #[schema(format = "uri")]
+    pub url: String,
+    #[schema(format = "date-time")]
+    pub expires_at: String,
+}
+
+impl From<UploadUrlDto> for UploadUrl {
+    fn from(dto: UploadUrlDto) -> Self {
+        UploadUrl {
+            id: dto.id,
+            url: dto.url,
+            expires_at: dto.expires_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ProjectStorage {
+    #[schema(format = "uuid")]
+    pub project_id: String,
+    pub used: u64,
+    pub quota: u64,
+    pub remaining: u64,
+}
+
+impl From<ProjectStorageDto> for ProjectStorage {
+    fn from(dto: ProjectStorageDto) -> Self {
+        Self {
+            project_id: dto.project_id,
+            used: dto.used,
+            quota: dto.quota,
+            remaining: dto.remaining,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpdateProjectStorage {
+    // 省略した場合は既定値に戻す
+    pub quota: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateFileExport {
+    #[schema(format = "uuid")]
+    pub form_id: String,
+    // trueの場合は、前回完了したエクスポート以降に更新された回答のファイルだけを含める
+    #[serde(default)]
+    pub incremental: bool,
+}
+
+impl From<CreateFileExport> for CreateFileExportCommand {
+    fn from(export: CreateFileExport) -> Self {
+        CreateFileExportCommand {
+            form_id: export.form_id,
+            incremental: export.incremental,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CreatedFileExport {
+    #[schema(format = "uuid")]
+    pub id: String,
+}
+
+impl From<CreatedFileExportDto> for CreatedFileExport {
+    fn from(dto: CreatedFileExportDto) -> Self {
+        Self { id: dto.id }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct FileExport {
+    #[schema(format = "uuid")]
+    pub id: String,
+    #[schema(format = "uuid")]
+    pub form_id: String,
+    pub status: FileExportStatus,
+    #[schema(format = "date-time")]
+    pub since: Option<String>,
+    pub total: i32,
+    pub processed: i32,
+    #[schema(format = "uri")]
+    pub url: Option<String>,
+    pub error: Option<String>,
+    #[schema(format = "date-time")]
+    pub created_at: String,
+    #[schema(format = "date-time")]
+    pub updated_at: String,
+}
+
+impl From<FileExportDto> for FileExport {
+    fn from(dto: FileExportDto) -> Self {
+        Self {
+            id: dto.id,
+            form_id: dto.form_id,
+            status: FileExportStatus::from(dto.status),
+            since: dto.since.map(|it| it.to_rfc3339()),
+            total: dto.total,
+            processed: dto.processed,
+            url: dto.url,
+            error: dto.error,
+            created_at: dto.created_at.to_rfc3339(),
+            updated_at: dto.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FileExportStatus {
+    Pending,
+    Running,
+    Completed,
+    Failed,
+}
+
+impl From<FileExportStatusDto> for FileExportStatus {
+    fn from(status: FileExportStatusDto) -> Self {
+        match status {
+            FileExportStatusDto::Pending => FileExportStatus::Pending,
+            FileExportStatusDto::Running => FileExportStatus::Running,
+            FileExportStatusDto::Completed => FileExportStatus::Completed,
+            FileExportStatusDto::Failed => FileExportStatus::Failed,
+        }
+    }
+}
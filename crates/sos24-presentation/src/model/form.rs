@@ -21,6 +21,12 @@ pub struct CreateForm {
     ends_at: String,
     categories: ProjectCategories,
     attributes: ProjectAttributes,
+    #[serde(default)]
+    #[schema(format = "uuid")]
+    included_projects: Vec<String>,
+    #[serde(default)]
+    #[schema(format = "uuid")]
+    excluded_projects: Vec<String>,
     items: Vec<NewFormItem>,
     #[schema(format = "uuid")]
     attachments: Vec<String>,
@@ -36,6 +42,8 @@ impl From<CreateForm> for CreateFormCommand {
             ends_at: create_form.ends_at,
             categories: ProjectCategoriesDto::from(create_form.categories),
             attributes: ProjectAttributesDto::from(create_form.attributes),
+            included_projects: create_form.included_projects,
+            excluded_projects: create_form.excluded_projects,
             items: create_form
                 .items
                 .into_iter()
@@ -83,6 +91,12 @@ pub struct UpdateForm {
     pub ends_at: String,
     pub categories: ProjectCategories,
     pub attributes: ProjectAttributes,
+    #[serde(default)]
+    #[schema(format = "uuid")]
+    pub included_projects: Vec<String>,
+    #[serde(default)]
+    #[schema(format = "uuid")]
+    pub excluded_projects: Vec<String>,
     pub items: Vec<NewFormItem>,
     #[schema(format = "uuid")]
     pub attachments: Vec<String>,
@@ -104,6 +118,8 @@ impl ConvertToUpdateFormDto for (String, UpdateForm) {
             ends_at: form.ends_at,
             categories: ProjectCategoriesDto::from(form.categories),
             attributes: ProjectAttributesDto::from(form.attributes),
+            included_projects: form.included_projects,
+            excluded_projects: form.excluded_projects,
             items: form.items.into_iter().map(NewFormItemDto::from).collect(),
             attachments: form.attachments,
         }
@@ -116,6 +132,7 @@ pub struct Form {
     pub id: String,
     pub title: String,
     pub description: String,
+    pub description_html: String,
     pub is_draft: bool,
     #[schema(format = "date-time")]
     pub starts_at: String,
@@ -123,6 +140,10 @@ pub struct Form {
     pub ends_at: String,
     pub categories: ProjectCategories,
     pub attributes: ProjectAttributes,
+    #[schema(format = "uuid")]
+    pub included_projects: Vec<String>,
+    #[schema(format = "uuid")]
+    pub excluded_projects: Vec<String>,
     pub items: Vec<FormItem>,
     #[schema(format = "uuid")]
     pub attachments: Vec<String>,
@@ -142,11 +163,14 @@ impl From<FormDto> for Form {
             id: form.id.to_string(),
             title: form.title,
             description: form.description,
+            description_html: form.description_html,
             is_draft: form.is_draft.value(),
             starts_at: form.starts_at.to_rfc3339(),
             ends_at: form.ends_at.to_rfc3339(),
             categories: ProjectCategories::from(form.categories),
             attributes: ProjectAttributes::from(form.attributes),
+            included_projects: form.included_projects,
+            excluded_projects: form.excluded_projects,
             items: form.items.into_iter().map(FormItem::from).collect(),
             attachments: form.attachments,
             answer_id: form.answer_id.map(|it| it.to_string()),
@@ -248,6 +272,7 @@ pub enum FormItemKind {
     File {
         extensions: Option<Vec<String>>,
         limit: Option<u32>,
+        max_size: Option<u64>,
     },
 }
 
@@ -274,7 +299,15 @@ impl From<FormItemKind> for FormItemKindDto {
                 min_selection,
                 max_selection,
             },
-            FormItemKind::File { extensions, limit } => FormItemKindDto::File { extensions, limit },
+            FormItemKind::File {
+                extensions,
+                limit,
+                max_size,
+            } => FormItemKindDto::File {
+                extensions,
+                limit,
+                max_size,
+            },
         }
     }
 }
@@ -302,7 +335,15 @@ impl From<FormItemKindDto> for FormItemKind {
                 min_selection,
                 max_selection,
             },
-            FormItemKindDto::File { extensions, limit } => FormItemKind::File { extensions, limit },
+            FormItemKindDto::File {
+                extensions,
+                limit,
+                max_size,
+            } => FormItemKind::File {
+                extensions,
+                limit,
+                max_size,
+            },
         }
     }
 }
@@ -17,6 +17,12 @@ pub struct CreateNews {
     attachments: Vec<String>,
     categories: ProjectCategories,
     attributes: ProjectAttributes,
+    #[serde(default)]
+    #[schema(format = "uuid")]
+    included_projects: Vec<String>,
+    #[serde(default)]
+    #[schema(format = "uuid")]
+    excluded_projects: Vec<String>,
     #[schema(format = "date-time")]
     scheduled_at: Option<String>,
 }
@@ -58,6 +64,8 @@ impl From<CreateNews> for CreateNewsCommand {
             attachments: news.attachments,
             categories: ProjectCategoriesDto::from(news.categories),
             attributes: ProjectAttributesDto::from(news.attributes),
+            included_projects: news.included_projects,
+            excluded_projects: news.excluded_projects,
             scheduled_at: news.scheduled_at,
         }
     }
@@ -78,6 +86,12 @@ pub struct UpdateNews {
     attachments: Vec<String>,
     categories: ProjectCategories,
     attributes: ProjectAttributes,
+    #[serde(default)]
+    #[schema(format = "uuid")]
+    included_projects: Vec<String>,
+    #[serde(default)]
+    #[schema(format = "uuid")]
+    excluded_projects: Vec<String>,
     #[schema(format = "date-time")]
     scheduled_at: Option<String>,
 }
@@ -97,6 +111,8 @@ impl ConvertToUpdateNewsDto for (String, UpdateNews) {
             attachments: news.attachments,
             categories: ProjectCategoriesDto::from(news.categories),
             attributes: ProjectAttributesDto::from(news.attributes),
+            included_projects: news.included_projects,
+            excluded_projects: news.excluded_projects,
             scheduled_at: news.scheduled_at,
         }
     }
@@ -109,10 +125,15 @@ pub struct News {
     pub state: NewsState,
     pub title: String,
     pub body: String,
+    pub body_html: String,
     #[schema(format = "uuid")]
     pub attachments: Vec<String>,
     pub categories: ProjectCategories,
     pub attributes: ProjectAttributes,
+    #[schema(format = "uuid")]
+    pub included_projects: Vec<String>,
+    #[schema(format = "uuid")]
+    pub excluded_projects: Vec<String>,
     #[schema(format = "date-time")]
     pub created_at: String,
     #[schema(format = "date-time")]
@@ -128,9 +149,12 @@ impl From<NewsDto> for News {
             state: NewsState::from(news.state),
             title: news.title,
             body: news.body,
+            body_html: news.body_html,
             attachments: news.attachments,
             categories: ProjectCategories::from(news.categories),
             attributes: ProjectAttributes::from(news.attributes),
+            included_projects: news.included_projects,
+            excluded_projects: news.excluded_projects,
             created_at: news.created_at.to_rfc3339(),
             updated_at: news.updated_at.to_rfc3339(),
             scheduled_at: news.scheduled_at,
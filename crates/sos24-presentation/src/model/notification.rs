@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use sos24_use_case::notification::dto::{NotificationDto, NotificationKindDto};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct Notification {
+    #[schema(format = "uuid")]
+    id: String,
+    kind: NotificationKind,
+    title: String,
+    body: String,
+    #[schema(format = "uri")]
+    url: String,
+    #[schema(format = "date-time")]
+    read_at: Option<String>,
+    #[schema(format = "date-time")]
+    created_at: String,
+}
+
+impl From<NotificationDto> for Notification {
+    fn from(dto: NotificationDto) -> Self {
+        Self {
+            id: dto.id,
+            kind: NotificationKind::from(dto.kind),
+            title: dto.title,
+            body: dto.body,
+            url: dto.url,
+            read_at: dto.read_at.map(|it| it.to_rfc3339()),
+            created_at: dto.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationKind {
+    NewsPublished,
+    FormStarted,
+    InvitationReceived,
+}
+
+impl From<NotificationKindDto> for NotificationKind {
+    fn from(kind: NotificationKindDto) -> Self {
+        match kind {
+            NotificationKindDto::NewsPublished => Self::NewsPublished,
+            NotificationKindDto::FormStarted => Self::FormStarted,
+            NotificationKindDto::InvitationReceived => Self::InvitationReceived,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UnreadNotificationCount {
+    pub count: i64,
+}
@@ -146,12 +146,16 @@ pub struct ProjectToBeExported {
     owner_email: String,
     #[serde(rename(serialize = "企画責任者電話番号"))]
     owner_phone_number: String,
+    #[serde(rename(serialize = "企画責任者電話番号（表示用）"))]
+    owner_phone_number_display: String,
     #[serde(rename(serialize = "副企画責任者"))]
     sub_owner_name: Option<String>,
     #[serde(rename(serialize = "副企画責任者メールアドレス"))]
     sub_owner_email: Option<String>,
     #[serde(rename(serialize = "副企画責任者電話番号"))]
     sub_owner_phone_number: Option<String>,
+    #[serde(rename(serialize = "副企画責任者電話番号（表示用）"))]
+    sub_owner_phone_number_display: Option<String>,
     #[serde(rename(serialize = "企画区分"))]
     category: String,
     #[serde(rename(serialize = "企画属性 屋内企画"))]
@@ -183,9 +187,11 @@ impl From<ProjectDto> for ProjectToBeExported {
             owner_name: project.owner_name,
             owner_email: project.owner_email,
             owner_phone_number: project.owner_phone_number,
+            owner_phone_number_display: project.owner_phone_number_display,
             sub_owner_name: project.sub_owner_name,
             sub_owner_email: project.sub_owner_email,
             sub_owner_phone_number: project.sub_owner_phone_number,
+            sub_owner_phone_number_display: project.sub_owner_phone_number_display,
             category: project.category.to_string(),
             attributes_inside: project.attributes.0.contains(&ProjectAttributeDto::Inside),
             attributes_outside: project.attributes.0.contains(&ProjectAttributeDto::Outside),
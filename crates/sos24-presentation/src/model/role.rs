@@ -0,0 +1,106 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::project::ProjectCategories;
+
+use sos24_use_case::project::dto::ProjectCategoriesDto;
+use sos24_use_case::role::{
+    dto::CustomRoleDto,
+    interactor::{
+        create::CreateCustomRoleCommand, set_user_roles::SetUserRolesCommand,
+        update::UpdateCustomRoleCommand,
+    },
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateCustomRole {
+    name: String,
+    // 権限の名前 (例: READ_PROJECT_ALL) の一覧
+    permissions: Vec<String>,
+    // 権限を適用する企画区分 (省略した場合はすべての企画区分)
+    project_categories: Option<ProjectCategories>,
+}
+
+impl From<CreateCustomRole> for CreateCustomRoleCommand {
+    fn from(role: CreateCustomRole) -> Self {
+        CreateCustomRoleCommand {
+            name: role.name,
+            permissions: role.permissions,
+            project_categories: role.project_categories.map(ProjectCategoriesDto::from),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CreatedCustomRole {
+    #[schema(format = "uuid")]
+    pub id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpdateCustomRole {
+    name: String,
+    permissions: Vec<String>,
+    project_categories: Option<ProjectCategories>,
+}
+
+pub trait ConvertToUpdateCustomRoleDto {
+    fn to_update_custom_role_dto(self) -> UpdateCustomRoleCommand;
+}
+
+impl ConvertToUpdateCustomRoleDto for (String, UpdateCustomRole) {
+    fn to_update_custom_role_dto(self) -> UpdateCustomRoleCommand {
+        let (id, role) = self;
+        UpdateCustomRoleCommand {
+            id,
+            name: role.name,
+            permissions: role.permissions,
+            project_categories: role.project_categories.map(ProjectCategoriesDto::from),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CustomRole {
+    #[schema(format = "uuid")]
+    id: String,
+    name: String,
+    permissions: Vec<String>,
+    project_categories: Option<ProjectCategories>,
+    #[schema(format = "date-time")]
+    created_at: String,
+    #[schema(format = "date-time")]
+    updated_at: String,
+}
+
+impl From<CustomRoleDto> for CustomRole {
+    fn from(dto: CustomRoleDto) -> Self {
+        Self {
+            id: dto.id,
+            name: dto.name,
+            permissions: dto.permissions,
+            project_categories: dto.project_categories.map(ProjectCategories::from),
+            created_at: dto.created_at.to_rfc3339(),
+            updated_at: dto.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpdateUserRoles {
+    role_ids: Vec<String>,
+}
+
+pub trait ConvertToSetUserRolesDto {
+    fn to_set_user_roles_dto(self) -> SetUserRolesCommand;
+}
+
+impl ConvertToSetUserRolesDto for (String, UpdateUserRoles) {
+    fn to_set_user_roles_dto(self) -> SetUserRolesCommand {
+        let (user_id, roles) = self;
+        SetUserRolesCommand {
+            user_id,
+            role_ids: roles.role_ids,
+        }
+    }
+}
@@ -2,10 +2,20 @@ use chrono_tz::Asia::Tokyo;
 use serde::{Deserialize, Serialize};
 
 use sos24_use_case::user::{
-    dto::{UserDto, UserRoleDto},
-    interactor::{create::CreateUserCommand, update::UpdateUserCommand},
+    dto::{
+        ApiTokenDto, ApiTokenScopeDto, CreatedApiTokenDto, ImpersonationLogDto,
+        ImportUserResultDto, ImportUserStatusDto, PersonalDataDto, UserDto, UserRoleDto,
+    },
+    interactor::{
+        create::CreateUserCommand, create_api_token::CreateApiTokenCommand, import::ImportUserRow,
+        update::UpdateUserCommand,
+    },
 };
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
+
+use super::form_answer::FormAnswer;
+use super::invitation::Invitation;
+use super::project::Project;
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct CreateUser {
@@ -124,6 +134,10 @@ pub struct UserTobeExported {
     kana_name: String,
     #[serde(rename(serialize = "メールアドレス"))]
     email: String,
+    #[serde(rename(serialize = "電話番号"))]
+    phone_number: String,
+    #[serde(rename(serialize = "電話番号（表示用）"))]
+    phone_number_display: String,
     #[serde(rename(serialize = "権限"))]
     role: String,
     #[serde(rename(serialize = "作成日時"))]
@@ -137,6 +151,8 @@ impl From<UserDto> for UserTobeExported {
             name: user.name,
             kana_name: user.kana_name,
             email: user.email,
+            phone_number: user.phone_number,
+            phone_number_display: user.phone_number_display,
             role: user.role.to_string(),
             created_at: user
                 .created_at
@@ -183,3 +199,243 @@ impl From<UserRoleDto> for UserRole {
         }
     }
 }
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateApiToken {
+    pub name: String,
+    pub scopes: Vec<ApiTokenScope>,
+    #[schema(format = "date-time")]
+    pub expires_at: Option<String>,
+}
+
+impl From<CreateApiToken> for CreateApiTokenCommand {
+    fn from(value: CreateApiToken) -> Self {
+        CreateApiTokenCommand {
+            name: value.name,
+            scopes: value
+                .scopes
+                .into_iter()
+                .map(ApiTokenScopeDto::from)
+                .collect(),
+            expires_at: value.expires_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CreatedApiToken {
+    #[schema(format = "uuid")]
+    pub id: String,
+    pub token: String,
+}
+
+impl From<CreatedApiTokenDto> for CreatedApiToken {
+    fn from(dto: CreatedApiTokenDto) -> Self {
+        CreatedApiToken {
+            id: dto.id,
+            token: dto.token,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ApiToken {
+    #[schema(format = "uuid")]
+    pub id: String,
+    pub name: String,
+    pub scopes: Vec<ApiTokenScope>,
+    #[schema(format = "date-time")]
+    pub expires_at: Option<String>,
+    #[schema(format = "date-time")]
+    pub last_used_at: Option<String>,
+    #[schema(format = "date-time")]
+    pub created_at: String,
+}
+
+impl From<ApiTokenDto> for ApiToken {
+    fn from(dto: ApiTokenDto) -> Self {
+        ApiToken {
+            id: dto.id,
+            name: dto.name,
+            scopes: dto.scopes.into_iter().map(ApiTokenScope::from).collect(),
+            expires_at: dto.expires_at.map(|it| it.to_rfc3339()),
+            last_used_at: dto.last_used_at.map(|it| it.to_rfc3339()),
+            created_at: dto.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ApiTokenScope {
+    ReadProjects,
+    ReadUsers,
+    ReadNews,
+    ReadForms,
+    ReadFormAnswers,
+    ReadInvitations,
+    ReadFiles,
+}
+
+impl From<ApiTokenScope> for ApiTokenScopeDto {
+    fn from(value: ApiTokenScope) -> Self {
+        match value {
+            ApiTokenScope::ReadProjects => ApiTokenScopeDto::ReadProjects,
+            ApiTokenScope::ReadUsers => ApiTokenScopeDto::ReadUsers,
+            ApiTokenScope::ReadNews => ApiTokenScopeDto::ReadNews,
+            ApiTokenScope::ReadForms => ApiTokenScopeDto::ReadForms,
+            ApiTokenScope::ReadFormAnswers => ApiTokenScopeDto::ReadFormAnswers,
+            ApiTokenScope::ReadInvitations => ApiTokenScopeDto::ReadInvitations,
+            ApiTokenScope::ReadFiles => ApiTokenScopeDto::ReadFiles,
+        }
+    }
+}
+
+impl From<ApiTokenScopeDto> for ApiTokenScope {
+    fn from(value: ApiTokenScopeDto) -> Self {
+        match value {
+            ApiTokenScopeDto::ReadProjects => ApiTokenScope::ReadProjects,
+            ApiTokenScopeDto::ReadUsers => ApiTokenScope::ReadUsers,
+            ApiTokenScopeDto::ReadNews => ApiTokenScope::ReadNews,
+            ApiTokenScopeDto::ReadForms => ApiTokenScope::ReadForms,
+            ApiTokenScopeDto::ReadFormAnswers => ApiTokenScope::ReadFormAnswers,
+            ApiTokenScopeDto::ReadInvitations => ApiTokenScope::ReadInvitations,
+            ApiTokenScopeDto::ReadFiles => ApiTokenScope::ReadFiles,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ImpersonationLog {
+    #[schema(format = "uuid")]
+    pub id: String,
+    // なりすましを行った管理者のID
+    pub impersonator_id: String,
+    // なりすまされたユーザーのID
+    pub user_id: String,
+    // なりすまして行ったリクエスト (例: "GET /projects/me")
+    pub request: String,
+    #[schema(format = "date-time")]
+    pub created_at: String,
+}
+
+impl From<ImpersonationLogDto> for ImpersonationLog {
+    fn from(dto: ImpersonationLogDto) -> Self {
+        ImpersonationLog {
+            id: dto.id,
+            impersonator_id: dto.impersonator_id,
+            user_id: dto.user_id,
+            request: dto.request,
+            created_at: dto.created_at.to_rfc3339(),
+        }
+    }
+}
+
+// 一括登録するCSVの1行
+#[derive(Debug, Deserialize)]
+pub struct UserToBeImported {
+    name: String,
+    kana_name: String,
+    email: String,
+    phone_number: String,
+    role: String,
+}
+
+impl From<UserToBeImported> for ImportUserRow {
+    fn from(value: UserToBeImported) -> Self {
+        ImportUserRow {
+            name: value.name,
+            kana_name: value.kana_name,
+            email: value.email,
+            phone_number: value.phone_number,
+            role: value.role,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ImportUsersQuery {
+    // trueの場合は検証のみを行い、ユーザーを作成しない
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ImportUserResult {
+    // データ行の番号 (1始まり、ヘッダーを含まない)
+    pub row: usize,
+    pub email: String,
+    pub status: ImportUserStatus,
+    pub user_id: Option<String>,
+    // パスワード設定用のリンクをメールで送信できたか
+    pub email_sent: bool,
+    pub error: Option<String>,
+}
+
+impl From<ImportUserResultDto> for ImportUserResult {
+    fn from(dto: ImportUserResultDto) -> Self {
+        ImportUserResult {
+            row: dto.row,
+            email: dto.email,
+            status: ImportUserStatus::from(dto.status),
+            user_id: dto.user_id,
+            email_sent: dto.email_sent,
+            error: dto.error,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportUserStatus {
+    Valid,
+    Created,
+    Failed,
+}
+
+impl From<ImportUserStatusDto> for ImportUserStatus {
+    fn from(value: ImportUserStatusDto) -> Self {
+        match value {
+            ImportUserStatusDto::Valid => ImportUserStatus::Valid,
+            ImportUserStatusDto::Created => ImportUserStatus::Created,
+            ImportUserStatusDto::Failed => ImportUserStatus::Failed,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PersonalDataFormat {
+    #[default]
+    Json,
+    Zip,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ExportPersonalDataQuery {
+    #[serde(default)]
+    #[param(inline)]
+    pub format: PersonalDataFormat,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PersonalData {
+    pub user: User,
+    pub project: Option<Project>,
+    pub invitations: Vec<Invitation>,
+    pub form_answers: Vec<FormAnswer>,
+    #[schema(format = "date-time")]
+    pub exported_at: String,
+}
+
+impl From<PersonalDataDto> for PersonalData {
+    fn from(dto: PersonalDataDto) -> Self {
+        PersonalData {
+            user: User::from(dto.user),
+            project: dto.project.map(Project::from),
+            invitations: dto.invitations.into_iter().map(Invitation::from).collect(),
+            form_answers: dto.form_answers.into_iter().map(FormAnswer::from).collect(),
+            exported_at: dto.exported_at.to_rfc3339(),
+        }
+    }
+}
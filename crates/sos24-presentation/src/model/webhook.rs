@@ -0,0 +1,223 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use sos24_use_case::webhook::{
+    dto::{
+        WebhookDeliveryDto, WebhookDeliveryStatusDto, WebhookDto, WebhookEventTypeDto,
+        WebhookFormatDto,
+    },
+    interactor::{create::CreateWebhookCommand, update::UpdateWebhookCommand},
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateWebhook {
+    name: String,
+    #[schema(format = "uri")]
+    url: String,
+    format: WebhookFormat,
+    event_types: Vec<WebhookEventType>,
+}
+
+impl From<CreateWebhook> for CreateWebhookCommand {
+    fn from(webhook: CreateWebhook) -> Self {
+        CreateWebhookCommand {
+            name: webhook.name,
+            url: webhook.url,
+            format: WebhookFormatDto::from(webhook.format),
+            event_types: webhook
+                .event_types
+                .into_iter()
+                .map(WebhookEventTypeDto::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CreatedWebhook {
+    #[schema(format = "uuid")]
+    pub id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpdateWebhook {
+    name: String,
+    #[schema(format = "uri")]
+    url: String,
+    format: WebhookFormat,
+    event_types: Vec<WebhookEventType>,
+}
+
+pub trait ConvertToUpdateWebhookDto {
+    fn to_update_webhook_dto(self) -> UpdateWebhookCommand;
+}
+
+impl ConvertToUpdateWebhookDto for (String, UpdateWebhook) {
+    fn to_update_webhook_dto(self) -> UpdateWebhookCommand {
+        let (id, webhook) = self;
+        UpdateWebhookCommand {
+            id,
+            name: webhook.name,
+            url: webhook.url,
+            format: WebhookFormatDto::from(webhook.format),
+            event_types: webhook
+                .event_types
+                .into_iter()
+                .map(WebhookEventTypeDto::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct Webhook {
+    #[schema(format = "uuid")]
+    id: String,
+    name: String,
+    #[schema(format = "uri")]
+    url: String,
+    secret: String,
+    format: WebhookFormat,
+    event_types: Vec<WebhookEventType>,
+    #[schema(format = "date-time")]
+    created_at: String,
+    #[schema(format = "date-time")]
+    updated_at: String,
+}
+
+impl From<WebhookDto> for Webhook {
+    fn from(dto: WebhookDto) -> Self {
+        Self {
+            id: dto.id,
+            name: dto.name,
+            url: dto.url,
+            secret: dto.secret,
+            format: WebhookFormat::from(dto.format),
+            event_types: dto
+                .event_types
+                .into_iter()
+                .map(WebhookEventType::from)
+                .collect(),
+            created_at: dto.created_at.to_rfc3339(),
+            updated_at: dto.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookFormat {
+    Json,
+    Slack,
+}
+
+impl From<WebhookFormat> for WebhookFormatDto {
+    fn from(format: WebhookFormat) -> Self {
+        match format {
+            WebhookFormat::Json => WebhookFormatDto::Json,
+            WebhookFormat::Slack => WebhookFormatDto::Slack,
+        }
+    }
+}
+
+impl From<WebhookFormatDto> for WebhookFormat {
+    fn from(format: WebhookFormatDto) -> Self {
+        match format {
+            WebhookFormatDto::Json => WebhookFormat::Json,
+            WebhookFormatDto::Slack => WebhookFormat::Slack,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEventType {
+    ProjectCreated,
+    ProjectUpdated,
+    ProjectDeleted,
+    FormAnswerSubmitted,
+    NewsPublished,
+    CommitteeNotification,
+}
+
+impl From<WebhookEventType> for WebhookEventTypeDto {
+    fn from(event_type: WebhookEventType) -> Self {
+        match event_type {
+            WebhookEventType::ProjectCreated => WebhookEventTypeDto::ProjectCreated,
+            WebhookEventType::ProjectUpdated => WebhookEventTypeDto::ProjectUpdated,
+            WebhookEventType::ProjectDeleted => WebhookEventTypeDto::ProjectDeleted,
+            WebhookEventType::FormAnswerSubmitted => WebhookEventTypeDto::FormAnswerSubmitted,
+            WebhookEventType::NewsPublished => WebhookEventTypeDto::NewsPublished,
+            WebhookEventType::CommitteeNotification => WebhookEventTypeDto::CommitteeNotification,
+        }
+    }
+}
+
+impl From<WebhookEventTypeDto> for WebhookEventType {
+    fn from(event_type: WebhookEventTypeDto) -> Self {
+        match event_type {
+            WebhookEventTypeDto::ProjectCreated => WebhookEventType::ProjectCreated,
+            WebhookEventTypeDto::ProjectUpdated => WebhookEventType::ProjectUpdated,
+            WebhookEventTypeDto::ProjectDeleted => WebhookEventType::ProjectDeleted,
+            WebhookEventTypeDto::FormAnswerSubmitted => WebhookEventType::FormAnswerSubmitted,
+            WebhookEventTypeDto::NewsPublished => WebhookEventType::NewsPublished,
+            WebhookEventTypeDto::CommitteeNotification => WebhookEventType::CommitteeNotification,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WebhookDelivery {
+    #[schema(format = "uuid")]
+    id: String,
+    #[schema(format = "uuid")]
+    webhook_id: String,
+    event_type: WebhookEventType,
+    payload: String,
+    status: WebhookDeliveryStatus,
+    attempts: i32,
+    response_status: Option<i32>,
+    last_error: Option<String>,
+    #[schema(format = "date-time")]
+    next_attempt_at: Option<String>,
+    #[schema(format = "date-time")]
+    created_at: String,
+    #[schema(format = "date-time")]
+    updated_at: String,
+}
+
+impl From<WebhookDeliveryDto> for WebhookDelivery {
+    fn from(dto: WebhookDeliveryDto) -> Self {
+        Self {
+            id: dto.id,
+            webhook_id: dto.webhook_id,
+            event_type: WebhookEventType::from(dto.event_type),
+            payload: dto.payload,
+            status: WebhookDeliveryStatus::from(dto.status),
+            attempts: dto.attempts,
+            response_status: dto.response_status,
+            last_error: dto.last_error,
+            next_attempt_at: dto.next_attempt_at.map(|it| it.to_rfc3339()),
+            created_at: dto.created_at.to_rfc3339(),
+            updated_at: dto.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookDeliveryStatus {
+    Pending,
+    Succeeded,
+    Failed,
+}
+
+impl From<WebhookDeliveryStatusDto> for WebhookDeliveryStatus {
+    fn from(status: WebhookDeliveryStatusDto) -> Self {
+        match status {
+            WebhookDeliveryStatusDto::Pending => WebhookDeliveryStatus::Pending,
+            WebhookDeliveryStatusDto::Succeeded => WebhookDeliveryStatus::Succeeded,
+            WebhookDeliveryStatusDto::Failed => WebhookDeliveryStatus::Failed,
+        }
+    }
+}
@@ -3,11 +3,13 @@
 use std::sync::Arc;
 
 use crate::config::Config;
+use crate::middleware::auth::TokenVerifier;
 use sos24_domain::entity::project_application_period::ProjectApplicationPeriod;
 use sos24_use_case::file::FileUseCase;
 use sos24_use_case::{
-    form::FormUseCase, form_answer::FormAnswerUseCase, invitation::InvitationUseCase,
-    news::NewsUseCase, project::ProjectUseCase, user::UserUseCase,
+    event::EventUseCase, form::FormUseCase, form_answer::FormAnswerUseCase,
+    invitation::InvitationUseCase, news::NewsUseCase, notification::NotificationUseCase,
+    project::ProjectUseCase, role::RoleUseCase, user::UserUseCase, webhook::WebhookUseCase,
 };
 
 #[cfg(not(test))]
@@ -25,13 +27,18 @@ mod modules {
 
 pub struct Modules {
     config: Config,
+    token_verifier: TokenVerifier,
+    event_use_case: EventUseCase<modules::Repositories, modules::Adapters>,
     form_use_case: FormUseCase<modules::Repositories, modules::Adapters>,
-    form_answer_use_case: FormAnswerUseCase<modules::Repositories>,
-    invitation_use_case: InvitationUseCase<modules::Repositories>,
+    form_answer_use_case: FormAnswerUseCase<modules::Repositories, modules::Adapters>,
+    invitation_use_case: InvitationUseCase<modules::Repositories, modules::Adapters>,
     news_use_case: NewsUseCase<modules::Repositories, modules::Adapters>,
-    file_use_case: FileUseCase<modules::Repositories>,
+    notification_use_case: NotificationUseCase<modules::Repositories>,
+    file_use_case: FileUseCase<modules::Repositories, modules::Adapters>,
     project_use_case: ProjectUseCase<modules::Repositories, modules::Adapters>,
-    user_use_case: UserUseCase<modules::Repositories>,
+    role_use_case: RoleUseCase<modules::Repositories>,
+    user_use_case: UserUseCase<modules::Repositories, modules::Adapters>,
+    webhook_use_case: WebhookUseCase<modules::Repositories, modules::Adapters>,
 }
 
 impl Modules {
@@ -39,15 +46,27 @@ impl Modules {
         &self.config
     }
 
+    pub fn token_verifier(&self) -> &TokenVerifier {
+        &self.token_verifier
+    }
+
+    pub fn event_use_case(&self) -> &EventUseCase<modules::Repositories, modules::Adapters> {
+        &self.event_use_case
+    }
+
     pub fn form_use_case(&self) -> &FormUseCase<modules::Repositories, modules::Adapters> {
         &self.form_use_case
     }
 
-    pub fn form_answer_use_case(&self) -> &FormAnswerUseCase<modules::Repositories> {
+    pub fn form_answer_use_case(
+        &self,
+    ) -> &FormAnswerUseCase<modules::Repositories, modules::Adapters> {
         &self.form_answer_use_case
     }
 
-    pub fn invitation_use_case(&self) -> &InvitationUseCase<modules::Repositories> {
+    pub fn invitation_use_case(
+        &self,
+    ) -> &InvitationUseCase<modules::Repositories, modules::Adapters> {
         &self.invitation_use_case
     }
 
@@ -55,7 +74,11 @@ impl Modules {
         &self.news_use_case
     }
 
-    pub fn file_use_case(&self) -> &FileUseCase<modules::Repositories> {
+    pub fn notification_use_case(&self) -> &NotificationUseCase<modules::Repositories> {
+        &self.notification_use_case
+    }
+
+    pub fn file_use_case(&self) -> &FileUseCase<modules::Repositories, modules::Adapters> {
         &self.file_use_case
     }
 
@@ -63,23 +86,37 @@ impl Modules {
         &self.project_use_case
     }
 
-    pub fn user_use_case(&self) -> &UserUseCase<modules::Repositories> {
+    pub fn role_use_case(&self) -> &RoleUseCase<modules::Repositories> {
+        &self.role_use_case
+    }
+
+    pub fn user_use_case(&self) -> &UserUseCase<modules::Repositories, modules::Adapters> {
         &self.user_use_case
     }
+
+    pub fn webhook_use_case(&self) -> &WebhookUseCase<modules::Repositories, modules::Adapters> {
+        &self.webhook_use_case
+    }
 }
 
 #[cfg(not(test))]
 pub async fn new(config: Config) -> anyhow::Result<Modules> {
+    use sos24_infrastructure::auth_user::AuthBackend;
     use sos24_infrastructure::shared::{
         firebase::FirebaseAuth, mongodb::MongoDb, postgresql::Postgresql, s3::S3,
         sendgrid::SendGrid,
     };
 
-    use crate::env;
+    use crate::{config::AuthProvider, env};
 
     let db = Postgresql::new(&env::postgres_db_url()).await?;
     let mongo_db = MongoDb::new(&env::mongodb_db_url(), &env::mongodb_db_name()).await?;
-    let auth = FirebaseAuth::new(&env::firebase_service_account_key()).await?;
+    let auth = match config.auth_provider {
+        AuthProvider::Firebase => AuthBackend::Firebase(Box::new(
+            FirebaseAuth::new(&env::firebase_service_account_key()).await?,
+        )),
+        AuthProvider::Local => AuthBackend::Local,
+    };
     let object_storage = S3::new(
         &env::s3_endpoint(),
         &env::s3_region(),
@@ -88,7 +125,7 @@ pub async fn new(config: Config) -> anyhow::Result<Modules> {
     )
     .await;
     let repositories = Arc::new(sos24_infrastructure::DefaultRepositories::new(
-        db,
+        db.clone(),
         mongo_db,
         auth,
         object_storage,
@@ -97,7 +134,8 @@ pub async fn new(config: Config) -> anyhow::Result<Modules> {
     let send_grid = SendGrid::new(env::send_grid_api_key());
     let adapters = Arc::new(sos24_infrastructure::DefaultAdapters::new(
         send_grid,
-        env::slack_webhook_url(),
+        env::clamav_address(),
+        db,
     ));
 
     let application_period = ProjectApplicationPeriod::new(
@@ -105,22 +143,37 @@ pub async fn new(config: Config) -> anyhow::Result<Modules> {
         config.project_application_end_at.clone(),
     );
 
+    let email_allowlist = config.email_allowlist.clone();
+
     Ok(Modules {
+        token_verifier: TokenVerifier::new(&config)?,
         config,
+        event_use_case: EventUseCase::new(Arc::clone(&repositories), Arc::clone(&adapters)),
         form_use_case: FormUseCase::new(Arc::clone(&repositories), Arc::clone(&adapters)),
-        form_answer_use_case: FormAnswerUseCase::new(Arc::clone(&repositories)),
+        form_answer_use_case: FormAnswerUseCase::new(
+            Arc::clone(&repositories),
+            Arc::clone(&adapters),
+        ),
         invitation_use_case: InvitationUseCase::new(
             Arc::clone(&repositories),
+            Arc::clone(&adapters),
             application_period.clone(),
         ),
         news_use_case: NewsUseCase::new(Arc::clone(&repositories), Arc::clone(&adapters)),
-        file_use_case: FileUseCase::new(Arc::clone(&repositories)),
+        notification_use_case: NotificationUseCase::new(Arc::clone(&repositories)),
+        file_use_case: FileUseCase::new(Arc::clone(&repositories), Arc::clone(&adapters)),
         project_use_case: ProjectUseCase::new(
             Arc::clone(&repositories),
             Arc::clone(&adapters),
             application_period,
         ),
-        user_use_case: UserUseCase::new(Arc::clone(&repositories)),
+        role_use_case: RoleUseCase::new(Arc::clone(&repositories)),
+        user_use_case: UserUseCase::new(
+            Arc::clone(&repositories),
+            Arc::clone(&adapters),
+            email_allowlist,
+        ),
+        webhook_use_case: WebhookUseCase::new(Arc::clone(&repositories), Arc::clone(&adapters)),
     })
 }
 
@@ -134,21 +187,38 @@ pub async fn new_test(
 
     let application_period = ProjectApplicationPeriod::default();
 
+    let config = Config::default();
+
+    let email_allowlist = config.email_allowlist.clone();
+
     Ok(Modules {
-        config: Config::default(),
+        token_verifier: TokenVerifier::new(&config)?,
+        config,
+        event_use_case: EventUseCase::new(Arc::clone(&repositories), Arc::clone(&adapters)),
         form_use_case: FormUseCase::new(Arc::clone(&repositories), Arc::clone(&adapters)),
-        form_answer_use_case: FormAnswerUseCase::new(Arc::clone(&repositories)),
+        form_answer_use_case: FormAnswerUseCase::new(
+            Arc::clone(&repositories),
+            Arc::clone(&adapters),
+        ),
         invitation_use_case: InvitationUseCase::new(
             Arc::clone(&repositories),
+            Arc::clone(&adapters),
             application_period.clone(),
         ),
         news_use_case: NewsUseCase::new(Arc::clone(&repositories), Arc::clone(&adapters)),
-        file_use_case: FileUseCase::new(Arc::clone(&repositories)),
+        notification_use_case: NotificationUseCase::new(Arc::clone(&repositories)),
+        file_use_case: FileUseCase::new(Arc::clone(&repositories), Arc::clone(&adapters)),
         project_use_case: ProjectUseCase::new(
             Arc::clone(&repositories),
             Arc::clone(&adapters),
             application_period,
         ),
-        user_use_case: UserUseCase::new(Arc::clone(&repositories)),
+        role_use_case: RoleUseCase::new(Arc::clone(&repositories)),
+        user_use_case: UserUseCase::new(
+            Arc::clone(&repositories),
+            Arc::clone(&adapters),
+            email_allowlist,
+        ),
+        webhook_use_case: WebhookUseCase::new(Arc::clone(&repositories), Arc::clone(&adapters)),
     })
 }
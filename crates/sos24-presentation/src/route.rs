@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use axum::http::{header, Method};
+use axum::http::{header, HeaderName, Method};
 use axum::{
     extract::DefaultBodyLimit,
     routing::{delete, get, post, put},
@@ -12,17 +12,22 @@ use tracing::Level;
 use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
 use utoipa::{Modify, OpenApi};
 
-use crate::{middleware::auth, module::Modules};
+use crate::{middleware, module::Modules};
 
+pub mod auth;
+pub mod event;
 pub mod file;
 pub mod form;
 pub mod form_answer;
 pub mod health;
 pub mod invitation;
 pub mod news;
+pub mod notification;
 pub mod project;
 pub mod project_application_period;
+pub mod role;
 pub mod user;
+pub mod webhook;
 
 pub fn create_app(modules: Arc<Modules>) -> Router {
     let news = Router::new()
@@ -36,17 +41,40 @@ pub fn create_app(modules: Arc<Modules>) -> Router {
         .route("/", post(file::handle_post))
         .layer(DefaultBodyLimit::max(modules.config().file_upload_limit))
         .route("/", get(file::handle_get))
+        .route("/upload-url", post(file::handle_post_upload_url))
         .route("/export", get(file::handle_export))
+        .route("/garbage", get(file::handle_get_garbage))
         .route("/{file_id}", get(file::handle_get_id))
-        .route("/{file_id}", delete(file::handle_delete_id));
+        .route("/{file_id}", delete(file::handle_delete_id))
+        .route("/{file_id}/complete", post(file::handle_post_id_complete))
+        .route("/{file_id}/thumbnail", get(file::handle_get_id_thumbnail));
+
+    let export = Router::new()
+        .route("/", post(file::handle_post_export))
+        .route("/{export_id}", get(file::handle_get_export_id));
 
     let user = Router::new()
         .route("/", get(user::handle_get))
         .route("/export", get(user::handle_export))
         .route("/me", get(user::handle_get_me))
+        .route("/me", delete(user::handle_delete_me))
+        .route("/me/export", get(user::handle_get_me_export))
+        .route("/me/tokens", get(user::handle_get_me_tokens))
+        .route("/me/tokens", post(user::handle_post_me_tokens))
+        .route(
+            "/me/tokens/{token_id}",
+            delete(user::handle_delete_me_tokens_id),
+        )
+        .route(
+            "/impersonation-logs",
+            get(user::handle_get_impersonation_logs),
+        )
+        .route("/import", post(user::handle_post_import))
         .route("/{user_id}", get(user::handle_get_id))
         .route("/{user_id}", delete(user::handle_delete_id))
-        .route("/{user_id}", put(user::handle_put_id));
+        .route("/{user_id}", put(user::handle_put_id))
+        .route("/{user_id}/roles", get(role::handle_get_user_id))
+        .route("/{user_id}/roles", put(role::handle_put_user_id));
 
     let project = Router::new()
         .route("/", get(project::handle_get))
@@ -55,7 +83,9 @@ pub fn create_app(modules: Arc<Modules>) -> Router {
         .route("/me", get(project::handle_get_me))
         .route("/{project_id}", get(project::handle_get_id))
         .route("/{project_id}", delete(project::handle_delete_id))
-        .route("/{project_id}", put(project::handle_put_id));
+        .route("/{project_id}", put(project::handle_put_id))
+        .route("/{project_id}/storage", get(project::handle_get_id_storage))
+        .route("/{project_id}/storage", put(project::handle_put_id_storage));
 
     let invitation = Router::new()
         .route("/", get(invitation::handle_get))
@@ -64,6 +94,15 @@ pub fn create_app(modules: Arc<Modules>) -> Router {
         .route("/{invitation_id}", delete(invitation::handle_delete_id))
         .route("/{invitation_id}", post(invitation::handle_post_id));
 
+    let notification = Router::new()
+        .route("/", get(notification::handle_get))
+        .route("/unread-count", get(notification::handle_get_unread_count))
+        .route("/read", post(notification::handle_post_read))
+        .route(
+            "/{notification_id}/read",
+            post(notification::handle_post_id_read),
+        );
+
     let form = Router::new()
         .route("/", get(form::handle_get))
         .route("/", post(form::handle_post))
@@ -78,22 +117,51 @@ pub fn create_app(modules: Arc<Modules>) -> Router {
         .route("/{form_answer_id}", get(form_answer::handle_get_id))
         .route("/{form_answer_id}", put(form_answer::handle_put_id));
 
+    let webhook = Router::new()
+        .route("/", get(webhook::handle_get))
+        .route("/", post(webhook::handle_post))
+        .route("/{webhook_id}", get(webhook::handle_get_id))
+        .route("/{webhook_id}", delete(webhook::handle_delete_id))
+        .route("/{webhook_id}", put(webhook::handle_put_id))
+        .route(
+            "/{webhook_id}/deliveries",
+            get(webhook::handle_get_id_deliveries),
+        );
+
+    let role = Router::new()
+        .route("/", get(role::handle_get))
+        .route("/", post(role::handle_post))
+        .route("/{role_id}", delete(role::handle_delete_id))
+        .route("/{role_id}", put(role::handle_put_id));
+
     let private_routes = Router::new()
         .nest("/news", news)
         .nest("/files", file)
+        .nest("/exports", export)
         .nest("/users", user)
         .nest("/projects", project)
         .nest("/invitations", invitation)
+        .nest("/notifications", notification)
+        .route("/events", get(event::handle_get))
         .nest("/forms", form)
         .nest("/form-answers", form_answers)
+        .nest("/webhooks", webhook)
+        .nest("/roles", role)
         .route_layer(axum::middleware::from_fn_with_state(
             Arc::clone(&modules),
-            auth::jwt_auth,
+            middleware::auth::jwt_auth,
         ));
 
     let public_routes = Router::new()
         .route("/health", get(health::handle_get))
-        .route("/users", post(user::handle_post))
+        .route("/auth/token", post(auth::handle_post_token))
+        .route(
+            "/users",
+            post(user::handle_post).route_layer(axum::middleware::from_fn_with_state(
+                Arc::clone(&modules),
+                middleware::auth::optional_jwt_auth,
+            )),
+        )
         .route(
             "/project-application-period",
             get(project_application_period::handle_get),
@@ -111,7 +179,11 @@ pub fn create_app(modules: Arc<Modules>) -> Router {
         )
         .layer(
             CorsLayer::new()
-                .allow_headers([header::AUTHORIZATION, header::CONTENT_TYPE])
+                .allow_headers([
+                    header::AUTHORIZATION,
+                    header::CONTENT_TYPE,
+                    HeaderName::from_static("x-impersonate-user"),
+                ])
                 .expose_headers([header::CONTENT_DISPOSITION])
                 .allow_methods([Method::GET, Method::PUT, Method::POST, Method::DELETE])
                 .allow_origin(Any),
@@ -131,14 +203,27 @@ use crate::route;
         (name = "forms", description = "申請関連の操作"),
         (name = "form-answers", description = "申請回答関連の操作"),
         (name = "invitations", description = "招待関連の操作"),
+        (name = "notifications", description = "通知関連の操作"),
+        (name = "events", description = "更新通知関連の操作"),
+        (name = "webhooks", description = "Webhook関連の操作"),
+        (name = "roles", description = "カスタムロール関連の操作"),
         (name = "meta", description = "状態確認関連の操作"),
+        (name = "auth", description = "認証関連の操作"),
     ),
     paths(
+        route::auth::handle_post_token,
+        route::event::handle_get,
         route::file::handle_get,
         route::file::handle_post,
+        route::file::handle_post_upload_url,
         route::file::handle_export,
+        route::file::handle_get_garbage,
         route::file::handle_get_id,
         route::file::handle_delete_id,
+        route::file::handle_post_id_complete,
+        route::file::handle_get_id_thumbnail,
+        route::file::handle_post_export,
+        route::file::handle_get_export_id,
         route::form::handle_get,
         route::form::handle_post,
         route::form::handle_get_id,
@@ -160,6 +245,10 @@ use crate::route;
         route::news::handle_get_id,
         route::news::handle_delete_id,
         route::news::handle_put_id,
+        route::notification::handle_get,
+        route::notification::handle_get_unread_count,
+        route::notification::handle_post_read,
+        route::notification::handle_post_id_read,
         route::project::handle_get,
         route::project::handle_post,
         route::project::handle_export,
@@ -167,14 +256,35 @@ use crate::route;
         route::project::handle_get_id,
         route::project::handle_delete_id,
         route::project::handle_put_id,
+        route::project::handle_get_id_storage,
+        route::project::handle_put_id_storage,
         route::project_application_period::handle_get,
+        route::role::handle_get,
+        route::role::handle_post,
+        route::role::handle_delete_id,
+        route::role::handle_put_id,
+        route::role::handle_get_user_id,
+        route::role::handle_put_user_id,
         route::user::handle_get,
         route::user::handle_post,
         route::user::handle_export,
         route::user::handle_get_me,
+        route::user::handle_delete_me,
+        route::user::handle_get_me_export,
+        route::user::handle_get_me_tokens,
+        route::user::handle_post_me_tokens,
+        route::user::handle_delete_me_tokens_id,
+        route::user::handle_get_impersonation_logs,
+        route::user::handle_post_import,
         route::user::handle_get_id,
         route::user::handle_delete_id,
         route::user::handle_put_id,
+        route::webhook::handle_get,
+        route::webhook::handle_post,
+        route::webhook::handle_get_id,
+        route::webhook::handle_delete_id,
+        route::webhook::handle_put_id,
+        route::webhook::handle_get_id_deliveries,
     ),
     modifiers(&SecurityAddon),
 )]
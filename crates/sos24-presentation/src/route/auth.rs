@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+
+use sos24_use_case::user::interactor::sign_in::SignInCommand;
+
+use crate::error::{AppError, ErrorResponse};
+use crate::middleware::auth::TokenVerifier;
+use crate::model::auth::{IdToken, SignIn};
+use crate::module::Modules;
+
+/// メールアドレスとパスワードでサインインし、IDトークンを発行する
+// 認証基盤にローカルの実装を用いている場合のみ利用できる
+#[utoipa::path(
+    post,
+    path = "/auth/token",
+    operation_id = "postAuthToken",
+    tag = "auth",
+    request_body(content = SignIn),
+    responses(
+        (status = 200, description = "OK", body = IdToken),
+        (status = 400, description = "Bad Request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Not Found", body = ErrorResponse),
+        (status = 422, description = "Unprocessable Entity", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error", body = ErrorResponse),
+    ),
+    security(()),
+)]
+pub async fn handle_post_token(
+    State(modules): State<Arc<Modules>>,
+    Json(sign_in): Json<SignIn>,
+) -> Result<impl IntoResponse, AppError> {
+    let TokenVerifier::Local(issuer) = modules.token_verifier() else {
+        return Err(AppError::new(
+            StatusCode::NOT_FOUND,
+            "auth/local-auth-disabled".to_string(),
+            "Local authentication is disabled.".to_string(),
+        ));
+    };
+
+    let user_id = modules
+        .user_use_case()
+        .sign_in(SignInCommand::from(sign_in))
+        .await
+        .map_err(|err| {
+            tracing::error!("Failed to sign in: {err:?}");
+            AppError::from(err)
+        })?;
+
+    let issued = issuer.issue(user_id).map_err(|err| {
+        tracing::error!("Failed to issue id token: {err:?}");
+        AppError::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "auth/failed-to-issue-token".to_string(),
+            err.to_string(),
+        )
+    })?;
+
+    Ok((
+        StatusCode::OK,
+        Json(IdToken {
+            id_token: issued.token,
+            expires_in: issued.expires_in,
+        }),
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use axum::{
+        body::Body,
+        http::{header, Method, Request, StatusCode},
+    };
+    use sos24_domain::test::repository::MockRepositories;
+    use sos24_use_case::shared::adapter::MockAdapters;
+    use tower::ServiceExt;
+
+    use crate::{module, route::create_app};
+
+    #[tokio::test]
+    async fn test_post_token_with_firebase() -> anyhow::Result<()> {
+        let repositories = MockRepositories::default();
+        let adapters = MockAdapters::default();
+        let modules = module::new_test(repositories, adapters).await.unwrap();
+        let app = create_app(Arc::new(modules));
+
+        let resp = app
+            .oneshot(
+                Request::builder()
+                    .method(Method::POST)
+                    .uri("/auth/token")
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .body(Body::from(
+                        r#"{"email":"user@s.tsukuba.ac.jp","password":"password"}"#,
+                    ))?,
+            )
+            .await?;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+use std::{convert::Infallible, sync::Arc};
+
+use axum::{
+    extract::State,
+    response::{
+        sse::{self, KeepAlive},
+        IntoResponse, Sse,
+    },
+    Extension,
+};
+use futures_util::stream;
+
+use crate::{
+    context::Context,
+    error::{AppError, ErrorResponse},
+    model::event::Event,
+    module::Modules,
+};
+
+/// 更新通知のイベントストリームの購読
+#[utoipa::path(
+    get,
+    path = "/events",
+    operation_id = "getEvents",
+    tag = "events",
+    responses(
+        (status = 200, description = "OK", content_type = "text/event-stream", body = Event),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error", body = ErrorResponse),
+    ),
+    security(("jwt_token" = [])),
+)]
+pub async fn handle_get(
+    Extension(ctx): Extension<Context>,
+    State(modules): State<Arc<Modules>>,
+) -> Result<impl IntoResponse, AppError> {
+    let subscription = modules
+        .event_use_case()
+        .subscribe(&ctx)
+        .await
+        .map_err(|err| {
+            tracing::error!("Failed to subscribe events: {err:?}");
+            AppError::from(err)
+        })?;
+
+    let stream = stream::unfold(subscription, |mut subscription| async move {
+        let event = Event::from(subscription.recv().await?);
+        let sse_event = match sse::Event::default().json_data(&event) {
+            Ok(sse_event) => sse_event,
+            Err(err) => {
+                tracing::error!("Failed to serialize event: {err:?}");
+                sse::Event::default().comment("failed to serialize event")
+            }
+        };
+        Some((Ok::<_, Infallible>(sse_event), subscription))
+    });
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
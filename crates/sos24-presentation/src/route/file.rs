@@ -1,23 +1,101 @@
 use std::sync::Arc;
 
+use anyhow::Context as _;
 use axum::body::Body;
 use axum::extract::{Multipart, Path, Query, State};
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
 use axum::{Extension, Json};
 use percent_encoding::NON_ALPHANUMERIC;
-use sos24_use_case::file::interactor::create::CreateFileCommand;
+use sos24_use_case::file::interactor::{
+    create::CreateFileCommand, create_export::CreateFileExportCommand,
+    create_upload_url::CreateUploadUrlCommand, find_by_id::FindFileQuery,
+};
+use tokio::io::AsyncWriteExt;
 use tokio_util::io::ReaderStream;
 
 use crate::context::Context;
 use crate::error::ErrorResponse;
-use crate::model::file::{CreateFile, CreatedFile, ExportFileQuery};
+use crate::model::file::{
+    CreateFile, CreateFileExport, CreateUploadUrl, CreatedFile, CreatedFileExport, ExportFileQuery,
+    FileExport, FileThumbnail, FileUrlQuery, UploadUrl,
+};
 use crate::{
     error::AppError,
-    model::file::{CreateFileQuery, File, FileInfo, Visibility},
+    model::file::{CreateFileQuery, File, FileGarbageReport, FileInfo, Visibility},
     module::Modules,
 };
 
+// 受信したファイルをアップロード側に渡すためのバッファのサイズ
+const STREAM_BUFFER_SIZE: usize = 64 * 1024;
+
+// 企画向けのファイルは、操作しているユーザーの企画を所有者とする
+async fn find_owner(
+    modules: &Modules,
+    ctx: &Context,
+    visibility: Visibility,
+) -> Result<Option<String>, AppError> {
+    match visibility {
+        Visibility::Private => Ok(Some(
+            modules
+                .project_use_case()
+                .find_owned(ctx)
+                .await?
+                .ok_or(AppError::new(
+                    StatusCode::NOT_FOUND,
+                    "file/project-not-found".to_string(),
+                    "Project not found".to_string(),
+                ))?
+                .id,
+        )),
+        Visibility::Public => Ok(None),
+    }
+}
+
+// アップロードされたファイルのスキャンとサムネイルの生成を裏で行う
+// 失敗したものはcronjobで再度処理される
+fn spawn_post_upload(modules: &Arc<Modules>, ids: Vec<String>) {
+    let modules = Arc::clone(modules);
+    tokio::spawn(async move {
+        let ctx = Context::new_system(modules.config().clone().into());
+        let bucket = modules.config().s3_bucket_name.clone();
+        for id in ids {
+            if let Err(err) = modules
+                .file_use_case()
+                .scan(&ctx, bucket.clone(), id.clone())
+                .await
+            {
+                tracing::error!("Failed to scan file {id}: {err:?}");
+                continue;
+            }
+            if let Err(err) = modules
+                .file_use_case()
+                .generate_thumbnail(&ctx, bucket.clone(), id.clone())
+                .await
+            {
+                tracing::error!("Failed to generate thumbnail {id}: {err:?}");
+            }
+        }
+    });
+}
+
+// エクスポートのアーカイブの作成を裏で行う
+// サーバーの停止などで中断されたものはcronjobで再度処理される
+fn spawn_export(modules: &Arc<Modules>, id: String) {
+    let modules = Arc::clone(modules);
+    tokio::spawn(async move {
+        let ctx = Context::new_system(modules.config().clone().into());
+        let bucket = modules.config().s3_bucket_name.clone();
+        if let Err(err) = modules
+            .file_use_case()
+            .run_export(&ctx, bucket, id.clone())
+            .await
+        {
+            tracing::error!("Failed to run file export {id}: {err:?}");
+        }
+    });
+}
+
 /// ファイル一覧の取得
 #[utoipa::path(
     get,
@@ -48,6 +126,33 @@ pub async fn handle_get(
         })
 }
 
+/// 参照されていないファイルの回収で削除される予定のファイルの取得
+#[utoipa::path(
+    get,
+    path = "/files/garbage",
+    operation_id = "getFilesGarbage",
+    tag = "files",
+    responses(
+        (status = 200, description = "OK", body = FileGarbageReport),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error", body = ErrorResponse),
+    ),
+    security(("jwt_token" = [])),
+)]
+pub async fn handle_get_garbage(
+    State(modules): State<Arc<Modules>>,
+    Extension(ctx): Extension<Context>,
+) -> Result<impl IntoResponse, AppError> {
+    let report = modules.file_use_case().find_garbage(&ctx).await;
+    report
+        .map(|report| (StatusCode::OK, Json(FileGarbageReport::from(report))))
+        .map_err(|err| {
+            tracing::error!("Failed to find garbage files: {err:?}");
+            err.into()
+        })
+}
+
 /// ファイルの作成
 #[utoipa::path(
     post,
@@ -64,6 +169,7 @@ pub async fn handle_get(
         (status = 400, description = "Bad Request", body = ErrorResponse),
         (status = 401, description = "Unauthorized", body = ErrorResponse),
         (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 413, description = "Payload Too Large", body = ErrorResponse),
         (status = 422, description = "Unprocessable Entity", body = ErrorResponse),
         (status = 500, description = "Internal Server Error", body = ErrorResponse),
     ),
@@ -75,25 +181,11 @@ pub async fn handle_post(
     Extension(ctx): Extension<Context>,
     mut multipart: Multipart,
 ) -> Result<impl IntoResponse, AppError> {
-    let mut filelist: Vec<CreateFileCommand> = vec![];
+    let owner = find_owner(&modules, &ctx, query.visibility).await?;
 
-    let owner: Option<String> = match query.visibility {
-        Visibility::Private => Some(
-            modules
-                .project_use_case()
-                .find_owned(&ctx)
-                .await?
-                .ok_or(AppError::new(
-                    StatusCode::NOT_FOUND,
-                    "file/project-not-found".to_string(),
-                    "Project not found".to_string(),
-                ))?
-                .id,
-        ),
-        Visibility::Public => None,
-    };
-
-    while let Some(file) = multipart.next_field().await.map_err(|_| {
+    let mut created_file_ids = vec![];
+    let mut duplicate_file_ids = vec![];
+    while let Some(mut file) = multipart.next_field().await.map_err(|_| {
         AppError::new(
             StatusCode::BAD_REQUEST,
             "file/not-found".to_string(),
@@ -114,19 +206,52 @@ pub async fn handle_post(
                         "File name was not provided".to_string(),
                     ))?,
                 };
-                let filebytes = match file.bytes().await {
-                    Ok(v) => v,
-                    Err(e) => Err(AppError::new(
-                        StatusCode::BAD_REQUEST,
-                        "file/bad-file-bytes".to_string(),
-                        e.body_text(),
-                    ))?,
+
+                let content_type = file.content_type().map(str::to_string);
+
+                // ファイル全体をメモリに載せないよう、受信しながらアップロードする
+                let (mut writer, reader) = tokio::io::duplex(STREAM_BUFFER_SIZE);
+                let upload = async {
+                    modules
+                        .file_use_case()
+                        .create(
+                            &ctx,
+                            modules.config().s3_bucket_name.clone(),
+                            "user-upload".to_string(),
+                            modules.config().file_upload_limit as u64,
+                            CreateFileCommand {
+                                filename,
+                                file: reader,
+                                content_type,
+                                owner: owner.clone(),
+                                is_standalone: query.standalone,
+                            },
+                        )
+                        .await
+                        .map_err(AppError::from)
                 };
-                filelist.push(CreateFileCommand {
-                    filename,
-                    file: filebytes.into(),
-                    owner: owner.clone(),
-                });
+                let receive = async move {
+                    while let Some(chunk) = file.chunk().await.map_err(|e| {
+                        AppError::new(
+                            StatusCode::BAD_REQUEST,
+                            "file/bad-file-bytes".to_string(),
+                            e.body_text(),
+                        )
+                    })? {
+                        writer
+                            .write_all(&chunk)
+                            .await
+                            .context("Failed to write file")?;
+                    }
+                    writer.shutdown().await.context("Failed to write file")?;
+                    Ok::<_, AppError>(())
+                };
+
+                // 受信に失敗した場合は、途中までの内容がアップロードされないようにアップロードごと中断する
+                // クライアントが切断した場合もFutureごと破棄され、進行中のマルチパートアップロードは中断される
+                let (created_file, _) = tokio::try_join!(upload, receive)?;
+                created_file_ids.push(created_file.id);
+                duplicate_file_ids.extend(created_file.duplicate_ids);
             }
             _ => {
                 return Err(AppError::new(
@@ -138,36 +263,71 @@ pub async fn handle_post(
         }
     }
 
-    if filelist.is_empty() {
+    if created_file_ids.is_empty() {
         return Err(AppError::new(
             StatusCode::BAD_REQUEST,
             "file/no-file-found".to_string(),
             "No content was provided".to_string(),
         ));
     }
-
-    let mut created_file_ids = vec![];
-    for file in filelist.into_iter() {
-        let id = modules
-            .file_use_case()
-            .create(
-                &ctx,
-                modules.config().s3_bucket_name.clone(),
-                "user-upload".to_string(),
-                file,
-            )
-            .await?;
-        created_file_ids.push(id);
-    }
+    spawn_post_upload(&modules, created_file_ids.clone());
 
     Ok((
         StatusCode::CREATED,
         Json(CreatedFile {
             ids: created_file_ids,
+            duplicate_ids: duplicate_file_ids,
         }),
     ))
 }
 
+/// 直接アップロードするための署名付きURLの発行
+#[utoipa::path(
+    post,
+    path = "/files/upload-url",
+    operation_id = "postFilesUploadUrl",
+    tag = "files",
+    params(CreateFileQuery),
+    request_body(content = CreateUploadUrl),
+    responses(
+        (status = 201, description = "Created", body = UploadUrl),
+        (status = 400, description = "Bad Request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not Found", body = ErrorResponse),
+        (status = 422, description = "Unprocessable Entity", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error", body = ErrorResponse),
+    ),
+    security(("jwt_token" = [])),
+)]
+pub async fn handle_post_upload_url(
+    Query(query): Query<CreateFileQuery>,
+    State(modules): State<Arc<Modules>>,
+    Extension(ctx): Extension<Context>,
+    Json(raw_upload): Json<CreateUploadUrl>,
+) -> Result<impl IntoResponse, AppError> {
+    let owner = find_owner(&modules, &ctx, query.visibility).await?;
+    let upload_url = modules
+        .file_use_case()
+        .create_upload_url(
+            &ctx,
+            modules.config().s3_bucket_name.clone(),
+            "user-upload".to_string(),
+            CreateUploadUrlCommand {
+                filename: raw_upload.filename,
+                owner,
+                is_standalone: query.standalone,
+            },
+        )
+        .await;
+    upload_url
+        .map(|upload_url| (StatusCode::CREATED, Json(UploadUrl::from(upload_url))))
+        .map_err(|err| {
+            tracing::error!("Failed to create upload url: {err}");
+            err.into()
+        })
+}
+
 /// ファイル一覧のエクスポート
 #[utoipa::path(
     get,
@@ -254,23 +414,31 @@ pub async fn handle_export(
     path = "/files/{file_id}",
     operation_id = "getFileById",
     tag = "files",
-    params(("file_id" = String, Path, format = "uuid")),
+    params(("file_id" = String, Path, format = "uuid"), FileUrlQuery),
     responses(
         (status = 200, description = "OK", body = File),
+        (status = 400, description = "Bad Request", body = ErrorResponse),
         (status = 401, description = "Unauthorized", body = ErrorResponse),
         (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 409, description = "Conflict", body = ErrorResponse),
         (status = 500, description = "Internal Server Error", body = ErrorResponse),
     ),
     security(("jwt_token" = [])),
 )]
 pub async fn handle_get_id(
     Path(id): Path<String>,
+    Query(query): Query<FileUrlQuery>,
     State(modules): State<Arc<Modules>>,
     Extension(ctx): Extension<Context>,
 ) -> Result<impl IntoResponse, AppError> {
     let raw_file = modules
         .file_use_case()
-        .find_by_id(&ctx, modules.config().s3_bucket_name.clone(), id)
+        .find_by_id(
+            &ctx,
+            modules.config().s3_bucket_name.clone(),
+            id,
+            FindFileQuery::from(query),
+        )
         .await;
     match raw_file {
         Ok(raw_file) => Ok((StatusCode::OK, Json(File::from(raw_file)))),
@@ -307,3 +475,156 @@ pub async fn handle_delete_id(
         err.into()
     })
 }
+
+/// 直接アップロードしたファイルの登録
+#[utoipa::path(
+    post,
+    path = "/files/{file_id}/complete",
+    operation_id = "postFileComplete",
+    tag = "files",
+    params(("file_id" = String, Path, format = "uuid")),
+    responses(
+        (status = 201, description = "Created", body = CreatedFile),
+        (status = 400, description = "Bad Request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not Found", body = ErrorResponse),
+        (status = 413, description = "Payload Too Large", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error", body = ErrorResponse),
+    ),
+    security(("jwt_token" = [])),
+)]
+pub async fn handle_post_id_complete(
+    Path(id): Path<String>,
+    State(modules): State<Arc<Modules>>,
+    Extension(ctx): Extension<Context>,
+) -> Result<impl IntoResponse, AppError> {
+    let res = modules
+        .file_use_case()
+        .complete_upload(
+            &ctx,
+            modules.config().s3_bucket_name.clone(),
+            "user-upload".to_string(),
+            modules.config().file_upload_limit as u64,
+            id,
+        )
+        .await;
+    res.map(|created_file| {
+        spawn_post_upload(&modules, vec![created_file.id.clone()]);
+        (
+            StatusCode::CREATED,
+            Json(CreatedFile {
+                ids: vec![created_file.id],
+                duplicate_ids: created_file.duplicate_ids,
+            }),
+        )
+    })
+    .map_err(|err| {
+        tracing::error!("Failed to complete upload: {err}");
+        err.into()
+    })
+}
+
+/// 特定のIDのファイルのサムネイルの取得
+#[utoipa::path(
+    get,
+    path = "/files/{file_id}/thumbnail",
+    operation_id = "getFileThumbnailById",
+    tag = "files",
+    params(("file_id" = String, Path, format = "uuid")),
+    responses(
+        (status = 200, description = "OK", body = FileThumbnail),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not Found", body = ErrorResponse),
+        (status = 409, description = "Conflict", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error", body = ErrorResponse),
+    ),
+    security(("jwt_token" = [])),
+)]
+pub async fn handle_get_id_thumbnail(
+    Path(id): Path<String>,
+    State(modules): State<Arc<Modules>>,
+    Extension(ctx): Extension<Context>,
+) -> Result<impl IntoResponse, AppError> {
+    let res = modules
+        .file_use_case()
+        .find_thumbnail_by_id(&ctx, modules.config().s3_bucket_name.clone(), id)
+        .await;
+    res.map(|thumbnail| (StatusCode::OK, Json(FileThumbnail::from(thumbnail))))
+        .map_err(|err| {
+            tracing::error!("Failed to find thumbnail: {err}");
+            err.into()
+        })
+}
+
+/// 申請に添付されたファイルのエクスポートの作成
+#[utoipa::path(
+    post,
+    path = "/exports",
+    operation_id = "postExport",
+    tag = "files",
+    request_body(content = CreateFileExport),
+    responses(
+        (status = 201, description = "Created", body = CreatedFileExport),
+        (status = 400, description = "Bad Request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not Found", body = ErrorResponse),
+        (status = 422, description = "Unprocessable Entity", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error", body = ErrorResponse),
+    ),
+    security(("jwt_token" = [])),
+)]
+pub async fn handle_post_export(
+    State(modules): State<Arc<Modules>>,
+    Extension(ctx): Extension<Context>,
+    Json(raw_export): Json<CreateFileExport>,
+) -> Result<impl IntoResponse, AppError> {
+    let export = CreateFileExportCommand::from(raw_export);
+    let created = modules
+        .file_use_case()
+        .create_export(&ctx, export)
+        .await
+        .map_err(|err| {
+            tracing::error!("Failed to create file export: {err:?}");
+            AppError::from(err)
+        })?;
+
+    spawn_export(&modules, created.id.clone());
+
+    Ok((StatusCode::CREATED, Json(CreatedFileExport::from(created))))
+}
+
+/// 特定のIDのエクスポートの進捗の取得
+#[utoipa::path(
+    get,
+    path = "/exports/{export_id}",
+    operation_id = "getExportById",
+    tag = "files",
+    params(("export_id" = String, Path, format = "uuid")),
+    responses(
+        (status = 200, description = "OK", body = FileExport),
+        (status = 400, description = "Bad Request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not Found", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error", body = ErrorResponse),
+    ),
+    security(("jwt_token" = [])),
+)]
+pub async fn handle_get_export_id(
+    Path(id): Path<String>,
+    State(modules): State<Arc<Modules>>,
+    Extension(ctx): Extension<Context>,
+) -> Result<impl IntoResponse, AppError> {
+    let res = modules
+        .file_use_case()
+        .find_export_by_id(&ctx, modules.config().s3_bucket_name.clone(), id)
+        .await;
+    res.map(|export| (StatusCode::OK, Json(FileExport::from(export))))
+        .map_err(|err| {
+            tracing::error!("Failed to find file export: {err:?}");
+            err.into()
+        })
+}
@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Extension, Json,
+};
+
+use crate::{
+    context::Context,
+    error::{AppError, ErrorResponse},
+    model::notification::{Notification, UnreadNotificationCount},
+    module::Modules,
+};
+
+/// 自分宛ての通知一覧の取得
+#[utoipa::path(
+    get,
+    path = "/notifications",
+    operation_id = "getNotifications",
+    tag = "notifications",
+    responses(
+        (status = 200, description = "OK", body = Vec<Notification>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error", body = ErrorResponse),
+    ),
+    security(("jwt_token" = [])),
+)]
+pub async fn handle_get(
+    Extension(ctx): Extension<Context>,
+    State(modules): State<Arc<Modules>>,
+) -> Result<impl IntoResponse, AppError> {
+    let raw_notification_list = modules.notification_use_case().list(&ctx).await;
+    raw_notification_list
+        .map(|raw_notification_list| {
+            let notification_list: Vec<Notification> = raw_notification_list
+                .into_iter()
+                .map(Notification::from)
+                .collect();
+            (StatusCode::OK, Json(notification_list))
+        })
+        .map_err(|err| {
+            tracing::error!("Failed to list notifications: {err:?}");
+            err.into()
+        })
+}
+
+/// 未読の通知数の取得
+#[utoipa::path(
+    get,
+    path = "/notifications/unread-count",
+    operation_id = "getUnreadNotificationCount",
+    tag = "notifications",
+    responses(
+        (status = 200, description = "OK", body = UnreadNotificationCount),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error", body = ErrorResponse),
+    ),
+    security(("jwt_token" = [])),
+)]
+pub async fn handle_get_unread_count(
+    Extension(ctx): Extension<Context>,
+    State(modules): State<Arc<Modules>>,
+) -> Result<impl IntoResponse, AppError> {
+    let res = modules.notification_use_case().count_unread(&ctx).await;
+    res.map(|count| (StatusCode::OK, Json(UnreadNotificationCount { count })))
+        .map_err(|err| {
+            tracing::error!("Failed to count unread notifications: {err:?}");
+            err.into()
+        })
+}
+
+/// 自分宛ての通知を全て既読にする
+#[utoipa::path(
+    post,
+    path = "/notifications/read",
+    operation_id = "postNotificationsRead",
+    tag = "notifications",
+    responses(
+        (status = 200, description = "OK"),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error", body = ErrorResponse),
+    ),
+    security(("jwt_token" = [])),
+)]
+pub async fn handle_post_read(
+    Extension(ctx): Extension<Context>,
+    State(modules): State<Arc<Modules>>,
+) -> Result<impl IntoResponse, AppError> {
+    let res = modules.notification_use_case().read_all(&ctx).await;
+    res.map(|_| StatusCode::OK).map_err(|err| {
+        tracing::error!("Failed to read all notifications: {err:?}");
+        err.into()
+    })
+}
+
+/// 特定のIDの通知を既読にする
+#[utoipa::path(
+    post,
+    path = "/notifications/{notification_id}/read",
+    operation_id = "postNotificationReadById",
+    tag = "notifications",
+    params(("notification_id" = String, Path, format="uuid")),
+    responses(
+        (status = 200, description = "OK"),
+        (status = 400, description = "Bad Request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not Found", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error", body = ErrorResponse),
+    ),
+    security(("jwt_token" = [])),
+)]
+pub async fn handle_post_id_read(
+    Path(id): Path<String>,
+    Extension(ctx): Extension<Context>,
+    State(modules): State<Arc<Modules>>,
+) -> Result<impl IntoResponse, AppError> {
+    let res = modules.notification_use_case().read(&ctx, id).await;
+    res.map(|_| StatusCode::OK).map_err(|err| {
+        tracing::error!("Failed to read notification: {err:?}");
+        err.into()
+    })
+}
@@ -12,6 +12,7 @@ use sos24_use_case::shared::context::ContextProvider;
 use crate::context::Context;
 use crate::csv::serialize_to_csv;
 use crate::error::{AppError, ErrorResponse};
+use crate::model::file::{ProjectStorage, UpdateProjectStorage};
 use crate::model::project::{ConvertToCreateProjectDto, CreatedProject, ProjectToBeExported};
 use crate::{
     model::project::{
@@ -256,3 +257,71 @@ pub async fn handle_put_id(
         err.into()
     })
 }
+
+/// 特定のIDの企画のファイルの容量の取得
+#[utoipa::path(
+    get,
+    path = "/projects/{project_id}/storage",
+    operation_id = "getProjectStorageById",
+    tag = "projects",
+    params(("project_id" = String, Path, format="uuid")),
+    responses(
+        (status = 200, description = "OK", body = ProjectStorage),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not Found", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error", body = ErrorResponse),
+    ),
+    security(("jwt_token" = [])),
+)]
+pub async fn handle_get_id_storage(
+    Path(id): Path<String>,
+    Extension(ctx): Extension<Context>,
+    State(modules): State<Arc<Modules>>,
+) -> Result<impl IntoResponse, AppError> {
+    let raw_storage = modules
+        .file_use_case()
+        .find_storage_by_project_id(&ctx, id)
+        .await;
+    raw_storage
+        .map(|raw_storage| (StatusCode::OK, Json(ProjectStorage::from(raw_storage))))
+        .map_err(|err| {
+            tracing::error!("Failed to find project storage: {err:?}");
+            err.into()
+        })
+}
+
+/// 特定のIDの企画のファイルの容量の上限を変更
+#[utoipa::path(
+    put,
+    path = "/projects/{project_id}/storage",
+    operation_id = "putProjectStorageById",
+    tag = "projects",
+    params(("project_id" = String, Path, format="uuid")),
+    request_body(content = UpdateProjectStorage),
+    responses(
+        (status = 200, description = "OK"),
+        (status = 400, description = "Bad Request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not Found", body = ErrorResponse),
+        (status = 422, description = "Unprocessable Entity", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error", body = ErrorResponse),
+    ),
+    security(("jwt_token" = [])),
+)]
+pub async fn handle_put_id_storage(
+    Path(id): Path<String>,
+    State(modules): State<Arc<Modules>>,
+    Extension(ctx): Extension<Context>,
+    Json(raw_storage): Json<UpdateProjectStorage>,
+) -> Result<impl IntoResponse, AppError> {
+    let res = modules
+        .file_use_case()
+        .update_storage_quota(&ctx, id, raw_storage.quota)
+        .await;
+    res.map(|_| StatusCode::OK).map_err(|err| {
+        tracing::error!("Failed to update project storage quota: {err:?}");
+        err.into()
+    })
+}
@@ -0,0 +1,205 @@
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::{Extension, Json};
+use sos24_use_case::role::interactor::create::CreateCustomRoleCommand;
+
+use crate::context::Context;
+use crate::error::{AppError, ErrorResponse};
+use crate::model::role::{
+    ConvertToSetUserRolesDto, ConvertToUpdateCustomRoleDto, CreateCustomRole, CreatedCustomRole,
+    CustomRole, UpdateCustomRole, UpdateUserRoles,
+};
+use crate::module::Modules;
+
+/// カスタムロール一覧の取得
+#[utoipa::path(
+    get,
+    path = "/roles",
+    operation_id = "getRoles",
+    tag = "roles",
+    responses(
+        (status = 200, description = "OK", body = Vec<CustomRole>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error", body = ErrorResponse),
+    ),
+    security(("jwt_token" = [])),
+)]
+pub async fn handle_get(
+    State(modules): State<Arc<Modules>>,
+    Extension(ctx): Extension<Context>,
+) -> Result<impl IntoResponse, AppError> {
+    let raw_role_list = modules.role_use_case().list(&ctx).await;
+    raw_role_list
+        .map(|raw_role_list| {
+            let role_list: Vec<CustomRole> =
+                raw_role_list.into_iter().map(CustomRole::from).collect();
+            (StatusCode::OK, Json(role_list))
+        })
+        .map_err(|err| {
+            tracing::error!("Failed to list custom roles: {err:?}");
+            err.into()
+        })
+}
+
+/// カスタムロールの作成
+#[utoipa::path(
+    post,
+    path = "/roles",
+    operation_id = "postRole",
+    tag = "roles",
+    request_body(content = CreateCustomRole),
+    responses(
+        (status = 201, description = "Created", body = CreatedCustomRole),
+        (status = 400, description = "Bad Request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 422, description = "Unprocessable Entity", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error", body = ErrorResponse),
+    ),
+    security(("jwt_token" = [])),
+)]
+pub async fn handle_post(
+    State(modules): State<Arc<Modules>>,
+    Extension(ctx): Extension<Context>,
+    Json(raw_role): Json<CreateCustomRole>,
+) -> Result<impl IntoResponse, AppError> {
+    let role = CreateCustomRoleCommand::from(raw_role);
+    let res = modules.role_use_case().create(&ctx, role).await;
+    res.map(|id| (StatusCode::CREATED, Json(CreatedCustomRole { id })))
+        .map_err(|err| {
+            tracing::error!("Failed to create custom role: {err:?}");
+            err.into()
+        })
+}
+
+/// 特定のIDのカスタムロールの削除
+#[utoipa::path(
+    delete,
+    path = "/roles/{role_id}",
+    operation_id = "deleteRoleById",
+    tag = "roles",
+    params(("role_id" = String, Path, format="uuid")),
+    responses(
+        (status = 200, description = "OK"),
+        (status = 400, description = "Bad Request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not Found", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error", body = ErrorResponse),
+    ),
+    security(("jwt_token" = [])),
+)]
+pub async fn handle_delete_id(
+    Path(id): Path<String>,
+    Extension(ctx): Extension<Context>,
+    State(modules): State<Arc<Modules>>,
+) -> Result<impl IntoResponse, AppError> {
+    let res = modules.role_use_case().delete_by_id(&ctx, id).await;
+    res.map(|_| StatusCode::OK).map_err(|err| {
+        tracing::error!("Failed to delete custom role: {err:?}");
+        err.into()
+    })
+}
+
+/// 特定のIDのカスタムロールを更新
+#[utoipa::path(
+    put,
+    path = "/roles/{role_id}",
+    operation_id = "putRoleById",
+    tag = "roles",
+    params(("role_id" = String, Path, format="uuid")),
+    request_body(content = UpdateCustomRole),
+    responses(
+        (status = 200, description = "OK"),
+        (status = 400, description = "Bad Request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not Found", body = ErrorResponse),
+        (status = 422, description = "Unprocessable Entity", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error", body = ErrorResponse),
+    ),
+    security(("jwt_token" = [])),
+)]
+pub async fn handle_put_id(
+    Path(id): Path<String>,
+    State(modules): State<Arc<Modules>>,
+    Extension(ctx): Extension<Context>,
+    Json(raw_role): Json<UpdateCustomRole>,
+) -> Result<impl IntoResponse, AppError> {
+    let role = (id, raw_role).to_update_custom_role_dto();
+    let res = modules.role_use_case().update(&ctx, role).await;
+    res.map(|_| StatusCode::OK).map_err(|err| {
+        tracing::error!("Failed to update custom role: {err:?}");
+        err.into()
+    })
+}
+
+/// 特定のユーザーに割り当てられたカスタムロールの取得
+#[utoipa::path(
+    get,
+    path = "/users/{user_id}/roles",
+    operation_id = "getUserRolesById",
+    tag = "roles",
+    params(("user_id" = String, Path)),
+    responses(
+        (status = 200, description = "OK", body = Vec<CustomRole>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error", body = ErrorResponse),
+    ),
+    security(("jwt_token" = [])),
+)]
+pub async fn handle_get_user_id(
+    Path(user_id): Path<String>,
+    Extension(ctx): Extension<Context>,
+    State(modules): State<Arc<Modules>>,
+) -> Result<impl IntoResponse, AppError> {
+    let raw_role_list = modules.role_use_case().list_by_user_id(&ctx, user_id).await;
+    raw_role_list
+        .map(|raw_role_list| {
+            let role_list: Vec<CustomRole> =
+                raw_role_list.into_iter().map(CustomRole::from).collect();
+            (StatusCode::OK, Json(role_list))
+        })
+        .map_err(|err| {
+            tracing::error!("Failed to list user custom roles: {err:?}");
+            err.into()
+        })
+}
+
+/// 特定のユーザーに割り当てるカスタムロールを更新
+#[utoipa::path(
+    put,
+    path = "/users/{user_id}/roles",
+    operation_id = "putUserRolesById",
+    tag = "roles",
+    params(("user_id" = String, Path)),
+    request_body(content = UpdateUserRoles),
+    responses(
+        (status = 200, description = "OK"),
+        (status = 400, description = "Bad Request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not Found", body = ErrorResponse),
+        (status = 422, description = "Unprocessable Entity", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error", body = ErrorResponse),
+    ),
+    security(("jwt_token" = [])),
+)]
+pub async fn handle_put_user_id(
+    Path(user_id): Path<String>,
+    State(modules): State<Arc<Modules>>,
+    Extension(ctx): Extension<Context>,
+    Json(raw_roles): Json<UpdateUserRoles>,
+) -> Result<impl IntoResponse, AppError> {
+    let command = (user_id, raw_roles).to_set_user_roles_dto();
+    let res = modules.role_use_case().set_user_roles(&ctx, command).await;
+    res.map(|_| StatusCode::OK).map_err(|err| {
+        tracing::error!("Failed to update user custom roles: {err:?}");
+        err.into()
+    })
+}
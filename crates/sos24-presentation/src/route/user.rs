@@ -1,20 +1,28 @@
 use std::sync::Arc;
 
+use axum::body::Body;
 use axum::response::Response;
 use axum::{
-    extract::{Path, State},
-    http::StatusCode,
+    extract::{Path, Query, State},
+    http::{Extensions, StatusCode},
     response::IntoResponse,
     Extension, Json,
 };
 
 use sos24_use_case::shared::context::ContextProvider;
 use sos24_use_case::user::interactor::create::CreateUserCommand;
+use sos24_use_case::user::interactor::create_api_token::CreateApiTokenCommand;
+use sos24_use_case::user::interactor::import::{ImportUserRow, ImportUsersCommand};
 
+use crate::archive::archive_to_zip;
 use crate::context::Context;
-use crate::csv::serialize_to_csv;
+use crate::csv::{deserialize_from_csv, serialize_to_csv};
 use crate::error::{AppError, ErrorResponse};
-use crate::model::user::CreatedUser;
+use crate::model::user::{
+    ApiToken, CreateApiToken, CreatedApiToken, CreatedUser, ExportPersonalDataQuery,
+    ImpersonationLog, ImportUserResult, ImportUsersQuery, PersonalData, PersonalDataFormat,
+    UserToBeImported,
+};
 use crate::{
     model::user::{
         ConvertToUpdateUserDto, CreateUser, UpdateUser, User, UserSummary, UserTobeExported,
@@ -112,17 +120,28 @@ pub async fn handle_export(
     responses(
         (status = 201, description = "Created", body = CreatedUser),
         (status = 400, description = "Bad Request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
         (status = 422, description = "Unprocessable Entity", body = ErrorResponse),
         (status = 500, description = "Internal Server Error", body = ErrorResponse),
     ),
-    security(()),
+    security((), ("jwt_token" = [])),
 )]
 pub async fn handle_post(
+    extensions: Extensions,
     State(modules): State<Arc<Modules>>,
     Json(raw_user): Json<CreateUser>,
 ) -> Result<impl IntoResponse, AppError> {
     let user = CreateUserCommand::from(raw_user);
-    let res = modules.user_use_case().create(user).await;
+    // 管理者がログインして作成する場合は、許可されていないドメインのメールアドレスも使える
+    let res = match extensions.get::<Context>() {
+        Some(ctx) => {
+            modules
+                .user_use_case()
+                .create_authenticated(ctx, user)
+                .await
+        }
+        None => modules.user_use_case().create(user).await,
+    };
     res.map(|id| (StatusCode::CREATED, Json(CreatedUser { id })))
         .map_err(|err| {
             tracing::error!("Failed to create user: {err:?}");
@@ -190,6 +209,277 @@ pub async fn handle_get_me(
     }
 }
 
+/// 自分の個人データのエクスポート
+#[utoipa::path(
+    get,
+    path = "/users/me/export",
+    operation_id = "getMyPersonalDataExport",
+    tag = "users",
+    params(ExportPersonalDataQuery),
+    responses(
+        (status = 200, description = "OK", content(
+            (PersonalData = "application/json"),
+            (String = "application/zip"),
+        )),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error", body = ErrorResponse),
+    ),
+    security(("jwt_token" = [])),
+)]
+pub async fn handle_get_me_export(
+    Query(query): Query<ExportPersonalDataQuery>,
+    State(modules): State<Arc<Modules>>,
+    Extension(ctx): Extension<Context>,
+) -> Result<Response, AppError> {
+    let personal_data = modules
+        .user_use_case()
+        .export_personal_data(&ctx)
+        .await
+        .map_err(|err| {
+            tracing::error!("Failed to export personal data: {err:?}");
+            AppError::from(err)
+        })?;
+    let personal_data = PersonalData::from(personal_data);
+
+    if let PersonalDataFormat::Json = query.format {
+        return Ok((StatusCode::OK, Json(personal_data)).into_response());
+    }
+
+    fn to_json<S: serde::Serialize>(value: &S) -> Result<Vec<u8>, AppError> {
+        serde_json::to_vec_pretty(value).map_err(|err| {
+            tracing::error!("Failed to serialize personal data: {err:?}");
+            AppError::new(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "user/failed-to-serialize".to_string(),
+                err.to_string(),
+            )
+        })
+    }
+
+    let entries = vec![
+        ("user.json".to_string(), to_json(&personal_data.user)?),
+        ("project.json".to_string(), to_json(&personal_data.project)?),
+        (
+            "invitations.json".to_string(),
+            to_json(&personal_data.invitations)?,
+        ),
+        (
+            "form_answers.json".to_string(),
+            to_json(&personal_data.form_answers)?,
+        ),
+    ];
+    let data = archive_to_zip(entries).await.map_err(|err| {
+        tracing::error!("Failed to archive personal data: {err:?}");
+        AppError::from(err)
+    })?;
+
+    Response::builder()
+        .header("Content-Type", "application/zip")
+        .header(
+            "Content-Disposition",
+            "attachment; filename=personal_data.zip",
+        )
+        .body(Body::from(data))
+        .map_err(|err| {
+            tracing::error!("Failed to create response: {err:?}");
+            AppError::new(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "user/failed-to-create-response".to_string(),
+                format!("{err:?}"),
+            )
+        })
+}
+
+/// 退会
+#[utoipa::path(
+    delete,
+    path = "/users/me",
+    operation_id = "deleteMyUser",
+    tag = "users",
+    responses(
+        (status = 200, description = "OK"),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 409, description = "Conflict", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error", body = ErrorResponse),
+    ),
+    security(("jwt_token" = [])),
+)]
+pub async fn handle_delete_me(
+    State(modules): State<Arc<Modules>>,
+    Extension(ctx): Extension<Context>,
+) -> Result<impl IntoResponse, AppError> {
+    let res = modules.user_use_case().delete_me(&ctx).await;
+    res.map(|_| StatusCode::OK).map_err(|err| {
+        tracing::error!("Failed to delete me: {err:?}");
+        err.into()
+    })
+}
+
+/// 自分のAPIトークン一覧の取得
+#[utoipa::path(
+    get,
+    path = "/users/me/tokens",
+    operation_id = "getMyApiTokens",
+    tag = "users",
+    responses(
+        (status = 200, description = "OK", body = Vec<ApiToken>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error", body = ErrorResponse),
+    ),
+    security(("jwt_token" = [])),
+)]
+pub async fn handle_get_me_tokens(
+    State(modules): State<Arc<Modules>>,
+    Extension(ctx): Extension<Context>,
+) -> Result<impl IntoResponse, AppError> {
+    let tokens = modules.user_use_case().list_api_tokens(&ctx).await;
+    tokens
+        .map(|tokens| {
+            let tokens: Vec<ApiToken> = tokens.into_iter().map(ApiToken::from).collect();
+            (StatusCode::OK, Json(tokens))
+        })
+        .map_err(|err| {
+            tracing::error!("Failed to list api tokens: {err:?}");
+            err.into()
+        })
+}
+
+/// APIトークンの作成
+#[utoipa::path(
+    post,
+    path = "/users/me/tokens",
+    operation_id = "postMyApiToken",
+    tag = "users",
+    request_body(content = CreateApiToken),
+    responses(
+        (status = 201, description = "Created", body = CreatedApiToken),
+        (status = 400, description = "Bad Request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 422, description = "Unprocessable Entity", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error", body = ErrorResponse),
+    ),
+    security(("jwt_token" = [])),
+)]
+pub async fn handle_post_me_tokens(
+    State(modules): State<Arc<Modules>>,
+    Extension(ctx): Extension<Context>,
+    Json(raw_token): Json<CreateApiToken>,
+) -> Result<impl IntoResponse, AppError> {
+    let command = CreateApiTokenCommand::from(raw_token);
+    let res = modules
+        .user_use_case()
+        .create_api_token(&ctx, command)
+        .await;
+    res.map(|created| (StatusCode::CREATED, Json(CreatedApiToken::from(created))))
+        .map_err(|err| {
+            tracing::error!("Failed to create api token: {err:?}");
+            err.into()
+        })
+}
+
+/// 自分のAPIトークンの削除
+#[utoipa::path(
+    delete,
+    path = "/users/me/tokens/{token_id}",
+    operation_id = "deleteMyApiTokenById",
+    tag = "users",
+    params(("token_id" = String, Path, format = "uuid")),
+    responses(
+        (status = 200, description = "OK"),
+        (status = 400, description = "Bad Request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not Found", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error", body = ErrorResponse),
+    ),
+    security(("jwt_token" = [])),
+)]
+pub async fn handle_delete_me_tokens_id(
+    Path(id): Path<String>,
+    Extension(ctx): Extension<Context>,
+    State(modules): State<Arc<Modules>>,
+) -> Result<impl IntoResponse, AppError> {
+    let res = modules.user_use_case().delete_api_token(&ctx, id).await;
+    res.map(|_| StatusCode::OK).map_err(|err| {
+        tracing::error!("Failed to delete api token: {err:?}");
+        err.into()
+    })
+}
+
+/// なりすましの記録一覧の取得
+#[utoipa::path(
+    get,
+    path = "/users/impersonation-logs",
+    operation_id = "getImpersonationLogs",
+    tag = "users",
+    responses(
+        (status = 200, description = "OK", body = Vec<ImpersonationLog>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error", body = ErrorResponse),
+    ),
+    security(("jwt_token" = [])),
+)]
+pub async fn handle_get_impersonation_logs(
+    State(modules): State<Arc<Modules>>,
+    Extension(ctx): Extension<Context>,
+) -> Result<impl IntoResponse, AppError> {
+    let logs = modules.user_use_case().list_impersonation_logs(&ctx).await;
+    logs.map(|logs| {
+        let logs: Vec<ImpersonationLog> = logs.into_iter().map(ImpersonationLog::from).collect();
+        (StatusCode::OK, Json(logs))
+    })
+    .map_err(|err| {
+        tracing::error!("Failed to list impersonation logs: {err:?}");
+        err.into()
+    })
+}
+
+/// CSVからのユーザーの一括登録
+#[utoipa::path(
+    post,
+    path = "/users/import",
+    operation_id = "postUsersImport",
+    tag = "users",
+    params(ImportUsersQuery),
+    request_body(content = String, content_type = "text/csv"),
+    responses(
+        (status = 200, description = "OK", body = Vec<ImportUserResult>),
+        (status = 400, description = "Bad Request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error", body = ErrorResponse),
+    ),
+    security(("jwt_token" = [])),
+)]
+pub async fn handle_post_import(
+    Query(query): Query<ImportUsersQuery>,
+    State(modules): State<Arc<Modules>>,
+    Extension(ctx): Extension<Context>,
+    body: String,
+) -> Result<impl IntoResponse, AppError> {
+    // CSVの列: name, kana_name, email, phone_number, role
+    let rows = deserialize_from_csv::<UserToBeImported>(&body)?;
+    let command = ImportUsersCommand {
+        rows: rows.into_iter().map(ImportUserRow::from).collect(),
+        dry_run: query.dry_run,
+    };
+
+    let results = modules.user_use_case().import(&ctx, command).await;
+    results
+        .map(|results| {
+            let results: Vec<ImportUserResult> =
+                results.into_iter().map(ImportUserResult::from).collect();
+            (StatusCode::OK, Json(results))
+        })
+        .map_err(|err| {
+            tracing::error!("Failed to import users: {err:?}");
+            err.into()
+        })
+}
+
 /// 特定のIDのユーザーの削除
 #[utoipa::path(
     delete,
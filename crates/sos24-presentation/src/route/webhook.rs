@@ -0,0 +1,208 @@
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::{Extension, Json};
+use sos24_use_case::webhook::interactor::create::CreateWebhookCommand;
+
+use crate::context::Context;
+use crate::error::{AppError, ErrorResponse};
+use crate::model::webhook::{
+    ConvertToUpdateWebhookDto, CreateWebhook, CreatedWebhook, UpdateWebhook, Webhook,
+    WebhookDelivery,
+};
+use crate::module::Modules;
+
+/// Webhook一覧の取得
+#[utoipa::path(
+    get,
+    path = "/webhooks",
+    operation_id = "getWebhooks",
+    tag = "webhooks",
+    responses(
+        (status = 200, description = "OK", body = Vec<Webhook>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error", body = ErrorResponse),
+    ),
+    security(("jwt_token" = [])),
+)]
+pub async fn handle_get(
+    State(modules): State<Arc<Modules>>,
+    Extension(ctx): Extension<Context>,
+) -> Result<impl IntoResponse, AppError> {
+    let raw_webhook_list = modules.webhook_use_case().list(&ctx).await;
+    raw_webhook_list
+        .map(|raw_webhook_list| {
+            let webhook_list: Vec<Webhook> =
+                raw_webhook_list.into_iter().map(Webhook::from).collect();
+            (StatusCode::OK, Json(webhook_list))
+        })
+        .map_err(|err| {
+            tracing::error!("Failed to list webhooks: {err:?}");
+            err.into()
+        })
+}
+
+/// Webhookの作成
+#[utoipa::path(
+    post,
+    path = "/webhooks",
+    operation_id = "postWebhook",
+    tag = "webhooks",
+    request_body(content = CreateWebhook),
+    responses(
+        (status = 201, description = "Created", body = CreatedWebhook),
+        (status = 400, description = "Bad Request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 422, description = "Unprocessable Entity", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error", body = ErrorResponse),
+    ),
+    security(("jwt_token" = [])),
+)]
+pub async fn handle_post(
+    State(modules): State<Arc<Modules>>,
+    Extension(ctx): Extension<Context>,
+    Json(raw_webhook): Json<CreateWebhook>,
+) -> Result<impl IntoResponse, AppError> {
+    let webhook = CreateWebhookCommand::from(raw_webhook);
+    let res = modules.webhook_use_case().create(&ctx, webhook).await;
+    res.map(|id| (StatusCode::CREATED, Json(CreatedWebhook { id })))
+        .map_err(|err| {
+            tracing::error!("Failed to create webhook: {err:?}");
+            err.into()
+        })
+}
+
+/// 特定のIDのWebhookの取得
+#[utoipa::path(
+    get,
+    path = "/webhooks/{webhook_id}",
+    operation_id = "getWebhookById",
+    tag = "webhooks",
+    params(("webhook_id" = String, Path, format="uuid")),
+    responses(
+        (status = 200, description = "OK", body = Webhook),
+        (status = 400, description = "Bad Request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not Found", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error", body = ErrorResponse),
+    ),
+    security(("jwt_token" = [])),
+)]
+pub async fn handle_get_id(
+    Path(id): Path<String>,
+    Extension(ctx): Extension<Context>,
+    State(modules): State<Arc<Modules>>,
+) -> Result<impl IntoResponse, AppError> {
+    let raw_webhook = modules.webhook_use_case().find_by_id(&ctx, id).await;
+    match raw_webhook {
+        Ok(raw_webhook) => Ok((StatusCode::OK, Json(Webhook::from(raw_webhook)))),
+        Err(err) => {
+            tracing::error!("Failed to find webhook: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+/// 特定のIDのWebhookの削除
+#[utoipa::path(
+    delete,
+    path = "/webhooks/{webhook_id}",
+    operation_id = "deleteWebhookById",
+    tag = "webhooks",
+    params(("webhook_id" = String, Path, format="uuid")),
+    responses(
+        (status = 200, description = "OK"),
+        (status = 400, description = "Bad Request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not Found", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error", body = ErrorResponse),
+    ),
+    security(("jwt_token" = [])),
+)]
+pub async fn handle_delete_id(
+    Path(id): Path<String>,
+    Extension(ctx): Extension<Context>,
+    State(modules): State<Arc<Modules>>,
+) -> Result<impl IntoResponse, AppError> {
+    let res = modules.webhook_use_case().delete_by_id(&ctx, id).await;
+    res.map(|_| StatusCode::OK).map_err(|err| {
+        tracing::error!("Failed to delete webhook: {err:?}");
+        err.into()
+    })
+}
+
+/// 特定のIDのWebhookを更新
+#[utoipa::path(
+    put,
+    path = "/webhooks/{webhook_id}",
+    operation_id = "putWebhookById",
+    tag = "webhooks",
+    params(("webhook_id" = String, Path, format="uuid")),
+    request_body(content = UpdateWebhook),
+    responses(
+        (status = 200, description = "OK"),
+        (status = 400, description = "Bad Request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not Found", body = ErrorResponse),
+        (status = 422, description = "Unprocessable Entity", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error", body = ErrorResponse),
+    ),
+    security(("jwt_token" = [])),
+)]
+pub async fn handle_put_id(
+    Path(id): Path<String>,
+    State(modules): State<Arc<Modules>>,
+    Extension(ctx): Extension<Context>,
+    Json(raw_webhook): Json<UpdateWebhook>,
+) -> Result<impl IntoResponse, AppError> {
+    let webhook = (id, raw_webhook).to_update_webhook_dto();
+    let res = modules.webhook_use_case().update(&ctx, webhook).await;
+    res.map(|_| StatusCode::OK).map_err(|err| {
+        tracing::error!("Failed to update webhook: {err:?}");
+        err.into()
+    })
+}
+
+/// 特定のIDのWebhookの配信履歴の取得
+#[utoipa::path(
+    get,
+    path = "/webhooks/{webhook_id}/deliveries",
+    operation_id = "getWebhookDeliveriesById",
+    tag = "webhooks",
+    params(("webhook_id" = String, Path, format="uuid")),
+    responses(
+        (status = 200, description = "OK", body = Vec<WebhookDelivery>),
+        (status = 400, description = "Bad Request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not Found", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error", body = ErrorResponse),
+    ),
+    security(("jwt_token" = [])),
+)]
+pub async fn handle_get_id_deliveries(
+    Path(id): Path<String>,
+    Extension(ctx): Extension<Context>,
+    State(modules): State<Arc<Modules>>,
+) -> Result<impl IntoResponse, AppError> {
+    let raw_delivery_list = modules.webhook_use_case().list_deliveries(&ctx, id).await;
+    raw_delivery_list
+        .map(|raw_delivery_list| {
+            let delivery_list: Vec<WebhookDelivery> = raw_delivery_list
+                .into_iter()
+                .map(WebhookDelivery::from)
+                .collect();
+            (StatusCode::OK, Json(delivery_list))
+        })
+        .map_err(|err| {
+            tracing::error!("Failed to list webhook deliveries: {err:?}");
+            err.into()
+        })
+}
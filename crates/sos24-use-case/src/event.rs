@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use sos24_domain::repository::Repositories;
+
+use crate::shared::{adapter::Adapters, context::ContextError};
+
+pub mod dto;
+pub mod interactor;
+
+#[derive(Debug, Error)]
+pub enum EventUseCaseError {
+    #[error(transparent)]
+    ContextError(#[from] ContextError),
+    #[error(transparent)]
+    InternalError(#[from] anyhow::Error),
+}
+
+pub struct EventUseCase<R: Repositories, A: Adapters> {
+    repositories: Arc<R>,
+    adapters: Arc<A>,
+}
+
+impl<R: Repositories, A: Adapters> EventUseCase<R, A> {
+    pub fn new(repositories: Arc<R>, adapters: Arc<A>) -> Self {
+        Self {
+            repositories,
+            adapters,
+        }
+    }
+}
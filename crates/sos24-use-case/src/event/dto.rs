@@ -0,0 +1,74 @@
+use crate::shared::adapter::event::Event;
+
+#[derive(Debug)]
+pub enum EventDto {
+    ProjectCreated {
+        project_id: String,
+    },
+    ProjectUpdated {
+        project_id: String,
+    },
+    ProjectDeleted {
+        project_id: String,
+    },
+    NewsPublished {
+        news_id: String,
+    },
+    FormStarted {
+        form_id: String,
+    },
+    FormAnswerSubmitted {
+        form_answer_id: String,
+        form_id: String,
+        project_id: String,
+    },
+    FormAnswerUpdated {
+        form_answer_id: String,
+        form_id: String,
+        project_id: String,
+    },
+    InvitationReceived {
+        invitation_id: String,
+        project_id: String,
+    },
+    CommitteeNotified {
+        message: String,
+    },
+}
+
+impl From<Event> for EventDto {
+    fn from(event: Event) -> Self {
+        match event {
+            Event::ProjectCreated(project) => Self::ProjectCreated {
+                project_id: project.id().clone().value().to_string(),
+            },
+            Event::ProjectUpdated(project) => Self::ProjectUpdated {
+                project_id: project.id().clone().value().to_string(),
+            },
+            Event::ProjectDeleted(project) => Self::ProjectDeleted {
+                project_id: project.id().clone().value().to_string(),
+            },
+            Event::NewsPublished(news) => Self::NewsPublished {
+                news_id: news.id().clone().value().to_string(),
+            },
+            Event::FormStarted(form) => Self::FormStarted {
+                form_id: form.id().clone().value().to_string(),
+            },
+            Event::FormAnswerSubmitted(form_answer) => Self::FormAnswerSubmitted {
+                form_answer_id: form_answer.id().clone().value().to_string(),
+                form_id: form_answer.form_id().clone().value().to_string(),
+                project_id: form_answer.project_id().clone().value().to_string(),
+            },
+            Event::FormAnswerUpdated(form_answer) => Self::FormAnswerUpdated {
+                form_answer_id: form_answer.id().clone().value().to_string(),
+                form_id: form_answer.form_id().clone().value().to_string(),
+                project_id: form_answer.project_id().clone().value().to_string(),
+            },
+            Event::InvitationReceived(invitation) => Self::InvitationReceived {
+                invitation_id: invitation.id().clone().value().to_string(),
+                project_id: invitation.project_id().clone().value().to_string(),
+            },
+            Event::CommitteeNotified(message) => Self::CommitteeNotified { message },
+        }
+    }
+}
@@ -0,0 +1,276 @@
+use std::sync::Arc;
+
+use tokio::sync::broadcast::{self, error::RecvError};
+
+use sos24_domain::{
+    entity::{
+        actor::Actor,
+        form_answer::FormAnswer,
+        permission::Permissions,
+        project::{Project, ProjectCategories, ProjectCategory},
+    },
+    repository::{project::ProjectRepository, Repositories},
+};
+
+use crate::{
+    event::{dto::EventDto, EventUseCase, EventUseCaseError},
+    shared::{
+        adapter::{
+            event::{Event, EventBus},
+            Adapters,
+        },
+        context::ContextProvider,
+    },
+};
+
+pub struct EventSubscription<R: Repositories> {
+    repositories: Arc<R>,
+    receiver: broadcast::Receiver<Event>,
+    actor: Actor,
+    project: Option<Project>,
+}
+
+impl<R: Repositories> EventSubscription<R> {
+    // 閲覧できるイベントが届くまで待機し、購読が終了した場合はNoneを返す
+    pub async fn recv(&mut self) -> Option<EventDto> {
+        loop {
+            let event = match self.receiver.recv().await {
+                Ok(event) => event,
+                Err(RecvError::Lagged(count)) => {
+                    tracing::warn!("{count}件のイベントを取りこぼしました");
+                    continue;
+                }
+                Err(RecvError::Closed) => return None,
+            };
+            if self.is_visible(&event).await {
+                return Some(EventDto::from(event));
+            }
+        }
+    }
+
+    async fn is_visible(&self, event: &Event) -> bool {
+        // 企画一覧を閲覧できるユーザー（実委人）は、閲覧できる企画区分向けのイベントを受け取る
+        let is_sent_to_me = |categories: ProjectCategories,
+                             is_sent_to: &dyn Fn(&Project) -> bool| {
+            self.actor.has_permission(Permissions::READ_PROJECT_ALL)
+                || ProjectCategory::ALL.iter().any(|category| {
+                    categories.matches(*category)
+                        && self
+                            .actor
+                            .has_project_permission(Permissions::READ_PROJECT_ALL, *category)
+                })
+                || self.project.as_ref().is_some_and(is_sent_to)
+        };
+        match event {
+            Event::ProjectCreated(project)
+            | Event::ProjectUpdated(project)
+            | Event::ProjectDeleted(project) => project.is_visible_to(&self.actor),
+            Event::NewsPublished(news) => {
+                news.is_visible_to(&self.actor)
+                    && is_sent_to_me(*news.categories(), &|it| news.is_sent_to(it))
+            }
+            Event::FormStarted(form) => {
+                form.is_visible_to(&self.actor, &chrono::Utc::now())
+                    && is_sent_to_me(*form.categories(), &|it| form.is_sent_to(it))
+            }
+            Event::FormAnswerSubmitted(form_answer) | Event::FormAnswerUpdated(form_answer) => {
+                self.is_form_answer_visible(form_answer).await
+            }
+            Event::InvitationReceived(invitation) => invitation.is_visible_to(&self.actor),
+            Event::CommitteeNotified(_) => self
+                .actor
+                .has_permission_in_any_category(Permissions::READ_PROJECT_ALL),
+        }
+    }
+
+    async fn is_form_answer_visible(&self, form_answer: &FormAnswer) -> bool {
+        let owned_project_id = self.project.as_ref().map(|it| it.id().clone());
+        if owned_project_id.as_ref() == Some(form_answer.project_id()) {
+            return true;
+        }
+        if !self
+            .actor
+            .has_permission_in_any_category(Permissions::READ_FORM_ANSWER_ALL)
+        {
+            return false;
+        }
+
+        // 回答した企画の企画区分によって閲覧できるかが変わるので、企画を取得して判定する
+        let project_with_owners = match self
+            .repositories
+            .project_repository()
+            .find_by_id(form_answer.project_id().clone())
+            .await
+        {
+            Ok(Some(project_with_owners)) => project_with_owners,
+            Ok(None) => return false,
+            Err(err) => {
+                tracing::warn!("回答した企画の取得に失敗しました: {err:?}");
+                return false;
+            }
+        };
+        form_answer.is_visible_to(
+            &self.actor,
+            owned_project_id,
+            *project_with_owners.project.category(),
+        )
+    }
+}
+
+impl<R: Repositories, A: Adapters> EventUseCase<R, A> {
+    pub async fn subscribe(
+        &self,
+        ctx: &impl ContextProvider,
+    ) -> Result<EventSubscription<R>, EventUseCaseError> {
+        let actor = ctx.actor(&*self.repositories).await?;
+        let project = ctx
+            .project(&*self.repositories)
+            .await?
+            .map(|project_with_owners| project_with_owners.project);
+
+        Ok(EventSubscription {
+            repositories: Arc::clone(&self.repositories),
+            receiver: self.adapters.event_bus().subscribe(),
+            actor,
+            project,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::{
+        entity::{invitation::InvitationPosition, user::UserRole},
+        test::{fixture, repository::MockRepositories},
+    };
+    use tokio::sync::broadcast;
+
+    use crate::{
+        event::{dto::EventDto, EventUseCase},
+        shared::{
+            adapter::{event::Event, MockAdapters},
+            context::TestContext,
+        },
+    };
+
+    #[tokio::test]
+    async fn 一般ユーザーは自分の企画のイベントのみ受け取る() {
+        let (sender, receiver) = broadcast::channel(16);
+        let mut repositories = MockRepositories::default();
+        repositories
+            .project_repository_mut()
+            .expect_find_by_owner_id()
+            .returning(|_| {
+                Ok(Some(fixture::project::project_with_owners1(
+                    fixture::user::user1(UserRole::General),
+                )))
+            });
+        let mut adapters = MockAdapters::default();
+        adapters
+            .event_bus_mut()
+            .expect_subscribe()
+            .return_once(move || receiver);
+        let use_case = EventUseCase::new(Arc::new(repositories), Arc::new(adapters));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let mut subscription = use_case.subscribe(&ctx).await.unwrap();
+
+        sender
+            .send(Event::FormAnswerUpdated(
+                fixture::form_answer::form_answer2(fixture::project::id2()),
+            ))
+            .unwrap();
+        sender
+            .send(Event::InvitationReceived(fixture::invitation::invitation(
+                fixture::user::id1(),
+                fixture::project::id1(),
+                InvitationPosition::SubOwner,
+            )))
+            .unwrap();
+
+        let res = subscription.recv().await;
+        assert!(matches!(res, Some(EventDto::InvitationReceived { .. })));
+    }
+
+    #[tokio::test]
+    async fn 企画区分を限定したロールは担当する企画区分の回答のイベントのみ受け取る() {
+        let (sender, receiver) = broadcast::channel(16);
+        let mut repositories = MockRepositories::default();
+        repositories
+            .project_repository_mut()
+            .expect_find_by_owner_id()
+            .returning(|_| Ok(None));
+        repositories
+            .project_repository_mut()
+            .expect_find_by_sub_owner_id()
+            .returning(|_| Ok(None));
+        repositories
+            .project_repository_mut()
+            .expect_find_by_id()
+            .returning(|id| {
+                let owner = fixture::user::user2(UserRole::General);
+                Ok(Some(if id == fixture::project::id1() {
+                    fixture::project::project_with_owners1(owner)
+                } else {
+                    fixture::project::project_with_owners2(owner)
+                }))
+            });
+        let mut adapters = MockAdapters::default();
+        adapters
+            .event_bus_mut()
+            .expect_subscribe()
+            .return_once(move || receiver);
+        let use_case = EventUseCase::new(Arc::new(repositories), Arc::new(adapters));
+
+        let actor = fixture::actor::actor1(UserRole::General)
+            .with_custom_roles(&[fixture::custom_role::stage_role1()]);
+        let ctx = TestContext::new(actor);
+        let mut subscription = use_case.subscribe(&ctx).await.unwrap();
+
+        sender
+            .send(Event::FormAnswerUpdated(
+                fixture::form_answer::form_answer1(fixture::project::id1()),
+            ))
+            .unwrap();
+        sender
+            .send(Event::FormAnswerUpdated(
+                fixture::form_answer::form_answer2(fixture::project::id2()),
+            ))
+            .unwrap();
+
+        let res = subscription.recv().await;
+        assert!(matches!(
+            res,
+            Some(EventDto::FormAnswerUpdated { project_id, .. })
+                if project_id == fixture::project::id2().value().to_string()
+        ));
+    }
+
+    #[tokio::test]
+    async fn 購読が終了するとnoneを返す() {
+        let (sender, receiver) = broadcast::channel(16);
+        let mut repositories = MockRepositories::default();
+        repositories
+            .project_repository_mut()
+            .expect_find_by_owner_id()
+            .returning(|_| Ok(None));
+        repositories
+            .project_repository_mut()
+            .expect_find_by_sub_owner_id()
+            .returning(|_| Ok(None));
+        let mut adapters = MockAdapters::default();
+        adapters
+            .event_bus_mut()
+            .expect_subscribe()
+            .return_once(move || receiver);
+        let use_case = EventUseCase::new(Arc::new(repositories), Arc::new(adapters));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let mut subscription = use_case.subscribe(&ctx).await.unwrap();
+
+        drop(sender);
+        assert!(subscription.recv().await.is_none());
+    }
+}
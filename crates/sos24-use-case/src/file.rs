@@ -2,17 +2,29 @@ use std::sync::Arc;
 
 use thiserror::Error;
 
-use sos24_domain::entity::file_data::{FileId, FileIdError};
+use sos24_domain::ensure;
+use sos24_domain::entity::actor::Actor;
+use sos24_domain::entity::file_data::{FileData, FileId, FileIdError, FileScanStatus};
+use sos24_domain::entity::file_export::{FileExportId, FileExportIdError};
+use sos24_domain::entity::file_object::FileSignedUrlExpiryError;
 use sos24_domain::entity::form::{FormId, FormIdError, FormItemId};
 use sos24_domain::entity::project::{ProjectId, ProjectIdError};
-use sos24_domain::repository::file_data::FileDataRepositoryError;
+use sos24_domain::entity::project_storage::{ProjectStorage, ProjectStorageQuota};
+use sos24_domain::repository::file_data::{FileDataRepository, FileDataRepositoryError};
+use sos24_domain::repository::file_export::FileExportRepositoryError;
 use sos24_domain::repository::file_object::FileObjectRepositoryError;
+use sos24_domain::repository::file_upload::FileUploadRepositoryError;
 use sos24_domain::repository::form::FormRepositoryError;
 use sos24_domain::repository::form_answer::FormAnswerRepositoryError;
-use sos24_domain::repository::project::ProjectRepositoryError;
+use sos24_domain::repository::news::NewsRepositoryError;
+use sos24_domain::repository::project::{ProjectRepository, ProjectRepositoryError};
+use sos24_domain::repository::project_storage_quota::{
+    ProjectStorageQuotaRepository, ProjectStorageQuotaRepositoryError,
+};
 use sos24_domain::{entity::permission::PermissionDeniedError, repository::Repositories};
 
-use crate::shared::context::ContextError;
+use crate::shared::adapter::Adapters;
+use crate::shared::context::{ContextError, ContextProvider};
 
 pub mod dto;
 pub mod interactor;
@@ -29,6 +41,24 @@ pub enum FileUseCaseError {
     FormNotFound(FormId),
     #[error("Form item not found: {0:?}")]
     FormItemNotFound(FormItemId),
+    #[error("Upload not found: {0:?}")]
+    UploadNotFound(FileId),
+    #[error("Upload expired: {0:?}")]
+    UploadExpired(FileId),
+    #[error("Object not uploaded: {0:?}")]
+    ObjectNotUploaded(FileId),
+    #[error("File not scanned yet: {0:?}")]
+    NotScanned(FileId),
+    #[error("File infected: {0:?}")]
+    Infected(FileId),
+    #[error("File could not be scanned: {0:?}")]
+    Unscannable(FileId),
+    #[error("Thumbnail not found: {0:?}")]
+    ThumbnailNotFound(FileId),
+    #[error("Storage quota exceeded: {0:?}")]
+    StorageQuotaExceeded(ProjectId),
+    #[error("Export not found: {0:?}")]
+    ExportNotFound(FileExportId),
 
     #[error(transparent)]
     FormRepositoryError(#[from] FormRepositoryError),
@@ -41,25 +71,116 @@ pub enum FileUseCaseError {
     #[error(transparent)]
     FileObjectRepositoryError(#[from] FileObjectRepositoryError),
     #[error(transparent)]
+    FileUploadRepositoryError(#[from] FileUploadRepositoryError),
+    #[error(transparent)]
     FileIdError(#[from] FileIdError),
     #[error(transparent)]
+    FileSignedUrlExpiryError(#[from] FileSignedUrlExpiryError),
+    #[error(transparent)]
+    FileExportRepositoryError(#[from] FileExportRepositoryError),
+    #[error(transparent)]
+    FileExportIdError(#[from] FileExportIdError),
+    #[error(transparent)]
     PermissionDeniedError(#[from] PermissionDeniedError),
     #[error(transparent)]
     ContextError(#[from] ContextError),
     #[error(transparent)]
+    NewsRepositoryError(#[from] NewsRepositoryError),
+    #[error(transparent)]
     ProjectRepositoryError(#[from] ProjectRepositoryError),
     #[error(transparent)]
     ProjectIdError(#[from] ProjectIdError),
     #[error(transparent)]
+    ProjectStorageQuotaRepositoryError(#[from] ProjectStorageQuotaRepositoryError),
+    #[error(transparent)]
     InternalError(#[from] anyhow::Error),
 }
 
-pub struct FileUseCase<R: Repositories> {
+pub struct FileUseCase<R: Repositories, A: Adapters> {
     repositories: Arc<R>,
+    adapters: Arc<A>,
 }
 
-impl<R: Repositories> FileUseCase<R> {
-    pub fn new(repositories: Arc<R>) -> Self {
-        Self { repositories }
+impl<R: Repositories, A: Adapters> FileUseCase<R, A> {
+    pub fn new(repositories: Arc<R>, adapters: Arc<A>) -> Self {
+        Self {
+            repositories,
+            adapters,
+        }
+    }
+
+    // 閲覧できる企画のファイルのうち、スキャンで問題がないと確認できたものだけを返す
+    async fn find_downloadable(
+        &self,
+        actor: &Actor,
+        id: FileId,
+    ) -> Result<FileData, FileUseCaseError> {
+        let file_data = self
+            .repositories
+            .file_data_repository()
+            .find_by_id(id.clone())
+            .await?
+            .ok_or(FileUseCaseError::NotFound(id.clone()))?;
+        if let Some(project_id) = file_data.owner().clone() {
+            let project_with_owners = self
+                .repositories
+                .project_repository()
+                .find_by_id(project_id)
+                .await?
+                .ok_or(FileUseCaseError::OwnerNotFound)?;
+            ensure!(project_with_owners.project.is_visible_to(actor));
+        }
+        match file_data.scan_status() {
+            // スキャナが設定されていない環境では、スキャンせずにダウンロードさせる
+            FileScanStatus::Clean | FileScanStatus::NotScanned => Ok(file_data),
+            FileScanStatus::Pending => Err(FileUseCaseError::NotScanned(id)),
+            FileScanStatus::Infected => Err(FileUseCaseError::Infected(id)),
+            FileScanStatus::Unscannable => Err(FileUseCaseError::Unscannable(id)),
+        }
+    }
+
+    // 同じ企画が同じ内容のファイルを既にアップロードしていれば、そのIDを返す
+    async fn find_duplicate_ids(
+        &self,
+        file_data: &FileData,
+    ) -> Result<Vec<String>, FileUseCaseError> {
+        let Some(owner) = file_data.owner() else {
+            return Ok(Vec::new());
+        };
+
+        let file_data_list = self
+            .repositories
+            .file_data_repository()
+            .find_by_owner_project(owner.clone())
+            .await?;
+        Ok(file_data_list
+            .into_iter()
+            .filter(|it| file_data.is_duplicate_of(it))
+            .map(|it| it.id().clone().value().to_string())
+            .collect())
+    }
+
+    // 個別に上限が設定されていなければ、設定ファイルの既定値を上限とする
+    async fn find_project_storage(
+        &self,
+        ctx: &impl ContextProvider,
+        project_id: ProjectId,
+    ) -> Result<ProjectStorage, FileUseCaseError> {
+        let quota = self
+            .repositories
+            .project_storage_quota_repository()
+            .find_by_project_id(project_id.clone())
+            .await?
+            .unwrap_or_else(|| ProjectStorageQuota::new(ctx.config().project_storage_quota));
+        let file_data_list = self
+            .repositories
+            .file_data_repository()
+            .find_by_owner_project(project_id.clone())
+            .await?;
+        Ok(ProjectStorage::calculate(
+            project_id,
+            &file_data_list,
+            quota,
+        ))
     }
 }
@@ -1,12 +1,19 @@
 use tokio::io::AsyncRead;
 
-use sos24_domain::entity::{file_data::FileData, file_object::FileSignedUrl};
+use sos24_domain::entity::{
+    file_data::{FileData, FileScanStatus},
+    file_export::{FileExport, FileExportStatus},
+    file_object::{DispositionType, FileSignedUrl},
+    file_upload::FileUpload,
+    project_storage::ProjectStorage,
+};
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct FileDto {
     pub id: String,
     pub filename: String,
     pub url: String,
+    pub thumbnail_url: Option<String>,
     pub owner: Option<String>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
@@ -16,6 +23,11 @@ pub struct FileInfoDto {
     pub id: String,
     pub filename: String,
     pub owner: Option<String>,
+    pub size: Option<i64>,
+    pub content_type: Option<String>,
+    pub checksum: Option<String>,
+    pub uploader: Option<String>,
+    pub scan_status: FileScanStatusDto,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
@@ -27,19 +39,48 @@ impl From<FileData> for FileInfoDto {
             id: filedata.id.value().to_string(),
             filename: filedata.name.value(),
             owner: filedata.owner.map(|it| it.value().to_string()),
+            size: filedata.size.map(|it| it.value()),
+            content_type: filedata.content_type.map(|it| it.value()),
+            checksum: filedata.checksum.map(|it| it.value()),
+            uploader: filedata.uploader.map(|it| it.value()),
+            scan_status: FileScanStatusDto::from(filedata.scan_status),
             created_at: filedata.created_at.value(),
             updated_at: filedata.updated_at.value(),
         }
     }
 }
 
-impl From<(FileSignedUrl, FileData)> for FileDto {
-    fn from((url, file_data_entity): (FileSignedUrl, FileData)) -> Self {
+#[derive(Debug)]
+pub enum FileScanStatusDto {
+    Pending,
+    Clean,
+    Infected,
+    Unscannable,
+    NotScanned,
+}
+
+impl From<FileScanStatus> for FileScanStatusDto {
+    fn from(entity: FileScanStatus) -> Self {
+        match entity {
+            FileScanStatus::Pending => FileScanStatusDto::Pending,
+            FileScanStatus::Clean => FileScanStatusDto::Clean,
+            FileScanStatus::Infected => FileScanStatusDto::Infected,
+            FileScanStatus::Unscannable => FileScanStatusDto::Unscannable,
+            FileScanStatus::NotScanned => FileScanStatusDto::NotScanned,
+        }
+    }
+}
+
+impl From<(FileSignedUrl, Option<FileSignedUrl>, FileData)> for FileDto {
+    fn from(
+        (url, thumbnail_url, file_data_entity): (FileSignedUrl, Option<FileSignedUrl>, FileData),
+    ) -> Self {
         let file_data = file_data_entity.destruct();
         Self {
             id: file_data.id.value().to_string(),
             filename: file_data.name.value().to_string(),
             url: url.value().to_string(),
+            thumbnail_url: thumbnail_url.map(|it| it.value().to_string()),
             owner: file_data.owner.map(|it| it.value().to_string()),
             created_at: file_data.created_at.value(),
             updated_at: file_data.updated_at.value(),
@@ -47,6 +88,146 @@ impl From<(FileSignedUrl, FileData)> for FileDto {
     }
 }
 
+#[derive(Debug)]
+pub struct FileThumbnailDto {
+    pub url: String,
+}
+
+impl From<FileSignedUrl> for FileThumbnailDto {
+    fn from(url: FileSignedUrl) -> Self {
+        Self {
+            url: url.value().to_string(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct CreatedFileDto {
+    pub id: String,
+    // 同じ企画が既にアップロードしている、同じ内容のファイルのID
+    pub duplicate_ids: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct UploadUrlDto {
+    pub id: String,
+    pub url: String,
+    pub expires_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<(FileSignedUrl, FileUpload)> for UploadUrlDto {
+    fn from((url, file_upload): (FileSignedUrl, FileUpload)) -> Self {
+        let file_upload = file_upload.destruct();
+        Self {
+            id: file_upload.id.value().to_string(),
+            url: url.value().to_string(),
+            expires_at: file_upload.expires_at.value(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ProjectStorageDto {
+    pub project_id: String,
+    pub used: u64,
+    pub quota: u64,
+    pub remaining: u64,
+}
+
+impl From<ProjectStorage> for ProjectStorageDto {
+    fn from(entity: ProjectStorage) -> Self {
+        let remaining = entity.remaining();
+        let storage = entity.destruct();
+        Self {
+            project_id: storage.project_id.value().to_string(),
+            used: storage.used,
+            quota: storage.quota.value(),
+            remaining,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct CreatedFileExportDto {
+    pub id: String,
+}
+
+#[derive(Debug)]
+pub struct FileExportDto {
+    pub id: String,
+    pub form_id: String,
+    pub status: FileExportStatusDto,
+    pub since: Option<chrono::DateTime<chrono::Utc>>,
+    pub total: i32,
+    pub processed: i32,
+    // 完了している場合のみ、アーカイブをダウンロードするための署名付きURLを返す
+    pub url: Option<String>,
+    pub error: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<(Option<FileSignedUrl>, FileExport)> for FileExportDto {
+    fn from((url, entity): (Option<FileSignedUrl>, FileExport)) -> Self {
+        let export = entity.destruct();
+        Self {
+            id: export.id.value().to_string(),
+            form_id: export.form_id.value().to_string(),
+            status: FileExportStatusDto::from(export.status),
+            since: export.since.map(|it| it.value()),
+            total: export.total.value(),
+            processed: export.processed.value(),
+            url: url.map(|it| it.value().to_string()),
+            error: export.error.map(|it| it.value()),
+            created_at: export.created_at.value(),
+            updated_at: export.updated_at.value(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum FileExportStatusDto {
+    Pending,
+    Running,
+    Completed,
+    Failed,
+}
+
+impl From<FileExportStatus> for FileExportStatusDto {
+    fn from(entity: FileExportStatus) -> Self {
+        match entity {
+            FileExportStatus::Pending => FileExportStatusDto::Pending,
+            FileExportStatus::Running => FileExportStatusDto::Running,
+            FileExportStatus::Completed => FileExportStatusDto::Completed,
+            FileExportStatus::Failed => FileExportStatusDto::Failed,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub enum FileDispositionDto {
+    Inline,
+    #[default]
+    Attachment,
+}
+
+impl From<FileDispositionDto> for DispositionType {
+    fn from(dto: FileDispositionDto) -> Self {
+        match dto {
+            FileDispositionDto::Inline => DispositionType::Inline,
+            FileDispositionDto::Attachment => DispositionType::Attachment,
+        }
+    }
+}
+
+// 参照されていないファイルの回収で削除される予定のファイル
+pub struct FileGarbageReportDto {
+    // 次の回収でソフトデリートされるファイル
+    pub to_be_deleted: Vec<FileInfoDto>,
+    // 次の回収でオブジェクトごと削除されるファイル
+    pub to_be_purged: Vec<FileInfoDto>,
+}
+
 pub struct ArchiveToBeExportedDto<R: AsyncRead> {
     pub filename: String,
     pub body: R,
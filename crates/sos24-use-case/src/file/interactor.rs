@@ -1,6 +1,18 @@
+pub mod clean_up_uploads;
+pub mod collect_garbage;
+pub mod complete_upload;
 pub mod create;
+pub mod create_export;
+pub mod create_upload_url;
 pub mod delete_by_id;
 pub mod export_by_form_id;
 pub mod export_by_owner;
 pub mod find_by_id;
+pub mod find_export_by_id;
+pub mod find_storage_by_project_id;
+pub mod find_thumbnail_by_id;
+pub mod generate_thumbnail;
 pub mod list;
+pub mod run_export;
+pub mod scan;
+pub mod update_storage_quota;
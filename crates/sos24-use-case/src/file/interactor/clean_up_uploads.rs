@@ -0,0 +1,83 @@
+use sos24_domain::{
+    entity::common::datetime::DateTime,
+    repository::{
+        file_object::FileObjectRepository, file_upload::FileUploadRepository, Repositories,
+    },
+};
+
+use crate::{
+    file::{FileUseCase, FileUseCaseError},
+    shared::{adapter::Adapters, context::ContextProvider},
+};
+
+impl<R: Repositories, A: Adapters> FileUseCase<R, A> {
+    // 完了の報告がないまま期限を過ぎたアップロードを、オブジェクトごと削除する
+    pub async fn clean_up_uploads(
+        &self,
+        _ctx: &impl ContextProvider,
+        bucket: String,
+    ) -> Result<(), FileUseCaseError> {
+        let upload_list = self
+            .repositories
+            .file_upload_repository()
+            .find_expired(DateTime::now())
+            .await?;
+
+        for upload in upload_list {
+            tracing::info!("期限切れのアップロードを削除します: {:?}", upload.id());
+
+            self.repositories
+                .file_object_repository()
+                .delete(bucket.clone(), upload.key().copy())
+                .await?;
+            self.repositories
+                .file_upload_repository()
+                .delete_by_id(upload.id().clone())
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::entity::user::UserRole;
+    use sos24_domain::test::fixture;
+    use sos24_domain::test::repository::MockRepositories;
+
+    use crate::file::FileUseCase;
+    use crate::shared::adapter::MockAdapters;
+    use crate::shared::context::TestContext;
+
+    #[tokio::test]
+    async fn 期限切れのアップロードはオブジェクトごと削除される() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .file_upload_repository_mut()
+            .expect_find_expired()
+            .returning(|_| {
+                Ok(vec![
+                    fixture::file_upload::file_upload(fixture::user::id1()),
+                ])
+            });
+        repositories
+            .file_object_repository_mut()
+            .expect_delete()
+            .times(1)
+            .returning(|_, _| Ok(()));
+        repositories
+            .file_upload_repository_mut()
+            .expect_delete_by_id()
+            .times(1)
+            .returning(|_| Ok(()));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::Administrator));
+        let res = use_case.clean_up_uploads(&ctx, String::new()).await;
+
+        assert!(res.is_ok());
+    }
+}
@@ -0,0 +1,250 @@
+use sos24_domain::{
+    ensure,
+    entity::{common::datetime::DateTime, file_data::FileData, permission::Permissions},
+    repository::{
+        file_data::FileDataRepository, file_object::FileObjectRepository, form::FormRepository,
+        form_answer::FormAnswerRepository, news::NewsRepository, Repositories,
+    },
+    service::file_garbage,
+};
+
+use crate::{
+    file::{
+        dto::{FileGarbageReportDto, FileInfoDto},
+        FileUseCase, FileUseCaseError,
+    },
+    shared::{adapter::Adapters, context::ContextProvider},
+};
+
+impl<R: Repositories, A: Adapters> FileUseCase<R, A> {
+    // どこからも参照されていないファイルをソフトデリートし、
+    // ソフトデリートから十分に時間が経ったものはオブジェクトごと削除する
+    pub async fn collect_garbage(
+        &self,
+        _ctx: &impl ContextProvider,
+        bucket: String,
+    ) -> Result<(), FileUseCaseError> {
+        let now = DateTime::now();
+
+        for file_data in self.find_unreferenced_files(&now).await? {
+            tracing::info!("参照されていないファイルを削除します: {:?}", file_data.id());
+            self.repositories
+                .file_data_repository()
+                .delete_as_garbage_by_id(file_data.id().clone())
+                .await?;
+        }
+
+        let garbage_list = self
+            .repositories
+            .file_data_repository()
+            .find_garbage_deleted_before(file_garbage::purge_threshold(&now))
+            .await?;
+        for file_data in garbage_list {
+            tracing::info!("ファイルを完全に削除します: {:?}", file_data.id());
+            if let Some(thumbnail_key) = file_data.thumbnail_key() {
+                self.repositories
+                    .file_object_repository()
+                    .delete(bucket.clone(), thumbnail_key)
+                    .await?;
+            }
+            self.repositories
+                .file_object_repository()
+                .delete(bucket.clone(), file_data.url().copy())
+                .await?;
+            self.repositories
+                .file_data_repository()
+                .purge_by_id(file_data.id().clone())
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    // 次の回収で削除されるファイルを、実際には削除せずに返す
+    pub async fn find_garbage(
+        &self,
+        ctx: &impl ContextProvider,
+    ) -> Result<FileGarbageReportDto, FileUseCaseError> {
+        let actor = ctx.actor(&*self.repositories).await?;
+        ensure!(actor.has_permission(Permissions::DELETE_FILE_ALL));
+
+        let now = DateTime::now();
+        let to_be_deleted = self.find_unreferenced_files(&now).await?;
+        let to_be_purged = self
+            .repositories
+            .file_data_repository()
+            .find_garbage_deleted_before(file_garbage::purge_threshold(&now))
+            .await?;
+
+        Ok(FileGarbageReportDto {
+            to_be_deleted: to_be_deleted.into_iter().map(FileInfoDto::from).collect(),
+            to_be_purged: to_be_purged.into_iter().map(FileInfoDto::from).collect(),
+        })
+    }
+
+    async fn find_unreferenced_files(
+        &self,
+        now: &DateTime,
+    ) -> Result<Vec<FileData>, FileUseCaseError> {
+        let file_data_list = self.repositories.file_data_repository().list().await?;
+        let form_list = self.repositories.form_repository().list().await?;
+        let form_answer_list = self.repositories.form_answer_repository().list().await?;
+        let news_list = self.repositories.news_repository().list().await?;
+
+        Ok(file_garbage::find_unreferenced(
+            file_data_list,
+            &form_list,
+            &form_answer_list,
+            &news_list,
+            now,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::entity::common::datetime::DateTime;
+    use sos24_domain::entity::file_data::FileData;
+    use sos24_domain::entity::permission::PermissionDeniedError;
+    use sos24_domain::entity::user::UserRole;
+    use sos24_domain::service::file_garbage::GRACE_PERIOD_DAYS;
+    use sos24_domain::test::fixture;
+    use sos24_domain::test::repository::MockRepositories;
+
+    use crate::file::{FileUseCase, FileUseCaseError};
+    use crate::shared::adapter::MockAdapters;
+    use crate::shared::context::TestContext;
+
+    // 猶予を過ぎたファイル
+    fn old_file_data() -> FileData {
+        let file_data = fixture::file_data::file_data(Some(fixture::project::id1())).destruct();
+        FileData::new(
+            file_data.id,
+            file_data.name,
+            file_data.url,
+            file_data.owner,
+            file_data.size,
+            file_data.content_type,
+            file_data.checksum,
+            file_data.uploader,
+            file_data.is_standalone,
+            file_data.scan_status,
+            file_data.thumbnail_status,
+            DateTime::new(chrono::Utc::now() - chrono::Duration::days(GRACE_PERIOD_DAYS + 1)),
+            file_data.updated_at,
+        )
+    }
+
+    fn mock_references(repositories: &mut MockRepositories) {
+        repositories
+            .form_repository_mut()
+            .expect_list()
+            .returning(|| Ok(vec![fixture::form::form1_opened()]));
+        repositories
+            .form_answer_repository_mut()
+            .expect_list()
+            .returning(|| Ok(vec![]));
+        repositories
+            .news_repository_mut()
+            .expect_list()
+            .returning(|| Ok(vec![fixture::news::news1()]));
+    }
+
+    #[tokio::test]
+    async fn 参照されていないファイルはソフトデリートされる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .file_data_repository_mut()
+            .expect_list()
+            .returning(|| Ok(vec![old_file_data()]));
+        mock_references(&mut repositories);
+        repositories
+            .file_data_repository_mut()
+            .expect_delete_as_garbage_by_id()
+            .times(1)
+            .returning(|_| Ok(()));
+        repositories
+            .file_data_repository_mut()
+            .expect_find_garbage_deleted_before()
+            .returning(|_| Ok(vec![]));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case.collect_garbage(&ctx, String::new()).await;
+
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn ソフトデリートから時間が経ったファイルはオブジェクトごと削除される() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .file_data_repository_mut()
+            .expect_list()
+            .returning(|| Ok(vec![]));
+        mock_references(&mut repositories);
+        repositories
+            .file_data_repository_mut()
+            .expect_find_garbage_deleted_before()
+            .returning(|_| Ok(vec![old_file_data()]));
+        repositories
+            .file_object_repository_mut()
+            .expect_delete()
+            .times(1)
+            .returning(|_, _| Ok(()));
+        repositories
+            .file_data_repository_mut()
+            .expect_purge_by_id()
+            .times(1)
+            .returning(|_| Ok(()));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case.collect_garbage(&ctx, String::new()).await;
+
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn 実委人は削除予定のファイルを確認できない() {
+        let repositories = MockRepositories::default();
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeViewer));
+        let res = use_case.find_garbage(&ctx).await;
+
+        assert!(matches!(
+            res,
+            Err(FileUseCaseError::PermissionDeniedError(
+                PermissionDeniedError
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn 実委人管理者は削除せずに削除予定のファイルを確認できる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .file_data_repository_mut()
+            .expect_list()
+            .returning(|| Ok(vec![old_file_data()]));
+        mock_references(&mut repositories);
+        repositories
+            .file_data_repository_mut()
+            .expect_find_garbage_deleted_before()
+            .returning(|_| Ok(vec![]));
+        repositories
+            .file_data_repository_mut()
+            .expect_delete_as_garbage_by_id()
+            .never();
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeOperator));
+        let res = use_case.find_garbage(&ctx).await.unwrap();
+
+        assert_eq!(res.to_be_deleted.len(), 1);
+        assert!(res.to_be_purged.is_empty());
+    }
+}
@@ -0,0 +1,425 @@
+use sos24_domain::{
+    ensure,
+    entity::{
+        file_data::{FileData, FileId},
+        file_object::FileObjectKey,
+    },
+    repository::{
+        file_data::FileDataRepository,
+        file_object::{FileObjectRepository, FileObjectRepositoryError},
+        file_upload::FileUploadRepository,
+        Repositories,
+    },
+};
+
+use crate::{
+    file::{dto::CreatedFileDto, FileUseCase, FileUseCaseError},
+    shared::{adapter::Adapters, context::ContextProvider},
+};
+
+impl<R: Repositories, A: Adapters> FileUseCase<R, A> {
+    // 署名付きURLでアップロードされたオブジェクトを確認し、ファイルとして登録する
+    pub async fn complete_upload(
+        &self,
+        ctx: &impl ContextProvider,
+        bucket: String,
+        key_prefix: String,
+        size_limit: u64,
+        id: String,
+    ) -> Result<CreatedFileDto, FileUseCaseError> {
+        let actor = ctx.actor(&*self.repositories).await?;
+
+        let id = FileId::try_from(id)?;
+        let upload = self
+            .repositories
+            .file_upload_repository()
+            .find_by_id(id.clone())
+            .await?
+            .ok_or(FileUseCaseError::UploadNotFound(id.clone()))?;
+        ensure!(upload.is_completable_by(&actor));
+        if upload.is_expired(&chrono::Utc::now()) {
+            return Err(FileUseCaseError::UploadExpired(id));
+        }
+
+        // 署名付きURLは有効期限までアップロード先に書き込めるので、確認した後に差し替えられないよう
+        // 別のキーにコピーしてから確認し、アップロード先のオブジェクトは削除する
+        let key = FileObjectKey::generate(key_prefix.as_str());
+        let copied = self
+            .repositories
+            .file_object_repository()
+            .copy(bucket.clone(), upload.key().copy(), key.copy())
+            .await?;
+        if !copied {
+            return Err(FileUseCaseError::ObjectNotUploaded(id));
+        }
+        self.repositories
+            .file_object_repository()
+            .delete(bucket.clone(), upload.key().copy())
+            .await?;
+
+        let metadata = self
+            .repositories
+            .file_object_repository()
+            .find_metadata(bucket.clone(), key.copy())
+            .await?
+            .ok_or(FileUseCaseError::ObjectNotUploaded(id.clone()))?;
+        let size = metadata.size().clone().value() as u64;
+        let error = if size > size_limit {
+            Some(FileObjectRepositoryError::SizeLimitExceeded(size_limit).into())
+        } else if let Some(project_id) = upload.owner() {
+            let storage = self.find_project_storage(ctx, project_id.clone()).await?;
+            (!storage.can_store(size))
+                .then(|| FileUseCaseError::StorageQuotaExceeded(project_id.clone()))
+        } else {
+            None
+        };
+        if let Some(error) = error {
+            self.repositories
+                .file_object_repository()
+                .delete(bucket, key)
+                .await?;
+            self.repositories
+                .file_upload_repository()
+                .delete_by_id(id)
+                .await?;
+            return Err(error);
+        }
+
+        let upload = upload.destruct();
+        let data = FileData::create_with_id(
+            upload.id,
+            upload.filename,
+            key,
+            upload.owner,
+            upload.uploader,
+            upload.is_standalone,
+            metadata,
+        );
+        let duplicate_ids = self.find_duplicate_ids(&data).await?;
+        self.repositories
+            .file_data_repository()
+            .create(data)
+            .await?;
+
+        self.repositories
+            .file_upload_repository()
+            .delete_by_id(id.clone())
+            .await?;
+
+        Ok(CreatedFileDto {
+            id: id.value().to_string(),
+            duplicate_ids,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::entity::common::datetime::DateTime;
+    use sos24_domain::entity::file_data::{FileIsStandalone, FileSize};
+    use sos24_domain::entity::file_object::FileObjectMetadata;
+    use sos24_domain::entity::file_upload::FileUpload;
+    use sos24_domain::entity::permission::PermissionDeniedError;
+    use sos24_domain::entity::user::UserRole;
+    use sos24_domain::repository::file_object::FileObjectRepositoryError;
+    use sos24_domain::test::fixture;
+    use sos24_domain::test::repository::MockRepositories;
+
+    use crate::file::{FileUseCase, FileUseCaseError};
+    use crate::shared::adapter::MockAdapters;
+    use crate::shared::context::TestContext;
+
+    fn metadata(size: i64) -> FileObjectMetadata {
+        FileObjectMetadata::new(
+            FileSize::new(size),
+            Some(fixture::file_data::content_type()),
+            fixture::file_data::checksum(),
+        )
+    }
+
+    #[tokio::test]
+    async fn アップロードした本人はアップロードを完了できる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .file_upload_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| {
+                Ok(Some(
+                    fixture::file_upload::file_upload(fixture::user::id1()),
+                ))
+            });
+        repositories
+            .file_object_repository_mut()
+            .expect_copy()
+            .returning(|_, _, _| Ok(true));
+        repositories
+            .file_object_repository_mut()
+            .expect_find_metadata()
+            .returning(|_, _| Ok(Some(metadata(11))));
+        repositories
+            .file_object_repository_mut()
+            .expect_delete()
+            .returning(|_, _| Ok(()));
+        repositories
+            .file_data_repository_mut()
+            .expect_create()
+            .returning(|_| Ok(()));
+        repositories
+            .file_upload_repository_mut()
+            .expect_delete_by_id()
+            .returning(|_| Ok(()));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .complete_upload(
+                &ctx,
+                String::new(),
+                "user-upload".to_string(),
+                u64::MAX,
+                fixture::file_data::id().value().to_string(),
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn 登録したファイルはアップロード先とは別のキーに保存される() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .file_upload_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| {
+                Ok(Some(
+                    fixture::file_upload::file_upload(fixture::user::id1()),
+                ))
+            });
+        repositories
+            .file_object_repository_mut()
+            .expect_copy()
+            .withf(|_, from, to| from == &fixture::file_object::key() && to != from)
+            .times(1)
+            .returning(|_, _, _| Ok(true));
+        repositories
+            .file_object_repository_mut()
+            .expect_delete()
+            .withf(|_, key| key == &fixture::file_object::key())
+            .times(1)
+            .returning(|_, _| Ok(()));
+        repositories
+            .file_object_repository_mut()
+            .expect_find_metadata()
+            .withf(|_, key| key != &fixture::file_object::key())
+            .returning(|_, _| Ok(Some(metadata(11))));
+        repositories
+            .file_data_repository_mut()
+            .expect_create()
+            .withf(|data| data.url() != &fixture::file_object::key())
+            .times(1)
+            .returning(|_| Ok(()));
+        repositories
+            .file_upload_repository_mut()
+            .expect_delete_by_id()
+            .returning(|_| Ok(()));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .complete_upload(
+                &ctx,
+                String::new(),
+                "user-upload".to_string(),
+                u64::MAX,
+                fixture::file_data::id().value().to_string(),
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn アップロードした本人以外はアップロードを完了できない() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .file_upload_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| {
+                Ok(Some(
+                    fixture::file_upload::file_upload(fixture::user::id1()),
+                ))
+            });
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor2(UserRole::General));
+        let res = use_case
+            .complete_upload(
+                &ctx,
+                String::new(),
+                "user-upload".to_string(),
+                u64::MAX,
+                fixture::file_data::id().value().to_string(),
+            )
+            .await;
+
+        assert!(matches!(
+            res,
+            Err(FileUseCaseError::PermissionDeniedError(
+                PermissionDeniedError
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn アップロードされていない場合は完了できない() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .file_upload_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| {
+                Ok(Some(
+                    fixture::file_upload::file_upload(fixture::user::id1()),
+                ))
+            });
+        repositories
+            .file_object_repository_mut()
+            .expect_copy()
+            .returning(|_, _, _| Ok(false));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .complete_upload(
+                &ctx,
+                String::new(),
+                "user-upload".to_string(),
+                u64::MAX,
+                fixture::file_data::id().value().to_string(),
+            )
+            .await;
+
+        assert!(matches!(res, Err(FileUseCaseError::ObjectNotUploaded(_))));
+    }
+
+    #[tokio::test]
+    async fn 上限を超えるファイルはアップロードを完了できない() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .file_upload_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| {
+                Ok(Some(
+                    fixture::file_upload::file_upload(fixture::user::id1()),
+                ))
+            });
+        repositories
+            .file_object_repository_mut()
+            .expect_copy()
+            .returning(|_, _, _| Ok(true));
+        repositories
+            .file_object_repository_mut()
+            .expect_find_metadata()
+            .returning(|_, _| Ok(Some(metadata(11))));
+        repositories
+            .file_object_repository_mut()
+            .expect_delete()
+            .times(2)
+            .returning(|_, _| Ok(()));
+        repositories
+            .file_upload_repository_mut()
+            .expect_delete_by_id()
+            .times(1)
+            .returning(|_| Ok(()));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .complete_upload(
+                &ctx,
+                String::new(),
+                "user-upload".to_string(),
+                10,
+                fixture::file_data::id().value().to_string(),
+            )
+            .await;
+
+        assert!(matches!(
+            res,
+            Err(FileUseCaseError::FileObjectRepositoryError(
+                FileObjectRepositoryError::SizeLimitExceeded(10)
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn 企画の容量の上限を超えるファイルはアップロードを完了できない() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .file_upload_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| {
+                Ok(Some(FileUpload::new(
+                    fixture::file_data::id(),
+                    fixture::file_data::filename(),
+                    fixture::file_object::key(),
+                    Some(fixture::project::id1()),
+                    fixture::user::id1(),
+                    FileIsStandalone::new(false),
+                    DateTime::new(chrono::Utc::now() + chrono::Duration::minutes(60)),
+                    fixture::datetime::now(),
+                )))
+            });
+        repositories
+            .file_object_repository_mut()
+            .expect_copy()
+            .returning(|_, _, _| Ok(true));
+        repositories
+            .file_object_repository_mut()
+            .expect_find_metadata()
+            .returning(|_, _| Ok(Some(metadata(11))));
+        repositories
+            .project_storage_quota_repository_mut()
+            .expect_find_by_project_id()
+            .returning(|_| Ok(Some(fixture::project_storage::quota())));
+        repositories
+            .file_data_repository_mut()
+            .expect_find_by_owner_project()
+            .returning(|_| {
+                Ok(vec![
+                    fixture::file_data::file_data(Some(
+                        fixture::project::id1()
+                    ));
+                    9
+                ])
+            });
+        repositories
+            .file_object_repository_mut()
+            .expect_delete()
+            .times(2)
+            .returning(|_, _| Ok(()));
+        repositories
+            .file_upload_repository_mut()
+            .expect_delete_by_id()
+            .times(1)
+            .returning(|_| Ok(()));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .complete_upload(
+                &ctx,
+                String::new(),
+                "user-upload".to_string(),
+                u64::MAX,
+                fixture::file_data::id().value().to_string(),
+            )
+            .await;
+
+        assert!(matches!(
+            res,
+            Err(FileUseCaseError::StorageQuotaExceeded(_))
+        ));
+    }
+}
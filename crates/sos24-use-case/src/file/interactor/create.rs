@@ -1,34 +1,44 @@
+use tokio::io::DuplexStream;
+
 use sos24_domain::{
     ensure,
     entity::{
-        file_data::{FileData, FileName},
-        file_object::{FileObject, FileObjectKey},
+        file_data::{FileContentType, FileData, FileIsStandalone, FileName},
+        file_object::FileObjectKey,
         permission::Permissions,
         project::ProjectId,
     },
-    repository::{file_data::FileDataRepository, file_object::FileObjectRepository, Repositories},
+    repository::{
+        file_data::FileDataRepository,
+        file_object::{FileObjectRepository, FileObjectRepositoryError},
+        Repositories,
+    },
 };
 
 use crate::{
-    file::{FileUseCase, FileUseCaseError},
-    shared::context::ContextProvider,
+    file::{dto::CreatedFileDto, FileUseCase, FileUseCaseError},
+    shared::{adapter::Adapters, context::ContextProvider},
 };
 
 #[derive(Debug)]
 pub struct CreateFileCommand {
     pub filename: String,
-    pub file: Vec<u8>,
+    pub file: DuplexStream,
+    pub content_type: Option<String>,
     pub owner: Option<String>,
+    // 企画への配布などのために単独でアップロードし、参照されていなくても回収させない
+    pub is_standalone: bool,
 }
 
-impl<R: Repositories> FileUseCase<R> {
+impl<R: Repositories, A: Adapters> FileUseCase<R, A> {
     pub async fn create(
         &self,
         ctx: &impl ContextProvider,
         bucket: String,
         key_prefix: String,
+        size_limit: u64,
         raw_file: CreateFileCommand,
-    ) -> Result<String, FileUseCaseError> {
+    ) -> Result<CreatedFileDto, FileUseCaseError> {
         let actor = ctx.actor(&*self.repositories).await?;
         let key = FileObjectKey::generate(key_prefix.as_str());
         let filename = FileName::sanitized(raw_file.filename);
@@ -43,21 +53,54 @@ impl<R: Repositories> FileUseCase<R> {
                 None
             }
         };
+        // 配布用のファイルは参照されていなくても回収されないので、公開ファイルを作れる人に限る
+        if raw_file.is_standalone {
+            ensure!(actor.has_permission(Permissions::CREATE_FILE_PUBLIC));
+        }
 
-        let object = FileObject::new(raw_file.file, key.clone());
-        self.repositories
+        // 企画の容量の残りが上限より小さければ、残りを超えた時点で中断する
+        let limit = match &owner {
+            Some(project_id) => {
+                let storage = self.find_project_storage(ctx, project_id.clone()).await?;
+                size_limit.min(storage.remaining())
+            }
+            None => size_limit,
+        };
+
+        let metadata = self
+            .repositories
             .file_object_repository()
-            .create(bucket, object)
-            .await?;
+            .create_stream(bucket, key.clone(), raw_file.file, limit)
+            .await
+            .map_err(|err| match (err, &owner) {
+                (FileObjectRepositoryError::SizeLimitExceeded(_), Some(project_id))
+                    if limit < size_limit =>
+                {
+                    FileUseCaseError::StorageQuotaExceeded(project_id.clone())
+                }
+                (err, _) => err.into(),
+            })?
+            .with_fallback_content_type(raw_file.content_type.map(FileContentType::new));
 
-        let data = FileData::create(filename, key, owner);
+        let data = FileData::create(
+            filename,
+            key,
+            owner,
+            actor.user_id().clone(),
+            FileIsStandalone::new(raw_file.is_standalone),
+            metadata,
+        );
+        let duplicate_ids = self.find_duplicate_ids(&data).await?;
         let id = data.id().clone();
         self.repositories
             .file_data_repository()
             .create(data)
             .await?;
 
-        Ok(id.value().to_string())
+        Ok(CreatedFileDto {
+            id: id.value().to_string(),
+            duplicate_ids,
+        })
     }
 }
 
@@ -67,25 +110,35 @@ mod tests {
 
     use sos24_domain::entity::permission::PermissionDeniedError;
     use sos24_domain::entity::user::UserRole;
+    use sos24_domain::repository::file_object::FileObjectRepositoryError;
     use sos24_domain::test::fixture;
     use sos24_domain::test::repository::MockRepositories;
 
     use crate::file::interactor::create::CreateFileCommand;
     use crate::file::{FileUseCase, FileUseCaseError};
+    use crate::shared::adapter::MockAdapters;
     use crate::shared::context::TestContext;
 
     #[tokio::test]
     async fn 実委人は自分の企画向けのファイルを作成できる() {
         let mut repositories = MockRepositories::default();
+        repositories
+            .project_storage_quota_repository_mut()
+            .expect_find_by_project_id()
+            .returning(|_| Ok(Some(fixture::project_storage::default_quota())));
         repositories
             .file_object_repository_mut()
-            .expect_create()
-            .returning(|_, _| Ok(()));
+            .expect_create_stream()
+            .returning(|_, _, _, _| Ok(fixture::file_object::metadata()));
         repositories
             .file_data_repository_mut()
             .expect_create()
             .returning(|_| Ok(()));
-        let use_case = FileUseCase::new(Arc::new(repositories));
+        repositories
+            .file_data_repository_mut()
+            .expect_find_by_owner_project()
+            .returning(|_| Ok(vec![]));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeViewer));
         let res = use_case
@@ -93,10 +146,13 @@ mod tests {
                 &ctx,
                 String::new(),
                 String::new(),
+                u64::MAX,
                 CreateFileCommand {
                     filename: fixture::file_data::filename().value(),
-                    file: fixture::file_object::data(),
+                    file: tokio::io::duplex(64).1,
+                    content_type: None,
                     owner: Some(fixture::project::id1().value().to_string()),
+                    is_standalone: false,
                 },
             )
             .await;
@@ -107,15 +163,23 @@ mod tests {
     #[tokio::test]
     async fn 実委人は他人の企画向けのファイルを作成できる() {
         let mut repositories = MockRepositories::default();
+        repositories
+            .project_storage_quota_repository_mut()
+            .expect_find_by_project_id()
+            .returning(|_| Ok(Some(fixture::project_storage::default_quota())));
         repositories
             .file_object_repository_mut()
-            .expect_create()
-            .returning(|_, _| Ok(()));
+            .expect_create_stream()
+            .returning(|_, _, _, _| Ok(fixture::file_object::metadata()));
         repositories
             .file_data_repository_mut()
             .expect_create()
             .returning(|_| Ok(()));
-        let use_case = FileUseCase::new(Arc::new(repositories));
+        repositories
+            .file_data_repository_mut()
+            .expect_find_by_owner_project()
+            .returning(|_| Ok(vec![]));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeViewer));
         let res = use_case
@@ -123,10 +187,13 @@ mod tests {
                 &ctx,
                 String::new(),
                 String::new(),
+                u64::MAX,
                 CreateFileCommand {
                     filename: fixture::file_data::filename().value(),
-                    file: fixture::file_object::data(),
+                    file: tokio::io::duplex(64).1,
+                    content_type: None,
                     owner: Some(fixture::project::id2().value().to_string()),
+                    is_standalone: false,
                 },
             )
             .await;
@@ -139,13 +206,13 @@ mod tests {
         let mut repositories = MockRepositories::default();
         repositories
             .file_object_repository_mut()
-            .expect_create()
-            .returning(|_, _| Ok(()));
+            .expect_create_stream()
+            .returning(|_, _, _, _| Ok(fixture::file_object::metadata()));
         repositories
             .file_data_repository_mut()
             .expect_create()
             .returning(|_| Ok(()));
-        let use_case = FileUseCase::new(Arc::new(repositories));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeViewer));
         let res = use_case
@@ -153,10 +220,13 @@ mod tests {
                 &ctx,
                 String::new(),
                 String::new(),
+                u64::MAX,
                 CreateFileCommand {
                     filename: fixture::file_data::filename().value(),
-                    file: fixture::file_object::data(),
+                    file: tokio::io::duplex(64).1,
+                    content_type: None,
                     owner: None,
+                    is_standalone: false,
                 },
             )
             .await;
@@ -174,13 +244,13 @@ mod tests {
         let mut repositories = MockRepositories::default();
         repositories
             .file_object_repository_mut()
-            .expect_create()
-            .returning(|_, _| Ok(()));
+            .expect_create_stream()
+            .returning(|_, _, _, _| Ok(fixture::file_object::metadata()));
         repositories
             .file_data_repository_mut()
             .expect_create()
             .returning(|_| Ok(()));
-        let use_case = FileUseCase::new(Arc::new(repositories));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeOperator));
         let res = use_case
@@ -188,14 +258,160 @@ mod tests {
                 &ctx,
                 String::new(),
                 String::new(),
+                u64::MAX,
                 CreateFileCommand {
                     filename: fixture::file_data::filename().value(),
-                    file: fixture::file_object::data(),
+                    file: tokio::io::duplex(64).1,
+                    content_type: None,
                     owner: None,
+                    is_standalone: false,
                 },
             )
             .await;
 
         assert!(res.is_ok());
     }
+
+    #[tokio::test]
+    async fn 上限を超えるファイルは作成できない() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .project_storage_quota_repository_mut()
+            .expect_find_by_project_id()
+            .returning(|_| Ok(Some(fixture::project_storage::default_quota())));
+        repositories
+            .file_object_repository_mut()
+            .expect_create_stream()
+            .returning(|_, _, _, size_limit| {
+                Err(FileObjectRepositoryError::SizeLimitExceeded(size_limit))
+            });
+        repositories
+            .file_data_repository_mut()
+            .expect_find_by_owner_project()
+            .returning(|_| Ok(vec![]));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeViewer));
+        let res = use_case
+            .create(
+                &ctx,
+                String::new(),
+                String::new(),
+                4,
+                CreateFileCommand {
+                    filename: fixture::file_data::filename().value(),
+                    file: tokio::io::duplex(64).1,
+                    content_type: None,
+                    owner: Some(fixture::project::id1().value().to_string()),
+                    is_standalone: false,
+                },
+            )
+            .await;
+
+        assert!(matches!(
+            res,
+            Err(FileUseCaseError::FileObjectRepositoryError(
+                FileObjectRepositoryError::SizeLimitExceeded(4)
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn 同じ企画が同じ内容のファイルを既に作成している場合は重複として知らせる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .project_storage_quota_repository_mut()
+            .expect_find_by_project_id()
+            .returning(|_| Ok(Some(fixture::project_storage::default_quota())));
+        repositories
+            .file_object_repository_mut()
+            .expect_create_stream()
+            .returning(|_, _, _, _| Ok(fixture::file_object::metadata()));
+        repositories
+            .file_data_repository_mut()
+            .expect_find_by_owner_project()
+            .returning(|_| {
+                Ok(vec![fixture::file_data::file_data(Some(
+                    fixture::project::id1(),
+                ))])
+            });
+        repositories
+            .file_data_repository_mut()
+            .expect_create()
+            .returning(|_| Ok(()));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeViewer));
+        let res = use_case
+            .create(
+                &ctx,
+                String::new(),
+                String::new(),
+                u64::MAX,
+                CreateFileCommand {
+                    filename: fixture::file_data::filename().value(),
+                    file: tokio::io::duplex(64).1,
+                    content_type: None,
+                    owner: Some(fixture::project::id1().value().to_string()),
+                    is_standalone: false,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            res.duplicate_ids,
+            vec![fixture::file_data::id().value().to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn 企画の容量の上限を超えるファイルは作成できない() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .project_storage_quota_repository_mut()
+            .expect_find_by_project_id()
+            .returning(|_| Ok(Some(fixture::project_storage::quota())));
+        repositories
+            .file_data_repository_mut()
+            .expect_find_by_owner_project()
+            .returning(|_| {
+                Ok(vec![
+                    fixture::file_data::file_data(Some(
+                        fixture::project::id1()
+                    ));
+                    9
+                ])
+            });
+        repositories
+            .file_object_repository_mut()
+            .expect_create_stream()
+            .withf(|_, _, _, size_limit| *size_limit == 1)
+            .returning(|_, _, _, size_limit| {
+                Err(FileObjectRepositoryError::SizeLimitExceeded(size_limit))
+            });
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeViewer));
+        let res = use_case
+            .create(
+                &ctx,
+                String::new(),
+                String::new(),
+                u64::MAX,
+                CreateFileCommand {
+                    filename: fixture::file_data::filename().value(),
+                    file: tokio::io::duplex(64).1,
+                    content_type: None,
+                    owner: Some(fixture::project::id1().value().to_string()),
+                    is_standalone: false,
+                },
+            )
+            .await;
+
+        assert!(matches!(
+            res,
+            Err(FileUseCaseError::StorageQuotaExceeded(_))
+        ));
+    }
 }
@@ -0,0 +1,169 @@
+use sos24_domain::{
+    ensure,
+    entity::{file_export::FileExport, form::FormId, permission::Permissions},
+    repository::{file_export::FileExportRepository, form::FormRepository, Repositories},
+};
+
+use crate::{
+    file::{dto::CreatedFileExportDto, FileUseCase, FileUseCaseError},
+    shared::{adapter::Adapters, context::ContextProvider},
+};
+
+#[derive(Debug)]
+pub struct CreateFileExportCommand {
+    pub form_id: String,
+    // trueの場合は、前回完了したエクスポート以降に更新された回答のファイルだけをエクスポートする
+    pub incremental: bool,
+}
+
+impl<R: Repositories, A: Adapters> FileUseCase<R, A> {
+    // エクスポートを登録するだけで、アーカイブの作成はrun_exportで行う
+    pub async fn create_export(
+        &self,
+        ctx: &impl ContextProvider,
+        raw_export: CreateFileExportCommand,
+    ) -> Result<CreatedFileExportDto, FileUseCaseError> {
+        let actor = ctx.actor(&*self.repositories).await?;
+        ensure!(actor.has_permission_in_any_category(Permissions::READ_FILE_ALL));
+
+        let form_id = FormId::try_from(raw_export.form_id)?;
+        self.repositories
+            .form_repository()
+            .find_by_id(form_id.clone())
+            .await?
+            .ok_or(FileUseCaseError::FormNotFound(form_id.clone()))?;
+
+        let previous = if raw_export.incremental {
+            self.repositories
+                .file_export_repository()
+                .find_latest_completed_by_form_id_and_requester(
+                    form_id.clone(),
+                    actor.user_id().clone(),
+                )
+                .await?
+        } else {
+            None
+        };
+
+        let export = FileExport::create(form_id, actor.user_id().clone(), previous.as_ref());
+        let id = export.id().clone();
+        self.repositories
+            .file_export_repository()
+            .create(export)
+            .await?;
+
+        Ok(CreatedFileExportDto {
+            id: id.value().to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::entity::permission::PermissionDeniedError;
+    use sos24_domain::entity::user::UserRole;
+    use sos24_domain::test::fixture;
+    use sos24_domain::test::repository::MockRepositories;
+
+    use crate::file::interactor::create_export::CreateFileExportCommand;
+    use crate::file::{FileUseCase, FileUseCaseError};
+    use crate::shared::adapter::MockAdapters;
+    use crate::shared::context::TestContext;
+
+    #[tokio::test]
+    async fn 一般ユーザーはエクスポートを作成できない() {
+        let repositories = MockRepositories::default();
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .create_export(
+                &ctx,
+                CreateFileExportCommand {
+                    form_id: fixture::form::id1().value().to_string(),
+                    incremental: false,
+                },
+            )
+            .await;
+
+        assert!(matches!(
+            res,
+            Err(FileUseCaseError::PermissionDeniedError(
+                PermissionDeniedError
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn 差分のエクスポートは前回のエクスポート以降を対象にする() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .form_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::form::form1_opened())));
+        repositories
+            .file_export_repository_mut()
+            .expect_find_latest_completed_by_form_id_and_requester()
+            .returning(|_, _| Ok(Some(fixture::file_export::file_export1())));
+        repositories
+            .file_export_repository_mut()
+            .expect_create()
+            .withf(|export| export.is_incremental())
+            .times(1)
+            .returning(|_| Ok(()));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeViewer));
+        let res = use_case
+            .create_export(
+                &ctx,
+                CreateFileExportCommand {
+                    form_id: fixture::form::id1().value().to_string(),
+                    incremental: true,
+                },
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn 差分のエクスポートは他のユーザーのエクスポートを基準にしない() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .form_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::form::form1_opened())));
+        // user1のエクスポートだけが完了している
+        repositories
+            .file_export_repository_mut()
+            .expect_find_latest_completed_by_form_id_and_requester()
+            .returning(|_, requested_by| {
+                Ok((requested_by == fixture::user::id1()).then(fixture::file_export::file_export1))
+            });
+        repositories
+            .file_export_repository_mut()
+            .expect_create()
+            .withf(|export| {
+                export.requested_by() == &fixture::user::id2() && !export.is_incremental()
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor2(UserRole::CommitteeViewer));
+        let res = use_case
+            .create_export(
+                &ctx,
+                CreateFileExportCommand {
+                    form_id: fixture::form::id1().value().to_string(),
+                    incremental: true,
+                },
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+}
@@ -0,0 +1,179 @@
+use anyhow::Context;
+
+use sos24_domain::{
+    ensure,
+    entity::{
+        file_data::{FileIsStandalone, FileName},
+        file_object::FileObjectKey,
+        file_upload::FileUpload,
+        permission::Permissions,
+        project::ProjectId,
+    },
+    repository::{
+        file_object::FileObjectRepository, file_upload::FileUploadRepository, Repositories,
+    },
+};
+
+use crate::{
+    file::{dto::UploadUrlDto, FileUseCase, FileUseCaseError},
+    shared::{adapter::Adapters, context::ContextProvider},
+};
+
+#[derive(Debug)]
+pub struct CreateUploadUrlCommand {
+    pub filename: String,
+    pub owner: Option<String>,
+    // 企画への配布などのために単独でアップロードし、参照されていなくても回収させない
+    pub is_standalone: bool,
+}
+
+impl<R: Repositories, A: Adapters> FileUseCase<R, A> {
+    pub async fn create_upload_url(
+        &self,
+        ctx: &impl ContextProvider,
+        bucket: String,
+        key_prefix: String,
+        raw_upload: CreateUploadUrlCommand,
+    ) -> Result<UploadUrlDto, FileUseCaseError> {
+        let actor = ctx.actor(&*self.repositories).await?;
+        let key = FileObjectKey::generate(key_prefix.as_str());
+        let filename = FileName::sanitized(raw_upload.filename);
+        let owner = match raw_upload.owner {
+            Some(it) => {
+                ensure!(actor.has_permission(Permissions::CREATE_FILE_PRIVATE));
+                Some(ProjectId::try_from(it)?)
+            }
+            None => {
+                // Publicなファイルは権限を持っていないと作れない
+                ensure!(actor.has_permission(Permissions::CREATE_FILE_PUBLIC));
+                None
+            }
+        };
+        // 配布用のファイルは参照されていなくても回収されないので、公開ファイルを作れる人に限る
+        if raw_upload.is_standalone {
+            ensure!(actor.has_permission(Permissions::CREATE_FILE_PUBLIC));
+        }
+
+        let upload = FileUpload::create(
+            filename,
+            key,
+            owner,
+            actor.user_id().clone(),
+            FileIsStandalone::new(raw_upload.is_standalone),
+        );
+        let expires_in = (upload.expires_at().clone().value() - chrono::Utc::now())
+            .to_std()
+            .context("Failed to calculate expiration")?;
+        let url = self
+            .repositories
+            .file_object_repository()
+            .generate_upload_url(bucket, upload.key().copy(), expires_in)
+            .await?;
+
+        self.repositories
+            .file_upload_repository()
+            .create(upload.clone())
+            .await?;
+
+        Ok(UploadUrlDto::from((url, upload)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::entity::permission::PermissionDeniedError;
+    use sos24_domain::entity::user::UserRole;
+    use sos24_domain::test::fixture;
+    use sos24_domain::test::repository::MockRepositories;
+
+    use crate::file::interactor::create_upload_url::CreateUploadUrlCommand;
+    use crate::file::{FileUseCase, FileUseCaseError};
+    use crate::shared::adapter::MockAdapters;
+    use crate::shared::context::TestContext;
+
+    #[tokio::test]
+    async fn 実委人は企画向けのファイルのアップロード用urlを発行できる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .file_object_repository_mut()
+            .expect_generate_upload_url()
+            .returning(|_, _, _| Ok(fixture::file_object::signed_url()));
+        repositories
+            .file_upload_repository_mut()
+            .expect_create()
+            .returning(|_| Ok(()));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeViewer));
+        let res = use_case
+            .create_upload_url(
+                &ctx,
+                String::new(),
+                String::new(),
+                CreateUploadUrlCommand {
+                    filename: fixture::file_data::filename().value(),
+                    owner: Some(fixture::project::id1().value().to_string()),
+                    is_standalone: false,
+                },
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn 実委人は一般公開のファイルのアップロード用urlを発行できない() {
+        let repositories = MockRepositories::default();
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeViewer));
+        let res = use_case
+            .create_upload_url(
+                &ctx,
+                String::new(),
+                String::new(),
+                CreateUploadUrlCommand {
+                    filename: fixture::file_data::filename().value(),
+                    owner: None,
+                    is_standalone: false,
+                },
+            )
+            .await;
+
+        assert!(matches!(
+            res,
+            Err(FileUseCaseError::PermissionDeniedError(
+                PermissionDeniedError
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn 一般ユーザーは配布用のファイルのアップロード用urlを発行できない() {
+        let repositories = MockRepositories::default();
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .create_upload_url(
+                &ctx,
+                String::new(),
+                String::new(),
+                CreateUploadUrlCommand {
+                    filename: fixture::file_data::filename().value(),
+                    owner: Some(fixture::project::id1().value().to_string()),
+                    is_standalone: true,
+                },
+            )
+            .await;
+
+        assert!(matches!(
+            res,
+            Err(FileUseCaseError::PermissionDeniedError(
+                PermissionDeniedError
+            ))
+        ));
+    }
+}
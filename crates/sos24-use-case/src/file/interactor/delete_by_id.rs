@@ -3,9 +3,10 @@ use sos24_domain::repository::file_data::FileDataRepository;
 use sos24_domain::{ensure, entity::file_data::FileId, repository::Repositories};
 
 use crate::file::{FileUseCase, FileUseCaseError};
+use crate::shared::adapter::Adapters;
 use crate::shared::context::ContextProvider;
 
-impl<R: Repositories> FileUseCase<R> {
+impl<R: Repositories, A: Adapters> FileUseCase<R, A> {
     pub async fn delete_by_id(
         &self,
         ctx: &impl ContextProvider,
@@ -40,12 +41,13 @@ mod tests {
     use sos24_domain::test::repository::MockRepositories;
 
     use crate::file::{FileUseCase, FileUseCaseError};
+    use crate::shared::adapter::MockAdapters;
     use crate::shared::context::TestContext;
 
     #[tokio::test]
     async fn 実委人はファイルを削除できない() {
         let repositories = MockRepositories::default();
-        let use_case = FileUseCase::new(Arc::new(repositories));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeViewer));
         let res = use_case
@@ -71,7 +73,7 @@ mod tests {
             .file_data_repository_mut()
             .expect_delete_by_id()
             .returning(|_| Ok(()));
-        let use_case = FileUseCase::new(Arc::new(repositories));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeOperator));
         let res = use_case
@@ -2,9 +2,11 @@ use std::sync::Arc;
 
 use tokio::io::AsyncRead;
 
+use sos24_domain::entity::actor::Actor;
+use sos24_domain::entity::common::datetime::DateTime;
 use sos24_domain::entity::file_data::FileName;
 use sos24_domain::entity::file_object::ArchiveEntry;
-use sos24_domain::entity::form::FormId;
+use sos24_domain::entity::form::{Form, FormId};
 use sos24_domain::entity::permission::Permissions;
 use sos24_domain::repository::file_object::FileObjectRepository;
 use sos24_domain::repository::form::FormRepository;
@@ -15,9 +17,10 @@ use sos24_domain::{ensure, repository::file_data::FileDataRepository};
 
 use crate::file::dto::ArchiveToBeExportedDto;
 use crate::file::{FileUseCase, FileUseCaseError};
+use crate::shared::adapter::Adapters;
 use crate::shared::context::ContextProvider;
 
-impl<R: Repositories> FileUseCase<R> {
+impl<R: Repositories, A: Adapters> FileUseCase<R, A> {
     pub async fn export_by_form_id(
         &self,
         ctx: &impl ContextProvider,
@@ -25,7 +28,7 @@ impl<R: Repositories> FileUseCase<R> {
         form_id: String,
     ) -> Result<ArchiveToBeExportedDto<impl AsyncRead>, FileUseCaseError> {
         let actor = ctx.actor(&*self.repositories).await?;
-        ensure!(actor.has_permission(Permissions::READ_FILE_ALL));
+        ensure!(actor.has_permission_in_any_category(Permissions::READ_FILE_ALL));
 
         let form_id = FormId::try_from(form_id)?;
         let form = self
@@ -35,14 +38,50 @@ impl<R: Repositories> FileUseCase<R> {
             .await?
             .ok_or(FileUseCaseError::FormNotFound(form_id.clone()))?;
 
+        let file_list = self.list_form_archive_entries(&actor, &form, None).await?;
+
+        let (writer, reader) = tokio::io::duplex(65535);
+        let repositories = Arc::clone(&self.repositories);
+        tokio::spawn(async move {
+            if let Err(err) = repositories
+                .file_object_repository()
+                .create_archive(bucket, file_list, writer, None)
+                .await
+            {
+                tracing::error!("Failed to create archive: {err:?}");
+            }
+        });
+
+        let form = form.destruct();
+        Ok(ArchiveToBeExportedDto {
+            filename: format!("{}_ファイル一覧.zip", form.title.value()),
+            body: reader,
+        })
+    }
+
+    // 申請の回答に添付されたファイルを、アーカイブ内でのファイル名とともに列挙する
+    // sinceを指定した場合は、それより後に更新された回答のファイルだけを列挙する
+    // actorがファイルを閲覧できない企画区分の企画の回答は含めない
+    pub(super) async fn list_form_archive_entries(
+        &self,
+        actor: &Actor,
+        form: &Form,
+        since: Option<&DateTime>,
+    ) -> Result<Vec<ArchiveEntry>, FileUseCaseError> {
         let form_answer_list = self
             .repositories
             .form_answer_repository()
-            .find_by_form_id(form_id.clone())
+            .find_by_form_id(form.id().clone())
             .await?;
 
         let mut file_list = Vec::new();
         for form_answer in form_answer_list {
+            // 差分のエクスポートでは、前回以降に更新されていない回答は含めない
+            if let Some(since) = since {
+                if form_answer.updated_at().clone().value() <= since.clone().value() {
+                    continue;
+                }
+            }
             let project_id = form_answer.project_id().clone();
             let project_with_owners = self
                 .repositories
@@ -50,6 +89,16 @@ impl<R: Repositories> FileUseCase<R> {
                 .find_by_id(project_id.clone())
                 .await?
                 .ok_or(FileUseCaseError::ProjectNotFound(project_id))?;
+            // 回答後に対象から除外された企画のファイルは含めない
+            if !form.is_sent_to(&project_with_owners.project) {
+                continue;
+            }
+            if !actor.has_project_permission(
+                Permissions::READ_FILE_ALL,
+                *project_with_owners.project.category(),
+            ) {
+                continue;
+            }
             let project = project_with_owners.project.destruct();
 
             let file_items = form_answer.list_file_items();
@@ -65,6 +114,10 @@ impl<R: Repositories> FileUseCase<R> {
                         .find_by_id(file_id.clone())
                         .await?
                         .ok_or(FileUseCaseError::NotFound(file_id))?;
+                    // スキャンが終わっていない・マルウェアが検出されたファイルは含めない
+                    if !file.is_downloadable() {
+                        continue;
+                    }
                     let file = file.destruct();
 
                     // {申請項目名}_{通し番号1}/{企画番号}_{企画名}_{通し番号2}_{オリジナルファイル名}
@@ -86,23 +139,7 @@ impl<R: Repositories> FileUseCase<R> {
             }
         }
 
-        let (writer, reader) = tokio::io::duplex(65535);
-        let repositories = Arc::clone(&self.repositories);
-        tokio::spawn(async move {
-            if let Err(err) = repositories
-                .file_object_repository()
-                .create_archive(bucket, file_list, writer)
-                .await
-            {
-                tracing::error!("Failed to create archive: {err:?}");
-            }
-        });
-
-        let form = form.destruct();
-        Ok(ArchiveToBeExportedDto {
-            filename: format!("{}_ファイル一覧.zip", form.title.value()),
-            body: reader,
-        })
+        Ok(file_list)
     }
 }
 
@@ -12,9 +12,10 @@ use sos24_domain::{ensure, repository::file_data::FileDataRepository};
 
 use crate::file::dto::ArchiveToBeExportedDto;
 use crate::file::{FileUseCase, FileUseCaseError};
+use crate::shared::adapter::Adapters;
 use crate::shared::context::ContextProvider;
 
-impl<R: Repositories> FileUseCase<R> {
+impl<R: Repositories, A: Adapters> FileUseCase<R, A> {
     pub async fn export_by_owner_project(
         &self,
         ctx: &impl ContextProvider,
@@ -39,6 +40,8 @@ impl<R: Repositories> FileUseCase<R> {
             .find_by_owner_project(owner_project)
             .await?
             .into_iter()
+            // スキャンが終わっていない・マルウェアが検出されたファイルは含めない
+            .filter(|file| file.is_downloadable())
             .map(|file| {
                 let file = file.destruct();
                 ArchiveEntry::new(file.url, file.name, file.updated_at)
@@ -50,7 +53,7 @@ impl<R: Repositories> FileUseCase<R> {
         tokio::spawn(async move {
             if let Err(err) = repositories
                 .file_object_repository()
-                .create_archive(bucket, file_list, writer)
+                .create_archive(bucket, file_list, writer, None)
                 .await
             {
                 tracing::error!("Failed to create archive: {err:?}");
@@ -1,50 +1,75 @@
-use sos24_domain::ensure;
-use sos24_domain::repository::file_data::FileDataRepository;
 use sos24_domain::repository::file_object::FileObjectRepository;
-use sos24_domain::repository::project::ProjectRepository;
 use sos24_domain::{
-    entity::{file_data::FileId, file_object::ContentDisposition},
+    entity::{
+        file_data::FileId,
+        file_object::{ContentDisposition, FileSignedUrlExpiry},
+    },
     repository::Repositories,
 };
 
-use crate::file::dto::FileDto;
+use crate::file::dto::{FileDispositionDto, FileDto};
 use crate::file::{FileUseCase, FileUseCaseError};
+use crate::shared::adapter::Adapters;
 use crate::shared::context::ContextProvider;
 
-impl<R: Repositories> FileUseCase<R> {
+#[derive(Debug, Default)]
+pub struct FindFileQuery {
+    // プレビューできないファイルは、インラインを指定してもダウンロードさせる
+    pub disposition: FileDispositionDto,
+    // 設定された有効期間より短くしたい場合に指定する(秒)
+    pub expires_in: Option<u64>,
+}
+
+impl<R: Repositories, A: Adapters> FileUseCase<R, A> {
     pub async fn find_by_id(
         &self,
         ctx: &impl ContextProvider,
         bucket: String,
         id: String,
+        query: FindFileQuery,
     ) -> Result<FileDto, FileUseCaseError> {
         let id = FileId::try_from(id)?;
+        let expires_in = query
+            .expires_in
+            .map(FileSignedUrlExpiry::try_from)
+            .transpose()?;
+        let expires_in = ctx.config().file_url_expires_in.clone().shorten(expires_in);
+
         let actor = ctx.actor(&*self.repositories).await?;
-        let raw_file_data = self
-            .repositories
-            .file_data_repository()
-            .find_by_id(id.clone())
-            .await?
-            .ok_or(FileUseCaseError::NotFound(id))?;
-        if let Some(project_id) = raw_file_data.owner().clone() {
-            let project_with_owners = self
-                .repositories
-                .project_repository()
-                .find_by_id(project_id)
-                .await?
-                .ok_or(FileUseCaseError::OwnerNotFound)?;
-            ensure!(project_with_owners.project.is_visible_to(&actor));
-        }
+        let raw_file_data = self.find_downloadable(&actor, id).await?;
+        let content_disposition = ContentDisposition::for_file(
+            query.disposition.into(),
+            raw_file_data.filename().clone(),
+            raw_file_data.content_type().as_ref(),
+        );
+        // ブラウザ上で表示させる場合は、S3に保存されたContent-Typeではなく、
+        // 内容から判定した種類で表示させる
+        let content_type = if content_disposition.is_inline() {
+            raw_file_data.content_type().clone()
+        } else {
+            None
+        };
         let signed_url = self
             .repositories
             .file_object_repository()
             .generate_url(
-                bucket,
+                bucket.clone(),
                 raw_file_data.url().copy(),
-                Some(ContentDisposition::from(raw_file_data.filename().clone())),
+                Some(content_disposition),
+                content_type,
+                expires_in.clone(),
             )
             .await?;
-        Ok(FileDto::from((signed_url, raw_file_data)))
+        let thumbnail_url = match raw_file_data.thumbnail_key() {
+            Some(key) => Some(
+                self.repositories
+                    .file_object_repository()
+                    .generate_url(bucket, key, None, None, expires_in)
+                    .await?,
+            ),
+            None => None,
+        };
+        Ok(FileDto::from((signed_url, thumbnail_url, raw_file_data)))
     }
 }
 
@@ -52,12 +77,17 @@ impl<R: Repositories> FileUseCase<R> {
 mod tests {
     use std::sync::Arc;
 
+    use sos24_domain::entity::file_data::{FileContentType, FileData, FileIsStandalone};
+    use sos24_domain::entity::file_object::FileSignedUrlExpiry;
     use sos24_domain::entity::permission::PermissionDeniedError;
     use sos24_domain::entity::user::UserRole;
     use sos24_domain::test::fixture;
     use sos24_domain::test::repository::MockRepositories;
 
+    use crate::file::dto::FileDispositionDto;
+    use crate::file::interactor::find_by_id::FindFileQuery;
     use crate::file::{FileUseCase, FileUseCaseError};
+    use crate::shared::adapter::MockAdapters;
     use crate::shared::context::TestContext;
 
     #[tokio::test]
@@ -70,8 +100,8 @@ mod tests {
         repositories
             .file_object_repository_mut()
             .expect_generate_url()
-            .returning(|_, _, _| Ok(fixture::file_object::signed_url()));
-        let use_case = FileUseCase::new(Arc::new(repositories));
+            .returning(|_, _, _, _, _| Ok(fixture::file_object::signed_url()));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
         let res = use_case
@@ -79,6 +109,7 @@ mod tests {
                 &ctx,
                 String::new(),
                 fixture::file_data::id().value().to_string(),
+                FindFileQuery::default(),
             )
             .await;
 
@@ -107,8 +138,8 @@ mod tests {
         repositories
             .file_object_repository_mut()
             .expect_generate_url()
-            .returning(|_, _, _| Ok(fixture::file_object::signed_url()));
-        let use_case = FileUseCase::new(Arc::new(repositories));
+            .returning(|_, _, _, _, _| Ok(fixture::file_object::signed_url()));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
         let res = use_case
@@ -116,6 +147,7 @@ mod tests {
                 &ctx,
                 String::new(),
                 fixture::file_data::id().value().to_string(),
+                FindFileQuery::default(),
             )
             .await;
 
@@ -141,7 +173,7 @@ mod tests {
                     fixture::user::user2(UserRole::General),
                 )))
             });
-        let use_case = FileUseCase::new(Arc::new(repositories));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
         let res = use_case
@@ -149,6 +181,7 @@ mod tests {
                 &ctx,
                 String::new(),
                 fixture::file_data::id().value().to_string(),
+                FindFileQuery::default(),
             )
             .await;
 
@@ -160,6 +193,37 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn スキャンが終わっていないファイルは取得できない() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .file_data_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| {
+                Ok(Some(FileData::create(
+                    fixture::file_data::filename(),
+                    fixture::file_object::key(),
+                    None,
+                    fixture::user::id1(),
+                    FileIsStandalone::new(false),
+                    fixture::file_object::metadata(),
+                )))
+            });
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .find_by_id(
+                &ctx,
+                String::new(),
+                fixture::file_data::id().value().to_string(),
+                FindFileQuery::default(),
+            )
+            .await;
+
+        assert!(matches!(res, Err(FileUseCaseError::NotScanned(_))));
+    }
+
     #[tokio::test]
     async fn 実委人は他人の企画のファイルを取得できる() {
         let mut repositories = MockRepositories::default();
@@ -182,8 +246,8 @@ mod tests {
         repositories
             .file_object_repository_mut()
             .expect_generate_url()
-            .returning(|_, _, _| Ok(fixture::file_object::signed_url()));
-        let use_case = FileUseCase::new(Arc::new(repositories));
+            .returning(|_, _, _, _, _| Ok(fixture::file_object::signed_url()));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeViewer));
         let res = use_case
@@ -191,6 +255,144 @@ mod tests {
                 &ctx,
                 String::new(),
                 fixture::file_data::id().value().to_string(),
+                FindFileQuery::default(),
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn 指定した表示方法と有効期間で署名付きurlを生成する() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .file_data_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::file_data::file_data(None))));
+        repositories
+            .file_object_repository_mut()
+            .expect_generate_url()
+            .withf(|_, _, _, _, expires_in| expires_in == &FileSignedUrlExpiry::new(300))
+            .returning(|_, _, _, _, _| Ok(fixture::file_object::signed_url()));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .find_by_id(
+                &ctx,
+                String::new(),
+                fixture::file_data::id().value().to_string(),
+                FindFileQuery {
+                    disposition: FileDispositionDto::Inline,
+                    expires_in: Some(300),
+                },
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn 範囲外の有効期間は指定できない() {
+        let repositories = MockRepositories::default();
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .find_by_id(
+                &ctx,
+                String::new(),
+                fixture::file_data::id().value().to_string(),
+                FindFileQuery {
+                    disposition: FileDispositionDto::Attachment,
+                    expires_in: Some(0),
+                },
+            )
+            .await;
+
+        assert!(matches!(
+            res,
+            Err(FileUseCaseError::FileSignedUrlExpiryError(_))
+        ));
+    }
+
+    fn image_file_data() -> FileData {
+        let file_data = fixture::file_data::file_data(None).destruct();
+        FileData::new(
+            file_data.id,
+            file_data.name,
+            file_data.url,
+            file_data.owner,
+            file_data.size,
+            Some(FileContentType::new("image/png".to_string())),
+            file_data.checksum,
+            file_data.uploader,
+            file_data.is_standalone,
+            file_data.scan_status,
+            file_data.thumbnail_status,
+            file_data.created_at,
+            file_data.updated_at,
+        )
+    }
+
+    #[tokio::test]
+    async fn インラインで表示する場合は判定した種類をcontent_typeに指定する() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .file_data_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(image_file_data())));
+        repositories
+            .file_object_repository_mut()
+            .expect_generate_url()
+            .withf(|_, _, content_disposition, content_type, _| {
+                content_disposition
+                    .as_ref()
+                    .is_some_and(|disposition| disposition.is_inline())
+                    && content_type == &Some(FileContentType::new("image/png".to_string()))
+            })
+            .times(1)
+            .returning(|_, _, _, _, _| Ok(fixture::file_object::signed_url()));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .find_by_id(
+                &ctx,
+                String::new(),
+                fixture::file_data::id().value().to_string(),
+                FindFileQuery {
+                    disposition: FileDispositionDto::Inline,
+                    expires_in: None,
+                },
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn ダウンロードさせる場合はcontent_typeを指定しない() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .file_data_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(image_file_data())));
+        repositories
+            .file_object_repository_mut()
+            .expect_generate_url()
+            .withf(|_, _, _, content_type, _| content_type.is_none())
+            .times(1)
+            .returning(|_, _, _, _, _| Ok(fixture::file_object::signed_url()));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .find_by_id(
+                &ctx,
+                String::new(),
+                fixture::file_data::id().value().to_string(),
+                FindFileQuery::default(),
             )
             .await;
 
@@ -0,0 +1,177 @@
+use sos24_domain::{
+    ensure,
+    entity::{
+        file_data::FileName,
+        file_export::{FileExportId, FileExportStatus},
+        file_object::ContentDisposition,
+        permission::Permissions,
+    },
+    repository::{
+        file_export::FileExportRepository, file_object::FileObjectRepository, form::FormRepository,
+        Repositories,
+    },
+};
+
+use crate::{
+    file::{dto::FileExportDto, FileUseCase, FileUseCaseError},
+    shared::{adapter::Adapters, context::ContextProvider},
+};
+
+impl<R: Repositories, A: Adapters> FileUseCase<R, A> {
+    pub async fn find_export_by_id(
+        &self,
+        ctx: &impl ContextProvider,
+        bucket: String,
+        id: String,
+    ) -> Result<FileExportDto, FileUseCaseError> {
+        let actor = ctx.actor(&*self.repositories).await?;
+        ensure!(actor.has_permission_in_any_category(Permissions::READ_FILE_ALL));
+
+        let id = FileExportId::try_from(id)?;
+        let export = self
+            .repositories
+            .file_export_repository()
+            .find_by_id(id.clone())
+            .await?
+            .ok_or(FileUseCaseError::ExportNotFound(id))?;
+        // エクスポートには依頼したユーザーが閲覧できる企画区分のファイルだけが含まれる
+        ensure!(
+            export.requested_by() == actor.user_id()
+                || actor.has_permission(Permissions::READ_FILE_ALL)
+        );
+
+        let url = match (export.status(), export.key()) {
+            (FileExportStatus::Completed, Some(key)) => {
+                let form = self
+                    .repositories
+                    .form_repository()
+                    .find_by_id(export.form_id().clone())
+                    .await?
+                    .ok_or(FileUseCaseError::FormNotFound(export.form_id().clone()))?;
+                let filename = if export.is_incremental() {
+                    format!("{}_ファイル一覧_差分.zip", form.title().clone().value())
+                } else {
+                    format!("{}_ファイル一覧.zip", form.title().clone().value())
+                };
+                let url = self
+                    .repositories
+                    .file_object_repository()
+                    .generate_url(
+                        bucket,
+                        key.copy(),
+                        Some(ContentDisposition::from(FileName::new(filename))),
+                        None,
+                        ctx.config().file_url_expires_in.clone(),
+                    )
+                    .await?;
+                Some(url)
+            }
+            _ => None,
+        };
+
+        Ok(FileExportDto::from((url, export)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::entity::file_object::FileObjectKey;
+    use sos24_domain::entity::permission::PermissionDeniedError;
+    use sos24_domain::entity::user::UserRole;
+    use sos24_domain::test::fixture;
+    use sos24_domain::test::repository::MockRepositories;
+
+    use crate::file::dto::FileExportStatusDto;
+    use crate::file::{FileUseCase, FileUseCaseError};
+    use crate::shared::adapter::MockAdapters;
+    use crate::shared::context::TestContext;
+
+    #[tokio::test]
+    async fn 一般ユーザーはエクスポートを取得できない() {
+        let repositories = MockRepositories::default();
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .find_export_by_id(
+                &ctx,
+                String::new(),
+                fixture::file_export::id1().value().to_string(),
+            )
+            .await;
+
+        assert!(matches!(
+            res,
+            Err(FileUseCaseError::PermissionDeniedError(
+                PermissionDeniedError
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn 処理中のエクスポートは進捗だけを返す() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .file_export_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| {
+                let mut export = fixture::file_export::file_export1();
+                export.start(3, &chrono::Utc::now());
+                Ok(Some(export))
+            });
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeViewer));
+        let res = use_case
+            .find_export_by_id(
+                &ctx,
+                String::new(),
+                fixture::file_export::id1().value().to_string(),
+            )
+            .await
+            .unwrap();
+
+        assert!(matches!(res.status, FileExportStatusDto::Running));
+        assert_eq!(res.total, 3);
+        assert_eq!(res.url, None);
+    }
+
+    #[tokio::test]
+    async fn 完了したエクスポートはダウンロード用のurlを返す() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .file_export_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| {
+                let mut export = fixture::file_export::file_export1();
+                let now = chrono::Utc::now();
+                export.start(3, &now);
+                export.complete(FileObjectKey::new("exports/1".to_string()), &now);
+                Ok(Some(export))
+            });
+        repositories
+            .form_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::form::form1_opened())));
+        repositories
+            .file_object_repository_mut()
+            .expect_generate_url()
+            .returning(|_, _, _, _, _| Ok(fixture::file_object::signed_url()));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeViewer));
+        let res = use_case
+            .find_export_by_id(
+                &ctx,
+                String::new(),
+                fixture::file_export::id1().value().to_string(),
+            )
+            .await
+            .unwrap();
+
+        assert!(matches!(res.status, FileExportStatusDto::Completed));
+        assert!(res.url.is_some());
+    }
+}
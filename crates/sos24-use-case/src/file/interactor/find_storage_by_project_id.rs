@@ -0,0 +1,139 @@
+use sos24_domain::{
+    ensure,
+    entity::project::ProjectId,
+    repository::{project::ProjectRepository, Repositories},
+};
+
+use crate::{
+    file::{dto::ProjectStorageDto, FileUseCase, FileUseCaseError},
+    shared::{adapter::Adapters, context::ContextProvider},
+};
+
+impl<R: Repositories, A: Adapters> FileUseCase<R, A> {
+    pub async fn find_storage_by_project_id(
+        &self,
+        ctx: &impl ContextProvider,
+        project_id: String,
+    ) -> Result<ProjectStorageDto, FileUseCaseError> {
+        let actor = ctx.actor(&*self.repositories).await?;
+
+        let project_id = ProjectId::try_from(project_id)?;
+        let project_with_owners = self
+            .repositories
+            .project_repository()
+            .find_by_id(project_id.clone())
+            .await?
+            .ok_or(FileUseCaseError::ProjectNotFound(project_id.clone()))?;
+        ensure!(project_with_owners.project.is_visible_to(&actor));
+
+        let storage = self.find_project_storage(ctx, project_id).await?;
+        Ok(ProjectStorageDto::from(storage))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::entity::permission::PermissionDeniedError;
+    use sos24_domain::entity::user::UserRole;
+    use sos24_domain::test::fixture;
+    use sos24_domain::test::repository::MockRepositories;
+
+    use crate::file::{FileUseCase, FileUseCaseError};
+    use crate::shared::adapter::MockAdapters;
+    use crate::shared::context::{ContextProvider, TestContext};
+
+    #[tokio::test]
+    async fn 一般ユーザーは自分の企画の容量を取得できる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .project_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| {
+                Ok(Some(fixture::project::project_with_owners1(
+                    fixture::user::user1(UserRole::General),
+                )))
+            });
+        repositories
+            .project_storage_quota_repository_mut()
+            .expect_find_by_project_id()
+            .returning(|_| Ok(None));
+        repositories
+            .file_data_repository_mut()
+            .expect_find_by_owner_project()
+            .returning(|_| {
+                Ok(vec![fixture::file_data::file_data(Some(
+                    fixture::project::id1(),
+                ))])
+            });
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .find_storage_by_project_id(&ctx, fixture::project::id1().value().to_string())
+            .await
+            .unwrap();
+
+        // 個別の上限がなければ既定値が使われる
+        assert_eq!(res.used, 11);
+        assert_eq!(res.quota, ctx.config().project_storage_quota);
+    }
+
+    #[tokio::test]
+    async fn 一般ユーザーは他人の企画の容量を取得できない() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .project_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| {
+                Ok(Some(fixture::project::project_with_owners1(
+                    fixture::user::user2(UserRole::General),
+                )))
+            });
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .find_storage_by_project_id(&ctx, fixture::project::id1().value().to_string())
+            .await;
+
+        assert!(matches!(
+            res,
+            Err(FileUseCaseError::PermissionDeniedError(
+                PermissionDeniedError
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn 個別に設定された上限が既定値より優先される() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .project_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| {
+                Ok(Some(fixture::project::project_with_owners1(
+                    fixture::user::user1(UserRole::General),
+                )))
+            });
+        repositories
+            .project_storage_quota_repository_mut()
+            .expect_find_by_project_id()
+            .returning(|_| Ok(Some(fixture::project_storage::quota())));
+        repositories
+            .file_data_repository_mut()
+            .expect_find_by_owner_project()
+            .returning(|_| Ok(vec![]));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .find_storage_by_project_id(&ctx, fixture::project::id1().value().to_string())
+            .await
+            .unwrap();
+
+        assert_eq!(res.quota, fixture::project_storage::quota().value());
+        assert_eq!(res.remaining, fixture::project_storage::quota().value());
+    }
+}
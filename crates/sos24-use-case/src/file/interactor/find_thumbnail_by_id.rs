@@ -0,0 +1,101 @@
+use sos24_domain::{
+    entity::file_data::FileId,
+    repository::{file_object::FileObjectRepository, Repositories},
+};
+
+use crate::{
+    file::{dto::FileThumbnailDto, FileUseCase, FileUseCaseError},
+    shared::{adapter::Adapters, context::ContextProvider},
+};
+
+impl<R: Repositories, A: Adapters> FileUseCase<R, A> {
+    pub async fn find_thumbnail_by_id(
+        &self,
+        ctx: &impl ContextProvider,
+        bucket: String,
+        id: String,
+    ) -> Result<FileThumbnailDto, FileUseCaseError> {
+        let id = FileId::try_from(id)?;
+        let actor = ctx.actor(&*self.repositories).await?;
+        let file_data = self.find_downloadable(&actor, id.clone()).await?;
+        let key = file_data
+            .thumbnail_key()
+            .ok_or(FileUseCaseError::ThumbnailNotFound(id))?;
+
+        let signed_url = self
+            .repositories
+            .file_object_repository()
+            .generate_url(
+                bucket,
+                key,
+                None,
+                None,
+                ctx.config().file_url_expires_in.clone(),
+            )
+            .await?;
+        Ok(FileThumbnailDto::from(signed_url))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::entity::user::UserRole;
+    use sos24_domain::test::fixture;
+    use sos24_domain::test::repository::MockRepositories;
+
+    use crate::file::{FileUseCase, FileUseCaseError};
+    use crate::shared::adapter::MockAdapters;
+    use crate::shared::context::TestContext;
+
+    #[tokio::test]
+    async fn 生成済みのサムネイルを取得できる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .file_data_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| {
+                let mut file_data = fixture::file_data::file_data(None);
+                file_data.set_thumbnail_result(true);
+                Ok(Some(file_data))
+            });
+        repositories
+            .file_object_repository_mut()
+            .expect_generate_url()
+            .returning(|_, _, _, _, _| Ok(fixture::file_object::signed_url()));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .find_thumbnail_by_id(
+                &ctx,
+                String::new(),
+                fixture::file_data::id().value().to_string(),
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn サムネイルがないファイルはサムネイルを取得できない() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .file_data_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::file_data::file_data(None))));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .find_thumbnail_by_id(
+                &ctx,
+                String::new(),
+                fixture::file_data::id().value().to_string(),
+            )
+            .await;
+
+        assert!(matches!(res, Err(FileUseCaseError::ThumbnailNotFound(_))));
+    }
+}
@@ -0,0 +1,296 @@
+use sos24_domain::{
+    entity::{
+        file_data::{FileData, FileId, FileThumbnailStatus},
+        file_object::FileObject,
+    },
+    repository::{file_data::FileDataRepository, file_object::FileObjectRepository, Repositories},
+};
+
+use crate::{
+    file::{FileUseCase, FileUseCaseError},
+    shared::{
+        adapter::{thumbnail::ThumbnailGenerator, Adapters},
+        context::ContextProvider,
+    },
+};
+
+impl<R: Repositories, A: Adapters> FileUseCase<R, A> {
+    // スキャン済みのファイルのサムネイルを生成し、元のファイルと同じ階層に保存する
+    pub async fn generate_thumbnail(
+        &self,
+        _ctx: &impl ContextProvider,
+        bucket: String,
+        id: String,
+    ) -> Result<(), FileUseCaseError> {
+        let id = FileId::try_from(id)?;
+        let file_data = self
+            .repositories
+            .file_data_repository()
+            .find_by_id(id.clone())
+            .await?
+            .ok_or(FileUseCaseError::NotFound(id))?;
+        self.generate_thumbnail_of(bucket, file_data).await
+    }
+
+    // サムネイルが生成されていないファイルをまとめて処理する
+    pub async fn generate_pending_thumbnails(
+        &self,
+        _ctx: &impl ContextProvider,
+        bucket: String,
+    ) -> Result<(), FileUseCaseError> {
+        let file_data_list = self
+            .repositories
+            .file_data_repository()
+            .find_by_thumbnail_status(FileThumbnailStatus::Pending)
+            .await?;
+
+        for file_data in file_data_list {
+            let id = file_data.id().clone();
+            if let Err(err) = self.generate_thumbnail_of(bucket.clone(), file_data).await {
+                tracing::error!("サムネイルの生成に失敗しました: {id:?}: {err:?}");
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn generate_thumbnail_of(
+        &self,
+        bucket: String,
+        mut file_data: FileData,
+    ) -> Result<(), FileUseCaseError> {
+        // スキャンが終わるまでは中身を開かない
+        if file_data.thumbnail_status() != &FileThumbnailStatus::Pending
+            || !file_data.is_downloadable()
+        {
+            return Ok(());
+        }
+
+        let content_type = match file_data.content_type() {
+            Some(content_type) if file_data.supports_thumbnail() => content_type.clone().value(),
+            _ => {
+                file_data.set_thumbnail_result(false);
+                self.repositories
+                    .file_data_repository()
+                    .update_thumbnail_status(file_data.id().clone(), *file_data.thumbnail_status())
+                    .await?;
+                return Ok(());
+            }
+        };
+
+        let (writer, reader) = tokio::io::duplex(65535);
+        let (downloaded, generated) = tokio::join!(
+            self.repositories.file_object_repository().download(
+                bucket.clone(),
+                file_data.url().copy(),
+                writer
+            ),
+            self.adapters
+                .thumbnail_generator()
+                .generate(content_type, reader),
+        );
+        // ダウンロードや生成に失敗したファイルを繰り返し処理しないよう、サムネイルなしとする
+        match (downloaded, generated) {
+            (Ok(()), Ok(thumbnail)) => {
+                self.repositories
+                    .file_object_repository()
+                    .create(
+                        bucket,
+                        FileObject::new(thumbnail, file_data.url().thumbnail()),
+                    )
+                    .await?;
+                file_data.set_thumbnail_result(true);
+            }
+            (downloaded, generated) => {
+                tracing::warn!(
+                    "サムネイルを生成できませんでした: {:?}: {:?}, {:?}",
+                    file_data.id(),
+                    downloaded.err(),
+                    generated.err()
+                );
+                file_data.set_thumbnail_result(false);
+            }
+        }
+
+        self.repositories
+            .file_data_repository()
+            .update_thumbnail_status(file_data.id().clone(), *file_data.thumbnail_status())
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::entity::file_data::{
+        FileContentType, FileData, FileScanStatus, FileThumbnailStatus,
+    };
+    use sos24_domain::entity::user::UserRole;
+    use sos24_domain::repository::file_object::FileObjectRepositoryError;
+    use sos24_domain::test::fixture;
+    use sos24_domain::test::repository::MockRepositories;
+
+    use crate::file::FileUseCase;
+    use crate::shared::adapter::MockAdapters;
+    use crate::shared::context::TestContext;
+
+    fn pending_file_data(content_type: &str, scan_status: FileScanStatus) -> FileData {
+        let file_data = fixture::file_data::file_data(None).destruct();
+        FileData::new(
+            file_data.id,
+            file_data.name,
+            file_data.url,
+            file_data.owner,
+            file_data.size,
+            Some(FileContentType::new(content_type.to_string())),
+            file_data.checksum,
+            file_data.uploader,
+            file_data.is_standalone,
+            scan_status,
+            FileThumbnailStatus::Pending,
+            file_data.created_at,
+            file_data.updated_at,
+        )
+    }
+
+    #[tokio::test]
+    async fn 画像のサムネイルを生成して保存する() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .file_data_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(pending_file_data("image/png", FileScanStatus::Clean))));
+        repositories
+            .file_object_repository_mut()
+            .expect_download()
+            .returning(|_, _, _| Ok(()));
+        repositories
+            .file_object_repository_mut()
+            .expect_create()
+            .withf(|_, object| object.key() == &fixture::file_object::key().thumbnail())
+            .times(1)
+            .returning(|_, _| Ok(()));
+        repositories
+            .file_data_repository_mut()
+            .expect_update_thumbnail_status()
+            .withf(|_, status| *status == FileThumbnailStatus::Ready)
+            .times(1)
+            .returning(|_, _| Ok(()));
+        let mut adapters = MockAdapters::default();
+        adapters
+            .thumbnail_generator_mut()
+            .expect_generate()
+            .returning(|_, _| Ok(vec![0x89, 0x50, 0x4e, 0x47]));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(adapters));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::Administrator));
+        let res = use_case
+            .generate_thumbnail(
+                &ctx,
+                String::new(),
+                fixture::file_data::id().value().to_string(),
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn ダウンロードに失敗したファイルはサムネイルなしとする() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .file_data_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(pending_file_data("image/png", FileScanStatus::Clean))));
+        repositories
+            .file_object_repository_mut()
+            .expect_download()
+            .returning(|_, _, _| {
+                Err(FileObjectRepositoryError::InternalError(anyhow::anyhow!(
+                    "failed"
+                )))
+            });
+        repositories
+            .file_object_repository_mut()
+            .expect_create()
+            .never();
+        repositories
+            .file_data_repository_mut()
+            .expect_update_thumbnail_status()
+            .withf(|_, status| *status == FileThumbnailStatus::Unavailable)
+            .times(1)
+            .returning(|_, _| Ok(()));
+        let mut adapters = MockAdapters::default();
+        adapters
+            .thumbnail_generator_mut()
+            .expect_generate()
+            .returning(|_, _| Err(anyhow::anyhow!("unexpected end of input")));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(adapters));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::Administrator));
+        let res = use_case
+            .generate_thumbnail(
+                &ctx,
+                String::new(),
+                fixture::file_data::id().value().to_string(),
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn 対応していない種類のファイルはサムネイルなしとする() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .file_data_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(pending_file_data("text/plain", FileScanStatus::Clean))));
+        repositories
+            .file_data_repository_mut()
+            .expect_update_thumbnail_status()
+            .withf(|_, status| *status == FileThumbnailStatus::Unavailable)
+            .times(1)
+            .returning(|_, _| Ok(()));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::Administrator));
+        let res = use_case
+            .generate_thumbnail(
+                &ctx,
+                String::new(),
+                fixture::file_data::id().value().to_string(),
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn スキャンが終わっていないファイルのサムネイルは生成しない() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .file_data_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| {
+                Ok(Some(pending_file_data(
+                    "image/png",
+                    FileScanStatus::Pending,
+                )))
+            });
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::Administrator));
+        let res = use_case
+            .generate_thumbnail(
+                &ctx,
+                String::new(),
+                fixture::file_data::id().value().to_string(),
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+}
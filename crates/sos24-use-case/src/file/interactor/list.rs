@@ -4,9 +4,10 @@ use sos24_domain::{ensure, repository::file_data::FileDataRepository};
 
 use crate::file::dto::FileInfoDto;
 use crate::file::{FileUseCase, FileUseCaseError};
+use crate::shared::adapter::Adapters;
 use crate::shared::context::ContextProvider;
 
-impl<R: Repositories> FileUseCase<R> {
+impl<R: Repositories, A: Adapters> FileUseCase<R, A> {
     pub async fn list(
         &self,
         ctx: &impl ContextProvider,
@@ -31,12 +32,13 @@ mod tests {
     use sos24_domain::test::repository::MockRepositories;
 
     use crate::file::{FileUseCase, FileUseCaseError};
+    use crate::shared::adapter::MockAdapters;
     use crate::shared::context::TestContext;
 
     #[tokio::test]
     async fn 一般ユーザーはファイル一覧を取得できない() {
         let repositories = MockRepositories::default();
-        let use_case = FileUseCase::new(Arc::new(repositories));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
         let res = use_case.list(&ctx).await;
@@ -56,7 +58,7 @@ mod tests {
             .file_data_repository_mut()
             .expect_list()
             .returning(|| Ok(vec![]));
-        let use_case = FileUseCase::new(Arc::new(repositories));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeViewer));
         let res = use_case.list(&ctx).await;
@@ -0,0 +1,335 @@
+use sos24_domain::{
+    entity::{
+        file_export::{FileExport, FileExportError, FileExportId},
+        file_object::FileObjectKey,
+    },
+    repository::{
+        file_export::FileExportRepository, file_object::FileObjectRepository, form::FormRepository,
+        Repositories,
+    },
+};
+
+use crate::{
+    file::{FileUseCase, FileUseCaseError},
+    shared::{
+        adapter::Adapters,
+        context::{actor_of, ContextProvider},
+    },
+};
+
+impl<R: Repositories, A: Adapters> FileUseCase<R, A> {
+    // エクスポートのアーカイブを作成してオブジェクトストレージに保存する
+    pub async fn run_export(
+        &self,
+        _ctx: &impl ContextProvider,
+        bucket: String,
+        id: String,
+    ) -> Result<(), FileUseCaseError> {
+        let id = FileExportId::try_from(id)?;
+        self.run_file_export(bucket, id).await
+    }
+
+    // 未着手のエクスポートと、サーバーの停止などで中断されたエクスポートを実行する
+    pub async fn run_pending_exports(
+        &self,
+        _ctx: &impl ContextProvider,
+        bucket: String,
+    ) -> Result<(), FileUseCaseError> {
+        let export_list = self
+            .repositories
+            .file_export_repository()
+            .find_runnable(FileExport::stale_before(&chrono::Utc::now()))
+            .await?;
+
+        for export in export_list {
+            let id = export.id().clone();
+            if let Err(err) = self.run_file_export(bucket.clone(), id.clone()).await {
+                tracing::error!("エクスポートに失敗しました: {id:?}: {err:?}");
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn run_file_export(
+        &self,
+        bucket: String,
+        id: FileExportId,
+    ) -> Result<(), FileUseCaseError> {
+        // 他のタスクが処理中か、既に終わっている
+        let acquired = self
+            .repositories
+            .file_export_repository()
+            .acquire(id.clone(), FileExport::stale_before(&chrono::Utc::now()))
+            .await?;
+        if !acquired {
+            return Ok(());
+        }
+
+        let mut export = self
+            .repositories
+            .file_export_repository()
+            .find_by_id(id.clone())
+            .await?
+            .ok_or(FileUseCaseError::ExportNotFound(id))?;
+
+        match self.create_export_archive(bucket, &mut export).await {
+            Ok(key) => {
+                export.complete(key, &chrono::Utc::now());
+                self.repositories
+                    .file_export_repository()
+                    .update(export)
+                    .await?;
+                Ok(())
+            }
+            Err(err) => {
+                export.fail(FileExportError::new(err.to_string()), &chrono::Utc::now());
+                self.repositories
+                    .file_export_repository()
+                    .update(export)
+                    .await?;
+                Err(err)
+            }
+        }
+    }
+
+    async fn create_export_archive(
+        &self,
+        bucket: String,
+        export: &mut FileExport,
+    ) -> Result<FileObjectKey, FileUseCaseError> {
+        let form = self
+            .repositories
+            .form_repository()
+            .find_by_id(export.form_id().clone())
+            .await?
+            .ok_or(FileUseCaseError::FormNotFound(export.form_id().clone()))?;
+        // エクスポートを依頼したユーザーが閲覧できるファイルだけを含める
+        let requester = actor_of(&*self.repositories, export.requested_by().clone()).await?;
+        let entry_list = self
+            .list_form_archive_entries(&requester, &form, export.since().as_ref())
+            .await?;
+
+        export.start(entry_list.len() as i32, &chrono::Utc::now());
+        self.repositories
+            .file_export_repository()
+            .update(export.clone())
+            .await?;
+
+        let key = FileObjectKey::generate("exports");
+        let (writer, reader) = tokio::io::duplex(65535);
+        let (progress, mut progress_receiver) = tokio::sync::mpsc::unbounded_channel();
+        let result = tokio::try_join!(
+            async {
+                self.repositories
+                    .file_object_repository()
+                    .create_archive(bucket.clone(), entry_list, writer, Some(progress))
+                    .await
+                    .map_err(FileUseCaseError::from)
+            },
+            async {
+                self.repositories
+                    .file_object_repository()
+                    .create_stream(bucket.clone(), key.copy(), reader, u64::MAX)
+                    .await
+                    .map_err(FileUseCaseError::from)
+            },
+            async {
+                let mut processed = 0;
+                while progress_receiver.recv().await.is_some() {
+                    processed += 1;
+                    export.set_processed(processed, &chrono::Utc::now());
+                    self.repositories
+                        .file_export_repository()
+                        .update(export.clone())
+                        .await?;
+                }
+                Ok::<_, FileUseCaseError>(())
+            },
+        );
+
+        if let Err(err) = result {
+            // 途中までのアーカイブが残らないようにする
+            if let Err(err) = self
+                .repositories
+                .file_object_repository()
+                .delete(bucket, key)
+                .await
+            {
+                tracing::warn!("作成途中のアーカイブの削除に失敗しました: {err:?}");
+            }
+            return Err(err);
+        }
+
+        Ok(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::entity::file_export::FileExportStatus;
+    use sos24_domain::entity::user::UserRole;
+    use sos24_domain::repository::file_object::FileObjectRepositoryError;
+    use sos24_domain::test::fixture;
+    use sos24_domain::test::repository::MockRepositories;
+
+    use crate::file::FileUseCase;
+    use crate::shared::adapter::MockAdapters;
+    use crate::shared::context::TestContext;
+
+    #[tokio::test]
+    async fn 他のタスクが処理中のエクスポートは実行しない() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .file_export_repository_mut()
+            .expect_acquire()
+            .returning(|_, _| Ok(false));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .run_export(
+                &ctx,
+                String::new(),
+                fixture::file_export::id1().value().to_string(),
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn アーカイブを保存してエクスポートを完了にする() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .file_export_repository_mut()
+            .expect_acquire()
+            .returning(|_, _| Ok(true));
+        repositories
+            .file_export_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::file_export::file_export1())));
+        repositories
+            .user_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::user::user1(UserRole::CommitteeViewer))));
+        repositories
+            .custom_role_repository_mut()
+            .expect_find_by_user_id()
+            .returning(|_| Ok(vec![]));
+        repositories
+            .form_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::form::form1_opened())));
+        repositories
+            .form_answer_repository_mut()
+            .expect_find_by_form_id()
+            .returning(|_| Ok(vec![]));
+        repositories
+            .file_object_repository_mut()
+            .expect_create_archive()
+            .returning(|_, _, _, _| Box::pin(async { Ok(()) }));
+        repositories
+            .file_object_repository_mut()
+            .expect_create_stream()
+            .returning(|_, _, _, _| Ok(fixture::file_object::metadata()));
+        repositories
+            .file_export_repository_mut()
+            .expect_update()
+            .withf(|export| export.status() == &FileExportStatus::Running)
+            .times(1)
+            .returning(|_| Ok(()));
+        repositories
+            .file_export_repository_mut()
+            .expect_update()
+            .withf(|export| export.status() == &FileExportStatus::Completed)
+            .times(1)
+            .returning(|_| Ok(()));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .run_export(
+                &ctx,
+                String::new(),
+                fixture::file_export::id1().value().to_string(),
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn アーカイブの作成に失敗した場合はエクスポートを失敗にする() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .file_export_repository_mut()
+            .expect_acquire()
+            .returning(|_, _| Ok(true));
+        repositories
+            .file_export_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::file_export::file_export1())));
+        repositories
+            .user_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::user::user1(UserRole::CommitteeViewer))));
+        repositories
+            .custom_role_repository_mut()
+            .expect_find_by_user_id()
+            .returning(|_| Ok(vec![]));
+        repositories
+            .form_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::form::form1_opened())));
+        repositories
+            .form_answer_repository_mut()
+            .expect_find_by_form_id()
+            .returning(|_| Ok(vec![]));
+        repositories
+            .file_object_repository_mut()
+            .expect_create_archive()
+            .returning(|_, _, _, _| {
+                Box::pin(async {
+                    Err(FileObjectRepositoryError::InternalError(anyhow::anyhow!(
+                        "failed"
+                    )))
+                })
+            });
+        repositories
+            .file_object_repository_mut()
+            .expect_create_stream()
+            .returning(|_, _, _, _| Ok(fixture::file_object::metadata()));
+        repositories
+            .file_object_repository_mut()
+            .expect_delete()
+            .times(1)
+            .returning(|_, _| Ok(()));
+        repositories
+            .file_export_repository_mut()
+            .expect_update()
+            .withf(|export| export.status() == &FileExportStatus::Running)
+            .times(1)
+            .returning(|_| Ok(()));
+        repositories
+            .file_export_repository_mut()
+            .expect_update()
+            .withf(|export| export.status() == &FileExportStatus::Failed)
+            .times(1)
+            .returning(|_| Ok(()));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .run_export(
+                &ctx,
+                String::new(),
+                fixture::file_export::id1().value().to_string(),
+            )
+            .await;
+
+        assert!(res.is_err());
+    }
+}
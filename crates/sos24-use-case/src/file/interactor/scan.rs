@@ -0,0 +1,418 @@
+use sos24_domain::{
+    entity::file_data::{FileData, FileId, FileScanStatus},
+    repository::{file_data::FileDataRepository, file_object::FileObjectRepository, Repositories},
+};
+
+use crate::{
+    file::{FileUseCase, FileUseCaseError},
+    shared::{
+        adapter::{
+            file_scanner::{FileScanner, ScanResult},
+            notification::Notifier,
+            Adapters,
+        },
+        context::ContextProvider,
+    },
+};
+
+impl<R: Repositories, A: Adapters> FileUseCase<R, A> {
+    // アップロードされたファイルをスキャンし、結果を記録する
+    pub async fn scan(
+        &self,
+        _ctx: &impl ContextProvider,
+        bucket: String,
+        id: String,
+    ) -> Result<(), FileUseCaseError> {
+        let id = FileId::try_from(id)?;
+        let file_data = self
+            .repositories
+            .file_data_repository()
+            .find_by_id(id.clone())
+            .await?
+            .ok_or(FileUseCaseError::NotFound(id))?;
+        self.scan_file_data(bucket, file_data).await
+    }
+
+    // スキャンが終わっていないファイルをまとめてスキャンする
+    pub async fn scan_pending(
+        &self,
+        _ctx: &impl ContextProvider,
+        bucket: String,
+    ) -> Result<(), FileUseCaseError> {
+        let mut file_data_list = self
+            .repositories
+            .file_data_repository()
+            .find_by_scan_status(FileScanStatus::Pending)
+            .await?;
+        // スキャナが設定されたら、スキャンせずにダウンロードを許可していたファイルもスキャンする
+        if self.adapters.file_scanner().is_enabled() {
+            file_data_list.extend(
+                self.repositories
+                    .file_data_repository()
+                    .find_by_scan_status(FileScanStatus::NotScanned)
+                    .await?,
+            );
+        }
+
+        for file_data in file_data_list {
+            let id = file_data.id().clone();
+            if let Err(err) = self.scan_file_data(bucket.clone(), file_data).await {
+                tracing::error!("ファイルのスキャンに失敗しました: {id:?}: {err:?}");
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn scan_file_data(
+        &self,
+        bucket: String,
+        mut file_data: FileData,
+    ) -> Result<(), FileUseCaseError> {
+        if !file_data.is_scan_pending() {
+            return Ok(());
+        }
+
+        // スキャナが設定されていなければ、ファイルをダウンロードせずにスキャンしていないことを記録する
+        // スキャナが設定された後に改めてスキャンする
+        if !self.adapters.file_scanner().is_enabled() {
+            if file_data.scan_status() == &FileScanStatus::NotScanned {
+                return Ok(());
+            }
+            tracing::warn!(
+                "clamdのアドレスが設定されていないため、スキャンせずにダウンロードを許可しました: {:?}",
+                file_data.id()
+            );
+            file_data.set_not_scanned();
+            self.repositories
+                .file_data_repository()
+                .update_scan_status(file_data.id().clone(), *file_data.scan_status())
+                .await?;
+            return Ok(());
+        }
+
+        let (writer, reader) = tokio::io::duplex(65535);
+        let (_, result) = tokio::try_join!(
+            async {
+                self.repositories
+                    .file_object_repository()
+                    .download(bucket, file_data.url().copy(), writer)
+                    .await
+                    .map_err(FileUseCaseError::from)
+            },
+            async {
+                self.adapters
+                    .file_scanner()
+                    .scan(reader)
+                    .await
+                    .map_err(FileUseCaseError::from)
+            },
+        )?;
+
+        match result {
+            ScanResult::TooLarge => file_data.set_unscannable(),
+            ScanResult::Clean | ScanResult::Infected(_) => {
+                file_data.set_scan_result(matches!(result, ScanResult::Infected(_)))
+            }
+        }
+        self.repositories
+            .file_data_repository()
+            .update_scan_status(file_data.id().clone(), *file_data.scan_status())
+            .await?;
+
+        match result {
+            ScanResult::Infected(signature) => {
+                tracing::warn!(
+                    "マルウェアが検出されました: {:?}: {signature}",
+                    file_data.id()
+                );
+                self.adapters
+                    .notifier()
+                    .notify(format!(
+                        "アップロードされたファイル「{}」(ID: {})からマルウェア({})が検出されました。ダウンロードを停止しています。",
+                        file_data.filename().clone().value(),
+                        file_data.id().clone().value(),
+                        signature,
+                    ))
+                    .await?;
+            }
+            ScanResult::TooLarge => {
+                // 毎回ダウンロードし直さないよう、スキャンできなかったことを記録して実委に確認してもらう
+                tracing::warn!(
+                    "ファイルが大きすぎるためスキャンできませんでした: {:?}",
+                    file_data.id()
+                );
+                self.adapters
+                    .notifier()
+                    .notify(format!(
+                        "アップロードされたファイル「{}」(ID: {})は大きすぎるためスキャンできませんでした。ダウンロードを停止しています。",
+                        file_data.filename().clone().value(),
+                        file_data.id().clone().value(),
+                    ))
+                    .await?;
+            }
+            ScanResult::Clean => {}
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::entity::file_data::{FileData, FileIsStandalone, FileScanStatus};
+    use sos24_domain::entity::user::UserRole;
+    use sos24_domain::test::fixture;
+    use sos24_domain::test::repository::MockRepositories;
+
+    use crate::file::FileUseCase;
+    use crate::shared::adapter::file_scanner::ScanResult;
+    use crate::shared::adapter::MockAdapters;
+    use crate::shared::context::TestContext;
+
+    fn pending_file_data() -> FileData {
+        FileData::create(
+            fixture::file_data::filename(),
+            fixture::file_object::key(),
+            None,
+            fixture::user::id1(),
+            FileIsStandalone::new(false),
+            fixture::file_object::metadata(),
+        )
+    }
+
+    #[tokio::test]
+    async fn 問題のないファイルはダウンロード可能になる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .file_data_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(pending_file_data())));
+        repositories
+            .file_object_repository_mut()
+            .expect_download()
+            .returning(|_, _, _| Ok(()));
+        repositories
+            .file_data_repository_mut()
+            .expect_update_scan_status()
+            .withf(|_, status| *status == FileScanStatus::Clean)
+            .times(1)
+            .returning(|_, _| Ok(()));
+        let mut adapters = MockAdapters::default();
+        adapters
+            .file_scanner_mut()
+            .expect_is_enabled()
+            .returning(|| true);
+        adapters
+            .file_scanner_mut()
+            .expect_scan()
+            .returning(|_| Ok(ScanResult::Clean));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(adapters));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .scan(
+                &ctx,
+                String::new(),
+                fixture::file_data::id().value().to_string(),
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn マルウェアが検出されたファイルは記録され実委に通知される() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .file_data_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(pending_file_data())));
+        repositories
+            .file_object_repository_mut()
+            .expect_download()
+            .returning(|_, _, _| Ok(()));
+        repositories
+            .file_data_repository_mut()
+            .expect_update_scan_status()
+            .withf(|_, status| *status == FileScanStatus::Infected)
+            .times(1)
+            .returning(|_, _| Ok(()));
+        let mut adapters = MockAdapters::default();
+        adapters
+            .file_scanner_mut()
+            .expect_is_enabled()
+            .returning(|| true);
+        adapters
+            .file_scanner_mut()
+            .expect_scan()
+            .returning(|_| Ok(ScanResult::Infected("Eicar-Signature".to_string())));
+        adapters
+            .notifier_mut()
+            .expect_notify()
+            .times(1)
+            .returning(|_| Ok(()));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(adapters));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .scan(
+                &ctx,
+                String::new(),
+                fixture::file_data::id().value().to_string(),
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn スキャン済みのファイルは再度スキャンしない() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .file_data_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::file_data::file_data(None))));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .scan(
+                &ctx,
+                String::new(),
+                fixture::file_data::id().value().to_string(),
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn スキャナが設定されていなければダウンロードせずにスキャンしていないことを記録する() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .file_data_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(pending_file_data())));
+        repositories
+            .file_object_repository_mut()
+            .expect_download()
+            .never();
+        repositories
+            .file_data_repository_mut()
+            .expect_update_scan_status()
+            .withf(|_, status| *status == FileScanStatus::NotScanned)
+            .times(1)
+            .returning(|_, _| Ok(()));
+        let mut adapters = MockAdapters::default();
+        adapters
+            .file_scanner_mut()
+            .expect_is_enabled()
+            .returning(|| false);
+        adapters.file_scanner_mut().expect_scan().never();
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(adapters));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .scan(
+                &ctx,
+                String::new(),
+                fixture::file_data::id().value().to_string(),
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn 大きすぎてスキャンできないファイルは記録され実委に通知される() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .file_data_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(pending_file_data())));
+        repositories
+            .file_object_repository_mut()
+            .expect_download()
+            .returning(|_, _, _| Ok(()));
+        repositories
+            .file_data_repository_mut()
+            .expect_update_scan_status()
+            .withf(|_, status| *status == FileScanStatus::Unscannable)
+            .times(1)
+            .returning(|_, _| Ok(()));
+        let mut adapters = MockAdapters::default();
+        adapters
+            .file_scanner_mut()
+            .expect_is_enabled()
+            .returning(|| true);
+        adapters
+            .file_scanner_mut()
+            .expect_scan()
+            .returning(|_| Ok(ScanResult::TooLarge));
+        adapters
+            .notifier_mut()
+            .expect_notify()
+            .times(1)
+            .returning(|_| Ok(()));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(adapters));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .scan(
+                &ctx,
+                String::new(),
+                fixture::file_data::id().value().to_string(),
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn スキャンせずに許可したファイルはスキャナが設定されるとスキャンされる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .file_data_repository_mut()
+            .expect_find_by_scan_status()
+            .withf(|status| *status == FileScanStatus::Pending)
+            .returning(|_| Ok(vec![]));
+        repositories
+            .file_data_repository_mut()
+            .expect_find_by_scan_status()
+            .withf(|status| *status == FileScanStatus::NotScanned)
+            .returning(|_| {
+                let mut file_data = pending_file_data();
+                file_data.set_not_scanned();
+                Ok(vec![file_data])
+            });
+        repositories
+            .file_object_repository_mut()
+            .expect_download()
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        repositories
+            .file_data_repository_mut()
+            .expect_update_scan_status()
+            .withf(|_, status| *status == FileScanStatus::Clean)
+            .times(1)
+            .returning(|_, _| Ok(()));
+        let mut adapters = MockAdapters::default();
+        adapters
+            .file_scanner_mut()
+            .expect_is_enabled()
+            .returning(|| true);
+        adapters
+            .file_scanner_mut()
+            .expect_scan()
+            .returning(|_| Ok(ScanResult::Clean));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(adapters));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case.scan_pending(&ctx, String::new()).await;
+
+        assert!(res.is_ok());
+    }
+}
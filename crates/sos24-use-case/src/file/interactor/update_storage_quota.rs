@@ -0,0 +1,135 @@
+use sos24_domain::{
+    ensure,
+    entity::{permission::Permissions, project::ProjectId, project_storage::ProjectStorageQuota},
+    repository::{
+        project::ProjectRepository, project_storage_quota::ProjectStorageQuotaRepository,
+        Repositories,
+    },
+};
+
+use crate::{
+    file::{FileUseCase, FileUseCaseError},
+    shared::{adapter::Adapters, context::ContextProvider},
+};
+
+impl<R: Repositories, A: Adapters> FileUseCase<R, A> {
+    // quotaがNoneの場合は個別の上限を削除し、既定値に戻す
+    pub async fn update_storage_quota(
+        &self,
+        ctx: &impl ContextProvider,
+        project_id: String,
+        quota: Option<u64>,
+    ) -> Result<(), FileUseCaseError> {
+        let actor = ctx.actor(&*self.repositories).await?;
+        ensure!(actor.has_permission(Permissions::UPDATE_PROJECT_ALL));
+
+        let project_id = ProjectId::try_from(project_id)?;
+        self.repositories
+            .project_repository()
+            .find_by_id(project_id.clone())
+            .await?
+            .ok_or(FileUseCaseError::ProjectNotFound(project_id.clone()))?;
+
+        match quota {
+            Some(quota) => {
+                self.repositories
+                    .project_storage_quota_repository()
+                    .upsert(project_id, ProjectStorageQuota::new(quota))
+                    .await?
+            }
+            None => {
+                self.repositories
+                    .project_storage_quota_repository()
+                    .delete_by_project_id(project_id)
+                    .await?
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::entity::permission::PermissionDeniedError;
+    use sos24_domain::entity::user::UserRole;
+    use sos24_domain::test::fixture;
+    use sos24_domain::test::repository::MockRepositories;
+
+    use crate::file::{FileUseCase, FileUseCaseError};
+    use crate::shared::adapter::MockAdapters;
+    use crate::shared::context::TestContext;
+
+    #[tokio::test]
+    async fn 一般ユーザーは企画の容量の上限を変更できない() {
+        let repositories = MockRepositories::default();
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .update_storage_quota(&ctx, fixture::project::id1().value().to_string(), Some(0))
+            .await;
+
+        assert!(matches!(
+            res,
+            Err(FileUseCaseError::PermissionDeniedError(
+                PermissionDeniedError
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn 実委人管理者は企画の容量の上限を変更できる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .project_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| {
+                Ok(Some(fixture::project::project_with_owners1(
+                    fixture::user::user1(UserRole::General),
+                )))
+            });
+        repositories
+            .project_storage_quota_repository_mut()
+            .expect_upsert()
+            .withf(|_, quota| quota.clone().value() == 100)
+            .times(1)
+            .returning(|_, _| Ok(()));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeOperator));
+        let res = use_case
+            .update_storage_quota(&ctx, fixture::project::id1().value().to_string(), Some(100))
+            .await;
+
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn 上限を指定しない場合は既定値に戻す() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .project_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| {
+                Ok(Some(fixture::project::project_with_owners1(
+                    fixture::user::user1(UserRole::General),
+                )))
+            });
+        repositories
+            .project_storage_quota_repository_mut()
+            .expect_delete_by_project_id()
+            .times(1)
+            .returning(|_| Ok(()));
+        let use_case = FileUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeOperator));
+        let res = use_case
+            .update_storage_quota(&ctx, fixture::project::id1().value().to_string(), None)
+            .await;
+
+        assert!(res.is_ok());
+    }
+}
@@ -9,7 +9,7 @@ use sos24_domain::entity::project::ProjectId;
 use sos24_domain::repository::project::ProjectRepositoryError;
 use sos24_domain::{
     entity::{
-        common::datetime::DateTimeError,
+        common::{datetime::DateTimeError, markdown::MarkdownError},
         form::{FormError, FormId, FormIdError, FormItemIdError},
         permission::PermissionDeniedError,
         project::ProjectIdError,
@@ -56,6 +56,8 @@ pub enum FormUseCaseError {
     #[error(transparent)]
     DateTimeError(#[from] DateTimeError),
     #[error(transparent)]
+    MarkdownError(#[from] MarkdownError),
+    #[error(transparent)]
     FormRepositoryError(#[from] FormRepositoryError),
     #[error(transparent)]
     ContextError(#[from] ContextError),
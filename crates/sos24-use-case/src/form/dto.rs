@@ -1,7 +1,7 @@
 use sos24_domain::entity::form::{
     Form, FormItem, FormItemAllowNewline, FormItemDescription, FormItemKind, FormItemLimit,
-    FormItemMax, FormItemMaxLength, FormItemMaxSelection, FormItemMin, FormItemMinLength,
-    FormItemMinSelection, FormItemName, FormItemOption, FormItemRequired,
+    FormItemMax, FormItemMaxLength, FormItemMaxSelection, FormItemMaxSize, FormItemMin,
+    FormItemMinLength, FormItemMinSelection, FormItemName, FormItemOption, FormItemRequired,
 };
 use sos24_domain::entity::form::{FormIsDraft, FormItemExtension};
 use sos24_domain::entity::form_answer::FormAnswer;
@@ -76,11 +76,14 @@ pub struct FormDto {
     pub id: String,
     pub title: String,
     pub description: String,
+    pub description_html: String,
     pub is_draft: FormIsDraftDto,
     pub starts_at: chrono::DateTime<chrono::Utc>,
     pub ends_at: chrono::DateTime<chrono::Utc>,
     pub categories: ProjectCategoriesDto,
     pub attributes: ProjectAttributesDto,
+    pub included_projects: Vec<String>,
+    pub excluded_projects: Vec<String>,
     pub items: Vec<FormItemDto>,
     pub attachments: Vec<String>,
     pub answer_id: Option<String>,
@@ -102,12 +105,23 @@ impl From<(Form, Option<FormAnswer>)> for FormDto {
         Self {
             id: form.id.value().to_string(),
             title: form.title.value(),
+            description_html: form.description.to_html(),
             description: form.description.value(),
             is_draft: FormIsDraftDto::from(form.is_draft),
             starts_at: form.starts_at.value(),
             ends_at: form.ends_at.value(),
             categories: ProjectCategoriesDto::from(form.categories),
             attributes: ProjectAttributesDto::from(form.attributes),
+            included_projects: form
+                .included_projects
+                .into_iter()
+                .map(|it| it.value().to_string())
+                .collect(),
+            excluded_projects: form
+                .excluded_projects
+                .into_iter()
+                .map(|it| it.value().to_string())
+                .collect(),
             items: form.items.into_iter().map(FormItemDto::from).collect(),
             attachments: form
                 .attachments
@@ -237,6 +251,7 @@ pub enum FormItemKindDto {
     File {
         extensions: Option<Vec<String>>,
         limit: Option<u32>,
+        max_size: Option<u64>,
     },
 }
 
@@ -269,9 +284,14 @@ impl TryFrom<FormItemKindDto> for FormItemKind {
                 min_selection.map(FormItemMinSelection::new),
                 max_selection.map(FormItemMaxSelection::new),
             )?),
-            FormItemKindDto::File { extensions, limit } => Ok(FormItemKind::new_file(
+            FormItemKindDto::File {
+                extensions,
+                limit,
+                max_size,
+            } => Ok(FormItemKind::new_file(
                 extensions.map(|it| it.into_iter().map(FormItemExtension::new).collect()),
                 limit.map(FormItemLimit::new),
+                max_size.map(FormItemMaxSize::new),
             )),
         }
     }
@@ -316,6 +336,7 @@ impl From<FormItemKind> for FormItemKindDto {
                         .extensions
                         .map(|it| it.into_iter().map(|it| it.value()).collect()),
                     limit: item.limit.map(|it| it.value()),
+                    max_size: item.max_size.map(|it| it.value()),
                 }
             }
         }
@@ -1,11 +1,18 @@
 use chrono_tz::Asia::Tokyo;
-use sos24_domain::repository::{form::FormRepository, project::ProjectRepository, Repositories};
+use sos24_domain::{
+    entity::notification::{
+        Notification, NotificationBody, NotificationKind, NotificationTitle, NotificationUrl,
+    },
+    repository::{form::FormRepository, project::ProjectRepository, Repositories},
+};
 
 use crate::{
     form::{FormUseCase, FormUseCaseError},
     shared::{
         adapter::{
             email::{Email, EmailSender, SendEmailCommand},
+            event::{Event, EventBus},
+            notification::Notifier,
             Adapters,
         },
         app_url,
@@ -29,9 +36,11 @@ impl<R: Repositories, A: Adapters> FormUseCase<R, A> {
         for form in form_list_to_notify {
             let target_project_list = project_list
                 .iter()
-                .filter(|project_with_owners| form.is_sent_to(&project_with_owners.project));
+                .filter(|project_with_owners| form.is_sent_to(&project_with_owners.project))
+                .collect::<Vec<_>>();
 
             let emails = target_project_list
+                .iter()
                 .flat_map(|project_with_owners| {
                     [
                         Some(project_with_owners.owner.email().clone().value()),
@@ -86,12 +95,54 @@ Email : {email}
                     url = app_url::form(ctx, form.id().clone()),
                     email = ctx.config().email_reply_to_address.clone(),
                 ),
+                html_body: None,
             };
             self.adapters.email_sender().send_email(command).await?;
 
+            let notifications = target_project_list
+                .iter()
+                .flat_map(|project_with_owners| {
+                    [
+                        Some(project_with_owners.owner.id().clone()),
+                        project_with_owners
+                            .sub_owner
+                            .as_ref()
+                            .map(|it| it.id().clone()),
+                    ]
+                })
+                .flatten()
+                .map(|user_id| {
+                    Notification::create(
+                        user_id,
+                        NotificationKind::FormStarted,
+                        NotificationTitle::new(format!(
+                            "申請「{}」が公開されました",
+                            form.title().clone().value()
+                        )),
+                        NotificationBody::new(format!(
+                            "回答終了時刻: {}",
+                            form.ends_at()
+                                .clone()
+                                .value()
+                                .with_timezone(&Tokyo)
+                                .format("%Y年%m月%d日 %H:%M")
+                        )),
+                        NotificationUrl::new(app_url::form(ctx, form.id().clone())),
+                    )
+                })
+                .collect();
+            self.adapters.notifier().notify_users(notifications).await?;
+
             let mut new_form = form;
             new_form.set_notified()?;
-            self.repositories.form_repository().update(new_form).await?;
+            self.repositories
+                .form_repository()
+                .update(new_form.clone())
+                .await?;
+
+            self.adapters
+                .event_bus()
+                .publish(Event::FormStarted(new_form));
         }
 
         Ok(())
@@ -4,7 +4,7 @@ use sos24_domain::{
         common::datetime::DateTime,
         file_data::FileId,
         form::{Form, FormDescription, FormIsDraft, FormItem, FormTitle},
-        project::{ProjectAttributes, ProjectCategories},
+        project::{ProjectAttributes, ProjectCategories, ProjectId},
     },
     repository::{form::FormRepository, Repositories},
 };
@@ -31,6 +31,8 @@ pub struct CreateFormCommand {
     pub ends_at: String,
     pub categories: ProjectCategoriesDto,
     pub attributes: ProjectAttributesDto,
+    pub included_projects: Vec<String>,
+    pub excluded_projects: Vec<String>,
     pub items: Vec<NewFormItemDto>,
     pub attachments: Vec<String>,
 }
@@ -43,12 +45,22 @@ impl<R: Repositories, A: Adapters> FormUseCase<R, A> {
     ) -> Result<String, FormUseCaseError> {
         let form = Form::create(
             FormTitle::new(raw_form.title),
-            FormDescription::new(raw_form.description),
+            FormDescription::try_from(raw_form.description)?,
             FormIsDraft::from(raw_form.is_draft),
             DateTime::try_from(raw_form.starts_at)?,
             DateTime::try_from(raw_form.ends_at)?,
             ProjectCategories::from(raw_form.categories),
             ProjectAttributes::from(raw_form.attributes),
+            raw_form
+                .included_projects
+                .into_iter()
+                .map(ProjectId::try_from)
+                .collect::<Result<_, _>>()?,
+            raw_form
+                .excluded_projects
+                .into_iter()
+                .map(ProjectId::try_from)
+                .collect::<Result<_, _>>()?,
             raw_form
                 .items
                 .into_iter()
@@ -136,6 +148,14 @@ mod tests {
                     ends_at: fixture::form::ends_at1_opened().value().to_rfc3339(),
                     categories: ProjectCategoriesDto::from(fixture::form::categories1()),
                     attributes: ProjectAttributesDto::from(fixture::form::attributes1()),
+                    included_projects: fixture::form::included_projects1()
+                        .into_iter()
+                        .map(|id| id.value().to_string())
+                        .collect(),
+                    excluded_projects: fixture::form::excluded_projects1()
+                        .into_iter()
+                        .map(|id| id.value().to_string())
+                        .collect(),
                     items: vec![NewFormItemDto::new(
                         fixture::form::formitem_name1().value(),
                         Some(fixture::form::description1().value()),
@@ -183,6 +203,14 @@ mod tests {
                     ends_at: fixture::form::ends_at1_opened().value().to_rfc3339(),
                     categories: ProjectCategoriesDto::from(fixture::form::categories1()),
                     attributes: ProjectAttributesDto::from(fixture::form::attributes1()),
+                    included_projects: fixture::form::included_projects1()
+                        .into_iter()
+                        .map(|id| id.value().to_string())
+                        .collect(),
+                    excluded_projects: fixture::form::excluded_projects1()
+                        .into_iter()
+                        .map(|id| id.value().to_string())
+                        .collect(),
                     items: vec![NewFormItemDto::new(
                         fixture::form::formitem_name1().value(),
                         Some(fixture::form::description1().value()),
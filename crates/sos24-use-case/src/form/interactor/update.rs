@@ -1,6 +1,6 @@
 use sos24_domain::entity::file_data::FileId;
 use sos24_domain::entity::form::{FormIsDraft, FormItem};
-use sos24_domain::entity::project::{ProjectAttributes, ProjectCategories};
+use sos24_domain::entity::project::{ProjectAttributes, ProjectCategories, ProjectId};
 use sos24_domain::{
     ensure,
     entity::{
@@ -27,6 +27,8 @@ pub struct UpdateFormCommand {
     pub ends_at: String,
     pub categories: ProjectCategoriesDto,
     pub attributes: ProjectAttributesDto,
+    pub included_projects: Vec<String>,
+    pub excluded_projects: Vec<String>,
     pub items: Vec<NewFormItemDto>,
     pub attachments: Vec<String>,
 }
@@ -62,11 +64,27 @@ impl<R: Repositories, A: Adapters> FormUseCase<R, A> {
 
         let mut new_form = form.clone();
         new_form.set_title(&actor, FormTitle::new(form_data.title))?;
-        new_form.set_description(&actor, FormDescription::new(form_data.description))?;
+        new_form.set_description(&actor, FormDescription::try_from(form_data.description)?)?;
         new_form.set_starts_at(&actor, DateTime::try_from(form_data.starts_at)?)?;
         new_form.set_ends_at(&actor, DateTime::try_from(form_data.ends_at)?)?;
         new_form.set_categories(&actor, ProjectCategories::from(form_data.categories))?;
         new_form.set_attributes(&actor, ProjectAttributes::from(form_data.attributes))?;
+        new_form.set_included_projects(
+            &actor,
+            form_data
+                .included_projects
+                .into_iter()
+                .map(ProjectId::try_from)
+                .collect::<Result<_, _>>()?,
+        )?;
+        new_form.set_excluded_projects(
+            &actor,
+            form_data
+                .excluded_projects
+                .into_iter()
+                .map(ProjectId::try_from)
+                .collect::<Result<_, _>>()?,
+        )?;
         new_form.set_is_draft(
             &actor,
             FormIsDraft::from(form_data.is_draft),
@@ -135,6 +153,14 @@ mod tests {
                     ends_at: fixture::form::ends_at2().value().to_rfc3339(),
                     categories: ProjectCategoriesDto::from(fixture::form::categories2()),
                     attributes: ProjectAttributesDto::from(fixture::form::attributes2()),
+                    included_projects: fixture::form::included_projects2()
+                        .into_iter()
+                        .map(|id| id.value().to_string())
+                        .collect(),
+                    excluded_projects: fixture::form::excluded_projects2()
+                        .into_iter()
+                        .map(|id| id.value().to_string())
+                        .collect(),
                     items: vec![NewFormItemDto::new(
                         fixture::form::formitem_name2().value(),
                         Some(fixture::form::description2().value()),
@@ -175,6 +201,14 @@ mod tests {
                     ends_at: fixture::form::ends_at2().value().to_rfc3339(),
                     categories: ProjectCategoriesDto::from(fixture::form::categories2()),
                     attributes: ProjectAttributesDto::from(fixture::form::attributes2()),
+                    included_projects: fixture::form::included_projects2()
+                        .into_iter()
+                        .map(|id| id.value().to_string())
+                        .collect(),
+                    excluded_projects: fixture::form::excluded_projects2()
+                        .into_iter()
+                        .map(|id| id.value().to_string())
+                        .collect(),
                     items: vec![NewFormItemDto::new(
                         fixture::form::formitem_name2().value(),
                         Some(fixture::form::description2().value()),
@@ -227,6 +261,14 @@ mod tests {
                     ends_at: fixture::form::ends_at2().value().to_rfc3339(),
                     categories: ProjectCategoriesDto::from(fixture::form::categories2()),
                     attributes: ProjectAttributesDto::from(fixture::form::attributes2()),
+                    included_projects: fixture::form::included_projects2()
+                        .into_iter()
+                        .map(|id| id.value().to_string())
+                        .collect(),
+                    excluded_projects: fixture::form::excluded_projects2()
+                        .into_iter()
+                        .map(|id| id.value().to_string())
+                        .collect(),
                     items: vec![NewFormItemDto::new(
                         fixture::form::formitem_name2().value(),
                         Some(fixture::form::description2().value()),
@@ -274,6 +316,14 @@ mod tests {
                     ends_at: fixture::form::ends_at2().value().to_rfc3339(),
                     categories: ProjectCategoriesDto::from(fixture::form::categories2()),
                     attributes: ProjectAttributesDto::from(fixture::form::attributes2()),
+                    included_projects: fixture::form::included_projects2()
+                        .into_iter()
+                        .map(|id| id.value().to_string())
+                        .collect(),
+                    excluded_projects: fixture::form::excluded_projects2()
+                        .into_iter()
+                        .map(|id| id.value().to_string())
+                        .collect(),
                     items: vec![NewFormItemDto::new(
                         fixture::form::formitem_name2().value(),
                         Some(fixture::form::description2().value()),
@@ -321,6 +371,14 @@ mod tests {
                     ends_at: fixture::form::ends_at2().value().to_rfc3339(),
                     categories: ProjectCategoriesDto::from(fixture::form::categories2()),
                     attributes: ProjectAttributesDto::from(fixture::form::attributes2()),
+                    included_projects: fixture::form::included_projects2()
+                        .into_iter()
+                        .map(|id| id.value().to_string())
+                        .collect(),
+                    excluded_projects: fixture::form::excluded_projects2()
+                        .into_iter()
+                        .map(|id| id.value().to_string())
+                        .collect(),
                     items: vec![NewFormItemDto::new(
                         fixture::form::formitem_name2().value(),
                         Some(fixture::form::description2().value()),
@@ -373,6 +431,14 @@ mod tests {
                     ends_at: fixture::form::ends_at2().value().to_rfc3339(),
                     categories: ProjectCategoriesDto::from(fixture::form::categories2()),
                     attributes: ProjectAttributesDto::from(fixture::form::attributes2()),
+                    included_projects: fixture::form::included_projects2()
+                        .into_iter()
+                        .map(|id| id.value().to_string())
+                        .collect(),
+                    excluded_projects: fixture::form::excluded_projects2()
+                        .into_iter()
+                        .map(|id| id.value().to_string())
+                        .collect(),
                     items: vec![NewFormItemDto::new(
                         fixture::form::formitem_name1().value(),
                         Some(fixture::form::description1().value()),
@@ -3,8 +3,9 @@ use std::sync::Arc;
 use sos24_domain::entity::form::FormItemIdError;
 use thiserror::Error;
 
-use sos24_domain::entity::file_data::{FileId, FileIdError};
-use sos24_domain::repository::file_data::FileDataRepositoryError;
+use sos24_domain::entity::file_data::{FileData, FileId, FileIdError};
+use sos24_domain::entity::form_answer::{FormAnswer, FormAnswerItemKind};
+use sos24_domain::repository::file_data::{FileDataRepository, FileDataRepositoryError};
 use sos24_domain::{
     entity::{
         form::{FormId, FormIdError},
@@ -19,6 +20,7 @@ use sos24_domain::{
     service::verify_form_answer::VerifyFormAnswerError,
 };
 
+use crate::shared::adapter::Adapters;
 use crate::shared::context::ContextError;
 
 use super::form::FormUseCaseError;
@@ -75,16 +77,40 @@ pub enum FormAnswerUseCaseError {
     InternalError(#[from] anyhow::Error),
 }
 
-pub struct FormAnswerUseCase<R: Repositories> {
+pub struct FormAnswerUseCase<R: Repositories, A: Adapters> {
     repositories: Arc<R>,
+    adapters: Arc<A>,
     creation_lock: tokio::sync::Mutex<()>, // FIXME
 }
 
-impl<R: Repositories> FormAnswerUseCase<R> {
-    pub fn new(repositories: Arc<R>) -> Self {
+impl<R: Repositories, A: Adapters> FormAnswerUseCase<R, A> {
+    pub fn new(repositories: Arc<R>, adapters: Arc<A>) -> Self {
         Self {
             repositories,
+            adapters,
             creation_lock: tokio::sync::Mutex::new(()),
         }
     }
+
+    // 回答が参照しているファイルを取得する
+    async fn find_answered_files(
+        &self,
+        form_answer: &FormAnswer,
+    ) -> Result<Vec<FileData>, FormAnswerUseCaseError> {
+        let mut file_data_list = Vec::new();
+        for item in form_answer.items() {
+            if let FormAnswerItemKind::File(value) = item.kind() {
+                for file_id in value.clone().value() {
+                    let file_data = self
+                        .repositories
+                        .file_data_repository()
+                        .find_by_id(file_id.clone())
+                        .await?
+                        .ok_or(FormAnswerUseCaseError::FileNotFound(file_id))?;
+                    file_data_list.push(file_data);
+                }
+            }
+        }
+        Ok(file_data_list)
+    }
 }
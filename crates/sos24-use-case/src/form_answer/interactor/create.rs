@@ -1,6 +1,5 @@
 use sos24_domain::entity::form::FormId;
-use sos24_domain::entity::form_answer::{FormAnswer, FormAnswerItem, FormAnswerItemKind};
-use sos24_domain::repository::file_data::FileDataRepository;
+use sos24_domain::entity::form_answer::{FormAnswer, FormAnswerItem};
 use sos24_domain::{
     ensure,
     entity::permission::Permissions,
@@ -13,6 +12,10 @@ use sos24_domain::{
 
 use crate::form_answer::dto::FormAnswerItemDto;
 use crate::form_answer::{FormAnswerUseCase, FormAnswerUseCaseError};
+use crate::shared::adapter::{
+    event::{Event, EventBus},
+    Adapters,
+};
 use crate::shared::context::ContextProvider;
 
 #[derive(Debug)]
@@ -21,7 +24,7 @@ pub struct CreateFormAnswerCommand {
     pub items: Vec<FormAnswerItemDto>,
 }
 
-impl<R: Repositories> FormAnswerUseCase<R> {
+impl<R: Repositories, A: Adapters> FormAnswerUseCase<R, A> {
     pub async fn create(
         &self,
         ctx: &impl ContextProvider,
@@ -65,20 +68,8 @@ impl<R: Repositories> FormAnswerUseCase<R> {
                 form_answer.form_id().clone(),
             ))?;
 
-        for item in form_answer.items() {
-            if let FormAnswerItemKind::File(value) = item.kind() {
-                for file_id in value.clone().value() {
-                    let _ = self
-                        .repositories
-                        .file_data_repository()
-                        .find_by_id(file_id.clone())
-                        .await?
-                        .ok_or(FormAnswerUseCaseError::FileNotFound(file_id))?;
-                }
-            }
-        }
-
-        verify_form_answer::verify(&form, &form_answer)?;
+        let file_data_list = self.find_answered_files(&form_answer).await?;
+        verify_form_answer::verify(&form, &form_answer, &file_data_list)?;
 
         let form_answer_id = {
             let lock = self.creation_lock.lock().await;
@@ -98,13 +89,17 @@ impl<R: Repositories> FormAnswerUseCase<R> {
             let form_answer_id = form_answer.id().clone();
             self.repositories
                 .form_answer_repository()
-                .create(form_answer)
+                .create(form_answer.clone())
                 .await?;
 
             drop(lock);
             form_answer_id
         };
 
+        self.adapters
+            .event_bus()
+            .publish(Event::FormAnswerSubmitted(form_answer));
+
         Ok(form_answer_id.value().to_string())
     }
 }
@@ -123,7 +118,7 @@ mod tests {
             dto::FormAnswerItemDto, interactor::create::CreateFormAnswerCommand, FormAnswerUseCase,
             FormAnswerUseCaseError,
         },
-        shared::context::TestContext,
+        shared::{adapter::MockAdapters, context::TestContext},
     };
 
     #[tokio::test]
@@ -157,7 +152,9 @@ mod tests {
             .form_answer_repository_mut()
             .expect_create()
             .returning(|_| Ok(()));
-        let use_case = FormAnswerUseCase::new(Arc::new(repositories));
+        let mut adapters = MockAdapters::default();
+        adapters.event_bus_mut().expect_publish().returning(|_| ());
+        let use_case = FormAnswerUseCase::new(Arc::new(repositories), Arc::new(adapters));
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
         let res = use_case
@@ -186,7 +183,8 @@ mod tests {
             .project_repository_mut()
             .expect_find_by_sub_owner_id()
             .returning(|_| Ok(None));
-        let use_case = FormAnswerUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = FormAnswerUseCase::new(Arc::new(repositories), Arc::new(adapters));
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeOperator));
         let res = use_case
@@ -239,7 +237,8 @@ mod tests {
             .form_answer_repository_mut()
             .expect_create()
             .returning(|_| Ok(()));
-        let use_case = FormAnswerUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = FormAnswerUseCase::new(Arc::new(repositories), Arc::new(adapters));
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
         let res = use_case
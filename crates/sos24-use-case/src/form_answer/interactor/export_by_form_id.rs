@@ -12,16 +12,17 @@ use sos24_domain::{
 
 use crate::form_answer::dto::{FormAnswerToBeExportedDto, FormAnswerToBeExportedListDto};
 use crate::form_answer::{FormAnswerUseCase, FormAnswerUseCaseError};
+use crate::shared::adapter::Adapters;
 use crate::shared::context::ContextProvider;
 
-impl<R: Repositories> FormAnswerUseCase<R> {
+impl<R: Repositories, A: Adapters> FormAnswerUseCase<R, A> {
     pub async fn export_by_form_id(
         &self,
         ctx: &impl ContextProvider,
         form_id: String,
     ) -> Result<FormAnswerToBeExportedListDto, FormAnswerUseCaseError> {
         let actor = ctx.actor(&*self.repositories).await?;
-        ensure!(actor.has_permission(Permissions::READ_FORM_ANSWER_ALL));
+        ensure!(actor.has_permission_in_any_category(Permissions::READ_FORM_ANSWER_ALL));
 
         let form_id = FormId::try_from(form_id.clone())?;
         let form = self
@@ -35,6 +36,12 @@ impl<R: Repositories> FormAnswerUseCase<R> {
         let target_project_list: Vec<_> = project_list
             .into_iter()
             .filter(|project_with_owners| form.is_sent_to(&project_with_owners.project))
+            .filter(|project_with_owners| {
+                actor.has_project_permission(
+                    Permissions::READ_FORM_ANSWER_ALL,
+                    *project_with_owners.project.category(),
+                )
+            })
             .collect();
 
         let header = export_header(&form);
@@ -7,16 +7,17 @@ use sos24_domain::{
 
 use crate::form_answer::dto::FormAnswerDto;
 use crate::form_answer::{FormAnswerUseCase, FormAnswerUseCaseError};
+use crate::shared::adapter::Adapters;
 use crate::shared::context::ContextProvider;
 
-impl<R: Repositories> FormAnswerUseCase<R> {
+impl<R: Repositories, A: Adapters> FormAnswerUseCase<R, A> {
     pub async fn find_by_form_id(
         &self,
         ctx: &impl ContextProvider,
         form_id: String,
     ) -> Result<Vec<FormAnswerDto>, FormAnswerUseCaseError> {
         let actor = ctx.actor(&*self.repositories).await?;
-        ensure!(actor.has_permission(Permissions::READ_FORM_ANSWER_ALL));
+        ensure!(actor.has_permission_in_any_category(Permissions::READ_FORM_ANSWER_ALL));
 
         let form_id = FormId::try_from(form_id)?;
         let raw_form = self
@@ -41,6 +42,12 @@ impl<R: Repositories> FormAnswerUseCase<R> {
                 .find_by_id(project_id.clone())
                 .await?
                 .ok_or(FormAnswerUseCaseError::ProjectNotFound(project_id.clone()))?;
+            if !actor.has_project_permission(
+                Permissions::READ_FORM_ANSWER_ALL,
+                *project_with_owners.project.category(),
+            ) {
+                continue;
+            }
             form_answer_list.push(FormAnswerDto::from((
                 raw_form_answer,
                 project_with_owners.project,
@@ -63,13 +70,14 @@ mod tests {
 
     use crate::{
         form_answer::{FormAnswerUseCase, FormAnswerUseCaseError},
-        shared::context::TestContext,
+        shared::{adapter::MockAdapters, context::TestContext},
     };
 
     #[tokio::test]
     async fn 一般ユーザーは特定の申請の回答一覧を取得できない() {
         let repositories = MockRepositories::default();
-        let use_case = FormAnswerUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = FormAnswerUseCase::new(Arc::new(repositories), Arc::new(adapters));
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
         let res = use_case
@@ -94,7 +102,8 @@ mod tests {
             .form_answer_repository_mut()
             .expect_find_by_form_id()
             .returning(|_| Ok(vec![]));
-        let use_case = FormAnswerUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = FormAnswerUseCase::new(Arc::new(repositories), Arc::new(adapters));
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeViewer));
         let res = use_case
@@ -7,9 +7,10 @@ use sos24_domain::{
 
 use crate::form_answer::dto::FormAnswerDto;
 use crate::form_answer::{FormAnswerUseCase, FormAnswerUseCaseError};
+use crate::shared::adapter::Adapters;
 use crate::shared::context::ContextProvider;
 
-impl<R: Repositories> FormAnswerUseCase<R> {
+impl<R: Repositories, A: Adapters> FormAnswerUseCase<R, A> {
     pub async fn find_by_id(
         &self,
         ctx: &impl ContextProvider,
@@ -61,7 +62,7 @@ mod tests {
 
     use crate::{
         form_answer::{FormAnswerUseCase, FormAnswerUseCaseError},
-        shared::context::TestContext,
+        shared::{adapter::MockAdapters, context::TestContext},
     };
 
     #[tokio::test]
@@ -87,7 +88,8 @@ mod tests {
             .form_repository_mut()
             .expect_find_by_id()
             .returning(|_| Ok(Some(fixture::form::form1_opened())));
-        let use_case = FormAnswerUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = FormAnswerUseCase::new(Arc::new(repositories), Arc::new(adapters));
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
         let res = use_case
@@ -115,7 +117,8 @@ mod tests {
                     fixture::user::user2(UserRole::General),
                 )))
             });
-        let use_case = FormAnswerUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = FormAnswerUseCase::new(Arc::new(repositories), Arc::new(adapters));
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
         let res = use_case
@@ -152,7 +155,8 @@ mod tests {
             .form_repository_mut()
             .expect_find_by_id()
             .returning(|_| Ok(Some(fixture::form::form1_opened())));
-        let use_case = FormAnswerUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = FormAnswerUseCase::new(Arc::new(repositories), Arc::new(adapters));
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeViewer));
         let res = use_case
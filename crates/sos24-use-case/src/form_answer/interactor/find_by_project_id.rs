@@ -7,9 +7,10 @@ use sos24_domain::{
 
 use crate::form_answer::dto::FormAnswerDto;
 use crate::form_answer::{FormAnswerUseCase, FormAnswerUseCaseError};
+use crate::shared::adapter::Adapters;
 use crate::shared::context::ContextProvider;
 
-impl<R: Repositories> FormAnswerUseCase<R> {
+impl<R: Repositories, A: Adapters> FormAnswerUseCase<R, A> {
     pub async fn find_by_project_id(
         &self,
         ctx: &impl ContextProvider,
@@ -67,7 +68,7 @@ mod tests {
 
     use crate::{
         form_answer::{FormAnswerUseCase, FormAnswerUseCaseError},
-        shared::context::TestContext,
+        shared::{adapter::MockAdapters, context::TestContext},
     };
 
     #[tokio::test]
@@ -85,7 +86,8 @@ mod tests {
             .form_answer_repository_mut()
             .expect_find_by_project_id()
             .returning(|_| Ok(vec![]));
-        let use_case = FormAnswerUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = FormAnswerUseCase::new(Arc::new(repositories), Arc::new(adapters));
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
         let res = use_case
@@ -109,7 +111,8 @@ mod tests {
             .form_answer_repository_mut()
             .expect_find_by_project_id()
             .returning(|_| Ok(vec![]));
-        let use_case = FormAnswerUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = FormAnswerUseCase::new(Arc::new(repositories), Arc::new(adapters));
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
         let res = use_case
@@ -138,7 +141,8 @@ mod tests {
             .form_answer_repository_mut()
             .expect_find_by_project_id()
             .returning(|_| Ok(vec![]));
-        let use_case = FormAnswerUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = FormAnswerUseCase::new(Arc::new(repositories), Arc::new(adapters));
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeViewer));
         let res = use_case
@@ -8,15 +8,16 @@ use sos24_domain::{
 
 use crate::form_answer::dto::FormAnswerDto;
 use crate::form_answer::{FormAnswerUseCase, FormAnswerUseCaseError};
+use crate::shared::adapter::Adapters;
 use crate::shared::context::ContextProvider;
 
-impl<R: Repositories> FormAnswerUseCase<R> {
+impl<R: Repositories, A: Adapters> FormAnswerUseCase<R, A> {
     pub async fn list(
         &self,
         ctx: &impl ContextProvider,
     ) -> Result<Vec<FormAnswerDto>, FormAnswerUseCaseError> {
         let actor = ctx.actor(&*self.repositories).await?;
-        ensure!(actor.has_permission(Permissions::READ_FORM_ANSWER_ALL));
+        ensure!(actor.has_permission_in_any_category(Permissions::READ_FORM_ANSWER_ALL));
 
         let raw_form_answer_list = self.repositories.form_answer_repository().list().await?;
 
@@ -29,6 +30,12 @@ impl<R: Repositories> FormAnswerUseCase<R> {
                 .find_by_id(project_id.clone())
                 .await?
                 .ok_or(FormAnswerUseCaseError::ProjectNotFound(project_id.clone()))?;
+            if !actor.has_project_permission(
+                Permissions::READ_FORM_ANSWER_ALL,
+                *project_with_owners.project.category(),
+            ) {
+                continue;
+            }
 
             let form_id = raw_form_answer.form_id();
             let raw_form = self
@@ -60,13 +67,14 @@ mod tests {
 
     use crate::{
         form_answer::{FormAnswerUseCase, FormAnswerUseCaseError},
-        shared::context::TestContext,
+        shared::{adapter::MockAdapters, context::TestContext},
     };
 
     #[tokio::test]
     async fn 一般ユーザーは回答一覧を取得できない() {
         let repositories = MockRepositories::default();
-        let use_case = FormAnswerUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = FormAnswerUseCase::new(Arc::new(repositories), Arc::new(adapters));
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
         let res = use_case.list(&ctx).await;
@@ -85,10 +93,48 @@ mod tests {
             .form_answer_repository_mut()
             .expect_list()
             .returning(|| Ok(vec![]));
-        let use_case = FormAnswerUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = FormAnswerUseCase::new(Arc::new(repositories), Arc::new(adapters));
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeViewer));
         let res = use_case.list(&ctx).await;
         assert!(res.is_ok());
     }
+
+    #[tokio::test]
+    async fn 企画区分を限定された実委人は担当する企画の回答のみ取得できる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .form_answer_repository_mut()
+            .expect_list()
+            .returning(|| {
+                Ok(vec![
+                    fixture::form_answer::form_answer1(fixture::project::id1()),
+                    fixture::form_answer::form_answer1(fixture::project::id2()),
+                ])
+            });
+        repositories
+            .project_repository_mut()
+            .expect_find_by_id()
+            .returning(|id| {
+                let owner = fixture::user::user2(UserRole::General);
+                Ok(Some(if id == fixture::project::id1() {
+                    fixture::project::project_with_owners1(owner)
+                } else {
+                    fixture::project::project_with_owners2(owner)
+                }))
+            });
+        repositories
+            .form_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::form::form1_opened())));
+        let adapters = MockAdapters::default();
+        let use_case = FormAnswerUseCase::new(Arc::new(repositories), Arc::new(adapters));
+
+        let actor = fixture::actor::actor1(UserRole::General)
+            .with_custom_roles(&[fixture::custom_role::stage_role1()]);
+        let ctx = TestContext::new(actor);
+        let res = use_case.list(&ctx).await;
+        assert!(matches!(res, Ok(list) if list.len() == 1));
+    }
 }
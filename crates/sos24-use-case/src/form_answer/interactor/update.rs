@@ -1,13 +1,22 @@
 use sos24_domain::{
     ensure,
     entity::form_answer::{FormAnswerId, FormAnswerItem},
-    repository::{form::FormRepository, form_answer::FormAnswerRepository, Repositories},
+    repository::{
+        form::FormRepository, form_answer::FormAnswerRepository, project::ProjectRepository,
+        Repositories,
+    },
     service::verify_form_answer,
 };
 
 use crate::{
     form_answer::{dto::FormAnswerItemDto, FormAnswerUseCase, FormAnswerUseCaseError},
-    shared::context::ContextProvider,
+    shared::{
+        adapter::{
+            event::{Event, EventBus},
+            Adapters,
+        },
+        context::ContextProvider,
+    },
 };
 
 pub struct UpdateFormAnswerCommand {
@@ -15,7 +24,7 @@ pub struct UpdateFormAnswerCommand {
     pub items: Vec<FormAnswerItemDto>,
 }
 
-impl<R: Repositories> FormAnswerUseCase<R> {
+impl<R: Repositories, A: Adapters> FormAnswerUseCase<R, A> {
     pub async fn update(
         &self,
         ctx: &impl ContextProvider,
@@ -34,7 +43,16 @@ impl<R: Repositories> FormAnswerUseCase<R> {
             .await?
             .ok_or(FormAnswerUseCaseError::NotFound(id))?;
 
-        ensure!(form_answer.is_updatable_by(&actor, owned_project_id.clone()));
+        let project_id = form_answer.project_id().clone();
+        let answered_project = self
+            .repositories
+            .project_repository()
+            .find_by_id(project_id.clone())
+            .await?
+            .ok_or(FormAnswerUseCaseError::ProjectNotFound(project_id))?;
+        let project_category = *answered_project.project.category();
+
+        ensure!(form_answer.is_updatable_by(&actor, owned_project_id.clone(), project_category));
 
         let form_id = form_answer.form_id().clone();
         let form = self
@@ -54,14 +72,20 @@ impl<R: Repositories> FormAnswerUseCase<R> {
             .into_iter()
             .map(FormAnswerItem::try_from)
             .collect::<Result<_, _>>()?;
-        new_form_answer.set_items(&actor, owned_project_id, new_items)?;
+        new_form_answer.set_items(&actor, owned_project_id, project_category, new_items)?;
 
-        verify_form_answer::verify(&form, &new_form_answer)?;
+        let file_data_list = self.find_answered_files(&new_form_answer).await?;
+        verify_form_answer::verify(&form, &new_form_answer, &file_data_list)?;
 
         self.repositories
             .form_answer_repository()
-            .update(new_form_answer)
+            .update(new_form_answer.clone())
             .await?;
+
+        self.adapters
+            .event_bus()
+            .publish(Event::FormAnswerUpdated(new_form_answer));
+
         Ok(())
     }
 }
@@ -80,7 +104,7 @@ mod tests {
             dto::FormAnswerItemDto, interactor::update::UpdateFormAnswerCommand, FormAnswerUseCase,
             FormAnswerUseCaseError,
         },
-        shared::context::TestContext,
+        shared::{adapter::MockAdapters, context::TestContext},
     };
 
     #[tokio::test]
@@ -94,6 +118,14 @@ mod tests {
                     fixture::user::user1(UserRole::General),
                 )))
             });
+        repositories
+            .project_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| {
+                Ok(Some(fixture::project::project_with_owners1(
+                    fixture::user::user1(UserRole::General),
+                )))
+            });
         repositories
             .form_answer_repository_mut()
             .expect_find_by_id()
@@ -110,7 +142,9 @@ mod tests {
             .form_answer_repository_mut()
             .expect_update()
             .returning(|_| Ok(()));
-        let use_case = FormAnswerUseCase::new(Arc::new(repositories));
+        let mut adapters = MockAdapters::default();
+        adapters.event_bus_mut().expect_publish().returning(|_| ());
+        let use_case = FormAnswerUseCase::new(Arc::new(repositories), Arc::new(adapters));
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
         let res = use_case
@@ -139,6 +173,14 @@ mod tests {
                     fixture::user::user1(UserRole::General),
                 )))
             });
+        repositories
+            .project_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| {
+                Ok(Some(fixture::project::project_with_owners1(
+                    fixture::user::user1(UserRole::General),
+                )))
+            });
         repositories
             .form_answer_repository_mut()
             .expect_find_by_id()
@@ -155,7 +197,8 @@ mod tests {
             .form_answer_repository_mut()
             .expect_update()
             .returning(|_| Ok(()));
-        let use_case = FormAnswerUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = FormAnswerUseCase::new(Arc::new(repositories), Arc::new(adapters));
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
         let res = use_case
@@ -189,6 +232,14 @@ mod tests {
             .project_repository_mut()
             .expect_find_by_sub_owner_id()
             .returning(|_| Ok(None));
+        repositories
+            .project_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| {
+                Ok(Some(fixture::project::project_with_owners1(
+                    fixture::user::user1(UserRole::General),
+                )))
+            });
         repositories
             .form_answer_repository_mut()
             .expect_find_by_id()
@@ -205,7 +256,9 @@ mod tests {
             .form_answer_repository_mut()
             .expect_update()
             .returning(|_| Ok(()));
-        let use_case = FormAnswerUseCase::new(Arc::new(repositories));
+        let mut adapters = MockAdapters::default();
+        adapters.event_bus_mut().expect_publish().returning(|_| ());
+        let use_case = FormAnswerUseCase::new(Arc::new(repositories), Arc::new(adapters));
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeOperator));
         let res = use_case
@@ -238,6 +291,14 @@ mod tests {
             .project_repository_mut()
             .expect_find_by_sub_owner_id()
             .returning(|_| Ok(None));
+        repositories
+            .project_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| {
+                Ok(Some(fixture::project::project_with_owners1(
+                    fixture::user::user1(UserRole::General),
+                )))
+            });
         repositories
             .form_answer_repository_mut()
             .expect_find_by_id()
@@ -254,7 +315,8 @@ mod tests {
             .form_answer_repository_mut()
             .expect_update()
             .returning(|_| Ok(()));
-        let use_case = FormAnswerUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = FormAnswerUseCase::new(Arc::new(repositories), Arc::new(adapters));
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeViewer));
         let res = use_case
@@ -283,6 +345,14 @@ mod tests {
             .project_repository_mut()
             .expect_find_by_sub_owner_id()
             .returning(|_| Ok(None));
+        repositories
+            .project_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| {
+                Ok(Some(fixture::project::project_with_owners1(
+                    fixture::user::user1(UserRole::General),
+                )))
+            });
         repositories
             .form_answer_repository_mut()
             .expect_find_by_id()
@@ -299,7 +369,9 @@ mod tests {
             .form_answer_repository_mut()
             .expect_update()
             .returning(|_| Ok(()));
-        let use_case = FormAnswerUseCase::new(Arc::new(repositories));
+        let mut adapters = MockAdapters::default();
+        adapters.event_bus_mut().expect_publish().returning(|_| ());
+        let use_case = FormAnswerUseCase::new(Arc::new(repositories), Arc::new(adapters));
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeOperator));
         let res = use_case
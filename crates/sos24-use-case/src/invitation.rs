@@ -17,7 +17,7 @@ use sos24_domain::{
     },
 };
 
-use crate::shared::context::ContextError;
+use crate::shared::{adapter::Adapters, context::ContextError};
 
 pub mod dto;
 pub mod interactor;
@@ -59,15 +59,21 @@ pub enum InvitationUseCaseError {
     InternalError(#[from] anyhow::Error),
 }
 
-pub struct InvitationUseCase<R: Repositories> {
+pub struct InvitationUseCase<R: Repositories, A: Adapters> {
     repositories: Arc<R>,
+    adapters: Arc<A>,
     project_application_period: ProjectApplicationPeriod, // TODO
 }
 
-impl<R: Repositories> InvitationUseCase<R> {
-    pub fn new(repositories: Arc<R>, project_application_period: ProjectApplicationPeriod) -> Self {
+impl<R: Repositories, A: Adapters> InvitationUseCase<R, A> {
+    pub fn new(
+        repositories: Arc<R>,
+        adapters: Arc<A>,
+        project_application_period: ProjectApplicationPeriod,
+    ) -> Self {
         Self {
             repositories,
+            adapters,
             project_application_period,
         }
     }
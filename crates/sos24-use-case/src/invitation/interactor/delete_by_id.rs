@@ -6,10 +6,10 @@ use sos24_domain::{
 
 use crate::{
     invitation::{InvitationUseCase, InvitationUseCaseError},
-    shared::context::ContextProvider,
+    shared::{adapter::Adapters, context::ContextProvider},
 };
 
-impl<R: Repositories> InvitationUseCase<R> {
+impl<R: Repositories, A: Adapters> InvitationUseCase<R, A> {
     pub async fn delete_by_id(
         &self,
         ctx: &impl ContextProvider,
@@ -46,14 +46,16 @@ mod tests {
 
     use crate::{
         invitation::{InvitationUseCase, InvitationUseCaseError},
-        shared::context::TestContext,
+        shared::{adapter::MockAdapters, context::TestContext},
     };
 
     #[tokio::test]
     async fn 実委人は招待を削除できない() {
         let repositories = MockRepositories::default();
+        let adapters = MockAdapters::default();
         let use_case = InvitationUseCase::new(
             Arc::new(repositories),
+            Arc::new(adapters),
             fixture::project_application_period::applicable_period(),
         );
 
@@ -86,8 +88,10 @@ mod tests {
             .invitation_repository_mut()
             .expect_delete_by_id()
             .returning(|_| Ok(()));
+        let adapters = MockAdapters::default();
         let use_case = InvitationUseCase::new(
             Arc::new(repositories),
+            Arc::new(adapters),
             fixture::project_application_period::applicable_period(),
         );
 
@@ -8,10 +8,10 @@ use sos24_domain::{
 
 use crate::{
     invitation::{dto::InvitationDto, InvitationUseCase, InvitationUseCaseError},
-    shared::context::ContextProvider,
+    shared::{adapter::Adapters, context::ContextProvider},
 };
 
-impl<R: Repositories> InvitationUseCase<R> {
+impl<R: Repositories, A: Adapters> InvitationUseCase<R, A> {
     pub async fn find_by_id(
         &self,
         _ctx: &impl ContextProvider,
@@ -58,7 +58,10 @@ mod tests {
         test::{fixture, repository::MockRepositories},
     };
 
-    use crate::{invitation::InvitationUseCase, shared::context::TestContext};
+    use crate::{
+        invitation::InvitationUseCase,
+        shared::{adapter::MockAdapters, context::TestContext},
+    };
 
     #[tokio::test]
     async fn 一般ユーザーは自分の企画への招待を取得できる() {
@@ -85,8 +88,10 @@ mod tests {
                     fixture::user::user1(UserRole::General),
                 )))
             });
+        let adapters = MockAdapters::default();
         let use_case = InvitationUseCase::new(
             Arc::new(repositories),
+            Arc::new(adapters),
             fixture::project_application_period::applicable_period(),
         );
 
@@ -122,8 +127,10 @@ mod tests {
                     fixture::user::user1(UserRole::General),
                 )))
             });
+        let adapters = MockAdapters::default();
         let use_case = InvitationUseCase::new(
             Arc::new(repositories),
+            Arc::new(adapters),
             fixture::project_application_period::applicable_period(),
         );
 
@@ -159,8 +166,10 @@ mod tests {
                     fixture::user::user1(UserRole::CommitteeViewer),
                 )))
             });
+        let adapters = MockAdapters::default();
         let use_case = InvitationUseCase::new(
             Arc::new(repositories),
+            Arc::new(adapters),
             fixture::project_application_period::applicable_period(),
         );
 
@@ -14,7 +14,7 @@ use sos24_domain::{
 
 use crate::{
     invitation::{dto::InvitationPositionDto, InvitationUseCase, InvitationUseCaseError},
-    shared::context::ContextProvider,
+    shared::{adapter::Adapters, context::ContextProvider},
 };
 
 #[derive(Debug)]
@@ -24,7 +24,7 @@ pub struct CreateInvitationCommand {
     pub position: InvitationPositionDto,
 }
 
-impl<R: Repositories> InvitationUseCase<R> {
+impl<R: Repositories, A: Adapters> InvitationUseCase<R, A> {
     pub async fn find_or_create(
         &self,
         ctx: &impl ContextProvider,
@@ -103,7 +103,7 @@ mod tests {
             dto::InvitationPositionDto, interactor::find_or_create::CreateInvitationCommand,
             InvitationUseCase, InvitationUseCaseError,
         },
-        shared::context::TestContext,
+        shared::{adapter::MockAdapters, context::TestContext},
     };
 
     #[tokio::test]
@@ -129,8 +129,10 @@ mod tests {
             .invitation_repository_mut()
             .expect_create()
             .returning(|_| Ok(()));
+        let adapters = MockAdapters::default();
         let use_case = InvitationUseCase::new(
             Arc::new(repositories),
+            Arc::new(adapters),
             fixture::project_application_period::applicable_period(),
         );
 
@@ -151,8 +153,10 @@ mod tests {
     #[tokio::test]
     async fn 一般ユーザーは企画募集期間外に自分の企画への招待を作成できない() {
         let repositories = MockRepositories::default();
+        let adapters = MockAdapters::default();
         let use_case = InvitationUseCase::new(
             Arc::new(repositories),
+            Arc::new(adapters),
             fixture::project_application_period::not_applicable_period(),
         );
 
@@ -194,8 +198,10 @@ mod tests {
             .invitation_repository_mut()
             .expect_create()
             .returning(|_| Ok(()));
+        let adapters = MockAdapters::default();
         let use_case = InvitationUseCase::new(
             Arc::new(repositories),
+            Arc::new(adapters),
             fixture::project_application_period::applicable_period(),
         );
 
@@ -241,8 +247,10 @@ mod tests {
             .invitation_repository_mut()
             .expect_create()
             .returning(|_| Ok(()));
+        let adapters = MockAdapters::default();
         let use_case = InvitationUseCase::new(
             Arc::new(repositories),
+            Arc::new(adapters),
             fixture::project_application_period::applicable_period(),
         );
 
@@ -283,8 +291,10 @@ mod tests {
             .invitation_repository_mut()
             .expect_create()
             .returning(|_| Ok(()));
+        let adapters = MockAdapters::default();
         let use_case = InvitationUseCase::new(
             Arc::new(repositories),
+            Arc::new(adapters),
             fixture::project_application_period::not_applicable_period(),
         );
 
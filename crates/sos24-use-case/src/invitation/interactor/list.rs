@@ -8,9 +8,10 @@ use sos24_domain::{
 
 use crate::invitation::dto::InvitationDto;
 use crate::invitation::{InvitationUseCase, InvitationUseCaseError};
+use crate::shared::adapter::Adapters;
 use crate::shared::context::ContextProvider;
 
-impl<R: Repositories> InvitationUseCase<R> {
+impl<R: Repositories, A: Adapters> InvitationUseCase<R, A> {
     pub async fn list(
         &self,
         ctx: &impl ContextProvider,
@@ -60,14 +61,16 @@ mod tests {
 
     use crate::{
         invitation::{InvitationUseCase, InvitationUseCaseError},
-        shared::context::TestContext,
+        shared::{adapter::MockAdapters, context::TestContext},
     };
 
     #[tokio::test]
     async fn 一般ユーザーは招待一覧を取得できない() {
         let repositories = MockRepositories::default();
+        let adapters = MockAdapters::default();
         let use_case = InvitationUseCase::new(
             Arc::new(repositories),
+            Arc::new(adapters),
             fixture::project_application_period::applicable_period(),
         );
 
@@ -88,8 +91,10 @@ mod tests {
             .invitation_repository_mut()
             .expect_list()
             .returning(|| Ok(vec![]));
+        let adapters = MockAdapters::default();
         let use_case = InvitationUseCase::new(
             Arc::new(repositories),
+            Arc::new(adapters),
             fixture::project_application_period::applicable_period(),
         );
 
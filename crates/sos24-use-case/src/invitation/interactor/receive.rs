@@ -1,6 +1,9 @@
 use sos24_domain::{
     entity::{
         invitation::{InvitationId, InvitationPosition},
+        notification::{
+            Notification, NotificationBody, NotificationKind, NotificationTitle, NotificationUrl,
+        },
         user::UserId,
     },
     repository::{invitation::InvitationRepository, project::ProjectRepository, Repositories},
@@ -8,10 +11,18 @@ use sos24_domain::{
 
 use crate::{
     invitation::{InvitationUseCase, InvitationUseCaseError},
-    shared::context::ContextProvider,
+    shared::{
+        adapter::{
+            event::{Event, EventBus},
+            notification::Notifier,
+            Adapters,
+        },
+        app_url,
+        context::ContextProvider,
+    },
 };
 
-impl<R: Repositories> InvitationUseCase<R> {
+impl<R: Repositories, A: Adapters> InvitationUseCase<R, A> {
     pub async fn receive(
         &self,
         ctx: &impl ContextProvider,
@@ -41,6 +52,7 @@ impl<R: Repositories> InvitationUseCase<R> {
             .await?
             .ok_or(InvitationUseCaseError::ProjectNotFound(project_id))?;
 
+        let project_title = project_with_owners.project.title().clone().value();
         let mut new_project = project_with_owners.project;
         let user_id = UserId::new(ctx.user_id().clone());
         match invitation.position() {
@@ -54,9 +66,28 @@ impl<R: Repositories> InvitationUseCase<R> {
 
         let mut new_invitation = invitation;
         new_invitation.receive(actor.user_id().clone())?;
+        let notification = Notification::create(
+            new_invitation.inviter().clone(),
+            NotificationKind::InvitationReceived,
+            NotificationTitle::new(format!("企画「{project_title}」への招待が受け取られました")),
+            NotificationBody::new(match new_invitation.position() {
+                InvitationPosition::Owner => "企画責任者が変更されました。".to_string(),
+                InvitationPosition::SubOwner => "副企画責任者が登録されました。".to_string(),
+            }),
+            NotificationUrl::new(app_url::project(ctx)),
+        );
         self.repositories
             .invitation_repository()
-            .update(new_invitation)
+            .update(new_invitation.clone())
+            .await?;
+
+        self.adapters
+            .event_bus()
+            .publish(Event::InvitationReceived(new_invitation));
+
+        self.adapters
+            .notifier()
+            .notify_users(vec![notification])
             .await?;
 
         Ok(())
@@ -72,7 +103,10 @@ mod tests {
         test::{fixture, repository::MockRepositories},
     };
 
-    use crate::{invitation::InvitationUseCase, shared::context::TestContext};
+    use crate::{
+        invitation::InvitationUseCase,
+        shared::{adapter::MockAdapters, context::TestContext},
+    };
 
     #[tokio::test]
     async fn 一般ユーザーは招待を受けられる() {
@@ -111,8 +145,15 @@ mod tests {
             .invitation_repository_mut()
             .expect_update()
             .returning(|_| Ok(()));
+        let mut adapters = MockAdapters::default();
+        adapters
+            .notifier_mut()
+            .expect_notify_users()
+            .returning(|_| Ok(()));
+        adapters.event_bus_mut().expect_publish().returning(|_| ());
         let use_case = InvitationUseCase::new(
             Arc::new(repositories),
+            Arc::new(adapters),
             fixture::project_application_period::applicable_period(),
         );
 
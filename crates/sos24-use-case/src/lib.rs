@@ -1,8 +1,12 @@
+pub mod event;
 pub mod file;
 pub mod form;
 pub mod form_answer;
 pub mod invitation;
 pub mod news;
+pub mod notification;
 pub mod project;
+pub mod role;
 pub mod shared;
 pub mod user;
+pub mod webhook;
@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use sos24_domain::entity::project::ProjectIdError;
 use sos24_domain::entity::user::UserId;
 use sos24_domain::repository::project::ProjectRepositoryError;
 use sos24_domain::repository::user::UserRepositoryError;
@@ -9,7 +10,7 @@ use sos24_domain::entity::file_data::{FileId, FileIdError};
 use sos24_domain::repository::file_data::FileDataRepositoryError;
 use sos24_domain::{
     entity::{
-        common::datetime::DateTimeError,
+        common::{datetime::DateTimeError, markdown::MarkdownError},
         news::{NewsId, NewsIdError},
         permission::PermissionDeniedError,
     },
@@ -41,6 +42,8 @@ pub enum NewsUseCaseError {
     #[error(transparent)]
     FileIdError(#[from] FileIdError),
     #[error(transparent)]
+    ProjectIdError(#[from] ProjectIdError),
+    #[error(transparent)]
     ProjectUseCaseError(#[from] ProjectUseCaseError),
     #[error(transparent)]
     ContextError(#[from] ContextError),
@@ -53,6 +56,8 @@ pub enum NewsUseCaseError {
     #[error(transparent)]
     DateTimeError(#[from] DateTimeError),
     #[error(transparent)]
+    MarkdownError(#[from] MarkdownError),
+    #[error(transparent)]
     InternalError(#[from] anyhow::Error),
 }
 
@@ -8,9 +8,12 @@ pub struct NewsDto {
     pub state: NewsStateDto,
     pub title: String,
     pub body: String,
+    pub body_html: String,
     pub attachments: Vec<String>,
     pub categories: ProjectCategoriesDto,
     pub attributes: ProjectAttributesDto,
+    pub included_projects: Vec<String>,
+    pub excluded_projects: Vec<String>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
     pub scheduled_at: Option<String>,
@@ -43,6 +46,7 @@ impl From<News> for NewsDto {
             id: news.id.value().to_string(),
             state,
             title: news.title.value(),
+            body_html: news.body.to_html(),
             body: news.body.value(),
             attachments: news
                 .attachments
@@ -51,6 +55,16 @@ impl From<News> for NewsDto {
                 .collect(),
             categories: ProjectCategoriesDto::from(news.categories),
             attributes: ProjectAttributesDto::from(news.attributes),
+            included_projects: news
+                .included_projects
+                .into_iter()
+                .map(|project_id| project_id.value().to_string())
+                .collect(),
+            excluded_projects: news
+                .excluded_projects
+                .into_iter()
+                .map(|project_id| project_id.value().to_string())
+                .collect(),
             created_at: news.created_at.value(),
             updated_at: news.updated_at.value(),
             scheduled_at,
@@ -1,6 +1,71 @@
+use sos24_domain::entity::news::News;
+
+use crate::shared::{
+    adapter::email::{escape_html, Email, SendEmailCommand},
+    app_url,
+    context::ContextProvider,
+};
+
 pub mod check_news_and_send_notify;
 pub mod create;
 pub mod delete_by_id;
 pub mod find_by_id;
 pub mod list;
 pub mod update;
+
+// お知らせの公開を企画責任者・副責任者に知らせるメールを組み立てる
+fn news_published_email(
+    ctx: &impl ContextProvider,
+    news: &News,
+    to: Vec<String>,
+) -> SendEmailCommand {
+    SendEmailCommand {
+        from: Email {
+            address: ctx.config().email_sender_address.clone(),
+            name: String::from("雙峰祭オンラインシステム"),
+        },
+        to,
+        reply_to: Some(ctx.config().email_reply_to_address.clone()),
+        subject: format!(
+            "お知らせ「{title}」が公開されました",
+            title = news.title().clone().value()
+        ),
+        body: format!(
+            r#"雙峰祭オンラインシステムでお知らせが公開されました。
+
+タイトル: {title}
+本文:
+{body}
+
+詳細は以下のリンクから確認できます。
+{url}
+
+※このメールは雙峰祭オンラインシステムが自動送信しています。
+＿＿＿
+筑波大学学園祭実行委員会
+Email : {email}
+電話 : 029-853-2899"#,
+            title = news.title().clone().value(),
+            body = news.body().to_plain_text(),
+            url = app_url::news(ctx, news.id().clone()),
+            email = ctx.config().email_reply_to_address.clone(),
+        ),
+        html_body: Some(format!(
+            r#"<p>雙峰祭オンラインシステムでお知らせが公開されました。</p>
+<p>タイトル: {title}</p>
+<p>本文:</p>
+{body}
+<p>詳細は以下のリンクから確認できます。<br>
+<a href="{url}">{url}</a></p>
+<p>※このメールは雙峰祭オンラインシステムが自動送信しています。<br>
+＿＿＿<br>
+筑波大学学園祭実行委員会<br>
+Email : {email}<br>
+電話 : 029-853-2899</p>"#,
+            title = escape_html(&news.title().clone().value()),
+            body = news.body().to_html(),
+            url = escape_html(&app_url::news(ctx, news.id().clone())),
+            email = escape_html(&ctx.config().email_reply_to_address),
+        )),
+    }
+}
@@ -2,15 +2,19 @@ use sos24_domain::{
     entity::{
         common::datetime::DateTime,
         news::{News, NewsState},
+        notification::{
+            Notification, NotificationBody, NotificationKind, NotificationTitle, NotificationUrl,
+        },
     },
     repository::{news::NewsRepository, project::ProjectRepository, Repositories},
 };
 
 use crate::{
-    news::{NewsUseCase, NewsUseCaseError},
+    news::{interactor::news_published_email, NewsUseCase, NewsUseCaseError},
     shared::{
         adapter::{
-            email::{Email, EmailSender, SendEmailCommand},
+            email::EmailSender,
+            event::{Event, EventBus},
             notification::Notifier,
             Adapters,
         },
@@ -34,9 +38,11 @@ impl<R: Repositories, A: Adapters> NewsUseCase<R, A> {
         for news in news_list_to_notify {
             let target_project_list = project_list
                 .iter()
-                .filter(|project_with_owners| news.is_sent_to(&project_with_owners.project));
+                .filter(|project_with_owners| news.is_sent_to(&project_with_owners.project))
+                .collect::<Vec<_>>();
 
             let emails = target_project_list
+                .iter()
                 .flat_map(|project_with_owners| {
                     [
                         Some(project_with_owners.owner.email().clone().value()),
@@ -49,40 +55,36 @@ impl<R: Repositories, A: Adapters> NewsUseCase<R, A> {
                 .flatten()
                 .collect::<Vec<_>>();
 
-            let command = SendEmailCommand {
-                from: Email {
-                    address: ctx.config().email_sender_address.clone(),
-                    name: String::from("雙峰祭オンラインシステム"),
-                },
-                to: emails,
-                reply_to: Some(ctx.config().email_reply_to_address.clone()),
-                subject: format!(
-                    "お知らせ「{title}」が公開されました",
-                    title = news.title().clone().value()
-                ),
-                body: format!(
-                    r#"雙峰祭オンラインシステムでお知らせが公開されました。
-
-タイトル: {title}
-本文:
-{body}
-
-詳細は以下のリンクから確認できます。
-{url}
-
-※このメールは雙峰祭オンラインシステムが自動送信しています。
-＿＿＿
-筑波大学学園祭実行委員会
-Email : {email}
-電話 : 029-853-2899"#,
-                    title = news.title().clone().value(),
-                    body = news.body().clone().value(),
-                    url = app_url::news(ctx, news.id().clone()),
-                    email = ctx.config().email_reply_to_address.clone(),
-                ),
-            };
+            let command = news_published_email(ctx, &news, emails);
             self.adapters.email_sender().send_email(command).await?;
 
+            let notifications = target_project_list
+                .iter()
+                .flat_map(|project_with_owners| {
+                    [
+                        Some(project_with_owners.owner.id().clone()),
+                        project_with_owners
+                            .sub_owner
+                            .as_ref()
+                            .map(|it| it.id().clone()),
+                    ]
+                })
+                .flatten()
+                .map(|user_id| {
+                    Notification::create(
+                        user_id,
+                        NotificationKind::NewsPublished,
+                        NotificationTitle::new(format!(
+                            "お知らせ「{}」が公開されました",
+                            news.title().clone().value()
+                        )),
+                        NotificationBody::new(news.body().to_plain_text()),
+                        NotificationUrl::new(app_url::news(ctx, news.id().clone())),
+                    )
+                })
+                .collect();
+            self.adapters.notifier().notify_users(notifications).await?;
+
             self.adapters
                 .notifier()
                 .notify(format!(
@@ -101,10 +103,19 @@ Email : {email}
                 news.attachments().clone(),
                 news.categories().clone(),
                 news.attributes().clone(),
+                news.included_projects().clone(),
+                news.excluded_projects().clone(),
                 DateTime::new(ctx.requested_at().clone()),
                 DateTime::new(ctx.requested_at().clone()),
             );
-            self.repositories.news_repository().update(new_news).await?;
+            self.repositories
+                .news_repository()
+                .update(new_news.clone())
+                .await?;
+
+            self.adapters
+                .event_bus()
+                .publish(Event::NewsPublished(new_news));
         }
 
         Ok(())
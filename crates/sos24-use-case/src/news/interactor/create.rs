@@ -5,8 +5,11 @@ use sos24_domain::{
         common::datetime::DateTime,
         file_data::FileId,
         news::{News, NewsBody, NewsState, NewsTitle},
+        notification::{
+            Notification, NotificationBody, NotificationKind, NotificationTitle, NotificationUrl,
+        },
         permission::Permissions,
-        project::{ProjectAttributes, ProjectCategories},
+        project::{ProjectAttributes, ProjectCategories, ProjectId},
     },
     repository::{
         file_data::FileDataRepository, news::NewsRepository, project::ProjectRepository,
@@ -15,11 +18,12 @@ use sos24_domain::{
 };
 
 use crate::{
-    news::{dto::NewsStateDto, NewsUseCase, NewsUseCaseError},
+    news::{dto::NewsStateDto, interactor::news_published_email, NewsUseCase, NewsUseCaseError},
     project::dto::{ProjectAttributesDto, ProjectCategoriesDto},
     shared::{
         adapter::{
-            email::{Email, EmailSender, SendEmailCommand},
+            email::EmailSender,
+            event::{Event, EventBus},
             notification::Notifier,
             Adapters,
         },
@@ -36,6 +40,8 @@ pub struct CreateNewsCommand {
     pub attachments: Vec<String>,
     pub categories: ProjectCategoriesDto,
     pub attributes: ProjectAttributesDto,
+    pub included_projects: Vec<String>,
+    pub excluded_projects: Vec<String>,
     pub scheduled_at: Option<String>,
 }
 
@@ -72,7 +78,7 @@ impl<R: Repositories, A: Adapters> NewsUseCase<R, A> {
         let news = News::create(
             raw_news.get_news_state()?,
             NewsTitle::new(raw_news.title),
-            NewsBody::new(raw_news.body),
+            NewsBody::try_from(raw_news.body)?,
             raw_news
                 .attachments
                 .into_iter()
@@ -80,6 +86,16 @@ impl<R: Repositories, A: Adapters> NewsUseCase<R, A> {
                 .collect::<Result<_, _>>()?,
             ProjectCategories::from(raw_news.categories),
             ProjectAttributes::from(raw_news.attributes),
+            raw_news
+                .included_projects
+                .into_iter()
+                .map(ProjectId::try_from)
+                .collect::<Result<_, _>>()?,
+            raw_news
+                .excluded_projects
+                .into_iter()
+                .map(ProjectId::try_from)
+                .collect::<Result<_, _>>()?,
         );
 
         for file_id in news.attachments() {
@@ -101,9 +117,11 @@ impl<R: Repositories, A: Adapters> NewsUseCase<R, A> {
             let project_list = self.repositories.project_repository().list().await?;
             let target_project_list = project_list
                 .into_iter()
-                .filter(|project_with_owners| news.is_sent_to(&project_with_owners.project));
+                .filter(|project_with_owners| news.is_sent_to(&project_with_owners.project))
+                .collect::<Vec<_>>();
 
             let emails = target_project_list
+                .iter()
                 .flat_map(|project_with_owners| {
                     [
                         Some(project_with_owners.owner.email().clone().value()),
@@ -116,39 +134,39 @@ impl<R: Repositories, A: Adapters> NewsUseCase<R, A> {
                 .flatten()
                 .collect::<Vec<_>>();
 
-            let command = SendEmailCommand {
-                from: Email {
-                    address: ctx.config().email_sender_address.clone(),
-                    name: String::from("雙峰祭オンラインシステム"),
-                },
-                to: emails,
-                reply_to: Some(ctx.config().email_reply_to_address.clone()),
-                subject: format!(
-                    "お知らせ「{title}」が公開されました",
-                    title = news.title().clone().value()
-                ),
-                body: format!(
-                    r#"雙峰祭オンラインシステムでお知らせが公開されました。
-
-タイトル: {title}
-本文:
-{body}
+            let command = news_published_email(ctx, &news, emails);
+            self.adapters.email_sender().send_email(command).await?;
 
-詳細は以下のリンクから確認できます。
-{url}
+            let notifications = target_project_list
+                .iter()
+                .flat_map(|project_with_owners| {
+                    [
+                        Some(project_with_owners.owner.id().clone()),
+                        project_with_owners
+                            .sub_owner
+                            .as_ref()
+                            .map(|it| it.id().clone()),
+                    ]
+                })
+                .flatten()
+                .map(|user_id| {
+                    Notification::create(
+                        user_id,
+                        NotificationKind::NewsPublished,
+                        NotificationTitle::new(format!(
+                            "お知らせ「{}」が公開されました",
+                            news.title().clone().value()
+                        )),
+                        NotificationBody::new(news.body().to_plain_text()),
+                        NotificationUrl::new(app_url::news(ctx, news.id().clone())),
+                    )
+                })
+                .collect();
+            self.adapters.notifier().notify_users(notifications).await?;
 
-※このメールは雙峰祭オンラインシステムが自動送信しています。
-＿＿＿
-筑波大学学園祭実行委員会
-Email : {email}
-電話 : 029-853-2899"#,
-                    title = news.title().clone().value(),
-                    body = news.body().clone().value(),
-                    url = app_url::news(ctx, news.id().clone()),
-                    email = ctx.config().email_reply_to_address.clone(),
-                ),
-            };
-            self.adapters.email_sender().send_email(command).await?;
+            self.adapters
+                .event_bus()
+                .publish(Event::NewsPublished(news.clone()));
         }
 
         self.adapters
@@ -219,6 +237,14 @@ mod tests {
                         .collect(),
                     categories: ProjectCategoriesDto::from(fixture::news::categories1()),
                     attributes: ProjectAttributesDto::from(fixture::news::attributes1()),
+                    included_projects: fixture::news::included_projects1()
+                        .into_iter()
+                        .map(|id| id.value().to_string())
+                        .collect(),
+                    excluded_projects: fixture::news::excluded_projects1()
+                        .into_iter()
+                        .map(|id| id.value().to_string())
+                        .collect(),
                     scheduled_at,
                 },
             )
@@ -251,6 +277,11 @@ mod tests {
             .notifier_mut()
             .expect_notify()
             .returning(|_| Ok(()));
+        adapters
+            .notifier_mut()
+            .expect_notify_users()
+            .returning(|_| Ok(()));
+        adapters.event_bus_mut().expect_publish().returning(|_| ());
         let use_case = NewsUseCase::new(Arc::new(repositories), Arc::new(adapters));
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeEditor));
@@ -268,6 +299,14 @@ mod tests {
                         .collect(),
                     categories: ProjectCategoriesDto::from(fixture::news::categories1()),
                     attributes: ProjectAttributesDto::from(fixture::news::attributes1()),
+                    included_projects: fixture::news::included_projects1()
+                        .into_iter()
+                        .map(|id| id.value().to_string())
+                        .collect(),
+                    excluded_projects: fixture::news::excluded_projects1()
+                        .into_iter()
+                        .map(|id| id.value().to_string())
+                        .collect(),
                     scheduled_at,
                 },
             )
@@ -295,6 +334,11 @@ mod tests {
             .notifier_mut()
             .expect_notify()
             .returning(|_| Ok(()));
+        adapters
+            .notifier_mut()
+            .expect_notify_users()
+            .returning(|_| Ok(()));
+        adapters.event_bus_mut().expect_publish().returning(|_| ());
         let use_case = NewsUseCase::new(Arc::new(repositories), Arc::new(adapters));
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeOperator));
@@ -312,6 +356,14 @@ mod tests {
                         .collect(),
                     categories: ProjectCategoriesDto::from(fixture::news::categories1()),
                     attributes: ProjectAttributesDto::from(fixture::news::attributes1()),
+                    included_projects: fixture::news::included_projects1()
+                        .into_iter()
+                        .map(|id| id.value().to_string())
+                        .collect(),
+                    excluded_projects: fixture::news::excluded_projects1()
+                        .into_iter()
+                        .map(|id| id.value().to_string())
+                        .collect(),
                     scheduled_at,
                 },
             )
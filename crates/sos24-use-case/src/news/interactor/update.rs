@@ -1,6 +1,6 @@
 use anyhow::anyhow;
 use sos24_domain::entity::file_data::FileId;
-use sos24_domain::entity::project::{ProjectAttributes, ProjectCategories};
+use sos24_domain::entity::project::{ProjectAttributes, ProjectCategories, ProjectId};
 use sos24_domain::repository::file_data::FileDataRepository;
 use sos24_domain::{
     entity::{
@@ -25,6 +25,8 @@ pub struct UpdateNewsCommand {
     pub attachments: Vec<String>,
     pub categories: ProjectCategoriesDto,
     pub attributes: ProjectAttributesDto,
+    pub included_projects: Vec<String>,
+    pub excluded_projects: Vec<String>,
     pub scheduled_at: Option<String>,
 }
 
@@ -72,7 +74,7 @@ impl<R: Repositories, A: Adapters> NewsUseCase<R, A> {
 
         new_news.set_state(&actor, new_state)?;
         new_news.set_title(&actor, NewsTitle::new(news_data.title))?;
-        new_news.set_body(&actor, NewsBody::new(news_data.body))?;
+        new_news.set_body(&actor, NewsBody::try_from(news_data.body)?)?;
 
         let new_attachments = news_data
             .attachments
@@ -94,6 +96,22 @@ impl<R: Repositories, A: Adapters> NewsUseCase<R, A> {
 
         new_news.set_categories(&actor, ProjectCategories::from(news_data.categories))?;
         new_news.set_attributes(&actor, ProjectAttributes::from(news_data.attributes))?;
+        new_news.set_included_projects(
+            &actor,
+            news_data
+                .included_projects
+                .into_iter()
+                .map(ProjectId::try_from)
+                .collect::<Result<_, _>>()?,
+        )?;
+        new_news.set_excluded_projects(
+            &actor,
+            news_data
+                .excluded_projects
+                .into_iter()
+                .map(ProjectId::try_from)
+                .collect::<Result<_, _>>()?,
+        )?;
 
         self.repositories.news_repository().update(new_news).await?;
         Ok(())
@@ -147,6 +165,14 @@ mod tests {
                         .collect(),
                     categories: ProjectCategoriesDto::from(fixture::news::categories2()),
                     attributes: ProjectAttributesDto::from(fixture::news::attributes2()),
+                    included_projects: fixture::news::included_projects2()
+                        .into_iter()
+                        .map(|id| id.value().to_string())
+                        .collect(),
+                    excluded_projects: fixture::news::excluded_projects2()
+                        .into_iter()
+                        .map(|id| id.value().to_string())
+                        .collect(),
                     scheduled_at,
                 },
             )
@@ -189,6 +215,14 @@ mod tests {
                         .collect(),
                     categories: ProjectCategoriesDto::from(fixture::news::categories2()),
                     attributes: ProjectAttributesDto::from(fixture::news::attributes2()),
+                    included_projects: fixture::news::included_projects2()
+                        .into_iter()
+                        .map(|id| id.value().to_string())
+                        .collect(),
+                    excluded_projects: fixture::news::excluded_projects2()
+                        .into_iter()
+                        .map(|id| id.value().to_string())
+                        .collect(),
                     scheduled_at,
                 },
             )
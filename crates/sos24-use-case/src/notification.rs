@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use sos24_domain::{
+    entity::{
+        notification::{NotificationId, NotificationIdError},
+        permission::PermissionDeniedError,
+    },
+    repository::{notification::NotificationRepositoryError, Repositories},
+};
+
+use crate::shared::context::ContextError;
+
+pub mod dto;
+pub mod interactor;
+
+#[derive(Debug, Error)]
+pub enum NotificationUseCaseError {
+    #[error("Notification not found: {0:?}")]
+    NotFound(NotificationId),
+
+    #[error(transparent)]
+    NotificationIdError(#[from] NotificationIdError),
+    #[error(transparent)]
+    NotificationRepositoryError(#[from] NotificationRepositoryError),
+    #[error(transparent)]
+    ContextError(#[from] ContextError),
+    #[error(transparent)]
+    PermissionDeniedError(#[from] PermissionDeniedError),
+    #[error(transparent)]
+    InternalError(#[from] anyhow::Error),
+}
+
+pub struct NotificationUseCase<R: Repositories> {
+    repositories: Arc<R>,
+}
+
+impl<R: Repositories> NotificationUseCase<R> {
+    pub fn new(repositories: Arc<R>) -> Self {
+        Self { repositories }
+    }
+}
@@ -0,0 +1,44 @@
+use sos24_domain::entity::notification::{Notification, NotificationKind};
+
+#[derive(Debug)]
+pub struct NotificationDto {
+    pub id: String,
+    pub kind: NotificationKindDto,
+    pub title: String,
+    pub body: String,
+    pub url: String,
+    pub read_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<Notification> for NotificationDto {
+    fn from(notification: Notification) -> Self {
+        let notification = notification.destruct();
+        Self {
+            id: notification.id.value().to_string(),
+            kind: NotificationKindDto::from(notification.kind),
+            title: notification.title.value(),
+            body: notification.body.value(),
+            url: notification.url.value(),
+            read_at: notification.read_at.map(|it| it.value()),
+            created_at: notification.created_at.value(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum NotificationKindDto {
+    NewsPublished,
+    FormStarted,
+    InvitationReceived,
+}
+
+impl From<NotificationKind> for NotificationKindDto {
+    fn from(entity: NotificationKind) -> Self {
+        match entity {
+            NotificationKind::NewsPublished => Self::NewsPublished,
+            NotificationKind::FormStarted => Self::FormStarted,
+            NotificationKind::InvitationReceived => Self::InvitationReceived,
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod count_unread;
+pub mod list;
+pub mod read;
+pub mod read_all;
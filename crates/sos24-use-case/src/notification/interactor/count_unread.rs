@@ -0,0 +1,26 @@
+use sos24_domain::ensure;
+use sos24_domain::{
+    entity::user::UserId,
+    repository::{notification::NotificationRepository, Repositories},
+};
+
+use crate::{
+    notification::{NotificationUseCase, NotificationUseCaseError},
+    shared::context::ContextProvider,
+};
+
+impl<R: Repositories> NotificationUseCase<R> {
+    pub async fn count_unread(
+        &self,
+        ctx: &impl ContextProvider,
+    ) -> Result<i64, NotificationUseCaseError> {
+        // 通知に対応するスコープはないので、APIトークンでは取得させない
+        ensure!(ctx.scopes().is_none());
+        let count = self
+            .repositories
+            .notification_repository()
+            .count_unread_by_user_id(UserId::new(ctx.user_id()))
+            .await?;
+        Ok(count)
+    }
+}
@@ -0,0 +1,56 @@
+use sos24_domain::ensure;
+use sos24_domain::repository::{notification::NotificationRepository, Repositories};
+
+use crate::{
+    notification::{dto::NotificationDto, NotificationUseCase, NotificationUseCaseError},
+    shared::context::ContextProvider,
+};
+
+impl<R: Repositories> NotificationUseCase<R> {
+    pub async fn list(
+        &self,
+        ctx: &impl ContextProvider,
+    ) -> Result<Vec<NotificationDto>, NotificationUseCaseError> {
+        // 通知に対応するスコープはないので、APIトークンでは取得させない
+        ensure!(ctx.scopes().is_none());
+        let actor = ctx.actor(&*self.repositories).await?;
+
+        let notification_list = self
+            .repositories
+            .notification_repository()
+            .find_by_user_id(actor.user_id().clone())
+            .await?;
+
+        Ok(notification_list
+            .into_iter()
+            .filter(|notification| notification.is_visible_to(&actor))
+            .map(NotificationDto::from)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::{
+        entity::user::UserRole,
+        test::{fixture, repository::MockRepositories},
+    };
+
+    use crate::{notification::NotificationUseCase, shared::context::TestContext};
+
+    #[tokio::test]
+    async fn 一般ユーザーは自分宛ての通知一覧を取得できる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .notification_repository_mut()
+            .expect_find_by_user_id()
+            .returning(|user_id| Ok(vec![fixture::notification::notification1(user_id)]));
+        let use_case = NotificationUseCase::new(Arc::new(repositories));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case.list(&ctx).await;
+        assert!(matches!(res, Ok(list) if list.len() == 1));
+    }
+}
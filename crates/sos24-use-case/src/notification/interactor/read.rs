@@ -0,0 +1,99 @@
+use sos24_domain::{
+    entity::{common::datetime::DateTime, notification::NotificationId},
+    repository::{notification::NotificationRepository, Repositories},
+};
+
+use crate::{
+    notification::{NotificationUseCase, NotificationUseCaseError},
+    shared::context::ContextProvider,
+};
+
+impl<R: Repositories> NotificationUseCase<R> {
+    pub async fn read(
+        &self,
+        ctx: &impl ContextProvider,
+        id: String,
+    ) -> Result<(), NotificationUseCaseError> {
+        let actor = ctx.actor(&*self.repositories).await?;
+
+        let id = NotificationId::try_from(id)?;
+        let notification = self
+            .repositories
+            .notification_repository()
+            .find_by_id(id.clone())
+            .await?
+            .ok_or(NotificationUseCaseError::NotFound(id.clone()))?;
+
+        if !notification.is_visible_to(&actor) {
+            return Err(NotificationUseCaseError::NotFound(id));
+        }
+
+        let mut new_notification = notification;
+        new_notification.read(&actor, DateTime::new(*ctx.requested_at()))?;
+        self.repositories
+            .notification_repository()
+            .update(new_notification)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::{
+        entity::user::UserRole,
+        test::{fixture, repository::MockRepositories},
+    };
+
+    use crate::{
+        notification::{NotificationUseCase, NotificationUseCaseError},
+        shared::context::TestContext,
+    };
+
+    #[tokio::test]
+    async fn 一般ユーザーは自分宛ての通知を既読にできる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .notification_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| {
+                Ok(Some(fixture::notification::notification1(
+                    fixture::user::id1(),
+                )))
+            });
+        repositories
+            .notification_repository_mut()
+            .expect_update()
+            .returning(|_| Ok(()));
+        let use_case = NotificationUseCase::new(Arc::new(repositories));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .read(&ctx, fixture::notification::id1().value().to_string())
+            .await;
+        assert!(matches!(res, Ok(())));
+    }
+
+    #[tokio::test]
+    async fn 一般ユーザーは他人宛ての通知を既読にできない() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .notification_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| {
+                Ok(Some(fixture::notification::notification1(
+                    fixture::user::id2(),
+                )))
+            });
+        let use_case = NotificationUseCase::new(Arc::new(repositories));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .read(&ctx, fixture::notification::id1().value().to_string())
+            .await;
+        assert!(matches!(res, Err(NotificationUseCaseError::NotFound(_))));
+    }
+}
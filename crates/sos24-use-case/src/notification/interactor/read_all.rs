@@ -0,0 +1,25 @@
+use sos24_domain::{
+    entity::common::datetime::DateTime,
+    repository::{notification::NotificationRepository, Repositories},
+};
+
+use crate::{
+    notification::{NotificationUseCase, NotificationUseCaseError},
+    shared::context::ContextProvider,
+};
+
+impl<R: Repositories> NotificationUseCase<R> {
+    pub async fn read_all(
+        &self,
+        ctx: &impl ContextProvider,
+    ) -> Result<(), NotificationUseCaseError> {
+        let actor = ctx.actor(&*self.repositories).await?;
+
+        self.repositories
+            .notification_repository()
+            .read_all_by_user_id(actor.user_id().clone(), DateTime::new(*ctx.requested_at()))
+            .await?;
+
+        Ok(())
+    }
+}
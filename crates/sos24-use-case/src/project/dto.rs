@@ -21,10 +21,13 @@ pub struct ProjectDto {
     pub owner_name: String,
     pub owner_email: String,
     pub owner_phone_number: String,
+    // ハイフン区切りの表記
+    pub owner_phone_number_display: String,
     pub sub_owner_id: Option<String>,
     pub sub_owner_name: Option<String>,
     pub sub_owner_email: Option<String>,
     pub sub_owner_phone_number: Option<String>,
+    pub sub_owner_phone_number_display: Option<String>,
     pub remarks: Option<String>,
     pub location_id: Option<String>,
     pub created_at: chrono::DateTime<chrono::Utc>,
@@ -36,13 +39,19 @@ impl From<ProjectWithOwners> for ProjectDto {
         let project = entity.project.destruct();
         let owner = entity.owner.destruct();
         let sub_owner = entity.sub_owner.map(|it| it.destruct());
-        let (sub_owner_name, sub_owner_email, sub_owner_phone_number) = match sub_owner {
+        let (
+            sub_owner_name,
+            sub_owner_email,
+            sub_owner_phone_number,
+            sub_owner_phone_number_display,
+        ) = match sub_owner {
             Some(user) => (
                 Some(user.name.value()),
                 Some(user.email.value()),
-                Some(user.phone_number.value()),
+                Some(user.phone_number.clone().value()),
+                Some(user.phone_number.display()),
             ),
-            None => (None, None, None),
+            None => (None, None, None, None),
         };
 
         Self {
@@ -57,11 +66,13 @@ impl From<ProjectWithOwners> for ProjectDto {
             owner_id: project.owner_id.value(),
             owner_name: owner.name.value(),
             owner_email: owner.email.value(),
+            owner_phone_number_display: owner.phone_number.display(),
             owner_phone_number: owner.phone_number.value(),
             sub_owner_id: project.sub_owner_id.map(|id| id.value()),
             sub_owner_name,
             sub_owner_email,
             sub_owner_phone_number,
+            sub_owner_phone_number_display,
             remarks: project.remarks.map(|it| it.value()),
             location_id: project.location_id.map(|it| it.value()),
             created_at: project.created_at.value(),
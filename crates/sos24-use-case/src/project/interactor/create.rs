@@ -9,6 +9,7 @@ use sos24_domain::repository::Repositories;
 use sos24_domain::{ensure, entity::project::Project};
 
 use crate::project::dto::ProjectAttributesDto;
+use crate::shared::adapter::event::{Event, EventBus};
 use crate::shared::adapter::notification::Notifier;
 use crate::shared::adapter::Adapters;
 use crate::shared::app_url;
@@ -45,7 +46,7 @@ impl<R: Repositories, A: Adapters> ProjectUseCase<R, A> {
         }
 
         let project_title = raw_project.title.clone();
-        let project_id = {
+        let project = {
             let lock = self.creation_lock.lock().await;
 
             if let Some(project_with_owners) = ctx.project(&*self.repositories).await? {
@@ -65,15 +66,19 @@ impl<R: Repositories, A: Adapters> ProjectUseCase<R, A> {
                 UserId::new(raw_project.owner_id),
             );
 
-            let project_id = project.id().clone();
             self.repositories
                 .project_repository()
-                .create(project)
+                .create(project.clone())
                 .await?;
 
             drop(lock);
-            project_id
+            project
         };
+        let project_id = project.id().clone();
+
+        self.adapters
+            .event_bus()
+            .publish(Event::ProjectCreated(project));
 
         self.adapters
             .notifier()
@@ -118,6 +123,7 @@ mod tests {
             .expect_find_by_sub_owner_id()
             .returning(|_| Ok(None));
         let mut adapters = MockAdapters::default();
+        adapters.event_bus_mut().expect_publish().returning(|_| ());
         adapters
             .notifier_mut()
             .expect_notify()
@@ -252,6 +258,7 @@ mod tests {
             .expect_find_by_sub_owner_id()
             .returning(|_| Ok(None));
         let mut adapters = MockAdapters::default();
+        adapters.event_bus_mut().expect_publish().returning(|_| ());
         adapters
             .notifier_mut()
             .expect_notify()
@@ -8,6 +8,7 @@ use sos24_domain::repository::project::ProjectRepository;
 use sos24_domain::repository::Repositories;
 
 use crate::project::{ProjectUseCase, ProjectUseCaseError};
+use crate::shared::adapter::event::{Event, EventBus};
 use crate::shared::adapter::Adapters;
 use crate::shared::context::ContextProvider;
 
@@ -21,7 +22,8 @@ impl<R: Repositories, A: Adapters> ProjectUseCase<R, A> {
         ensure!(actor.has_permission(Permissions::DELETE_PROJECT_ALL));
 
         let id = ProjectId::try_from(id)?;
-        self.repositories
+        let project_with_owners = self
+            .repositories
             .project_repository()
             .find_by_id(id.clone())
             .await?
@@ -47,6 +49,9 @@ impl<R: Repositories, A: Adapters> ProjectUseCase<R, A> {
             .delete_by_owner_project(id)
             .await?;
 
+        self.adapters
+            .event_bus()
+            .publish(Event::ProjectDeleted(project_with_owners.project));
         Ok(())
     }
 }
@@ -121,7 +126,8 @@ mod tests {
             .file_data_repository_mut()
             .expect_delete_by_owner_project()
             .returning(|_| Ok(()));
-        let adapters = MockAdapters::default();
+        let mut adapters = MockAdapters::default();
+        adapters.event_bus_mut().expect_publish().returning(|_| ());
         let use_case = ProjectUseCase::new(
             Arc::new(repositories),
             Arc::new(adapters),
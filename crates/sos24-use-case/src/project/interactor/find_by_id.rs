@@ -26,8 +26,9 @@ impl<R: Repositories, A: Adapters> ProjectUseCase<R, A> {
             .ok_or(ProjectUseCaseError::NotFound(id))?;
         ensure!(project_with_owners.project.is_visible_to(&actor));
 
+        let category = *project_with_owners.project.category();
         let mut project = ProjectDto::from(project_with_owners);
-        if !actor.has_permission(Permissions::READ_PROJECT_ALL) {
+        if !actor.has_project_permission(Permissions::READ_PROJECT_ALL, category) {
             project.remarks = None;
         }
 
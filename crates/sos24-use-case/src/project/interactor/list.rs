@@ -14,10 +14,15 @@ impl<R: Repositories, A: Adapters> ProjectUseCase<R, A> {
         ctx: &impl ContextProvider,
     ) -> Result<Vec<ProjectDto>, ProjectUseCaseError> {
         let actor = ctx.actor(&*self.repositories).await?;
-        ensure!(actor.has_permission(Permissions::READ_PROJECT_ALL));
+        ensure!(actor.has_permission_in_any_category(Permissions::READ_PROJECT_ALL));
 
+        // 企画区分を限定された実委人には、担当する企画区分の企画のみを返す
         let project_list = self.repositories.project_repository().list().await?;
-        Ok(project_list.into_iter().map(ProjectDto::from).collect())
+        Ok(project_list
+            .into_iter()
+            .filter(|project_with_owners| project_with_owners.project.is_visible_to(&actor))
+            .map(ProjectDto::from)
+            .collect())
     }
 }
 
@@ -72,4 +77,33 @@ mod tests {
         let res = use_case.list(&ctx).await;
         assert!(matches!(res, Ok(list) if list.is_empty()));
     }
+
+    #[tokio::test]
+    async fn 企画区分を限定された実委人は担当する企画のみ取得できる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .project_repository_mut()
+            .expect_list()
+            .returning(|| {
+                Ok(vec![
+                    fixture::project::project_with_owners1(fixture::user::user2(UserRole::General)),
+                    fixture::project::project_with_owners2(fixture::user::user2(UserRole::General)),
+                ])
+            });
+        let adapters = MockAdapters::default();
+        let use_case = ProjectUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::project_application_period::applicable_period(),
+        );
+
+        let actor = fixture::actor::actor1(UserRole::General)
+            .with_custom_roles(&[fixture::custom_role::stage_role1()]);
+        let ctx = TestContext::new(actor);
+        let res = use_case.list(&ctx).await;
+        assert!(matches!(
+            res,
+            Ok(list) if list.len() == 1 && list[0].id == fixture::project::id2().value().to_string()
+        ));
+    }
 }
@@ -9,6 +9,7 @@ use sos24_domain::repository::Repositories;
 
 use crate::project::dto::{ProjectAttributesDto, ProjectCategoryDto};
 use crate::project::{ProjectUseCase, ProjectUseCaseError};
+use crate::shared::adapter::event::{Event, EventBus};
 use crate::shared::adapter::Adapters;
 use crate::shared::context::ContextProvider;
 
@@ -44,10 +45,12 @@ impl<R: Repositories, A: Adapters> ProjectUseCase<R, A> {
         ensure!(project_with_owners.project.is_visible_to(&actor));
         ensure!(project_with_owners.project.is_updatable_by(&actor));
 
-        if !actor.has_permission(Permissions::UPDATE_PROJECT_ALL)
-            && !self
-                .project_application_period
-                .can_create_project(&actor, ctx.requested_at())
+        if !actor.has_project_permission(
+            Permissions::UPDATE_PROJECT_ALL,
+            *project_with_owners.project.category(),
+        ) && !self
+            .project_application_period
+            .can_create_project(&actor, ctx.requested_at())
         {
             return Err(ProjectUseCaseError::ApplicationsNotAccepted);
         }
@@ -79,8 +82,12 @@ impl<R: Repositories, A: Adapters> ProjectUseCase<R, A> {
 
         self.repositories
             .project_repository()
-            .update(new_project)
+            .update(new_project.clone())
             .await?;
+
+        self.adapters
+            .event_bus()
+            .publish(Event::ProjectUpdated(new_project));
         Ok(())
     }
 }
@@ -115,7 +122,8 @@ mod tests {
             .project_repository_mut()
             .expect_update()
             .returning(|_| Ok(()));
-        let adapters = MockAdapters::default();
+        let mut adapters = MockAdapters::default();
+        adapters.event_bus_mut().expect_publish().returning(|_| ());
         let use_case = ProjectUseCase::new(
             Arc::new(repositories),
             Arc::new(adapters),
@@ -249,7 +257,8 @@ mod tests {
             .project_repository_mut()
             .expect_update()
             .returning(|_| Ok(()));
-        let adapters = MockAdapters::default();
+        let mut adapters = MockAdapters::default();
+        adapters.event_bus_mut().expect_publish().returning(|_| ());
         let use_case = ProjectUseCase::new(
             Arc::new(repositories),
             Arc::new(adapters),
@@ -291,7 +300,8 @@ mod tests {
             .project_repository_mut()
             .expect_update()
             .returning(|_| Ok(()));
-        let adapters = MockAdapters::default();
+        let mut adapters = MockAdapters::default();
+        adapters.event_bus_mut().expect_publish().returning(|_| ());
         let use_case = ProjectUseCase::new(
             Arc::new(repositories),
             Arc::new(adapters),
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use sos24_domain::{
+    entity::{
+        custom_role::{CustomRoleId, CustomRoleIdError},
+        permission::{PermissionDeniedError, PermissionNameError},
+        user::UserId,
+    },
+    repository::{custom_role::CustomRoleRepositoryError, user::UserRepositoryError, Repositories},
+};
+
+use crate::shared::context::ContextError;
+
+pub mod dto;
+pub mod interactor;
+
+#[derive(Debug, Error)]
+pub enum RoleUseCaseError {
+    #[error("Custom role not found: {0:?}")]
+    NotFound(CustomRoleId),
+    #[error("User not found: {0:?}")]
+    UserNotFound(UserId),
+
+    #[error(transparent)]
+    CustomRoleIdError(#[from] CustomRoleIdError),
+    #[error(transparent)]
+    PermissionNameError(#[from] PermissionNameError),
+    #[error(transparent)]
+    CustomRoleRepositoryError(#[from] CustomRoleRepositoryError),
+    #[error(transparent)]
+    UserRepositoryError(#[from] UserRepositoryError),
+    #[error(transparent)]
+    ContextError(#[from] ContextError),
+    #[error(transparent)]
+    PermissionDeniedError(#[from] PermissionDeniedError),
+    #[error(transparent)]
+    InternalError(#[from] anyhow::Error),
+}
+
+pub struct RoleUseCase<R: Repositories> {
+    repositories: Arc<R>,
+}
+
+impl<R: Repositories> RoleUseCase<R> {
+    pub fn new(repositories: Arc<R>) -> Self {
+        Self { repositories }
+    }
+}
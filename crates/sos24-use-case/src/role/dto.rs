@@ -0,0 +1,27 @@
+use sos24_domain::entity::custom_role::CustomRole;
+
+use crate::project::dto::ProjectCategoriesDto;
+
+#[derive(Debug)]
+pub struct CustomRoleDto {
+    pub id: String,
+    pub name: String,
+    pub permissions: Vec<String>,
+    pub project_categories: Option<ProjectCategoriesDto>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<CustomRole> for CustomRoleDto {
+    fn from(role: CustomRole) -> Self {
+        let role = role.destruct();
+        Self {
+            id: role.id.value().to_string(),
+            name: role.name.value(),
+            permissions: role.permissions.names(),
+            project_categories: role.project_categories.map(ProjectCategoriesDto::from),
+            created_at: role.created_at.value(),
+            updated_at: role.updated_at.value(),
+        }
+    }
+}
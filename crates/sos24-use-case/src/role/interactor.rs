@@ -0,0 +1,6 @@
+pub mod create;
+pub mod delete_by_id;
+pub mod list;
+pub mod list_by_user_id;
+pub mod set_user_roles;
+pub mod update;
@@ -0,0 +1,113 @@
+use sos24_domain::{
+    entity::{
+        custom_role::{CustomRole, CustomRoleName},
+        permission::Permissions,
+        project::ProjectCategories,
+    },
+    repository::{custom_role::CustomRoleRepository, Repositories},
+};
+
+use crate::{
+    project::dto::ProjectCategoriesDto,
+    role::{RoleUseCase, RoleUseCaseError},
+    shared::context::ContextProvider,
+};
+
+#[derive(Debug)]
+pub struct CreateCustomRoleCommand {
+    pub name: String,
+    pub permissions: Vec<String>,
+    pub project_categories: Option<ProjectCategoriesDto>,
+}
+
+impl<R: Repositories> RoleUseCase<R> {
+    pub async fn create(
+        &self,
+        ctx: &impl ContextProvider,
+        raw_role: CreateCustomRoleCommand,
+    ) -> Result<String, RoleUseCaseError> {
+        let actor = ctx.actor(&*self.repositories).await?;
+
+        let role = CustomRole::create(
+            &actor,
+            CustomRoleName::new(raw_role.name),
+            Permissions::from_names(&raw_role.permissions)?,
+            raw_role.project_categories.map(ProjectCategories::from),
+        )?;
+        let id = role.id().clone();
+        self.repositories
+            .custom_role_repository()
+            .create(role)
+            .await?;
+
+        Ok(id.value().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::{
+        entity::{permission::PermissionDeniedError, user::UserRole},
+        test::{fixture, repository::MockRepositories},
+    };
+
+    use crate::{
+        role::{interactor::create::CreateCustomRoleCommand, RoleUseCase, RoleUseCaseError},
+        shared::context::TestContext,
+    };
+
+    fn command(permissions: Vec<&str>) -> CreateCustomRoleCommand {
+        CreateCustomRoleCommand {
+            name: fixture::custom_role::name1().value(),
+            permissions: permissions.into_iter().map(String::from).collect(),
+            project_categories: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn 管理者はロールを作成できる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .custom_role_repository_mut()
+            .expect_create()
+            .returning(|_| Ok(()));
+        let use_case = RoleUseCase::new(Arc::new(repositories));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::Administrator));
+        let res = use_case
+            .create(&ctx, command(vec!["READ_PROJECT_ALL", "READ_USER_ALL"]))
+            .await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn 存在しない権限を含むロールは作成できない() {
+        let repositories = MockRepositories::default();
+        let use_case = RoleUseCase::new(Arc::new(repositories));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::Administrator));
+        let res = use_case
+            .create(&ctx, command(vec!["READ_EVERYTHING"]))
+            .await;
+        assert!(matches!(res, Err(RoleUseCaseError::PermissionNameError(_))));
+    }
+
+    #[tokio::test]
+    async fn 実委人管理者はロールを作成できない() {
+        let repositories = MockRepositories::default();
+        let use_case = RoleUseCase::new(Arc::new(repositories));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeOperator));
+        let res = use_case
+            .create(&ctx, command(vec!["READ_PROJECT_ALL"]))
+            .await;
+        assert!(matches!(
+            res,
+            Err(RoleUseCaseError::PermissionDeniedError(
+                PermissionDeniedError
+            ))
+        ));
+    }
+}
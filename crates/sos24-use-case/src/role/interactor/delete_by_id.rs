@@ -0,0 +1,92 @@
+use sos24_domain::{
+    ensure,
+    entity::custom_role::CustomRoleId,
+    repository::{custom_role::CustomRoleRepository, Repositories},
+};
+
+use crate::{
+    role::{RoleUseCase, RoleUseCaseError},
+    shared::context::ContextProvider,
+};
+
+impl<R: Repositories> RoleUseCase<R> {
+    pub async fn delete_by_id(
+        &self,
+        ctx: &impl ContextProvider,
+        id: String,
+    ) -> Result<(), RoleUseCaseError> {
+        let actor = ctx.actor(&*self.repositories).await?;
+
+        let id = CustomRoleId::try_from(id)?;
+        let role = self
+            .repositories
+            .custom_role_repository()
+            .find_by_id(id.clone())
+            .await?
+            .ok_or(RoleUseCaseError::NotFound(id.clone()))?;
+        ensure!(role.is_deletable_by(&actor));
+
+        // 割り当ても合わせて削除される
+        self.repositories
+            .custom_role_repository()
+            .delete_by_id(id)
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::{
+        entity::{permission::PermissionDeniedError, user::UserRole},
+        test::{fixture, repository::MockRepositories},
+    };
+
+    use crate::{
+        role::{RoleUseCase, RoleUseCaseError},
+        shared::context::TestContext,
+    };
+
+    #[tokio::test]
+    async fn 管理者はロールを削除できる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .custom_role_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::custom_role::custom_role1())));
+        repositories
+            .custom_role_repository_mut()
+            .expect_delete_by_id()
+            .returning(|_| Ok(()));
+        let use_case = RoleUseCase::new(Arc::new(repositories));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::Administrator));
+        let res = use_case
+            .delete_by_id(&ctx, fixture::custom_role::id1().value().to_string())
+            .await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn 実委人管理者はロールを削除できない() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .custom_role_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::custom_role::custom_role1())));
+        let use_case = RoleUseCase::new(Arc::new(repositories));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeOperator));
+        let res = use_case
+            .delete_by_id(&ctx, fixture::custom_role::id1().value().to_string())
+            .await;
+        assert!(matches!(
+            res,
+            Err(RoleUseCaseError::PermissionDeniedError(
+                PermissionDeniedError
+            ))
+        ));
+    }
+}
@@ -0,0 +1,67 @@
+use sos24_domain::{
+    ensure,
+    entity::permission::Permissions,
+    repository::{custom_role::CustomRoleRepository, Repositories},
+};
+
+use crate::{
+    role::{dto::CustomRoleDto, RoleUseCase, RoleUseCaseError},
+    shared::context::ContextProvider,
+};
+
+impl<R: Repositories> RoleUseCase<R> {
+    pub async fn list(
+        &self,
+        ctx: &impl ContextProvider,
+    ) -> Result<Vec<CustomRoleDto>, RoleUseCaseError> {
+        let actor = ctx.actor(&*self.repositories).await?;
+        ensure!(actor.has_permission(Permissions::READ_ROLE_ALL));
+
+        let role_list = self.repositories.custom_role_repository().list().await?;
+        Ok(role_list.into_iter().map(CustomRoleDto::from).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::{
+        entity::{permission::PermissionDeniedError, user::UserRole},
+        test::{fixture, repository::MockRepositories},
+    };
+
+    use crate::{
+        role::{RoleUseCase, RoleUseCaseError},
+        shared::context::TestContext,
+    };
+
+    #[tokio::test]
+    async fn 実委人編集者はロール一覧を取得できる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .custom_role_repository_mut()
+            .expect_list()
+            .returning(|| Ok(vec![fixture::custom_role::custom_role1()]));
+        let use_case = RoleUseCase::new(Arc::new(repositories));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeEditor));
+        let res = use_case.list(&ctx).await;
+        assert!(matches!(res, Ok(list) if list.len() == 1));
+    }
+
+    #[tokio::test]
+    async fn 実委人起草者はロール一覧を取得できない() {
+        let repositories = MockRepositories::default();
+        let use_case = RoleUseCase::new(Arc::new(repositories));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeDrafter));
+        let res = use_case.list(&ctx).await;
+        assert!(matches!(
+            res,
+            Err(RoleUseCaseError::PermissionDeniedError(
+                PermissionDeniedError
+            ))
+        ));
+    }
+}
@@ -0,0 +1,79 @@
+use sos24_domain::{
+    ensure,
+    entity::{permission::Permissions, user::UserId},
+    repository::{custom_role::CustomRoleRepository, Repositories},
+};
+
+use crate::{
+    role::{dto::CustomRoleDto, RoleUseCase, RoleUseCaseError},
+    shared::context::ContextProvider,
+};
+
+impl<R: Repositories> RoleUseCase<R> {
+    pub async fn list_by_user_id(
+        &self,
+        ctx: &impl ContextProvider,
+        user_id: String,
+    ) -> Result<Vec<CustomRoleDto>, RoleUseCaseError> {
+        let actor = ctx.actor(&*self.repositories).await?;
+
+        // 自分に割り当てられたロールは誰でも確認できる
+        let user_id = UserId::new(user_id);
+        ensure!(actor.user_id() == &user_id || actor.has_permission(Permissions::READ_ROLE_ALL));
+
+        let role_list = self
+            .repositories
+            .custom_role_repository()
+            .find_by_user_id(user_id)
+            .await?;
+        Ok(role_list.into_iter().map(CustomRoleDto::from).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::{
+        entity::{permission::PermissionDeniedError, user::UserRole},
+        test::{fixture, repository::MockRepositories},
+    };
+
+    use crate::{
+        role::{RoleUseCase, RoleUseCaseError},
+        shared::context::TestContext,
+    };
+
+    #[tokio::test]
+    async fn 一般ユーザーは自分のロールを取得できる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .custom_role_repository_mut()
+            .expect_find_by_user_id()
+            .returning(|_| Ok(vec![fixture::custom_role::custom_role1()]));
+        let use_case = RoleUseCase::new(Arc::new(repositories));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .list_by_user_id(&ctx, fixture::user::id1().value())
+            .await;
+        assert!(matches!(res, Ok(list) if list.len() == 1));
+    }
+
+    #[tokio::test]
+    async fn 一般ユーザーは他人のロールを取得できない() {
+        let repositories = MockRepositories::default();
+        let use_case = RoleUseCase::new(Arc::new(repositories));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .list_by_user_id(&ctx, fixture::user::id2().value())
+            .await;
+        assert!(matches!(
+            res,
+            Err(RoleUseCaseError::PermissionDeniedError(
+                PermissionDeniedError
+            ))
+        ));
+    }
+}
@@ -0,0 +1,187 @@
+use std::collections::HashSet;
+
+use sos24_domain::{
+    ensure,
+    entity::{custom_role::CustomRoleId, permission::Permissions, user::UserId},
+    repository::{custom_role::CustomRoleRepository, user::UserRepository, Repositories},
+};
+
+use crate::{
+    role::{RoleUseCase, RoleUseCaseError},
+    shared::context::ContextProvider,
+};
+
+#[derive(Debug)]
+pub struct SetUserRolesCommand {
+    pub user_id: String,
+    pub role_ids: Vec<String>,
+}
+
+impl<R: Repositories> RoleUseCase<R> {
+    pub async fn set_user_roles(
+        &self,
+        ctx: &impl ContextProvider,
+        command: SetUserRolesCommand,
+    ) -> Result<(), RoleUseCaseError> {
+        let actor = ctx.actor(&*self.repositories).await?;
+        ensure!(actor.has_permission(Permissions::UPDATE_USER_ALL));
+
+        let user_id = UserId::new(command.user_id);
+        self.repositories
+            .user_repository()
+            .find_by_id(user_id.clone())
+            .await?
+            .ok_or(RoleUseCaseError::UserNotFound(user_id.clone()))?;
+
+        let mut new_roles = Vec::new();
+        for role_id in command.role_ids {
+            let role_id = CustomRoleId::try_from(role_id)?;
+            let role = self
+                .repositories
+                .custom_role_repository()
+                .find_by_id(role_id.clone())
+                .await?
+                .ok_or(RoleUseCaseError::NotFound(role_id))?;
+            if !new_roles.contains(&role) {
+                new_roles.push(role);
+            }
+        }
+        let current_roles = self
+            .repositories
+            .custom_role_repository()
+            .find_by_user_id(user_id.clone())
+            .await?;
+
+        // 付与・剥奪するロールはすべて自分の権限の範囲内でなければならない
+        let current_ids = current_roles
+            .iter()
+            .map(|role| role.id().clone().value())
+            .collect::<HashSet<_>>();
+        let new_ids = new_roles
+            .iter()
+            .map(|role| role.id().clone().value())
+            .collect::<HashSet<_>>();
+        for role in current_roles.iter().chain(new_roles.iter()) {
+            let id = role.id().clone().value();
+            if current_ids.contains(&id) != new_ids.contains(&id) {
+                ensure!(role.is_assignable_by(&actor));
+            }
+        }
+
+        self.repositories
+            .custom_role_repository()
+            .set_user_roles(
+                user_id,
+                new_roles
+                    .into_iter()
+                    .map(|role| role.id().clone())
+                    .collect(),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::{
+        entity::{
+            custom_role::CustomRole,
+            permission::{PermissionDeniedError, Permissions},
+            user::UserRole,
+        },
+        test::{fixture, repository::MockRepositories},
+    };
+
+    use crate::{
+        role::{interactor::set_user_roles::SetUserRolesCommand, RoleUseCase, RoleUseCaseError},
+        shared::context::TestContext,
+    };
+
+    fn command() -> SetUserRolesCommand {
+        SetUserRolesCommand {
+            user_id: fixture::user::id2().value(),
+            role_ids: vec![fixture::custom_role::id1().value().to_string()],
+        }
+    }
+
+    #[tokio::test]
+    async fn 実委人編集者は自分の権限の範囲内のロールを付与できる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .user_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::user::user2(UserRole::General))));
+        repositories
+            .custom_role_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::custom_role::custom_role1())));
+        repositories
+            .custom_role_repository_mut()
+            .expect_find_by_user_id()
+            .returning(|_| Ok(vec![]));
+        repositories
+            .custom_role_repository_mut()
+            .expect_set_user_roles()
+            .returning(|_, _| Ok(()));
+        let use_case = RoleUseCase::new(Arc::new(repositories));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeEditor));
+        let res = use_case.set_user_roles(&ctx, command()).await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn 実委人編集者は自分の権限を超えるロールを付与できない() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .user_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::user::user2(UserRole::General))));
+        repositories
+            .custom_role_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| {
+                let role = fixture::custom_role::custom_role1().destruct();
+                Ok(Some(CustomRole::new(
+                    role.id,
+                    role.name,
+                    Permissions::DELETE_USER_ALL,
+                    role.project_categories,
+                    role.created_at,
+                    role.updated_at,
+                )))
+            });
+        repositories
+            .custom_role_repository_mut()
+            .expect_find_by_user_id()
+            .returning(|_| Ok(vec![]));
+        let use_case = RoleUseCase::new(Arc::new(repositories));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeEditor));
+        let res = use_case.set_user_roles(&ctx, command()).await;
+        assert!(matches!(
+            res,
+            Err(RoleUseCaseError::PermissionDeniedError(
+                PermissionDeniedError
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn 実委人起草者はロールを付与できない() {
+        let repositories = MockRepositories::default();
+        let use_case = RoleUseCase::new(Arc::new(repositories));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeDrafter));
+        let res = use_case.set_user_roles(&ctx, command()).await;
+        assert!(matches!(
+            res,
+            Err(RoleUseCaseError::PermissionDeniedError(
+                PermissionDeniedError
+            ))
+        ));
+    }
+}
@@ -0,0 +1,120 @@
+use sos24_domain::{
+    ensure,
+    entity::{
+        custom_role::{CustomRoleId, CustomRoleName},
+        permission::Permissions,
+        project::ProjectCategories,
+    },
+    repository::{custom_role::CustomRoleRepository, Repositories},
+};
+
+use crate::{
+    project::dto::ProjectCategoriesDto,
+    role::{RoleUseCase, RoleUseCaseError},
+    shared::context::ContextProvider,
+};
+
+#[derive(Debug)]
+pub struct UpdateCustomRoleCommand {
+    pub id: String,
+    pub name: String,
+    pub permissions: Vec<String>,
+    pub project_categories: Option<ProjectCategoriesDto>,
+}
+
+impl<R: Repositories> RoleUseCase<R> {
+    pub async fn update(
+        &self,
+        ctx: &impl ContextProvider,
+        role_data: UpdateCustomRoleCommand,
+    ) -> Result<(), RoleUseCaseError> {
+        let actor = ctx.actor(&*self.repositories).await?;
+
+        let id = CustomRoleId::try_from(role_data.id)?;
+        let role = self
+            .repositories
+            .custom_role_repository()
+            .find_by_id(id.clone())
+            .await?
+            .ok_or(RoleUseCaseError::NotFound(id))?;
+        ensure!(role.is_visible_to(&actor));
+
+        let mut new_role = role;
+        new_role.set_name(&actor, CustomRoleName::new(role_data.name))?;
+        new_role.set_permissions(&actor, Permissions::from_names(&role_data.permissions)?)?;
+        new_role.set_project_categories(
+            &actor,
+            role_data.project_categories.map(ProjectCategories::from),
+        )?;
+
+        self.repositories
+            .custom_role_repository()
+            .update(new_role)
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::{
+        entity::{permission::PermissionDeniedError, user::UserRole},
+        test::{fixture, repository::MockRepositories},
+    };
+
+    use crate::{
+        role::{interactor::update::UpdateCustomRoleCommand, RoleUseCase, RoleUseCaseError},
+        shared::context::TestContext,
+    };
+
+    fn command() -> UpdateCustomRoleCommand {
+        UpdateCustomRoleCommand {
+            id: fixture::custom_role::id1().value().to_string(),
+            name: fixture::custom_role::name2().value(),
+            permissions: vec![
+                "READ_PROJECT_ALL".to_string(),
+                "UPDATE_PROJECT_ALL".to_string(),
+            ],
+            project_categories: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn 管理者はロールを更新できる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .custom_role_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::custom_role::custom_role1())));
+        repositories
+            .custom_role_repository_mut()
+            .expect_update()
+            .returning(|_| Ok(()));
+        let use_case = RoleUseCase::new(Arc::new(repositories));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::Administrator));
+        let res = use_case.update(&ctx, command()).await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn 実委人編集者はロールを更新できない() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .custom_role_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::custom_role::custom_role1())));
+        let use_case = RoleUseCase::new(Arc::new(repositories));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeEditor));
+        let res = use_case.update(&ctx, command()).await;
+        assert!(matches!(
+            res,
+            Err(RoleUseCaseError::PermissionDeniedError(
+                PermissionDeniedError
+            ))
+        ));
+    }
+}
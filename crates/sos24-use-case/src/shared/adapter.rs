@@ -1,20 +1,39 @@
-use self::{email::MockEmailSender, notification::MockNotifier};
+use self::{
+    email::MockEmailSender, event::MockEventBus, file_scanner::MockFileScanner,
+    notification::MockNotifier, thumbnail::MockThumbnailGenerator, webhook::MockWebhookSender,
+};
 
 pub mod email;
+pub mod event;
+pub mod file_scanner;
 pub mod notification;
+pub mod thumbnail;
+pub mod webhook;
 
 pub trait Adapters: Send + Sync + 'static {
     type EmailSenderImpl: email::EmailSender;
+    type EventBusImpl: event::EventBus;
+    type FileScannerImpl: file_scanner::FileScanner;
     type NotifierImpl: notification::Notifier;
+    type ThumbnailGeneratorImpl: thumbnail::ThumbnailGenerator;
+    type WebhookSenderImpl: webhook::WebhookSender;
 
     fn email_sender(&self) -> &Self::EmailSenderImpl;
+    fn event_bus(&self) -> &Self::EventBusImpl;
+    fn file_scanner(&self) -> &Self::FileScannerImpl;
     fn notifier(&self) -> &Self::NotifierImpl;
+    fn thumbnail_generator(&self) -> &Self::ThumbnailGeneratorImpl;
+    fn webhook_sender(&self) -> &Self::WebhookSenderImpl;
 }
 
 #[derive(Default)]
 pub struct MockAdapters {
     email_sender: MockEmailSender,
+    event_bus: MockEventBus,
+    file_scanner: MockFileScanner,
     notifier: MockNotifier,
+    thumbnail_generator: MockThumbnailGenerator,
+    webhook_sender: MockWebhookSender,
 }
 
 impl MockAdapters {
@@ -22,20 +41,56 @@ impl MockAdapters {
         &mut self.email_sender
     }
 
+    pub fn event_bus_mut(&mut self) -> &mut MockEventBus {
+        &mut self.event_bus
+    }
+
+    pub fn file_scanner_mut(&mut self) -> &mut MockFileScanner {
+        &mut self.file_scanner
+    }
+
     pub fn notifier_mut(&mut self) -> &mut MockNotifier {
         &mut self.notifier
     }
+
+    pub fn thumbnail_generator_mut(&mut self) -> &mut MockThumbnailGenerator {
+        &mut self.thumbnail_generator
+    }
+
+    pub fn webhook_sender_mut(&mut self) -> &mut MockWebhookSender {
+        &mut self.webhook_sender
+    }
 }
 
 impl Adapters for MockAdapters {
     type EmailSenderImpl = MockEmailSender;
+    type EventBusImpl = MockEventBus;
+    type FileScannerImpl = MockFileScanner;
     type NotifierImpl = MockNotifier;
+    type ThumbnailGeneratorImpl = MockThumbnailGenerator;
+    type WebhookSenderImpl = MockWebhookSender;
 
     fn email_sender(&self) -> &Self::EmailSenderImpl {
         &self.email_sender
     }
 
+    fn event_bus(&self) -> &Self::EventBusImpl {
+        &self.event_bus
+    }
+
+    fn file_scanner(&self) -> &Self::FileScannerImpl {
+        &self.file_scanner
+    }
+
     fn notifier(&self) -> &Self::NotifierImpl {
         &self.notifier
     }
+
+    fn thumbnail_generator(&self) -> &Self::ThumbnailGeneratorImpl {
+        &self.thumbnail_generator
+    }
+
+    fn webhook_sender(&self) -> &Self::WebhookSenderImpl {
+        &self.webhook_sender
+    }
 }
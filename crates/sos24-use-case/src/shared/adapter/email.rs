@@ -7,6 +7,8 @@ pub struct SendEmailCommand {
     pub reply_to: Option<String>,
     pub subject: String,
     pub body: String,
+    // 指定された場合はtext/plainとtext/htmlのマルチパートで送信する
+    pub html_body: Option<String>,
 }
 
 pub struct Email {
@@ -19,3 +21,19 @@ pub struct Email {
 pub trait EmailSender: Send + Sync + 'static {
     async fn send_email(&self, command: SendEmailCommand) -> anyhow::Result<()>;
 }
+
+/// HTMLメールの本文に埋め込む文字列をエスケープする
+pub fn escape_html(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
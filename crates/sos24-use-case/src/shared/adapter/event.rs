@@ -0,0 +1,26 @@
+use mockall::automock;
+use sos24_domain::entity::{
+    form::Form, form_answer::FormAnswer, invitation::Invitation, news::News, project::Project,
+};
+use tokio::sync::broadcast;
+
+#[derive(Debug, Clone)]
+pub enum Event {
+    ProjectCreated(Project),
+    ProjectUpdated(Project),
+    ProjectDeleted(Project),
+    NewsPublished(News),
+    FormStarted(Form),
+    FormAnswerSubmitted(FormAnswer),
+    FormAnswerUpdated(FormAnswer),
+    InvitationReceived(Invitation),
+    // 実委向けの通知
+    CommitteeNotified(String),
+}
+
+#[automock]
+pub trait EventBus: Send + Sync + 'static {
+    // 購読者がいない場合でもエラーにはしない
+    fn publish(&self, event: Event);
+    fn subscribe(&self) -> broadcast::Receiver<Event>;
+}
@@ -0,0 +1,21 @@
+use mockall::automock;
+use tokio::io::DuplexStream;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScanResult {
+    Clean,
+    // 検出されたマルウェアの名前
+    Infected(String),
+    // スキャナの上限を超える大きさのためスキャンできなかった
+    TooLarge,
+}
+
+#[automock]
+#[allow(async_fn_in_trait)]
+pub trait FileScanner: Send + Sync + 'static {
+    // スキャナが設定されていなければfalse
+    fn is_enabled(&self) -> bool;
+
+    // readerから読み出した内容をスキャンする
+    async fn scan(&self, reader: DuplexStream) -> anyhow::Result<ScanResult>;
+}
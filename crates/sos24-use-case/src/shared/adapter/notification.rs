@@ -1,7 +1,11 @@
 use mockall::automock;
+use sos24_domain::entity::notification::Notification;
 
 #[automock]
 #[allow(async_fn_in_trait)]
 pub trait Notifier: Send + Sync + 'static {
+    // 実委向けの通知
     async fn notify(&self, message: String) -> anyhow::Result<()>;
+    // 各ユーザーのお知らせ受信箱への通知
+    async fn notify_users(&self, notifications: Vec<Notification>) -> anyhow::Result<()>;
 }
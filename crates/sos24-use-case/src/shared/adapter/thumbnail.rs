@@ -0,0 +1,11 @@
+use mockall::automock;
+use tokio::io::DuplexStream;
+
+#[automock]
+#[allow(async_fn_in_trait)]
+pub trait ThumbnailGenerator: Send + Sync + 'static {
+    // readerから読み出したファイルのサムネイルをPNG形式で生成する
+    // PDFの場合は1ページ目のプレビューを生成する
+    async fn generate(&self, content_type: String, reader: DuplexStream)
+        -> anyhow::Result<Vec<u8>>;
+}
@@ -0,0 +1,18 @@
+use mockall::automock;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookRequest {
+    pub url: String,
+    pub delivery_id: String,
+    pub event_type: String,
+    pub body: String,
+    // Noneの場合は署名を付けずに送信する
+    pub secret: Option<String>,
+}
+
+#[automock]
+#[allow(async_fn_in_trait)]
+pub trait WebhookSender: Send + Sync + 'static {
+    // 送信先が返したステータスコードを返す
+    async fn send(&self, request: WebhookRequest) -> anyhow::Result<u16>;
+}
@@ -10,6 +10,10 @@ pub fn news(ctx: &impl ContextProvider, news_id: NewsId) -> String {
     format!("{}/news/{}", ctx.config().app_url, news_id.value())
 }
 
+pub fn project(ctx: &impl ContextProvider) -> String {
+    format!("{}/project", ctx.config().app_url)
+}
+
 pub fn committee_form(ctx: &impl ContextProvider, form_id: FormId) -> String {
     format!(
         "{}/committee/forms/{}",
@@ -1,9 +1,12 @@
 use sos24_domain::{
     entity::{
         actor::Actor,
+        file_object::FileSignedUrlExpiry,
+        permission::Permissions,
         user::{User, UserId},
     },
     repository::{
+        custom_role::{CustomRoleRepository, CustomRoleRepositoryError},
         project::{ProjectRepository, ProjectRepositoryError, ProjectWithOwners},
         user::{UserRepository, UserRepositoryError},
         Repositories,
@@ -20,6 +23,8 @@ pub enum ContextError {
     UserRepositoryError(#[from] UserRepositoryError),
     #[error(transparent)]
     ProjectRepositoryError(#[from] ProjectRepositoryError),
+    #[error(transparent)]
+    CustomRoleRepositoryError(#[from] CustomRoleRepositoryError),
 }
 
 #[derive(Clone, Debug, Default)]
@@ -27,6 +32,10 @@ pub struct Config {
     pub email_sender_address: String,
     pub email_reply_to_address: String,
     pub app_url: String,
+    // 企画ごとにアップロードできるファイルの合計サイズの既定値(バイト)
+    pub project_storage_quota: u64,
+    // ダウンロード用の署名付きURLの有効期間
+    pub file_url_expires_in: FileSignedUrlExpiry,
 }
 
 #[allow(async_fn_in_trait)]
@@ -34,6 +43,14 @@ pub trait ContextProvider: Send + Sync + 'static {
     fn user_id(&self) -> String;
     fn requested_at(&self) -> &chrono::DateTime<chrono::Utc>;
     fn config(&self) -> &Config;
+    // APIトークンで認証した場合は、トークンで許可された権限
+    fn scopes(&self) -> Option<Permissions> {
+        None
+    }
+    // 管理者が他のユーザーになりすましている場合は、なりすましている管理者のID
+    fn impersonator(&self) -> Option<String> {
+        None
+    }
 
     async fn user<R: Repositories>(&self, repositories: &R) -> Result<User, ContextError> {
         let user_id = UserId::new(self.user_id());
@@ -45,8 +62,18 @@ pub trait ContextProvider: Send + Sync + 'static {
     }
 
     async fn actor<R: Repositories>(&self, repositories: &R) -> Result<Actor, ContextError> {
-        let user = self.user(repositories).await?;
-        Ok(Actor::new(user.id().clone(), user.role().clone()))
+        if let Some(impersonator) = self.impersonator() {
+            tracing::warn!(
+                "ユーザー {impersonator} が {} になりすましています",
+                self.user_id()
+            );
+        }
+
+        let actor = actor_of(repositories, UserId::new(self.user_id())).await?;
+        Ok(match self.scopes() {
+            Some(scopes) => actor.with_scopes(scopes),
+            None => actor,
+        })
     }
 
     async fn project<R: Repositories>(
@@ -74,10 +101,29 @@ pub trait ContextProvider: Send + Sync + 'static {
     }
 }
 
+// 指定したユーザーの権限を持つActorを組み立てる
+// リクエストのコンテキストがないバックグラウンドの処理で、処理を依頼したユーザーの権限を確認するのに用いる
+pub async fn actor_of<R: Repositories>(
+    repositories: &R,
+    user_id: UserId,
+) -> Result<Actor, ContextError> {
+    let user = repositories
+        .user_repository()
+        .find_by_id(user_id.clone())
+        .await?
+        .ok_or(ContextError::UserNotFound(user_id))?;
+    let custom_roles = repositories
+        .custom_role_repository()
+        .find_by_user_id(user.id().clone())
+        .await?;
+    Ok(Actor::new(user.id().clone(), user.role().clone()).with_custom_roles(&custom_roles))
+}
+
 pub struct TestContext {
     actor: Actor,
     requested_at: chrono::DateTime<chrono::Utc>,
     config: Config,
+    scopes: Option<Permissions>,
 }
 
 impl TestContext {
@@ -86,6 +132,16 @@ impl TestContext {
             actor,
             requested_at: chrono::Utc::now(),
             config: Config::default(),
+            scopes: None,
+        }
+    }
+
+    // APIトークンで認証したリクエストとして扱う
+    pub fn with_scopes(self, scopes: Permissions) -> Self {
+        Self {
+            actor: self.actor.with_scopes(scopes.clone()),
+            scopes: Some(scopes),
+            ..self
         }
     }
 }
@@ -103,6 +159,10 @@ impl ContextProvider for TestContext {
         &self.config
     }
 
+    fn scopes(&self) -> Option<Permissions> {
+        self.scopes.clone()
+    }
+
     async fn actor<R: Repositories>(&self, _repositories: &R) -> Result<Actor, ContextError> {
         Ok(self.actor.clone())
     }
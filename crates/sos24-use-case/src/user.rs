@@ -2,14 +2,24 @@ use std::sync::Arc;
 
 use thiserror::Error;
 
-use sos24_domain::entity::common::email::EmailError;
+use sos24_domain::entity::api_token::{ApiTokenError, ApiTokenId, ApiTokenIdError};
+use sos24_domain::entity::common::datetime::DateTimeError;
+use sos24_domain::entity::common::email::{EmailAllowlist, EmailError};
+use sos24_domain::entity::impersonation_log::ImpersonationLogError;
 use sos24_domain::entity::permission::PermissionDeniedError;
-use sos24_domain::entity::user::UserId;
+use sos24_domain::entity::project::ProjectId;
+use sos24_domain::entity::user::{UserId, UserPhoneNumberError};
+use sos24_domain::repository::api_token::ApiTokenRepositoryError;
 use sos24_domain::repository::firebase_user::FirebaseUserRepositoryError;
+use sos24_domain::repository::form::FormRepositoryError;
+use sos24_domain::repository::form_answer::FormAnswerRepositoryError;
+use sos24_domain::repository::impersonation_log::ImpersonationLogRepositoryError;
+use sos24_domain::repository::invitation::InvitationRepositoryError;
 use sos24_domain::repository::project::ProjectRepositoryError;
 use sos24_domain::repository::user::UserRepositoryError;
 use sos24_domain::repository::Repositories;
 
+use crate::shared::adapter::Adapters;
 use crate::shared::context::ContextError;
 
 pub mod dto;
@@ -22,6 +32,14 @@ pub enum UserUseCaseError {
 
     #[error("Users already exist")]
     UsersAlreadyExist,
+    #[error("Invalid email or password")]
+    InvalidCredentials,
+    #[error("API token not found: {0:?}")]
+    ApiTokenNotFound(ApiTokenId),
+    #[error("Invalid or expired API token")]
+    InvalidApiToken,
+    #[error("User still owns project: {0:?}")]
+    StillOwnsProject(ProjectId),
 
     #[error(transparent)]
     ProjectRepositoryError(#[from] ProjectRepositoryError),
@@ -34,17 +52,43 @@ pub enum UserUseCaseError {
     #[error(transparent)]
     EmailError(#[from] EmailError),
     #[error(transparent)]
+    UserPhoneNumberError(#[from] UserPhoneNumberError),
+    #[error(transparent)]
+    InvitationRepositoryError(#[from] InvitationRepositoryError),
+    #[error(transparent)]
+    FormRepositoryError(#[from] FormRepositoryError),
+    #[error(transparent)]
+    FormAnswerRepositoryError(#[from] FormAnswerRepositoryError),
+    #[error(transparent)]
+    ApiTokenRepositoryError(#[from] ApiTokenRepositoryError),
+    #[error(transparent)]
+    ApiTokenError(#[from] ApiTokenError),
+    #[error(transparent)]
+    ApiTokenIdError(#[from] ApiTokenIdError),
+    #[error(transparent)]
+    DateTimeError(#[from] DateTimeError),
+    #[error(transparent)]
+    ImpersonationLogError(#[from] ImpersonationLogError),
+    #[error(transparent)]
+    ImpersonationLogRepositoryError(#[from] ImpersonationLogRepositoryError),
+    #[error(transparent)]
     PermissionDeniedError(#[from] PermissionDeniedError),
     #[error(transparent)]
     InternalError(#[from] anyhow::Error),
 }
 
-pub struct UserUseCase<R: Repositories> {
+pub struct UserUseCase<R: Repositories, A: Adapters> {
     repositories: Arc<R>,
+    adapters: Arc<A>,
+    email_allowlist: EmailAllowlist,
 }
 
-impl<R: Repositories> UserUseCase<R> {
-    pub fn new(repositories: Arc<R>) -> Self {
-        Self { repositories }
+impl<R: Repositories, A: Adapters> UserUseCase<R, A> {
+    pub fn new(repositories: Arc<R>, adapters: Arc<A>, email_allowlist: EmailAllowlist) -> Self {
+        Self {
+            repositories,
+            adapters,
+            email_allowlist,
+        }
     }
 }
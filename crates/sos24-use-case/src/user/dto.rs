@@ -1,6 +1,12 @@
+use sos24_domain::entity::api_token::{ApiToken, ApiTokenScope};
+use sos24_domain::entity::impersonation_log::ImpersonationLog;
 use sos24_domain::entity::project::Project;
 use sos24_domain::entity::user::{User, UserRole};
 
+use crate::form_answer::dto::FormAnswerDto;
+use crate::invitation::dto::InvitationDto;
+use crate::project::dto::ProjectDto;
+
 #[derive(Debug)]
 pub struct UserDto {
     pub id: String,
@@ -8,6 +14,8 @@ pub struct UserDto {
     pub kana_name: String,
     pub email: String,
     pub phone_number: String,
+    // ハイフン区切りの表記
+    pub phone_number_display: String,
     pub role: UserRoleDto,
     pub owned_project_id: Option<String>,
     pub owned_project_title: Option<String>,
@@ -31,6 +39,7 @@ impl From<(User, Option<Project>)> for UserDto {
             name: user.name.value(),
             kana_name: user.kana_name.value(),
             email: user.email.value(),
+            phone_number_display: user.phone_number.display(),
             phone_number: user.phone_number.value(),
             role: UserRoleDto::from(user.role),
             owned_project_id: project_id.map(|id| id.to_string()),
@@ -89,3 +98,137 @@ impl std::fmt::Display for UserRoleDto {
         }
     }
 }
+
+#[derive(Debug)]
+pub struct ApiTokenDto {
+    pub id: String,
+    pub name: String,
+    pub scopes: Vec<ApiTokenScopeDto>,
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub last_used_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<ApiToken> for ApiTokenDto {
+    fn from(token: ApiToken) -> Self {
+        let token = token.destruct();
+        Self {
+            id: token.id.value().to_string(),
+            name: token.name.value(),
+            scopes: token
+                .scopes
+                .into_iter()
+                .map(ApiTokenScopeDto::from)
+                .collect(),
+            expires_at: token.expires_at.map(|it| it.value()),
+            last_used_at: token.last_used_at.map(|it| it.value()),
+            created_at: token.created_at.value(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct CreatedApiTokenDto {
+    pub id: String,
+    // 作成時にのみ返す
+    pub token: String,
+}
+
+// APIトークンで認証したユーザーと、そのトークンで許可された権限
+#[derive(Debug)]
+pub struct ApiTokenCredentialDto {
+    pub user_id: String,
+    pub permissions: u64,
+}
+
+#[derive(Debug)]
+pub enum ApiTokenScopeDto {
+    ReadProjects,
+    ReadUsers,
+    ReadNews,
+    ReadForms,
+    ReadFormAnswers,
+    ReadInvitations,
+    ReadFiles,
+}
+
+impl From<ApiTokenScopeDto> for ApiTokenScope {
+    fn from(value: ApiTokenScopeDto) -> Self {
+        match value {
+            ApiTokenScopeDto::ReadProjects => ApiTokenScope::ReadProjects,
+            ApiTokenScopeDto::ReadUsers => ApiTokenScope::ReadUsers,
+            ApiTokenScopeDto::ReadNews => ApiTokenScope::ReadNews,
+            ApiTokenScopeDto::ReadForms => ApiTokenScope::ReadForms,
+            ApiTokenScopeDto::ReadFormAnswers => ApiTokenScope::ReadFormAnswers,
+            ApiTokenScopeDto::ReadInvitations => ApiTokenScope::ReadInvitations,
+            ApiTokenScopeDto::ReadFiles => ApiTokenScope::ReadFiles,
+        }
+    }
+}
+
+impl From<ApiTokenScope> for ApiTokenScopeDto {
+    fn from(entity: ApiTokenScope) -> Self {
+        match entity {
+            ApiTokenScope::ReadProjects => ApiTokenScopeDto::ReadProjects,
+            ApiTokenScope::ReadUsers => ApiTokenScopeDto::ReadUsers,
+            ApiTokenScope::ReadNews => ApiTokenScopeDto::ReadNews,
+            ApiTokenScope::ReadForms => ApiTokenScopeDto::ReadForms,
+            ApiTokenScope::ReadFormAnswers => ApiTokenScopeDto::ReadFormAnswers,
+            ApiTokenScope::ReadInvitations => ApiTokenScopeDto::ReadInvitations,
+            ApiTokenScope::ReadFiles => ApiTokenScopeDto::ReadFiles,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ImpersonationLogDto {
+    pub id: String,
+    pub impersonator_id: String,
+    pub user_id: String,
+    pub request: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<ImpersonationLog> for ImpersonationLogDto {
+    fn from(log: ImpersonationLog) -> Self {
+        let log = log.destruct();
+        Self {
+            id: log.id.value().to_string(),
+            impersonator_id: log.impersonator_id.value(),
+            user_id: log.user_id.value(),
+            request: log.request.value(),
+            created_at: log.created_at.value(),
+        }
+    }
+}
+
+// 一括登録の各行の結果
+#[derive(Debug)]
+pub struct ImportUserResultDto {
+    // データ行の番号 (1始まり、ヘッダーを含まない)
+    pub row: usize,
+    pub email: String,
+    pub status: ImportUserStatusDto,
+    pub user_id: Option<String>,
+    // パスワード設定用のリンクをメールで送信できたか
+    pub email_sent: bool,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ImportUserStatusDto {
+    // dry-runで検証に成功した
+    Valid,
+    Created,
+    Failed,
+}
+
+// 本人に開示する個人データ
+#[derive(Debug)]
+pub struct PersonalDataDto {
+    pub user: UserDto,
+    pub project: Option<ProjectDto>,
+    pub invitations: Vec<InvitationDto>,
+    pub form_answers: Vec<FormAnswerDto>,
+    pub exported_at: chrono::DateTime<chrono::Utc>,
+}
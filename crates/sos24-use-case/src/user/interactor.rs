@@ -1,6 +1,16 @@
+pub mod authenticate_api_token;
 pub mod create;
 pub mod create_admin;
+pub mod create_api_token;
+pub mod delete_api_token;
 pub mod delete_by_id;
+pub mod delete_me;
+pub mod export_personal_data;
 pub mod find_by_id;
+pub mod impersonate;
+pub mod import;
 pub mod list;
+pub mod list_api_tokens;
+pub mod list_impersonation_logs;
+pub mod sign_in;
 pub mod update;
@@ -0,0 +1,120 @@
+use sos24_domain::entity::api_token::ApiTokenSecret;
+use sos24_domain::entity::common::datetime::DateTime;
+use sos24_domain::repository::{api_token::ApiTokenRepository, Repositories};
+
+use crate::shared::adapter::Adapters;
+use crate::user::dto::ApiTokenCredentialDto;
+use crate::user::{UserUseCase, UserUseCaseError};
+
+impl<R: Repositories, A: Adapters> UserUseCase<R, A> {
+    pub async fn authenticate_api_token(
+        &self,
+        token: String,
+    ) -> Result<ApiTokenCredentialDto, UserUseCaseError> {
+        let token_hash = ApiTokenSecret::new(token).hash();
+        let token = self
+            .repositories
+            .api_token_repository()
+            .find_by_token_hash(token_hash)
+            .await?
+            .ok_or(UserUseCaseError::InvalidApiToken)?;
+
+        let now = chrono::Utc::now();
+        if token.is_expired(&now) {
+            return Err(UserUseCaseError::InvalidApiToken);
+        }
+
+        self.repositories
+            .api_token_repository()
+            .update_last_used_at(token.id().clone(), DateTime::new(now))
+            .await?;
+
+        Ok(ApiTokenCredentialDto {
+            user_id: token.user_id().clone().value(),
+            permissions: token.permissions().bits(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::entity::{
+        api_token::{ApiToken, ApiTokenName, ApiTokenScope},
+        common::datetime::DateTime,
+        user::UserRole,
+    };
+    use sos24_domain::test::{fixture, repository::MockRepositories};
+
+    use crate::shared::adapter::MockAdapters;
+    use crate::user::{UserUseCase, UserUseCaseError};
+
+    #[tokio::test]
+    async fn 有効なapiトークンで認証できる() {
+        let (token, secret) = ApiToken::create(
+            &fixture::actor::actor1(UserRole::CommitteeViewer),
+            ApiTokenName::new("export".to_string()),
+            vec![ApiTokenScope::ReadProjects],
+            None,
+        )
+        .unwrap();
+
+        let mut repositories = MockRepositories::default();
+        repositories
+            .api_token_repository_mut()
+            .expect_find_by_token_hash()
+            .returning(move |_| Ok(Some(token.clone())));
+        repositories
+            .api_token_repository_mut()
+            .expect_update_last_used_at()
+            .returning(|_, _| Ok(()));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
+
+        let res = use_case.authenticate_api_token(secret.value()).await;
+        assert!(matches!(
+            res,
+            Ok(credential) if credential.user_id == fixture::user::id1().value()
+                && credential.permissions == ApiTokenScope::ReadProjects.permissions().bits()
+        ));
+    }
+
+    #[tokio::test]
+    async fn 期限切れのapiトークンでは認証できない() {
+        let token = fixture::api_token::api_token1().destruct();
+        let token = ApiToken::new(
+            token.id,
+            token.user_id,
+            token.name,
+            token.scopes,
+            token.token_hash,
+            Some(DateTime::new(
+                chrono::Utc::now() - chrono::Duration::seconds(1),
+            )),
+            token.last_used_at,
+            token.created_at,
+        );
+
+        let mut repositories = MockRepositories::default();
+        repositories
+            .api_token_repository_mut()
+            .expect_find_by_token_hash()
+            .returning(move |_| Ok(Some(token.clone())));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
+
+        let res = use_case
+            .authenticate_api_token("sos_expired".to_string())
+            .await;
+        assert!(matches!(res, Err(UserUseCaseError::InvalidApiToken)));
+    }
+}
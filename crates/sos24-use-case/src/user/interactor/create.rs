@@ -1,12 +1,15 @@
+use sos24_domain::entity::actor::Actor;
 use sos24_domain::entity::firebase_user::FirebaseUserEmail;
 use sos24_domain::entity::user::{
-    User, UserEmail, UserId, UserKanaName, UserName, UserPhoneNumber,
+    User, UserEmail, UserId, UserKanaName, UserName, UserPhoneNumber, UserRole,
 };
 use sos24_domain::{
     entity::firebase_user::{FirebaseUserPassword, NewFirebaseUser},
     repository::{firebase_user::FirebaseUserRepository, user::UserRepository, Repositories},
 };
 
+use crate::shared::adapter::Adapters;
+use crate::shared::context::ContextProvider;
 use crate::user::{UserUseCase, UserUseCaseError};
 
 #[derive(Debug)]
@@ -18,8 +21,35 @@ pub struct CreateUserCommand {
     pub phone_number: String,
 }
 
-impl<R: Repositories> UserUseCase<R> {
+impl<R: Repositories, A: Adapters> UserUseCase<R, A> {
     pub async fn create(&self, raw_user: CreateUserCommand) -> Result<String, UserUseCaseError> {
+        self.create_user(None, raw_user).await
+    }
+
+    // ログインしたユーザーが他のユーザーを作成する
+    // 管理者であれば、許可されていないドメインのメールアドレスでも作成できる
+    pub async fn create_authenticated(
+        &self,
+        ctx: &impl ContextProvider,
+        raw_user: CreateUserCommand,
+    ) -> Result<String, UserUseCaseError> {
+        let actor = ctx.actor(&*self.repositories).await?;
+        self.create_user(Some(&actor), raw_user).await
+    }
+
+    async fn create_user(
+        &self,
+        actor: Option<&Actor>,
+        raw_user: CreateUserCommand,
+    ) -> Result<String, UserUseCaseError> {
+        let email = UserEmail::try_from(raw_user.email.clone())?;
+        self.email_allowlist.ensure_registrable(
+            &email.clone().raw_value(),
+            actor,
+            &UserRole::General,
+        )?;
+        let phone_number = UserPhoneNumber::try_from(raw_user.phone_number)?;
+
         let firebase_user = NewFirebaseUser::new(
             FirebaseUserEmail::try_from(raw_user.email.clone())?,
             FirebaseUserPassword::new(raw_user.password.clone()),
@@ -34,8 +64,8 @@ impl<R: Repositories> UserUseCase<R> {
             UserId::from(firebase_user_id.clone()),
             UserName::new(raw_user.name),
             UserKanaName::new(raw_user.kana_name),
-            UserEmail::try_from(raw_user.email)?,
-            UserPhoneNumber::new(raw_user.phone_number),
+            email,
+            phone_number,
         );
 
         let user_id = user.id().clone();
@@ -61,10 +91,16 @@ mod tests {
     use anyhow::anyhow;
 
     use sos24_domain::{
+        entity::{
+            common::email::{EmailAllowlist, EmailError},
+            user::{UserPhoneNumberError, UserRole},
+        },
         repository::user::UserRepositoryError,
         test::{fixture, repository::MockRepositories},
     };
 
+    use crate::shared::adapter::MockAdapters;
+    use crate::shared::context::TestContext;
     use crate::user::{interactor::create::CreateUserCommand, UserUseCase, UserUseCaseError};
 
     #[tokio::test]
@@ -78,7 +114,12 @@ mod tests {
             .user_repository_mut()
             .expect_create()
             .returning(|_| Ok(()));
-        let use_case = UserUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
 
         let res = use_case
             .create(CreateUserCommand {
@@ -108,7 +149,12 @@ mod tests {
             .expect_delete_by_id()
             .times(1)
             .returning(|_| Ok(()));
-        let use_case = UserUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
 
         let res = use_case
             .create(CreateUserCommand {
@@ -126,4 +172,146 @@ mod tests {
             ))
         ));
     }
+
+    #[tokio::test]
+    async fn 許可されていないドメインのメールアドレスではユーザーを作成できない() {
+        let repositories = MockRepositories::default();
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
+
+        let res = use_case
+            .create(CreateUserCommand {
+                name: fixture::user::name1().value(),
+                kana_name: fixture::user::kana_name1().value(),
+                email: "guest@example.com".to_string(),
+                password: fixture::firebase_user::password1().value(),
+                phone_number: fixture::user::phone_number1().value(),
+            })
+            .await;
+        assert!(matches!(
+            res,
+            Err(UserUseCaseError::EmailError(EmailError::InvalidDomain))
+        ));
+    }
+
+    #[tokio::test]
+    async fn 管理者は許可されていないドメインのメールアドレスでもユーザーを作成できる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .firebase_user_repository_mut()
+            .expect_create()
+            .returning(|_| Ok(fixture::firebase_user::id1()));
+        repositories
+            .user_repository_mut()
+            .expect_create()
+            .returning(|_| Ok(()));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::Administrator));
+        let res = use_case
+            .create_authenticated(
+                &ctx,
+                CreateUserCommand {
+                    name: fixture::user::name1().value(),
+                    kana_name: fixture::user::kana_name1().value(),
+                    email: "guest@example.com".to_string(),
+                    password: fixture::firebase_user::password1().value(),
+                    phone_number: fixture::user::phone_number1().value(),
+                },
+            )
+            .await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn 実委人は許可されていないドメインのメールアドレスでユーザーを作成できない() {
+        let repositories = MockRepositories::default();
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeOperator));
+        let res = use_case
+            .create_authenticated(
+                &ctx,
+                CreateUserCommand {
+                    name: fixture::user::name1().value(),
+                    kana_name: fixture::user::kana_name1().value(),
+                    email: "guest@example.com".to_string(),
+                    password: fixture::firebase_user::password1().value(),
+                    phone_number: fixture::user::phone_number1().value(),
+                },
+            )
+            .await;
+        assert!(matches!(
+            res,
+            Err(UserUseCaseError::EmailError(EmailError::InvalidDomain))
+        ));
+    }
+
+    #[tokio::test]
+    async fn 設定で許可したアドレスではユーザーを作成できる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .firebase_user_repository_mut()
+            .expect_create()
+            .returning(|_| Ok(fixture::firebase_user::id1()));
+        repositories
+            .user_repository_mut()
+            .expect_create()
+            .returning(|_| Ok(()));
+        let allowlist = EmailAllowlist::try_from("*.tsukuba.ac.jp,guest@example.com").unwrap();
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(Arc::new(repositories), Arc::new(adapters), allowlist);
+
+        let res = use_case
+            .create(CreateUserCommand {
+                name: fixture::user::name1().value(),
+                kana_name: fixture::user::kana_name1().value(),
+                email: "guest@example.com".to_string(),
+                password: fixture::firebase_user::password1().value(),
+                phone_number: fixture::user::phone_number1().value(),
+            })
+            .await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn 不正な電話番号ではユーザーを作成できない() {
+        let repositories = MockRepositories::default();
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
+
+        let res = use_case
+            .create(CreateUserCommand {
+                name: fixture::user::name1().value(),
+                kana_name: fixture::user::kana_name1().value(),
+                email: fixture::user::email1().value(),
+                password: fixture::firebase_user::password1().value(),
+                phone_number: "090-1234".to_string(),
+            })
+            .await;
+        assert!(matches!(
+            res,
+            Err(UserUseCaseError::UserPhoneNumberError(
+                UserPhoneNumberError::InvalidFormat(_)
+            ))
+        ));
+    }
 }
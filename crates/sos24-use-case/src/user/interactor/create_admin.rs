@@ -8,11 +8,12 @@ use sos24_domain::{
     repository::{firebase_user::FirebaseUserRepository, user::UserRepository, Repositories},
 };
 
+use crate::shared::adapter::Adapters;
 use crate::user::{UserUseCase, UserUseCaseError};
 
 use super::create::CreateUserCommand;
 
-impl<R: Repositories> UserUseCase<R> {
+impl<R: Repositories, A: Adapters> UserUseCase<R, A> {
     pub async fn create_admin(
         &self,
         raw_user: CreateUserCommand,
@@ -21,6 +22,8 @@ impl<R: Repositories> UserUseCase<R> {
             return Err(UserUseCaseError::UsersAlreadyExist);
         }
 
+        let phone_number = UserPhoneNumber::try_from(raw_user.phone_number)?;
+
         let firebase_user = NewFirebaseUser::new(
             FirebaseUserEmail::try_from(raw_user.email.clone())?,
             FirebaseUserPassword::new(raw_user.password.clone()),
@@ -36,7 +39,7 @@ impl<R: Repositories> UserUseCase<R> {
             UserName::new(raw_user.name),
             UserKanaName::new(raw_user.kana_name),
             UserEmail::try_from(raw_user.email)?,
-            UserPhoneNumber::new(raw_user.phone_number),
+            phone_number,
             UserRole::Administrator,
             now.clone(),
             now,
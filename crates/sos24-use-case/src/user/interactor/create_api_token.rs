@@ -0,0 +1,126 @@
+use sos24_domain::ensure;
+use sos24_domain::entity::api_token::{ApiToken, ApiTokenName, ApiTokenScope};
+use sos24_domain::entity::common::datetime::DateTime;
+use sos24_domain::repository::{api_token::ApiTokenRepository, Repositories};
+
+use crate::shared::adapter::Adapters;
+use crate::shared::context::ContextProvider;
+use crate::user::dto::{ApiTokenScopeDto, CreatedApiTokenDto};
+use crate::user::{UserUseCase, UserUseCaseError};
+
+#[derive(Debug)]
+pub struct CreateApiTokenCommand {
+    pub name: String,
+    pub scopes: Vec<ApiTokenScopeDto>,
+    pub expires_at: Option<String>,
+}
+
+impl<R: Repositories, A: Adapters> UserUseCase<R, A> {
+    pub async fn create_api_token(
+        &self,
+        ctx: &impl ContextProvider,
+        command: CreateApiTokenCommand,
+    ) -> Result<CreatedApiTokenDto, UserUseCaseError> {
+        // APIトークンでは自分のトークンを操作させない
+        ensure!(ctx.scopes().is_none());
+        let actor = ctx.actor(&*self.repositories).await?;
+
+        let expires_at = command.expires_at.map(DateTime::try_from).transpose()?;
+        let (token, secret) = ApiToken::create(
+            &actor,
+            ApiTokenName::new(command.name),
+            command
+                .scopes
+                .into_iter()
+                .map(ApiTokenScope::from)
+                .collect(),
+            expires_at,
+        )?;
+
+        let id = token.id().clone();
+        self.repositories
+            .api_token_repository()
+            .create(token)
+            .await?;
+
+        Ok(CreatedApiTokenDto {
+            id: id.value().to_string(),
+            token: secret.value(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::entity::{
+        api_token::{ApiTokenError, ApiTokenSecret},
+        user::UserRole,
+    };
+    use sos24_domain::test::{fixture, repository::MockRepositories};
+
+    use crate::shared::adapter::MockAdapters;
+    use crate::shared::context::TestContext;
+    use crate::user::{
+        dto::ApiTokenScopeDto, interactor::create_api_token::CreateApiTokenCommand, UserUseCase,
+        UserUseCaseError,
+    };
+
+    #[tokio::test]
+    async fn 実委人閲覧者は企画を閲覧するapiトークンを作成できる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .api_token_repository_mut()
+            .expect_create()
+            .returning(|_| Ok(()));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeViewer));
+        let res = use_case
+            .create_api_token(
+                &ctx,
+                CreateApiTokenCommand {
+                    name: "export".to_string(),
+                    scopes: vec![ApiTokenScopeDto::ReadProjects],
+                    expires_at: None,
+                },
+            )
+            .await;
+        assert!(matches!(res, Ok(created) if ApiTokenSecret::is_api_token(&created.token)));
+    }
+
+    #[tokio::test]
+    async fn 実委人閲覧者はユーザーを閲覧するapiトークンを作成できない() {
+        let repositories = MockRepositories::default();
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeViewer));
+        let res = use_case
+            .create_api_token(
+                &ctx,
+                CreateApiTokenCommand {
+                    name: "export".to_string(),
+                    scopes: vec![ApiTokenScopeDto::ReadUsers],
+                    expires_at: None,
+                },
+            )
+            .await;
+        assert!(matches!(
+            res,
+            Err(UserUseCaseError::ApiTokenError(
+                ApiTokenError::PermissionDenied(_)
+            ))
+        ));
+    }
+}
@@ -0,0 +1,95 @@
+use sos24_domain::ensure;
+use sos24_domain::entity::api_token::ApiTokenId;
+use sos24_domain::repository::{api_token::ApiTokenRepository, Repositories};
+
+use crate::shared::adapter::Adapters;
+use crate::shared::context::ContextProvider;
+use crate::user::{UserUseCase, UserUseCaseError};
+
+impl<R: Repositories, A: Adapters> UserUseCase<R, A> {
+    pub async fn delete_api_token(
+        &self,
+        ctx: &impl ContextProvider,
+        id: String,
+    ) -> Result<(), UserUseCaseError> {
+        // APIトークンでは自分のトークンを操作させない
+        ensure!(ctx.scopes().is_none());
+        let actor = ctx.actor(&*self.repositories).await?;
+
+        let id = ApiTokenId::try_from(id)?;
+        let token = self
+            .repositories
+            .api_token_repository()
+            .find_by_id(id.clone())
+            .await?
+            .ok_or(UserUseCaseError::ApiTokenNotFound(id.clone()))?;
+        // 他人のトークンは存在も明かさない
+        if !token.is_visible_to(&actor) {
+            return Err(UserUseCaseError::ApiTokenNotFound(id));
+        }
+
+        self.repositories
+            .api_token_repository()
+            .delete_by_id(id)
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::entity::user::UserRole;
+    use sos24_domain::test::{fixture, repository::MockRepositories};
+
+    use crate::shared::adapter::MockAdapters;
+    use crate::shared::context::TestContext;
+    use crate::user::{UserUseCase, UserUseCaseError};
+
+    #[tokio::test]
+    async fn 自分のapiトークンを削除できる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .api_token_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::api_token::api_token1())));
+        repositories
+            .api_token_repository_mut()
+            .expect_delete_by_id()
+            .returning(|_| Ok(()));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeViewer));
+        let res = use_case
+            .delete_api_token(&ctx, fixture::api_token::id1().value().to_string())
+            .await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn 管理者でも他人のapiトークンは削除できない() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .api_token_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::api_token::api_token1())));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
+
+        let ctx = TestContext::new(fixture::actor::actor2(UserRole::Administrator));
+        let res = use_case
+            .delete_api_token(&ctx, fixture::api_token::id1().value().to_string())
+            .await;
+        assert!(matches!(res, Err(UserUseCaseError::ApiTokenNotFound(_))));
+    }
+}
@@ -5,10 +5,11 @@ use sos24_domain::{
     repository::{user::UserRepository, Repositories},
 };
 
+use crate::shared::adapter::Adapters;
 use crate::shared::context::ContextProvider;
 use crate::user::{UserUseCase, UserUseCaseError};
 
-impl<R: Repositories> UserUseCase<R> {
+impl<R: Repositories, A: Adapters> UserUseCase<R, A> {
     pub async fn delete_by_id(
         &self,
         ctx: &impl ContextProvider,
@@ -37,6 +38,7 @@ mod tests {
     use sos24_domain::entity::user::UserRole;
     use sos24_domain::test::{fixture, repository::MockRepositories};
 
+    use crate::shared::adapter::MockAdapters;
     use crate::shared::context::TestContext;
     use crate::user::{UserUseCase, UserUseCaseError};
 
@@ -51,7 +53,12 @@ mod tests {
             .user_repository_mut()
             .expect_delete_by_id()
             .returning(|_| Ok(()));
-        let use_case = UserUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeViewer));
         let res = use_case
@@ -76,7 +83,12 @@ mod tests {
             .user_repository_mut()
             .expect_delete_by_id()
             .returning(|_| Ok(()));
-        let use_case = UserUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeEditor));
         let res = use_case
@@ -101,7 +113,12 @@ mod tests {
             .user_repository_mut()
             .expect_delete_by_id()
             .returning(|_| Ok(()));
-        let use_case = UserUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeOperator));
         let res = use_case
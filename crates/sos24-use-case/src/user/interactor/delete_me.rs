@@ -0,0 +1,214 @@
+use sos24_domain::ensure;
+use sos24_domain::entity::firebase_user::FirebaseUserId;
+use sos24_domain::repository::api_token::ApiTokenRepository;
+use sos24_domain::repository::firebase_user::FirebaseUserRepository;
+use sos24_domain::repository::project::ProjectRepository;
+use sos24_domain::repository::{user::UserRepository, Repositories};
+
+use crate::shared::adapter::Adapters;
+use crate::shared::context::ContextProvider;
+use crate::user::{UserUseCase, UserUseCaseError};
+
+impl<R: Repositories, A: Adapters> UserUseCase<R, A> {
+    // 退会する
+    // 個人情報を消去した上でユーザーを削除し、ログインできないようにする
+    pub async fn delete_me(&self, ctx: &impl ContextProvider) -> Result<(), UserUseCaseError> {
+        // APIトークンやなりすましでは退会させない
+        ensure!(ctx.scopes().is_none() && ctx.impersonator().is_none());
+        let actor = ctx.actor(&*self.repositories).await?;
+
+        let user_id = actor.user_id().clone();
+        let mut user = self
+            .repositories
+            .user_repository()
+            .find_by_id(user_id.clone())
+            .await?
+            .ok_or(UserUseCaseError::NotFound(user_id.clone()))?;
+
+        // 企画の責任者・副責任者のままでは退会できないので、先に他のユーザーへ引き継いでもらう
+        if let Some(project_with_owners) = self
+            .repositories
+            .project_repository()
+            .find_by_owner_id(user_id.clone())
+            .await?
+        {
+            return Err(UserUseCaseError::StillOwnsProject(
+                project_with_owners.project.id().clone(),
+            ));
+        }
+        if let Some(project_with_owners) = self
+            .repositories
+            .project_repository()
+            .find_by_sub_owner_id(user_id.clone())
+            .await?
+        {
+            return Err(UserUseCaseError::StillOwnsProject(
+                project_with_owners.project.id().clone(),
+            ));
+        }
+
+        // 認証ユーザーを先に削除する
+        // 以降の処理に失敗してもログインはできなくなり、残ったデータは管理者がユーザー削除で片付けられる
+        self.repositories
+            .firebase_user_repository()
+            .delete_by_id(FirebaseUserId::from(user_id.clone()))
+            .await?;
+
+        let token_list = self
+            .repositories
+            .api_token_repository()
+            .find_by_user_id(user_id.clone())
+            .await?;
+        for token in token_list {
+            self.repositories
+                .api_token_repository()
+                .delete_by_id(token.id().clone())
+                .await?;
+        }
+
+        user.anonymize(&actor)?;
+        self.repositories.user_repository().update(user).await?;
+        self.repositories
+            .user_repository()
+            .delete_by_id(user_id)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::entity::user::UserRole;
+    use sos24_domain::repository::firebase_user::FirebaseUserRepositoryError;
+    use sos24_domain::test::{fixture, repository::MockRepositories};
+
+    use crate::shared::adapter::MockAdapters;
+    use crate::shared::context::TestContext;
+    use crate::user::{UserUseCase, UserUseCaseError};
+
+    #[tokio::test]
+    async fn 一般ユーザーは退会できる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .user_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::user::user1(UserRole::General))));
+        repositories
+            .project_repository_mut()
+            .expect_find_by_owner_id()
+            .returning(|_| Ok(None));
+        repositories
+            .project_repository_mut()
+            .expect_find_by_sub_owner_id()
+            .returning(|_| Ok(None));
+        repositories
+            .api_token_repository_mut()
+            .expect_find_by_user_id()
+            .returning(|_| Ok(vec![]));
+        repositories
+            .user_repository_mut()
+            .expect_update()
+            .withf(|user| {
+                user.id() == &fixture::user::id1() && user.email() != &fixture::user::email1()
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+        repositories
+            .user_repository_mut()
+            .expect_delete_by_id()
+            .times(1)
+            .returning(|_| Ok(()));
+        repositories
+            .firebase_user_repository_mut()
+            .expect_delete_by_id()
+            .times(1)
+            .returning(|_| Ok(()));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case.delete_me(&ctx).await;
+        assert!(matches!(res, Ok(())));
+    }
+
+    #[tokio::test]
+    async fn 企画責任者は退会できない() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .user_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::user::user1(UserRole::General))));
+        repositories
+            .project_repository_mut()
+            .expect_find_by_owner_id()
+            .returning(|_| {
+                Ok(Some(fixture::project::project_with_owners1(
+                    fixture::user::user1(UserRole::General),
+                )))
+            });
+        repositories
+            .user_repository_mut()
+            .expect_delete_by_id()
+            .never();
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case.delete_me(&ctx).await;
+        assert!(matches!(res, Err(UserUseCaseError::StillOwnsProject(_))));
+    }
+
+    #[tokio::test]
+    async fn 認証ユーザーの削除に失敗した場合は個人情報を消去しない() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .user_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::user::user1(UserRole::General))));
+        repositories
+            .project_repository_mut()
+            .expect_find_by_owner_id()
+            .returning(|_| Ok(None));
+        repositories
+            .project_repository_mut()
+            .expect_find_by_sub_owner_id()
+            .returning(|_| Ok(None));
+        repositories
+            .firebase_user_repository_mut()
+            .expect_delete_by_id()
+            .returning(|_| {
+                Err(FirebaseUserRepositoryError::InternalError(anyhow::anyhow!(
+                    "failed"
+                )))
+            });
+        repositories.user_repository_mut().expect_update().never();
+        repositories
+            .user_repository_mut()
+            .expect_delete_by_id()
+            .never();
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case.delete_me(&ctx).await;
+        assert!(matches!(
+            res,
+            Err(UserUseCaseError::FirebaseUserRepositoryError(_))
+        ));
+    }
+}
@@ -0,0 +1,207 @@
+use sos24_domain::ensure;
+use sos24_domain::entity::permission::Permissions;
+use sos24_domain::repository::form::FormRepository;
+use sos24_domain::repository::form_answer::FormAnswerRepository;
+use sos24_domain::repository::invitation::InvitationRepository;
+use sos24_domain::repository::project::ProjectRepository;
+use sos24_domain::repository::{user::UserRepository, Repositories};
+
+use crate::form_answer::dto::FormAnswerDto;
+use crate::invitation::dto::InvitationDto;
+use crate::project::dto::ProjectDto;
+use crate::shared::adapter::Adapters;
+use crate::shared::context::ContextProvider;
+use crate::user::dto::{PersonalDataDto, UserDto};
+use crate::user::{UserUseCase, UserUseCaseError};
+
+impl<R: Repositories, A: Adapters> UserUseCase<R, A> {
+    // 自分に関する個人データをまとめて取得する
+    pub async fn export_personal_data(
+        &self,
+        ctx: &impl ContextProvider,
+    ) -> Result<PersonalDataDto, UserUseCaseError> {
+        // APIトークンやなりすましでは個人データを持ち出させない
+        ensure!(ctx.scopes().is_none() && ctx.impersonator().is_none());
+        let actor = ctx.actor(&*self.repositories).await?;
+
+        let user_id = actor.user_id().clone();
+        let raw_user = self
+            .repositories
+            .user_repository()
+            .find_by_id(user_id.clone())
+            .await?
+            .ok_or(UserUseCaseError::NotFound(user_id.clone()))?;
+
+        let project_with_owners = match self
+            .repositories
+            .project_repository()
+            .find_by_owner_id(user_id.clone())
+            .await?
+        {
+            Some(project_with_owners) => Some(project_with_owners),
+            None => {
+                self.repositories
+                    .project_repository()
+                    .find_by_sub_owner_id(user_id.clone())
+                    .await?
+            }
+        };
+
+        let mut form_answer_list = Vec::new();
+        if let Some(project_with_owners) = &project_with_owners {
+            let project = &project_with_owners.project;
+            let raw_form_answer_list = self
+                .repositories
+                .form_answer_repository()
+                .find_by_project_id(project.id().clone())
+                .await?;
+
+            for raw_form_answer in raw_form_answer_list {
+                let form_id = raw_form_answer.form_id();
+                if let Some(raw_form) = self
+                    .repositories
+                    .form_repository()
+                    .find_by_id(form_id.clone())
+                    .await?
+                {
+                    form_answer_list.push(FormAnswerDto::from((
+                        raw_form_answer,
+                        project.clone(),
+                        raw_form,
+                    )));
+                } else {
+                    tracing::warn!("申請が見つからないため、回答を除外します: {form_id:?}");
+                }
+            }
+        }
+
+        let raw_invitation_list = self
+            .repositories
+            .invitation_repository()
+            .find_by_inviter(user_id.clone())
+            .await?;
+
+        let mut invitation_list = Vec::new();
+        for raw_invitation in raw_invitation_list {
+            let project_id = raw_invitation.project_id();
+            if let Some(invited_project) = self
+                .repositories
+                .project_repository()
+                .find_by_id(project_id.clone())
+                .await?
+            {
+                invitation_list.push(InvitationDto::from((
+                    raw_invitation,
+                    raw_user.clone(),
+                    invited_project.project,
+                )));
+            } else {
+                tracing::warn!("企画が見つからないため、招待を除外します: {project_id:?}");
+            }
+        }
+
+        let raw_project = project_with_owners
+            .as_ref()
+            .map(|project_with_owners| project_with_owners.project.clone());
+        // 備考は実委人向けのものなので、企画を閲覧する権限がなければ含めない
+        let project = project_with_owners.map(|project_with_owners| {
+            let category = *project_with_owners.project.category();
+            let mut project = ProjectDto::from(project_with_owners);
+            if !actor.has_project_permission(Permissions::READ_PROJECT_ALL, category) {
+                project.remarks = None;
+            }
+            project
+        });
+        Ok(PersonalDataDto {
+            user: UserDto::from((raw_user, raw_project)),
+            project,
+            invitations: invitation_list,
+            form_answers: form_answer_list,
+            exported_at: chrono::Utc::now(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::entity::actor::Actor;
+    use sos24_domain::entity::invitation::InvitationPosition;
+    use sos24_domain::entity::project::ProjectRemarks;
+    use sos24_domain::entity::user::UserRole;
+    use sos24_domain::test::{fixture, repository::MockRepositories};
+
+    use crate::shared::adapter::MockAdapters;
+    use crate::shared::context::TestContext;
+    use crate::user::UserUseCase;
+
+    #[tokio::test]
+    async fn 一般ユーザーは自分の個人データを取得できる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .user_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::user::user1(UserRole::General))));
+        repositories
+            .project_repository_mut()
+            .expect_find_by_owner_id()
+            .returning(|_| {
+                let mut project_with_owners =
+                    fixture::project::project_with_owners1(fixture::user::user1(UserRole::General));
+                project_with_owners
+                    .project
+                    .set_remarks(
+                        &Actor::new_admin(),
+                        ProjectRemarks::new("実委人向けの備考".to_string()),
+                    )
+                    .unwrap();
+                Ok(Some(project_with_owners))
+            });
+        repositories
+            .project_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| {
+                Ok(Some(fixture::project::project_with_owners1(
+                    fixture::user::user1(UserRole::General),
+                )))
+            });
+        repositories
+            .form_answer_repository_mut()
+            .expect_find_by_project_id()
+            .returning(|_| {
+                Ok(vec![fixture::form_answer::form_answer1(
+                    fixture::project::id1(),
+                )])
+            });
+        repositories
+            .form_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::form::form1_opened())));
+        repositories
+            .invitation_repository_mut()
+            .expect_find_by_inviter()
+            .returning(|_| {
+                Ok(vec![fixture::invitation::invitation(
+                    fixture::user::id1(),
+                    fixture::project::id1(),
+                    InvitationPosition::SubOwner,
+                )])
+            });
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case.export_personal_data(&ctx).await.unwrap();
+        assert_eq!(res.user.id, fixture::user::id1().value());
+        let project = res.project.unwrap();
+        assert_eq!(project.id, fixture::project::id1().value().to_string());
+        assert_eq!(project.remarks, None);
+        assert_eq!(res.invitations.len(), 1);
+        assert_eq!(res.form_answers.len(), 1);
+    }
+}
@@ -4,11 +4,12 @@ use sos24_domain::entity::user::UserId;
 use sos24_domain::repository::project::ProjectRepository;
 use sos24_domain::repository::{user::UserRepository, Repositories};
 
+use crate::shared::adapter::Adapters;
 use crate::shared::context::ContextProvider;
 use crate::user::dto::UserDto;
 use crate::user::{UserUseCase, UserUseCaseError};
 
-impl<R: Repositories> UserUseCase<R> {
+impl<R: Repositories, A: Adapters> UserUseCase<R, A> {
     pub async fn find_by_id(
         &self,
         ctx: &impl ContextProvider,
@@ -57,10 +58,11 @@ async fn find_owned_project(
 mod tests {
     use std::sync::Arc;
 
-    use sos24_domain::entity::permission::PermissionDeniedError;
+    use sos24_domain::entity::permission::{PermissionDeniedError, Permissions};
     use sos24_domain::entity::user::UserRole;
     use sos24_domain::test::{fixture, repository::MockRepositories};
 
+    use crate::shared::adapter::MockAdapters;
     use crate::shared::context::TestContext;
     use crate::user::{UserUseCase, UserUseCaseError};
 
@@ -79,7 +81,12 @@ mod tests {
             .project_repository_mut()
             .expect_find_by_sub_owner_id()
             .returning(|_| Ok(None));
-        let use_case = UserUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeViewer));
         let res = use_case
@@ -103,7 +110,12 @@ mod tests {
             .project_repository_mut()
             .expect_find_by_sub_owner_id()
             .returning(|_| Ok(None));
-        let use_case = UserUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeViewer));
         let res = use_case
@@ -132,7 +144,12 @@ mod tests {
             .project_repository_mut()
             .expect_find_by_sub_owner_id()
             .returning(|_| Ok(None));
-        let use_case = UserUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeDrafter));
         let res = use_case
@@ -161,7 +178,12 @@ mod tests {
             .project_repository_mut()
             .expect_find_by_sub_owner_id()
             .returning(|_| Ok(None));
-        let use_case = UserUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeEditor));
         let res = use_case
@@ -185,7 +207,12 @@ mod tests {
             .project_repository_mut()
             .expect_find_by_sub_owner_id()
             .returning(|_| Ok(None));
-        let use_case = UserUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeOperator));
         let res = use_case
@@ -193,4 +220,39 @@ mod tests {
             .await;
         assert!(res.is_ok());
     }
+
+    #[tokio::test]
+    async fn ユーザーのスコープがないapiトークンでは自分のユーザーを取得できない() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .user_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::user::user1(UserRole::General))));
+        repositories
+            .project_repository_mut()
+            .expect_find_by_owner_id()
+            .returning(|_| Ok(None));
+        repositories
+            .project_repository_mut()
+            .expect_find_by_sub_owner_id()
+            .returning(|_| Ok(None));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General))
+            .with_scopes(Permissions::READ_NEWS_ALL);
+        let res = use_case
+            .find_by_id(&ctx, fixture::user::id1().value())
+            .await;
+        assert!(matches!(
+            res,
+            Err(UserUseCaseError::PermissionDeniedError(
+                PermissionDeniedError
+            ))
+        ));
+    }
 }
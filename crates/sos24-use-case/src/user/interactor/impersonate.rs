@@ -0,0 +1,180 @@
+use sos24_domain::entity::impersonation_log::{ImpersonationLog, ImpersonationLogRequest};
+use sos24_domain::entity::user::UserId;
+use sos24_domain::repository::{impersonation_log::ImpersonationLogRepository, Repositories};
+
+use crate::shared::adapter::Adapters;
+use crate::shared::context::{actor_of, ContextError, ContextProvider};
+use crate::user::{UserUseCase, UserUseCaseError};
+
+#[derive(Debug)]
+pub struct ImpersonateCommand {
+    pub user_id: String,
+    // なりすまして行うリクエスト (例: "GET /projects/me")
+    pub request: String,
+}
+
+impl<R: Repositories, A: Adapters> UserUseCase<R, A> {
+    // なりすましが可能か確認し、記録を残す
+    pub async fn impersonate(
+        &self,
+        ctx: &impl ContextProvider,
+        command: ImpersonateCommand,
+    ) -> Result<(), UserUseCaseError> {
+        let actor = ctx.actor(&*self.repositories).await?;
+
+        // カスタムロールを含めた権限で比較するため、対象のユーザーのActorを組み立てる
+        let user = actor_of(&*self.repositories, UserId::new(command.user_id))
+            .await
+            .map_err(|err| match err {
+                ContextError::UserNotFound(user_id) => UserUseCaseError::NotFound(user_id),
+                err => err.into(),
+            })?;
+
+        let log =
+            ImpersonationLog::create(&actor, &user, ImpersonationLogRequest::new(command.request))?;
+        tracing::warn!(
+            "ユーザー {:?} が {:?} としてリクエストを行います: {:?}",
+            log.impersonator_id(),
+            log.user_id(),
+            log.request()
+        );
+        self.repositories
+            .impersonation_log_repository()
+            .create(log)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::entity::{
+        impersonation_log::ImpersonationLogError, permission::PermissionDeniedError, user::UserRole,
+    };
+    use sos24_domain::test::{fixture, repository::MockRepositories};
+
+    use crate::shared::adapter::MockAdapters;
+    use crate::shared::context::TestContext;
+    use crate::user::interactor::impersonate::ImpersonateCommand;
+    use crate::user::{UserUseCase, UserUseCaseError};
+
+    fn command() -> ImpersonateCommand {
+        ImpersonateCommand {
+            user_id: fixture::user::id2().value(),
+            request: fixture::impersonation_log::request1().value(),
+        }
+    }
+
+    #[tokio::test]
+    async fn 管理者は一般ユーザーになりすませる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .user_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::user::user2(UserRole::General))));
+        repositories
+            .custom_role_repository_mut()
+            .expect_find_by_user_id()
+            .returning(|_| Ok(vec![]));
+        repositories
+            .impersonation_log_repository_mut()
+            .expect_create()
+            .times(1)
+            .returning(|_| Ok(()));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::Administrator));
+        let res = use_case.impersonate(&ctx, command()).await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn 実委人管理者はなりすませない() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .user_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::user::user2(UserRole::General))));
+        repositories
+            .custom_role_repository_mut()
+            .expect_find_by_user_id()
+            .returning(|_| Ok(vec![]));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeOperator));
+        let res = use_case.impersonate(&ctx, command()).await;
+        assert!(matches!(
+            res,
+            Err(UserUseCaseError::ImpersonationLogError(
+                ImpersonationLogError::PermissionDenied(PermissionDeniedError)
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn 存在しないユーザーにはなりすませない() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .user_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(None));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::Administrator));
+        let res = use_case.impersonate(&ctx, command()).await;
+        assert!(matches!(res, Err(UserUseCaseError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn カスタムロールで自分にない権限を持つユーザーにはなりすませない() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .user_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::user::user2(UserRole::General))));
+        repositories
+            .custom_role_repository_mut()
+            .expect_find_by_user_id()
+            .returning(|_| Ok(vec![fixture::custom_role::stage_role1()]));
+        repositories
+            .impersonation_log_repository_mut()
+            .expect_create()
+            .never();
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
+
+        let ctx = TestContext::new(
+            fixture::actor::actor1(UserRole::CommitteeViewer)
+                .with_custom_roles(&[fixture::custom_role::impersonator_role1()]),
+        );
+        let res = use_case.impersonate(&ctx, command()).await;
+        assert!(matches!(
+            res,
+            Err(UserUseCaseError::ImpersonationLogError(
+                ImpersonationLogError::CannotImpersonatePrivilegedUser
+            ))
+        ));
+    }
+}
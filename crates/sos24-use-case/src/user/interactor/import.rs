@@ -0,0 +1,415 @@
+use std::collections::HashSet;
+
+use sos24_domain::ensure;
+use sos24_domain::entity::actor::Actor;
+use sos24_domain::entity::firebase_user::{
+    FirebaseUserEmail, FirebaseUserPassword, NewFirebaseUser,
+};
+use sos24_domain::entity::permission::Permissions;
+use sos24_domain::entity::user::{
+    User, UserEmail, UserId, UserKanaName, UserName, UserPhoneNumber, UserRole,
+};
+use sos24_domain::repository::{
+    firebase_user::FirebaseUserRepository, user::UserRepository, Repositories,
+};
+
+use crate::shared::adapter::email::{escape_html, Email, EmailSender, SendEmailCommand};
+use crate::shared::adapter::Adapters;
+use crate::shared::context::ContextProvider;
+use crate::user::dto::{ImportUserResultDto, ImportUserStatusDto};
+use crate::user::{UserUseCase, UserUseCaseError};
+
+#[derive(Debug)]
+pub struct ImportUsersCommand {
+    pub rows: Vec<ImportUserRow>,
+    // trueの場合は検証のみを行い、ユーザーを作成しない
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportUserRow {
+    pub name: String,
+    pub kana_name: String,
+    pub email: String,
+    pub phone_number: String,
+    // APIと同じ表記 (例: committee_editor)
+    pub role: String,
+}
+
+// 検証済みの行
+struct ValidatedRow {
+    name: UserName,
+    kana_name: UserKanaName,
+    email: UserEmail,
+    phone_number: UserPhoneNumber,
+    role: UserRole,
+}
+
+impl<R: Repositories, A: Adapters> UserUseCase<R, A> {
+    pub async fn import(
+        &self,
+        ctx: &impl ContextProvider,
+        command: ImportUsersCommand,
+    ) -> Result<Vec<ImportUserResultDto>, UserUseCaseError> {
+        let actor = ctx.actor(&*self.repositories).await?;
+        ensure!(actor.has_permission(Permissions::IMPORT_USER));
+
+        // 既存のユーザーや、CSV内の他の行と重複していないか確認する
+        let existing_users = self.repositories.user_repository().list().await?;
+        let mut emails = existing_users
+            .iter()
+            .map(|user| user.email().clone().value().to_ascii_lowercase())
+            .collect::<HashSet<_>>();
+        let mut phone_numbers = existing_users
+            .iter()
+            .map(|user| user.phone_number().clone().value())
+            .collect::<HashSet<_>>();
+
+        let mut results = Vec::with_capacity(command.rows.len());
+        for (index, row) in command.rows.into_iter().enumerate() {
+            let mut result = ImportUserResultDto {
+                row: index + 1,
+                email: row.email.clone(),
+                status: ImportUserStatusDto::Failed,
+                user_id: None,
+                email_sent: false,
+                error: None,
+            };
+
+            let validated = match self.validate_row(&actor, row) {
+                Ok(validated) => validated,
+                Err(error) => {
+                    result.error = Some(error);
+                    results.push(result);
+                    continue;
+                }
+            };
+            if !emails.insert(validated.email.clone().value().to_ascii_lowercase()) {
+                result.error = Some("Email already used".to_string());
+                results.push(result);
+                continue;
+            }
+            if !phone_numbers.insert(validated.phone_number.clone().value()) {
+                result.error = Some("Phone number already used".to_string());
+                results.push(result);
+                continue;
+            }
+
+            if command.dry_run {
+                result.status = ImportUserStatusDto::Valid;
+                results.push(result);
+                continue;
+            }
+
+            match self.import_row(ctx, &actor, validated).await {
+                Ok((user_id, email_sent)) => {
+                    result.status = ImportUserStatusDto::Created;
+                    result.user_id = Some(user_id);
+                    result.email_sent = email_sent;
+                }
+                Err(error) => {
+                    tracing::error!("Failed to import user: {error}");
+                    result.error = Some(error.to_string());
+                }
+            }
+            results.push(result);
+        }
+
+        Ok(results)
+    }
+
+    fn validate_row(&self, actor: &Actor, row: ImportUserRow) -> Result<ValidatedRow, String> {
+        if row.name.trim().is_empty() || row.kana_name.trim().is_empty() {
+            return Err("Name is empty".to_string());
+        }
+        let role = parse_role(&row.role).ok_or(format!("Invalid role: {}", row.role))?;
+        if &role > actor.role() {
+            return Err(format!("Cannot import a user with role: {}", row.role));
+        }
+        let email = UserEmail::try_from(row.email).map_err(|e| e.to_string())?;
+        self.email_allowlist
+            .ensure_registrable(&email.clone().raw_value(), Some(actor), &role)
+            .map_err(|e| e.to_string())?;
+        let phone_number =
+            UserPhoneNumber::try_from(row.phone_number).map_err(|e| e.to_string())?;
+
+        Ok(ValidatedRow {
+            name: UserName::new(row.name.trim().to_string()),
+            kana_name: UserKanaName::new(row.kana_name.trim().to_string()),
+            email,
+            phone_number,
+            role,
+        })
+    }
+
+    // ユーザーを作成し、パスワード設定用のリンクを送信する
+    // メールの送信に失敗してもユーザーの作成は取り消さない
+    async fn import_row(
+        &self,
+        ctx: &impl ContextProvider,
+        actor: &Actor,
+        row: ValidatedRow,
+    ) -> Result<(String, bool), UserUseCaseError> {
+        let firebase_email = FirebaseUserEmail::from(row.email.clone());
+        let firebase_user =
+            NewFirebaseUser::new(firebase_email.clone(), FirebaseUserPassword::generate());
+        let firebase_user_id = self
+            .repositories
+            .firebase_user_repository()
+            .create(firebase_user)
+            .await?;
+
+        let user = User::import(
+            actor,
+            UserId::from(firebase_user_id.clone()),
+            row.name,
+            row.kana_name,
+            row.email,
+            row.phone_number,
+            row.role,
+        );
+        let res = match user {
+            Ok(user) => self
+                .repositories
+                .user_repository()
+                .create(user)
+                .await
+                .map_err(UserUseCaseError::from),
+            Err(e) => Err(e.into()),
+        };
+        if let Err(e) = res {
+            self.repositories
+                .firebase_user_repository()
+                .delete_by_id(firebase_user_id)
+                .await?;
+            return Err(e);
+        }
+
+        let user_id = firebase_user_id.value();
+        let email_sent = match self.send_password_setup_email(ctx, firebase_email).await {
+            Ok(()) => true,
+            Err(e) => {
+                tracing::error!("Failed to send password setup email: {e}");
+                false
+            }
+        };
+        Ok((user_id, email_sent))
+    }
+
+    async fn send_password_setup_email(
+        &self,
+        ctx: &impl ContextProvider,
+        email: FirebaseUserEmail,
+    ) -> Result<(), UserUseCaseError> {
+        let link = self
+            .repositories
+            .firebase_user_repository()
+            .generate_password_reset_link(email.clone())
+            .await?;
+
+        let command = SendEmailCommand {
+            from: Email {
+                address: ctx.config().email_sender_address.clone(),
+                name: String::from("雙峰祭オンラインシステム"),
+            },
+            to: vec![email.value()],
+            reply_to: Some(ctx.config().email_reply_to_address.clone()),
+            subject: String::from("雙峰祭オンラインシステムのアカウントが作成されました"),
+            body: format!(
+                r#"雙峰祭オンラインシステムのアカウントが作成されました。
+以下のリンクからパスワードを設定してください。
+{link}
+
+※このメールは雙峰祭オンラインシステムが自動送信しています。
+＿＿＿
+筑波大学学園祭実行委員会
+Email : {email}
+電話 : 029-853-2899"#,
+                email = ctx.config().email_reply_to_address.clone(),
+            ),
+            html_body: Some(format!(
+                r#"<p>雙峰祭オンラインシステムのアカウントが作成されました。<br>
+以下のリンクからパスワードを設定してください。<br>
+<a href="{link}">{link}</a></p>
+<p>※このメールは雙峰祭オンラインシステムが自動送信しています。<br>
+＿＿＿<br>
+筑波大学学園祭実行委員会<br>
+Email : {email}<br>
+電話 : 029-853-2899</p>"#,
+                link = escape_html(&link),
+                email = escape_html(&ctx.config().email_reply_to_address),
+            )),
+        };
+        self.adapters.email_sender().send_email(command).await?;
+        Ok(())
+    }
+}
+
+fn parse_role(value: &str) -> Option<UserRole> {
+    match value.trim() {
+        "administrator" => Some(UserRole::Administrator),
+        "committee_operator" => Some(UserRole::CommitteeOperator),
+        "committee_editor" => Some(UserRole::CommitteeEditor),
+        "committee_drafter" => Some(UserRole::CommitteeDrafter),
+        "committee_viewer" => Some(UserRole::CommitteeViewer),
+        "general" => Some(UserRole::General),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::entity::{permission::PermissionDeniedError, user::UserRole};
+    use sos24_domain::test::{fixture, repository::MockRepositories};
+
+    use crate::shared::adapter::MockAdapters;
+    use crate::shared::context::TestContext;
+    use crate::user::dto::ImportUserStatusDto;
+    use crate::user::interactor::import::{ImportUserRow, ImportUsersCommand};
+    use crate::user::{UserUseCase, UserUseCaseError};
+
+    fn row(email: &str, phone_number: &str, role: &str) -> ImportUserRow {
+        ImportUserRow {
+            name: fixture::user::name1().value(),
+            kana_name: fixture::user::kana_name1().value(),
+            email: email.to_string(),
+            phone_number: phone_number.to_string(),
+            role: role.to_string(),
+        }
+    }
+
+    fn rows() -> Vec<ImportUserRow> {
+        vec![
+            row(
+                "s0000001@u.tsukuba.ac.jp",
+                "090-1111-1111",
+                "committee_editor",
+            ),
+            row("invalid", "090-2222-2222", "committee_viewer"),
+            row(
+                "s0000003@u.tsukuba.ac.jp",
+                "090-1111-1111",
+                "committee_viewer",
+            ),
+            row("s0000004@u.tsukuba.ac.jp", "090-4444-4444", "owner"),
+        ]
+    }
+
+    #[tokio::test]
+    async fn 管理者はdry_runで各行を検証できる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .user_repository_mut()
+            .expect_list()
+            .returning(|| Ok(vec![]));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::Administrator));
+        let res = use_case
+            .import(
+                &ctx,
+                ImportUsersCommand {
+                    rows: rows(),
+                    dry_run: true,
+                },
+            )
+            .await
+            .unwrap();
+        let statuses = res.iter().map(|it| &it.status).collect::<Vec<_>>();
+        assert_eq!(
+            statuses,
+            vec![
+                &ImportUserStatusDto::Valid,
+                &ImportUserStatusDto::Failed,
+                &ImportUserStatusDto::Failed,
+                &ImportUserStatusDto::Failed,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn 管理者はユーザーを一括登録できる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .user_repository_mut()
+            .expect_list()
+            .returning(|| Ok(vec![]));
+        repositories
+            .firebase_user_repository_mut()
+            .expect_create()
+            .times(1)
+            .returning(|_| Ok(fixture::firebase_user::id1()));
+        repositories
+            .user_repository_mut()
+            .expect_create()
+            .times(1)
+            .returning(|_| Ok(()));
+        repositories
+            .firebase_user_repository_mut()
+            .expect_generate_password_reset_link()
+            .returning(|_| Ok("https://example.com/reset".to_string()));
+        let mut adapters = MockAdapters::default();
+        adapters
+            .email_sender_mut()
+            .expect_send_email()
+            .times(1)
+            .returning(|_| Ok(()));
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::Administrator));
+        let res = use_case
+            .import(
+                &ctx,
+                ImportUsersCommand {
+                    rows: rows(),
+                    dry_run: false,
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(res[0].status, ImportUserStatusDto::Created);
+        assert!(res[0].email_sent);
+        assert!(res[1..]
+            .iter()
+            .all(|it| it.status == ImportUserStatusDto::Failed));
+    }
+
+    #[tokio::test]
+    async fn 実委人管理者はユーザーを一括登録できない() {
+        let repositories = MockRepositories::default();
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeOperator));
+        let res = use_case
+            .import(
+                &ctx,
+                ImportUsersCommand {
+                    rows: rows(),
+                    dry_run: true,
+                },
+            )
+            .await;
+        assert!(matches!(
+            res,
+            Err(UserUseCaseError::PermissionDeniedError(
+                PermissionDeniedError
+            ))
+        ));
+    }
+}
@@ -4,12 +4,13 @@ use sos24_domain::{
     repository::{user::UserRepository, Repositories},
 };
 
+use crate::shared::adapter::Adapters;
 use crate::{
     shared::context::ContextProvider,
     user::{dto::UserDto, UserUseCase, UserUseCaseError},
 };
 
-impl<R: Repositories> UserUseCase<R> {
+impl<R: Repositories, A: Adapters> UserUseCase<R, A> {
     pub async fn list(&self, ctx: &impl ContextProvider) -> Result<Vec<UserDto>, UserUseCaseError> {
         let actor = ctx.actor(&*self.repositories).await?;
         ensure!(actor.has_permission(Permissions::READ_USER_ALL));
@@ -31,6 +32,7 @@ mod tests {
         test::{fixture, repository::MockRepositories},
     };
 
+    use crate::shared::adapter::MockAdapters;
     use crate::{
         shared::context::TestContext,
         user::{UserUseCase, UserUseCaseError},
@@ -39,7 +41,12 @@ mod tests {
     #[tokio::test]
     async fn 実委人閲覧者はユーザー一覧を取得できない() {
         let repositories = MockRepositories::default();
-        let use_case = UserUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeViewer));
         let res = use_case.list(&ctx).await;
@@ -54,7 +61,12 @@ mod tests {
     #[tokio::test]
     async fn 実委人起草者はユーザー一覧を取得できない() {
         let repositories = MockRepositories::default();
-        let use_case = UserUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeDrafter));
         let res = use_case.list(&ctx).await;
@@ -73,7 +85,12 @@ mod tests {
             .user_repository_mut()
             .expect_list()
             .returning(|| Ok(vec![]));
-        let use_case = UserUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeEditor));
         let res = use_case.list(&ctx).await;
@@ -87,7 +104,12 @@ mod tests {
             .user_repository_mut()
             .expect_list()
             .returning(|| Ok(vec![]));
-        let use_case = UserUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeOperator));
         let res = use_case.list(&ctx).await;
@@ -0,0 +1,86 @@
+use sos24_domain::ensure;
+use sos24_domain::repository::{api_token::ApiTokenRepository, Repositories};
+
+use crate::shared::adapter::Adapters;
+use crate::shared::context::ContextProvider;
+use crate::user::dto::ApiTokenDto;
+use crate::user::{UserUseCase, UserUseCaseError};
+
+impl<R: Repositories, A: Adapters> UserUseCase<R, A> {
+    pub async fn list_api_tokens(
+        &self,
+        ctx: &impl ContextProvider,
+    ) -> Result<Vec<ApiTokenDto>, UserUseCaseError> {
+        // APIトークンでは自分のトークンを操作させない
+        ensure!(ctx.scopes().is_none());
+        let actor = ctx.actor(&*self.repositories).await?;
+
+        let tokens = self
+            .repositories
+            .api_token_repository()
+            .find_by_user_id(actor.user_id().clone())
+            .await?;
+        Ok(tokens
+            .into_iter()
+            .filter(|token| token.is_visible_to(&actor))
+            .map(ApiTokenDto::from)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::entity::permission::{PermissionDeniedError, Permissions};
+    use sos24_domain::entity::user::UserRole;
+    use sos24_domain::test::{fixture, repository::MockRepositories};
+
+    use crate::shared::adapter::MockAdapters;
+    use crate::shared::context::TestContext;
+    use crate::user::{UserUseCase, UserUseCaseError};
+
+    #[tokio::test]
+    async fn 一般ユーザーは自分のapiトークン一覧を取得できる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .api_token_repository_mut()
+            .expect_find_by_user_id()
+            .returning(|_| Ok(vec![]));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case.list_api_tokens(&ctx).await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn apiトークンではapiトークン一覧を取得できない() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .api_token_repository_mut()
+            .expect_find_by_user_id()
+            .never();
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::Administrator))
+            .with_scopes(Permissions::READ_USER_ALL);
+        let res = use_case.list_api_tokens(&ctx).await;
+        assert!(matches!(
+            res,
+            Err(UserUseCaseError::PermissionDeniedError(
+                PermissionDeniedError
+            ))
+        ));
+    }
+}
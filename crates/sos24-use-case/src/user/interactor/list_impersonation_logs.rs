@@ -0,0 +1,85 @@
+use sos24_domain::ensure;
+use sos24_domain::entity::permission::Permissions;
+use sos24_domain::repository::{impersonation_log::ImpersonationLogRepository, Repositories};
+
+use crate::shared::adapter::Adapters;
+use crate::shared::context::ContextProvider;
+use crate::user::dto::ImpersonationLogDto;
+use crate::user::{UserUseCase, UserUseCaseError};
+
+impl<R: Repositories, A: Adapters> UserUseCase<R, A> {
+    pub async fn list_impersonation_logs(
+        &self,
+        ctx: &impl ContextProvider,
+    ) -> Result<Vec<ImpersonationLogDto>, UserUseCaseError> {
+        let actor = ctx.actor(&*self.repositories).await?;
+        ensure!(actor.has_permission(Permissions::READ_IMPERSONATION_LOG_ALL));
+
+        let logs = self
+            .repositories
+            .impersonation_log_repository()
+            .list()
+            .await?;
+        Ok(logs
+            .into_iter()
+            .filter(|log| log.is_visible_to(&actor))
+            .map(ImpersonationLogDto::from)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::entity::{permission::PermissionDeniedError, user::UserRole};
+    use sos24_domain::test::{fixture, repository::MockRepositories};
+
+    use crate::shared::adapter::MockAdapters;
+    use crate::shared::context::TestContext;
+    use crate::user::{UserUseCase, UserUseCaseError};
+
+    #[tokio::test]
+    async fn 管理者はなりすましの記録を取得できる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .impersonation_log_repository_mut()
+            .expect_list()
+            .returning(|| {
+                Ok(vec![fixture::impersonation_log::impersonation_log1(
+                    fixture::user::id1(),
+                    fixture::user::id2(),
+                )])
+            });
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::Administrator));
+        let res = use_case.list_impersonation_logs(&ctx).await;
+        assert!(matches!(res, Ok(logs) if logs.len() == 1));
+    }
+
+    #[tokio::test]
+    async fn 実委人管理者はなりすましの記録を取得できない() {
+        let repositories = MockRepositories::default();
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeOperator));
+        let res = use_case.list_impersonation_logs(&ctx).await;
+        assert!(matches!(
+            res,
+            Err(UserUseCaseError::PermissionDeniedError(
+                PermissionDeniedError
+            ))
+        ));
+    }
+}
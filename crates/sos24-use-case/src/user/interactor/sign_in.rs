@@ -0,0 +1,83 @@
+use sos24_domain::entity::firebase_user::{FirebaseUserEmail, FirebaseUserPassword};
+use sos24_domain::repository::{firebase_user::FirebaseUserRepository, Repositories};
+
+use crate::shared::adapter::Adapters;
+use crate::user::{UserUseCase, UserUseCaseError};
+
+#[derive(Debug)]
+pub struct SignInCommand {
+    pub email: String,
+    pub password: String,
+}
+
+impl<R: Repositories, A: Adapters> UserUseCase<R, A> {
+    pub async fn sign_in(&self, command: SignInCommand) -> Result<String, UserUseCaseError> {
+        let firebase_user_id = self
+            .repositories
+            .firebase_user_repository()
+            .verify_password(
+                FirebaseUserEmail::try_from(command.email)?,
+                FirebaseUserPassword::new(command.password),
+            )
+            .await?
+            .ok_or(UserUseCaseError::InvalidCredentials)?;
+
+        Ok(firebase_user_id.value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::test::{fixture, repository::MockRepositories};
+
+    use crate::shared::adapter::MockAdapters;
+    use crate::user::{interactor::sign_in::SignInCommand, UserUseCase, UserUseCaseError};
+
+    #[tokio::test]
+    async fn 正しいパスワードでサインインできる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .firebase_user_repository_mut()
+            .expect_verify_password()
+            .returning(|_, _| Ok(Some(fixture::firebase_user::id1())));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
+
+        let res = use_case
+            .sign_in(SignInCommand {
+                email: fixture::firebase_user::email1().value(),
+                password: fixture::firebase_user::password1().value(),
+            })
+            .await;
+        assert!(matches!(res, Ok(id) if id == fixture::firebase_user::id1().value()));
+    }
+
+    #[tokio::test]
+    async fn 誤ったパスワードではサインインできない() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .firebase_user_repository_mut()
+            .expect_verify_password()
+            .returning(|_, _| Ok(None));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
+
+        let res = use_case
+            .sign_in(SignInCommand {
+                email: fixture::firebase_user::email1().value(),
+                password: fixture::firebase_user::password1().value(),
+            })
+            .await;
+        assert!(matches!(res, Err(UserUseCaseError::InvalidCredentials)));
+    }
+}
@@ -6,6 +6,7 @@ use sos24_domain::entity::user::{
 use sos24_domain::repository::firebase_user::FirebaseUserRepository;
 use sos24_domain::repository::{user::UserRepository, Repositories};
 
+use crate::shared::adapter::Adapters;
 use crate::shared::context::ContextProvider;
 use crate::user::dto::UserRoleDto;
 use crate::user::{UserUseCase, UserUseCaseError};
@@ -20,7 +21,7 @@ pub struct UpdateUserCommand {
     pub role: UserRoleDto,
 }
 
-impl<R: Repositories> UserUseCase<R> {
+impl<R: Repositories, A: Adapters> UserUseCase<R, A> {
     pub async fn update(
         &self,
         ctx: &impl ContextProvider,
@@ -41,7 +42,7 @@ impl<R: Repositories> UserUseCase<R> {
 
         new_user.set_name(&actor, UserName::new(user_data.name))?;
         new_user.set_kana_name(&actor, UserKanaName::new(user_data.kana_name))?;
-        new_user.set_phone_number(&actor, UserPhoneNumber::new(user_data.phone_number))?;
+        new_user.set_phone_number(&actor, UserPhoneNumber::try_from(user_data.phone_number)?)?;
 
         let old_role = new_user.role().clone();
         let new_role = UserRole::from(user_data.role);
@@ -54,6 +55,11 @@ impl<R: Repositories> UserUseCase<R> {
         let old_email = new_user.email().clone();
         let new_email = UserEmail::try_from(user_data.email)?;
         if old_email != new_email {
+            self.email_allowlist.ensure_registrable(
+                &new_email.clone().raw_value(),
+                Some(&actor),
+                new_user.role(),
+            )?;
             let firebase_user_new_email = new_email.clone().into();
             new_user.set_email(&actor, new_email)?;
             self.repositories
@@ -81,10 +87,12 @@ impl<R: Repositories> UserUseCase<R> {
 mod tests {
     use std::sync::Arc;
 
+    use sos24_domain::entity::common::email::EmailError;
     use sos24_domain::entity::permission::PermissionDeniedError;
     use sos24_domain::entity::user::UserRole;
     use sos24_domain::test::{fixture, repository::MockRepositories};
 
+    use crate::shared::adapter::MockAdapters;
     use crate::shared::context::TestContext;
     use crate::user::dto::UserRoleDto;
     use crate::user::interactor::update::UpdateUserCommand;
@@ -105,7 +113,12 @@ mod tests {
             .firebase_user_repository_mut()
             .expect_update_email_by_id()
             .returning(|_, _| Ok(()));
-        let use_case = UserUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeViewer));
         let res = use_case
@@ -135,7 +148,12 @@ mod tests {
             .user_repository_mut()
             .expect_update()
             .returning(|_| Ok(()));
-        let use_case = UserUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeViewer));
         let res = use_case
@@ -170,7 +188,12 @@ mod tests {
             .user_repository_mut()
             .expect_update()
             .returning(|_| Ok(()));
-        let use_case = UserUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeDrafter));
         let res = use_case
@@ -205,7 +228,12 @@ mod tests {
             .user_repository_mut()
             .expect_update()
             .returning(|_| Ok(()));
-        let use_case = UserUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeViewer));
         let res = use_case
@@ -240,7 +268,12 @@ mod tests {
             .user_repository_mut()
             .expect_update()
             .returning(|_| Ok(()));
-        let use_case = UserUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeDrafter));
         let res = use_case
@@ -275,7 +308,12 @@ mod tests {
             .user_repository_mut()
             .expect_update()
             .returning(|_| Ok(()));
-        let use_case = UserUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeEditor));
         let res = use_case
@@ -305,7 +343,12 @@ mod tests {
             .user_repository_mut()
             .expect_update()
             .returning(|_| Ok(()));
-        let use_case = UserUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeOperator));
         let res = use_case
@@ -335,7 +378,12 @@ mod tests {
             .user_repository_mut()
             .expect_update()
             .returning(|_| Ok(()));
-        let use_case = UserUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeOperator));
         let res = use_case
@@ -368,7 +416,12 @@ mod tests {
             .user_repository_mut()
             .expect_update()
             .returning(|_| Ok(()));
-        let use_case = UserUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::Administrator));
         let res = use_case
@@ -398,7 +451,12 @@ mod tests {
             .user_repository_mut()
             .expect_update()
             .returning(|_| Ok(()));
-        let use_case = UserUseCase::new(Arc::new(repositories));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
 
         let ctx = TestContext::new(fixture::actor::actor1(UserRole::Administrator));
         let res = use_case
@@ -416,4 +474,77 @@ mod tests {
             .await;
         assert!(res.is_ok());
     }
+
+    #[tokio::test]
+    async fn 一般ユーザーは許可されていないドメインのメールアドレスに変更できない() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .user_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::user::user1(UserRole::General))));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .update(
+                &ctx,
+                UpdateUserCommand {
+                    id: fixture::user::id1().value(),
+                    name: fixture::user::name1().value(),
+                    kana_name: fixture::user::kana_name1().value(),
+                    email: "guest@example.com".to_string(),
+                    phone_number: fixture::user::phone_number1().value(),
+                    role: UserRoleDto::from(UserRole::General),
+                },
+            )
+            .await;
+        assert!(matches!(
+            res,
+            Err(UserUseCaseError::EmailError(EmailError::InvalidDomain))
+        ));
+    }
+
+    #[tokio::test]
+    async fn 管理者は許可されていないドメインのメールアドレスに変更できる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .user_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::user::user2(UserRole::General))));
+        repositories
+            .user_repository_mut()
+            .expect_update()
+            .returning(|_| Ok(()));
+        repositories
+            .firebase_user_repository_mut()
+            .expect_update_email_by_id()
+            .returning(|_, _| Ok(()));
+        let adapters = MockAdapters::default();
+        let use_case = UserUseCase::new(
+            Arc::new(repositories),
+            Arc::new(adapters),
+            fixture::email::allowlist(),
+        );
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::Administrator));
+        let res = use_case
+            .update(
+                &ctx,
+                UpdateUserCommand {
+                    id: fixture::user::id2().value(),
+                    name: fixture::user::name2().value(),
+                    kana_name: fixture::user::kana_name2().value(),
+                    email: "guest@example.com".to_string(),
+                    phone_number: fixture::user::phone_number2().value(),
+                    role: UserRoleDto::from(UserRole::CommitteeViewer),
+                },
+            )
+            .await;
+        assert!(res.is_ok());
+    }
 }
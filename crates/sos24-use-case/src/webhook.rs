@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use sos24_domain::{
+    entity::{
+        form::FormId,
+        permission::PermissionDeniedError,
+        project::ProjectId,
+        webhook::{WebhookId, WebhookIdError, WebhookUrlError},
+    },
+    repository::{
+        form::FormRepositoryError, project::ProjectRepositoryError,
+        webhook::WebhookRepositoryError, webhook_delivery::WebhookDeliveryRepositoryError,
+        Repositories,
+    },
+};
+
+use crate::shared::{adapter::Adapters, context::ContextError};
+
+pub mod content;
+pub mod dto;
+pub mod interactor;
+
+#[derive(Debug, Error)]
+pub enum WebhookUseCaseError {
+    #[error("Webhook not found: {0:?}")]
+    NotFound(WebhookId),
+    #[error("Form not found: {0:?}")]
+    FormNotFound(FormId),
+    #[error("Project not found: {0:?}")]
+    ProjectNotFound(ProjectId),
+
+    #[error(transparent)]
+    WebhookIdError(#[from] WebhookIdError),
+    #[error(transparent)]
+    WebhookUrlError(#[from] WebhookUrlError),
+    #[error(transparent)]
+    WebhookRepositoryError(#[from] WebhookRepositoryError),
+    #[error(transparent)]
+    WebhookDeliveryRepositoryError(#[from] WebhookDeliveryRepositoryError),
+    #[error(transparent)]
+    FormRepositoryError(#[from] FormRepositoryError),
+    #[error(transparent)]
+    ProjectRepositoryError(#[from] ProjectRepositoryError),
+    #[error(transparent)]
+    ContextError(#[from] ContextError),
+    #[error(transparent)]
+    PermissionDeniedError(#[from] PermissionDeniedError),
+    #[error(transparent)]
+    InternalError(#[from] anyhow::Error),
+}
+
+pub struct WebhookUseCase<R: Repositories, A: Adapters> {
+    repositories: Arc<R>,
+    adapters: Arc<A>,
+}
+
+impl<R: Repositories, A: Adapters> WebhookUseCase<R, A> {
+    pub fn new(repositories: Arc<R>, adapters: Arc<A>) -> Self {
+        Self {
+            repositories,
+            adapters,
+        }
+    }
+}
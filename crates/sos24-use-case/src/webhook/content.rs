@@ -0,0 +1,92 @@
+use serde_json::json;
+
+use sos24_domain::entity::{
+    webhook::{Webhook, WebhookEventType, WebhookFormat},
+    webhook_delivery::{WebhookDelivery, WebhookPayload},
+};
+
+use crate::webhook::dto::WebhookEventTypeDto;
+
+// Webhookで配信するイベントの内容
+pub struct WebhookContent {
+    pub(crate) event_type: WebhookEventType,
+    // JSON形式で送るイベント固有のデータ
+    pub(crate) data: serde_json::Value,
+    // Slack形式で送るメッセージ
+    pub(crate) message: String,
+}
+
+impl WebhookContent {
+    pub fn committee_notification(message: String) -> Self {
+        Self {
+            event_type: WebhookEventType::CommitteeNotification,
+            data: json!({ "message": message }),
+            message,
+        }
+    }
+
+    pub fn event_type(&self) -> &WebhookEventType {
+        &self.event_type
+    }
+
+    // 各Webhookの形式に合わせたペイロードで配信を作成する
+    pub fn deliveries(&self, webhook_list: Vec<Webhook>) -> Vec<WebhookDelivery> {
+        let payload = json!({
+            "type": WebhookEventTypeDto::from(self.event_type.clone()).name(),
+            "created_at": chrono::Utc::now().to_rfc3339(),
+            "data": self.data,
+        })
+        .to_string();
+        let slack_payload = json!({ "text": self.message }).to_string();
+
+        webhook_list
+            .into_iter()
+            .map(|webhook| {
+                WebhookDelivery::create(
+                    webhook.id().clone(),
+                    self.event_type.clone(),
+                    WebhookPayload::new(match webhook.format() {
+                        WebhookFormat::Json => payload.clone(),
+                        WebhookFormat::Slack => slack_payload.clone(),
+                    }),
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sos24_domain::{
+        entity::{webhook::WebhookEventType, webhook_delivery::WebhookDeliveryStatus},
+        test::fixture,
+    };
+
+    use crate::webhook::content::WebhookContent;
+
+    #[test]
+    fn 実委向けの通知をwebhookの形式に合わせて配信する() {
+        let content = WebhookContent::committee_notification(String::from(
+            "ファイルをスキャンできませんでした",
+        ));
+        let deliveries = content.deliveries(vec![
+            fixture::webhook::webhook1(),
+            fixture::webhook::webhook_slack(),
+        ]);
+
+        assert_eq!(deliveries.len(), 2);
+        assert!(deliveries.iter().all(|delivery| {
+            delivery.event_type() == &WebhookEventType::CommitteeNotification
+                && delivery.status() == &WebhookDeliveryStatus::Pending
+        }));
+        assert!(deliveries[0]
+            .payload()
+            .clone()
+            .value()
+            .contains(r#""type":"committee.notification""#));
+        assert_eq!(
+            deliveries[1].payload().clone().value(),
+            r#"{"text":"ファイルをスキャンできませんでした"}"#
+        );
+    }
+}
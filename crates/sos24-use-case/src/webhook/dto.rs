@@ -0,0 +1,161 @@
+use sos24_domain::entity::{
+    webhook::{Webhook, WebhookEventType, WebhookFormat},
+    webhook_delivery::{WebhookDelivery, WebhookDeliveryStatus},
+};
+
+#[derive(Debug)]
+pub struct WebhookDto {
+    pub id: String,
+    pub name: String,
+    pub url: String,
+    pub secret: String,
+    pub format: WebhookFormatDto,
+    pub event_types: Vec<WebhookEventTypeDto>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<Webhook> for WebhookDto {
+    fn from(webhook: Webhook) -> Self {
+        let webhook = webhook.destruct();
+        Self {
+            id: webhook.id.value().to_string(),
+            name: webhook.name.value(),
+            url: webhook.url.value(),
+            secret: webhook.secret.value(),
+            format: WebhookFormatDto::from(webhook.format),
+            event_types: webhook
+                .event_types
+                .into_iter()
+                .map(WebhookEventTypeDto::from)
+                .collect(),
+            created_at: webhook.created_at.value(),
+            updated_at: webhook.updated_at.value(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WebhookFormatDto {
+    Json,
+    Slack,
+}
+
+impl From<WebhookFormatDto> for WebhookFormat {
+    fn from(dto: WebhookFormatDto) -> Self {
+        match dto {
+            WebhookFormatDto::Json => WebhookFormat::Json,
+            WebhookFormatDto::Slack => WebhookFormat::Slack,
+        }
+    }
+}
+
+impl From<WebhookFormat> for WebhookFormatDto {
+    fn from(entity: WebhookFormat) -> Self {
+        match entity {
+            WebhookFormat::Json => WebhookFormatDto::Json,
+            WebhookFormat::Slack => WebhookFormatDto::Slack,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WebhookEventTypeDto {
+    ProjectCreated,
+    ProjectUpdated,
+    ProjectDeleted,
+    FormAnswerSubmitted,
+    NewsPublished,
+    CommitteeNotification,
+}
+
+impl WebhookEventTypeDto {
+    // 送信するペイロードやヘッダーで使う名前
+    pub fn name(&self) -> &'static str {
+        match self {
+            WebhookEventTypeDto::ProjectCreated => "project.created",
+            WebhookEventTypeDto::ProjectUpdated => "project.updated",
+            WebhookEventTypeDto::ProjectDeleted => "project.deleted",
+            WebhookEventTypeDto::FormAnswerSubmitted => "form_answer.submitted",
+            WebhookEventTypeDto::NewsPublished => "news.published",
+            WebhookEventTypeDto::CommitteeNotification => "committee.notification",
+        }
+    }
+}
+
+impl From<WebhookEventTypeDto> for WebhookEventType {
+    fn from(dto: WebhookEventTypeDto) -> Self {
+        match dto {
+            WebhookEventTypeDto::ProjectCreated => WebhookEventType::ProjectCreated,
+            WebhookEventTypeDto::ProjectUpdated => WebhookEventType::ProjectUpdated,
+            WebhookEventTypeDto::ProjectDeleted => WebhookEventType::ProjectDeleted,
+            WebhookEventTypeDto::FormAnswerSubmitted => WebhookEventType::FormAnswerSubmitted,
+            WebhookEventTypeDto::NewsPublished => WebhookEventType::NewsPublished,
+            WebhookEventTypeDto::CommitteeNotification => WebhookEventType::CommitteeNotification,
+        }
+    }
+}
+
+impl From<WebhookEventType> for WebhookEventTypeDto {
+    fn from(entity: WebhookEventType) -> Self {
+        match entity {
+            WebhookEventType::ProjectCreated => WebhookEventTypeDto::ProjectCreated,
+            WebhookEventType::ProjectUpdated => WebhookEventTypeDto::ProjectUpdated,
+            WebhookEventType::ProjectDeleted => WebhookEventTypeDto::ProjectDeleted,
+            WebhookEventType::FormAnswerSubmitted => WebhookEventTypeDto::FormAnswerSubmitted,
+            WebhookEventType::NewsPublished => WebhookEventTypeDto::NewsPublished,
+            WebhookEventType::CommitteeNotification => WebhookEventTypeDto::CommitteeNotification,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct WebhookDeliveryDto {
+    pub id: String,
+    pub webhook_id: String,
+    pub event_type: WebhookEventTypeDto,
+    pub payload: String,
+    pub status: WebhookDeliveryStatusDto,
+    pub attempts: i32,
+    pub response_status: Option<i32>,
+    pub last_error: Option<String>,
+    pub next_attempt_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<WebhookDelivery> for WebhookDeliveryDto {
+    fn from(delivery: WebhookDelivery) -> Self {
+        let delivery = delivery.destruct();
+        Self {
+            id: delivery.id.value().to_string(),
+            webhook_id: delivery.webhook_id.value().to_string(),
+            event_type: WebhookEventTypeDto::from(delivery.event_type),
+            payload: delivery.payload.value(),
+            status: WebhookDeliveryStatusDto::from(delivery.status),
+            attempts: delivery.attempts.value(),
+            response_status: delivery.response_status.map(|it| it.value()),
+            last_error: delivery.last_error.map(|it| it.value()),
+            next_attempt_at: delivery.next_attempt_at.map(|it| it.value()),
+            created_at: delivery.created_at.value(),
+            updated_at: delivery.updated_at.value(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum WebhookDeliveryStatusDto {
+    Pending,
+    Succeeded,
+    Failed,
+}
+
+impl From<WebhookDeliveryStatus> for WebhookDeliveryStatusDto {
+    fn from(entity: WebhookDeliveryStatus) -> Self {
+        match entity {
+            WebhookDeliveryStatus::Pending => WebhookDeliveryStatusDto::Pending,
+            WebhookDeliveryStatus::Succeeded => WebhookDeliveryStatusDto::Succeeded,
+            WebhookDeliveryStatus::Failed => WebhookDeliveryStatusDto::Failed,
+        }
+    }
+}
@@ -0,0 +1,10 @@
+pub mod create;
+pub mod create_slack_notification;
+pub mod delete_by_id;
+pub mod deliver_pending;
+pub mod dispatch_events;
+pub mod enqueue;
+pub mod find_by_id;
+pub mod list;
+pub mod list_deliveries;
+pub mod update;
@@ -0,0 +1,112 @@
+use sos24_domain::{
+    ensure,
+    entity::{
+        permission::Permissions,
+        webhook::{Webhook, WebhookEventType, WebhookFormat, WebhookName, WebhookUrl},
+    },
+    repository::{webhook::WebhookRepository, Repositories},
+};
+
+use crate::{
+    shared::{adapter::Adapters, context::ContextProvider},
+    webhook::{
+        dto::{WebhookEventTypeDto, WebhookFormatDto},
+        WebhookUseCase, WebhookUseCaseError,
+    },
+};
+
+#[derive(Debug)]
+pub struct CreateWebhookCommand {
+    pub name: String,
+    pub url: String,
+    pub format: WebhookFormatDto,
+    pub event_types: Vec<WebhookEventTypeDto>,
+}
+
+impl<R: Repositories, A: Adapters> WebhookUseCase<R, A> {
+    pub async fn create(
+        &self,
+        ctx: &impl ContextProvider,
+        raw_webhook: CreateWebhookCommand,
+    ) -> Result<String, WebhookUseCaseError> {
+        let actor = ctx.actor(&*self.repositories).await?;
+        ensure!(actor.has_permission(Permissions::CREATE_WEBHOOK));
+
+        let webhook = Webhook::create(
+            WebhookName::new(raw_webhook.name),
+            WebhookUrl::try_from(raw_webhook.url)?,
+            WebhookFormat::from(raw_webhook.format),
+            raw_webhook
+                .event_types
+                .into_iter()
+                .map(WebhookEventType::from)
+                .collect(),
+        );
+        let webhook_id = webhook.id().clone();
+        self.repositories
+            .webhook_repository()
+            .create(webhook)
+            .await?;
+
+        Ok(webhook_id.value().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::{
+        entity::{permission::PermissionDeniedError, user::UserRole},
+        test::{fixture, repository::MockRepositories},
+    };
+
+    use crate::{
+        shared::{adapter::MockAdapters, context::TestContext},
+        webhook::{
+            dto::{WebhookEventTypeDto, WebhookFormatDto},
+            interactor::create::CreateWebhookCommand,
+            WebhookUseCase, WebhookUseCaseError,
+        },
+    };
+
+    fn command() -> CreateWebhookCommand {
+        CreateWebhookCommand {
+            name: fixture::webhook::name1().value(),
+            url: fixture::webhook::url1().value(),
+            format: WebhookFormatDto::Json,
+            event_types: vec![WebhookEventTypeDto::ProjectCreated],
+        }
+    }
+
+    #[tokio::test]
+    async fn 管理者はwebhookを作成できる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .webhook_repository_mut()
+            .expect_create()
+            .returning(|_| Ok(()));
+        let adapters = MockAdapters::default();
+        let use_case = WebhookUseCase::new(Arc::new(repositories), Arc::new(adapters));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::Administrator));
+        let res = use_case.create(&ctx, command()).await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn 実委人管理者はwebhookを作成できない() {
+        let repositories = MockRepositories::default();
+        let adapters = MockAdapters::default();
+        let use_case = WebhookUseCase::new(Arc::new(repositories), Arc::new(adapters));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeOperator));
+        let res = use_case.create(&ctx, command()).await;
+        assert!(matches!(
+            res,
+            Err(WebhookUseCaseError::PermissionDeniedError(
+                PermissionDeniedError
+            ))
+        ));
+    }
+}
@@ -0,0 +1,96 @@
+use sos24_domain::{
+    entity::webhook::{Webhook, WebhookEventType, WebhookFormat, WebhookName, WebhookUrl},
+    repository::{webhook::WebhookRepository, Repositories},
+};
+
+use crate::{
+    shared::adapter::Adapters,
+    webhook::{WebhookUseCase, WebhookUseCaseError},
+};
+
+impl<R: Repositories, A: Adapters> WebhookUseCase<R, A> {
+    // 起動時に指定されたSlackのWebhook URLを、実委向けの通知を受け取るWebhookとして登録する
+    // 同じURLのWebhookが作成されたことがある場合は、管理者が削除したものも含めて何もしない
+    pub async fn create_slack_notification(&self, url: String) -> Result<(), WebhookUseCaseError> {
+        let url = WebhookUrl::try_from(url)?;
+        let exists = self
+            .repositories
+            .webhook_repository()
+            .exists_by_url_including_deleted(url.clone())
+            .await?;
+        if exists {
+            return Ok(());
+        }
+
+        let webhook = Webhook::create(
+            WebhookName::new(String::from("Slack通知")),
+            url,
+            WebhookFormat::Slack,
+            vec![WebhookEventType::CommitteeNotification],
+        );
+        self.repositories
+            .webhook_repository()
+            .create(webhook)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::{
+        entity::webhook::{WebhookEventType, WebhookFormat},
+        test::{fixture, repository::MockRepositories},
+    };
+
+    use crate::{shared::adapter::MockAdapters, webhook::WebhookUseCase};
+
+    #[tokio::test]
+    async fn slackのwebhookを実委向けの通知の配信先として登録する() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .webhook_repository_mut()
+            .expect_exists_by_url_including_deleted()
+            .returning(|_| Ok(false));
+        repositories
+            .webhook_repository_mut()
+            .expect_create()
+            .withf(|webhook| {
+                webhook.format() == &WebhookFormat::Slack
+                    && webhook.event_types() == &vec![WebhookEventType::CommitteeNotification]
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+        let use_case =
+            WebhookUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let res = use_case
+            .create_slack_notification(fixture::webhook::webhook_slack().url().clone().value())
+            .await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn 同じurlのwebhookが作成されたことがある場合は登録しない() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .webhook_repository_mut()
+            .expect_exists_by_url_including_deleted()
+            .withf(|url| url == fixture::webhook::webhook_slack().url())
+            .returning(|_| Ok(true));
+        repositories
+            .webhook_repository_mut()
+            .expect_create()
+            .never();
+        let use_case =
+            WebhookUseCase::new(Arc::new(repositories), Arc::new(MockAdapters::default()));
+
+        let res = use_case
+            .create_slack_notification(fixture::webhook::webhook_slack().url().clone().value())
+            .await;
+        assert!(res.is_ok());
+    }
+}
@@ -0,0 +1,88 @@
+use sos24_domain::{
+    ensure,
+    entity::{permission::Permissions, webhook::WebhookId},
+    repository::{webhook::WebhookRepository, Repositories},
+};
+
+use crate::{
+    shared::{adapter::Adapters, context::ContextProvider},
+    webhook::{WebhookUseCase, WebhookUseCaseError},
+};
+
+impl<R: Repositories, A: Adapters> WebhookUseCase<R, A> {
+    pub async fn delete_by_id(
+        &self,
+        ctx: &impl ContextProvider,
+        id: String,
+    ) -> Result<(), WebhookUseCaseError> {
+        let actor = ctx.actor(&*self.repositories).await?;
+        ensure!(actor.has_permission(Permissions::DELETE_WEBHOOK_ALL));
+
+        let id = WebhookId::try_from(id)?;
+        self.repositories
+            .webhook_repository()
+            .find_by_id(id.clone())
+            .await?
+            .ok_or(WebhookUseCaseError::NotFound(id.clone()))?;
+
+        self.repositories
+            .webhook_repository()
+            .delete_by_id(id)
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::{
+        entity::{permission::PermissionDeniedError, user::UserRole},
+        test::{fixture, repository::MockRepositories},
+    };
+
+    use crate::{
+        shared::{adapter::MockAdapters, context::TestContext},
+        webhook::{WebhookUseCase, WebhookUseCaseError},
+    };
+
+    #[tokio::test]
+    async fn 管理者はwebhookを削除できる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .webhook_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::webhook::webhook1())));
+        repositories
+            .webhook_repository_mut()
+            .expect_delete_by_id()
+            .returning(|_| Ok(()));
+        let adapters = MockAdapters::default();
+        let use_case = WebhookUseCase::new(Arc::new(repositories), Arc::new(adapters));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::Administrator));
+        let res = use_case
+            .delete_by_id(&ctx, fixture::webhook::id1().value().to_string())
+            .await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn 実委人管理者はwebhookを削除できない() {
+        let repositories = MockRepositories::default();
+        let adapters = MockAdapters::default();
+        let use_case = WebhookUseCase::new(Arc::new(repositories), Arc::new(adapters));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeOperator));
+        let res = use_case
+            .delete_by_id(&ctx, fixture::webhook::id1().value().to_string())
+            .await;
+        assert!(matches!(
+            res,
+            Err(WebhookUseCaseError::PermissionDeniedError(
+                PermissionDeniedError
+            ))
+        ));
+    }
+}
@@ -0,0 +1,264 @@
+use sos24_domain::{
+    entity::{
+        common::datetime::DateTime,
+        webhook::{Webhook, WebhookFormat},
+        webhook_delivery::{WebhookDelivery, WebhookDeliveryError, WebhookResponseStatus},
+    },
+    repository::{
+        webhook::WebhookRepository, webhook_delivery::WebhookDeliveryRepository, Repositories,
+    },
+};
+
+use crate::{
+    shared::{
+        adapter::{
+            webhook::{WebhookRequest, WebhookSender},
+            Adapters,
+        },
+        context::ContextProvider,
+    },
+    webhook::{dto::WebhookEventTypeDto, WebhookUseCase, WebhookUseCaseError},
+};
+
+impl<R: Repositories, A: Adapters> WebhookUseCase<R, A> {
+    // 送信時刻を過ぎた配信を送信する
+    // 新しく作成された配信と、再送待ちの配信の両方が対象になる
+    pub async fn deliver_pending(
+        &self,
+        _ctx: &impl ContextProvider,
+    ) -> Result<(), WebhookUseCaseError> {
+        let delivery_list = self
+            .repositories
+            .webhook_delivery_repository()
+            .find_due(DateTime::now())
+            .await?;
+
+        for delivery in delivery_list {
+            let id = delivery.id().clone();
+            if let Err(err) = self.deliver_one(delivery).await {
+                tracing::error!("Webhookの配信に失敗しました: {id:?}: {err:?}");
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn deliver_one(&self, delivery: WebhookDelivery) -> Result<(), WebhookUseCaseError> {
+        let webhook_id = delivery.webhook_id().clone();
+        let Some(webhook) = self
+            .repositories
+            .webhook_repository()
+            .find_by_id(webhook_id)
+            .await?
+        else {
+            // 削除されたWebhookへの配信は、毎回取得し直さないよう失敗として記録する
+            let mut new_delivery = delivery;
+            new_delivery.abandon(
+                WebhookDeliveryError::new("Webhook was deleted".to_string()),
+                &chrono::Utc::now(),
+            );
+            self.repositories
+                .webhook_delivery_repository()
+                .update(new_delivery)
+                .await?;
+            return Ok(());
+        };
+        self.deliver(&webhook, delivery).await
+    }
+
+    async fn deliver(
+        &self,
+        webhook: &Webhook,
+        delivery: WebhookDelivery,
+    ) -> Result<(), WebhookUseCaseError> {
+        let request = WebhookRequest {
+            url: webhook.url().clone().value(),
+            delivery_id: delivery.id().clone().value().to_string(),
+            event_type: WebhookEventTypeDto::from(delivery.event_type().clone())
+                .name()
+                .to_string(),
+            body: delivery.payload().clone().value(),
+            // Slackは署名を検証しないため付けない
+            secret: match webhook.format() {
+                WebhookFormat::Json => Some(webhook.secret().clone().value()),
+                WebhookFormat::Slack => None,
+            },
+        };
+
+        let mut new_delivery = delivery;
+        let now = chrono::Utc::now();
+        match self.adapters.webhook_sender().send(request).await {
+            Ok(status) if (200..300).contains(&status) => {
+                new_delivery.succeed(WebhookResponseStatus::new(status.into()), &now);
+            }
+            Ok(status) => {
+                tracing::warn!("Webhookの送信先がエラーを返しました: {status}");
+                new_delivery.fail(
+                    Some(WebhookResponseStatus::new(status.into())),
+                    WebhookDeliveryError::new(format!("HTTP {status}")),
+                    &now,
+                );
+            }
+            Err(err) => {
+                tracing::warn!("Webhookの送信に失敗しました: {err:?}");
+                new_delivery.fail(None, WebhookDeliveryError::new(err.to_string()), &now);
+            }
+        }
+
+        self.repositories
+            .webhook_delivery_repository()
+            .update(new_delivery)
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::{
+        entity::{user::UserRole, webhook_delivery::WebhookDeliveryStatus},
+        repository::webhook::WebhookRepositoryError,
+        test::{fixture, repository::MockRepositories},
+    };
+
+    use crate::{
+        shared::{adapter::MockAdapters, context::TestContext},
+        webhook::WebhookUseCase,
+    };
+
+    #[tokio::test]
+    async fn 再送時刻を過ぎた配信を送り直す() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .webhook_delivery_repository_mut()
+            .expect_find_due()
+            .returning(|_| Ok(vec![fixture::webhook_delivery::webhook_delivery1()]));
+        repositories
+            .webhook_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::webhook::webhook1())));
+        repositories
+            .webhook_delivery_repository_mut()
+            .expect_update()
+            .withf(|delivery| delivery.status() == &WebhookDeliveryStatus::Succeeded)
+            .returning(|_| Ok(()));
+        let mut adapters = MockAdapters::default();
+        adapters
+            .webhook_sender_mut()
+            .expect_send()
+            .withf(|request| request.secret == Some(fixture::webhook::secret1().value()))
+            .returning(|_| Ok(200));
+        let use_case = WebhookUseCase::new(Arc::new(repositories), Arc::new(adapters));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::Administrator));
+        let res = use_case.deliver_pending(&ctx).await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn 送信に失敗した配信は再送待ちになる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .webhook_delivery_repository_mut()
+            .expect_find_due()
+            .returning(|_| Ok(vec![fixture::webhook_delivery::webhook_delivery1()]));
+        repositories
+            .webhook_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::webhook::webhook1())));
+        repositories
+            .webhook_delivery_repository_mut()
+            .expect_update()
+            .withf(|delivery| {
+                delivery.status() == &WebhookDeliveryStatus::Pending
+                    && delivery.attempts().clone().value() == 1
+            })
+            .returning(|_| Ok(()));
+        let mut adapters = MockAdapters::default();
+        adapters
+            .webhook_sender_mut()
+            .expect_send()
+            .returning(|_| Ok(500));
+        let use_case = WebhookUseCase::new(Arc::new(repositories), Arc::new(adapters));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::Administrator));
+        let res = use_case.deliver_pending(&ctx).await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn 削除されたwebhookへの配信は送信せずに失敗とする() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .webhook_delivery_repository_mut()
+            .expect_find_due()
+            .returning(|_| Ok(vec![fixture::webhook_delivery::webhook_delivery1()]));
+        repositories
+            .webhook_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(None));
+        repositories
+            .webhook_delivery_repository_mut()
+            .expect_update()
+            .withf(|delivery| {
+                delivery.status() == &WebhookDeliveryStatus::Failed
+                    && delivery.next_attempt_at().is_none()
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+        let mut adapters = MockAdapters::default();
+        adapters.webhook_sender_mut().expect_send().never();
+        let use_case = WebhookUseCase::new(Arc::new(repositories), Arc::new(adapters));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::Administrator));
+        let res = use_case.deliver_pending(&ctx).await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn 一部の配信でエラーが起きても残りの配信を送信する() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .webhook_delivery_repository_mut()
+            .expect_find_due()
+            .returning(|_| {
+                Ok(vec![
+                    fixture::webhook_delivery::webhook_delivery1(),
+                    fixture::webhook_delivery::webhook_delivery1(),
+                ])
+            });
+        repositories
+            .webhook_repository_mut()
+            .expect_find_by_id()
+            .times(1)
+            .returning(|_| {
+                Err(WebhookRepositoryError::InternalError(anyhow::anyhow!(
+                    "failed"
+                )))
+            });
+        repositories
+            .webhook_repository_mut()
+            .expect_find_by_id()
+            .times(1)
+            .returning(|_| Ok(Some(fixture::webhook::webhook1())));
+        repositories
+            .webhook_delivery_repository_mut()
+            .expect_update()
+            .withf(|delivery| delivery.status() == &WebhookDeliveryStatus::Succeeded)
+            .times(1)
+            .returning(|_| Ok(()));
+        let mut adapters = MockAdapters::default();
+        adapters
+            .webhook_sender_mut()
+            .expect_send()
+            .times(1)
+            .returning(|_| Ok(200));
+        let use_case = WebhookUseCase::new(Arc::new(repositories), Arc::new(adapters));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::Administrator));
+        let res = use_case.deliver_pending(&ctx).await;
+        assert!(res.is_ok());
+    }
+}
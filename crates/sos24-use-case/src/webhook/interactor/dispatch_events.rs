@@ -0,0 +1,77 @@
+use tokio::sync::broadcast::error::RecvError;
+
+use sos24_domain::repository::Repositories;
+
+use crate::{
+    shared::{
+        adapter::{event::EventBus, Adapters},
+        context::ContextProvider,
+    },
+    webhook::WebhookUseCase,
+};
+
+impl<R: Repositories, A: Adapters> WebhookUseCase<R, A> {
+    // イベントバスを購読し、届いたイベントをWebhookで配信し続ける
+    pub async fn dispatch_events(&self, ctx: &impl ContextProvider) {
+        let mut receiver = self.adapters.event_bus().subscribe();
+        loop {
+            let event = match receiver.recv().await {
+                Ok(event) => event,
+                Err(RecvError::Lagged(count)) => {
+                    tracing::warn!("{count}件のイベントを取りこぼしました");
+                    continue;
+                }
+                Err(RecvError::Closed) => return,
+            };
+            if let Err(err) = self.enqueue(ctx, event).await {
+                tracing::error!("Failed to enqueue webhook deliveries: {err:?}");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::{
+        entity::user::UserRole,
+        test::{fixture, repository::MockRepositories},
+    };
+    use tokio::sync::broadcast;
+
+    use crate::{
+        shared::{
+            adapter::{event::Event, MockAdapters},
+            context::TestContext,
+        },
+        webhook::WebhookUseCase,
+    };
+
+    #[tokio::test]
+    async fn 購読が終了するまでイベントを配信する() {
+        let (sender, receiver) = broadcast::channel(16);
+        let mut repositories = MockRepositories::default();
+        repositories
+            .webhook_repository_mut()
+            .expect_find_by_event_type()
+            .times(1)
+            .returning(|_| Ok(vec![]));
+        let mut adapters = MockAdapters::default();
+        adapters
+            .event_bus_mut()
+            .expect_subscribe()
+            .return_once(move || receiver);
+        let use_case = WebhookUseCase::new(Arc::new(repositories), Arc::new(adapters));
+
+        sender
+            .send(Event::ProjectCreated(fixture::project::project1(
+                fixture::user::id1(),
+            )))
+            .unwrap();
+        drop(sender);
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::Administrator));
+        use_case.dispatch_events(&ctx).await;
+    }
+}
@@ -0,0 +1,310 @@
+use serde_json::json;
+
+use sos24_domain::{
+    entity::{project::Project, webhook::WebhookEventType},
+    repository::{
+        form::FormRepository, project::ProjectRepository, webhook::WebhookRepository,
+        webhook_delivery::WebhookDeliveryRepository, Repositories,
+    },
+};
+
+use crate::{
+    shared::{
+        adapter::{event::Event, Adapters},
+        app_url,
+        context::ContextProvider,
+    },
+    webhook::{content::WebhookContent, WebhookUseCase, WebhookUseCaseError},
+};
+
+impl<R: Repositories, A: Adapters> WebhookUseCase<R, A> {
+    // イベントを購読しているWebhookへの配信を作成する
+    // 送信はdeliver_pendingで行い、イベントの購読を送信先の応答で止めないようにする
+    pub async fn enqueue(
+        &self,
+        ctx: &impl ContextProvider,
+        event: Event,
+    ) -> Result<(), WebhookUseCaseError> {
+        let Some(content) = self.content_of(ctx, event).await? else {
+            return Ok(());
+        };
+
+        let webhook_list = self
+            .repositories
+            .webhook_repository()
+            .find_by_event_type(content.event_type.clone())
+            .await?;
+        if webhook_list.is_empty() {
+            return Ok(());
+        }
+
+        for delivery in content.deliveries(webhook_list) {
+            let webhook_id = delivery.webhook_id().clone();
+            // 1つのWebhookへの配信の作成に失敗しても、他のWebhookへの配信は作成する
+            if let Err(err) = self
+                .repositories
+                .webhook_delivery_repository()
+                .create(delivery)
+                .await
+            {
+                tracing::error!("Webhookの配信の作成に失敗しました: {webhook_id:?}: {err:?}");
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn content_of(
+        &self,
+        ctx: &impl ContextProvider,
+        event: Event,
+    ) -> Result<Option<WebhookContent>, WebhookUseCaseError> {
+        let content = match event {
+            Event::ProjectCreated(project) => WebhookContent {
+                event_type: WebhookEventType::ProjectCreated,
+                message: format!(
+                    "企画「{}」が登録されました。\n{}",
+                    project.title().clone().value(),
+                    app_url::committee_project(ctx, project.id().clone()),
+                ),
+                data: project_data(project),
+            },
+            Event::ProjectUpdated(project) => WebhookContent {
+                event_type: WebhookEventType::ProjectUpdated,
+                message: format!(
+                    "企画「{}」が更新されました。\n{}",
+                    project.title().clone().value(),
+                    app_url::committee_project(ctx, project.id().clone()),
+                ),
+                data: project_data(project),
+            },
+            Event::ProjectDeleted(project) => WebhookContent {
+                event_type: WebhookEventType::ProjectDeleted,
+                message: format!(
+                    "企画「{}」が削除されました。",
+                    project.title().clone().value(),
+                ),
+                data: project_data(project),
+            },
+            Event::FormAnswerSubmitted(form_answer) => {
+                let form_id = form_answer.form_id().clone();
+                let form = self
+                    .repositories
+                    .form_repository()
+                    .find_by_id(form_id.clone())
+                    .await?
+                    .ok_or(WebhookUseCaseError::FormNotFound(form_id.clone()))?;
+                let project_id = form_answer.project_id().clone();
+                let project_with_owners = self
+                    .repositories
+                    .project_repository()
+                    .find_by_id(project_id.clone())
+                    .await?
+                    .ok_or(WebhookUseCaseError::ProjectNotFound(project_id.clone()))?;
+
+                let form_title = form.title().clone().value();
+                let project_title = project_with_owners.project.title().clone().value();
+                WebhookContent {
+                    event_type: WebhookEventType::FormAnswerSubmitted,
+                    message: format!(
+                        "企画「{}」が申請「{}」に回答しました。\n{}",
+                        project_title,
+                        form_title,
+                        app_url::committee_form(ctx, form_id.clone()),
+                    ),
+                    data: json!({
+                        "id": form_answer.id().clone().value().to_string(),
+                        "form_id": form_id.value().to_string(),
+                        "form_title": form_title,
+                        "project_id": project_id.value().to_string(),
+                        "project_title": project_title,
+                    }),
+                }
+            }
+            Event::NewsPublished(news) => WebhookContent {
+                event_type: WebhookEventType::NewsPublished,
+                message: format!(
+                    "お知らせ「{}」が公開されました。\n{}",
+                    news.title().clone().value(),
+                    app_url::committee_news(ctx, news.id().clone()),
+                ),
+                data: json!({
+                    "id": news.id().clone().value().to_string(),
+                    "title": news.title().clone().value(),
+                }),
+            },
+            // 実委向けの通知は、イベントの取りこぼしで失われないようNotifierが配信を作成する
+            Event::CommitteeNotified(_)
+            | Event::FormStarted(_)
+            | Event::FormAnswerUpdated(_)
+            | Event::InvitationReceived(_) => return Ok(None),
+        };
+        Ok(Some(content))
+    }
+}
+
+fn project_data(project: Project) -> serde_json::Value {
+    let project = project.destruct();
+    json!({
+        "id": project.id.value().to_string(),
+        "index": project.index.value(),
+        "title": project.title.value(),
+        "group_name": project.group_name.value(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::{
+        entity::{user::UserRole, webhook_delivery::WebhookDeliveryStatus},
+        repository::webhook_delivery::WebhookDeliveryRepositoryError,
+        test::{fixture, repository::MockRepositories},
+    };
+
+    use crate::{
+        shared::{
+            adapter::{event::Event, MockAdapters},
+            context::TestContext,
+        },
+        webhook::WebhookUseCase,
+    };
+
+    #[tokio::test]
+    async fn 購読しているwebhookへの配信を作成する() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .webhook_repository_mut()
+            .expect_find_by_event_type()
+            .returning(|_| Ok(vec![fixture::webhook::webhook1()]));
+        repositories
+            .webhook_delivery_repository_mut()
+            .expect_create()
+            .withf(|delivery| {
+                delivery.status() == &WebhookDeliveryStatus::Pending
+                    && delivery
+                        .payload()
+                        .clone()
+                        .value()
+                        .contains(r#""type":"project.created""#)
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+        let mut adapters = MockAdapters::default();
+        adapters.webhook_sender_mut().expect_send().never();
+        let use_case = WebhookUseCase::new(Arc::new(repositories), Arc::new(adapters));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .enqueue(
+                &ctx,
+                Event::ProjectCreated(fixture::project::project1(fixture::user::id1())),
+            )
+            .await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn slack形式のwebhookにはメッセージを配信する() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .webhook_repository_mut()
+            .expect_find_by_event_type()
+            .returning(|_| Ok(vec![fixture::webhook::webhook_slack()]));
+        repositories
+            .webhook_delivery_repository_mut()
+            .expect_create()
+            .withf(|delivery| delivery.payload().clone().value().contains(r#""text":"#))
+            .times(1)
+            .returning(|_| Ok(()));
+        let adapters = MockAdapters::default();
+        let use_case = WebhookUseCase::new(Arc::new(repositories), Arc::new(adapters));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .enqueue(
+                &ctx,
+                Event::ProjectCreated(fixture::project::project1(fixture::user::id1())),
+            )
+            .await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn 配信の作成に失敗しても他のwebhookへの配信は作成する() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .webhook_repository_mut()
+            .expect_find_by_event_type()
+            .returning(|_| {
+                Ok(vec![
+                    fixture::webhook::webhook1(),
+                    fixture::webhook::webhook_slack(),
+                ])
+            });
+        repositories
+            .webhook_delivery_repository_mut()
+            .expect_create()
+            .withf(|delivery| delivery.payload().clone().value().contains(r#""type":"#))
+            .times(1)
+            .returning(|_| {
+                Err(WebhookDeliveryRepositoryError::InternalError(
+                    anyhow::anyhow!("failed"),
+                ))
+            });
+        repositories
+            .webhook_delivery_repository_mut()
+            .expect_create()
+            .times(1)
+            .returning(|_| Ok(()));
+        let adapters = MockAdapters::default();
+        let use_case = WebhookUseCase::new(Arc::new(repositories), Arc::new(adapters));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .enqueue(
+                &ctx,
+                Event::ProjectCreated(fixture::project::project1(fixture::user::id1())),
+            )
+            .await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn 実委向けの通知のイベントからは配信を作成しない() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .webhook_repository_mut()
+            .expect_find_by_event_type()
+            .never();
+        repositories
+            .webhook_delivery_repository_mut()
+            .expect_create()
+            .never();
+        let adapters = MockAdapters::default();
+        let use_case = WebhookUseCase::new(Arc::new(repositories), Arc::new(adapters));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .enqueue(
+                &ctx,
+                Event::CommitteeNotified(String::from("ファイルをスキャンできませんでした")),
+            )
+            .await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn 配信対象外のイベントは無視する() {
+        let repositories = MockRepositories::default();
+        let adapters = MockAdapters::default();
+        let use_case = WebhookUseCase::new(Arc::new(repositories), Arc::new(adapters));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::General));
+        let res = use_case
+            .enqueue(&ctx, Event::FormStarted(fixture::form::form1_opened()))
+            .await;
+        assert!(res.is_ok());
+    }
+}
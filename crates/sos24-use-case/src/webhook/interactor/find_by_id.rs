@@ -0,0 +1,85 @@
+use sos24_domain::{
+    ensure,
+    entity::webhook::WebhookId,
+    repository::{webhook::WebhookRepository, Repositories},
+};
+
+use crate::{
+    shared::{adapter::Adapters, context::ContextProvider},
+    webhook::{dto::WebhookDto, WebhookUseCase, WebhookUseCaseError},
+};
+
+impl<R: Repositories, A: Adapters> WebhookUseCase<R, A> {
+    pub async fn find_by_id(
+        &self,
+        ctx: &impl ContextProvider,
+        id: String,
+    ) -> Result<WebhookDto, WebhookUseCaseError> {
+        let actor = ctx.actor(&*self.repositories).await?;
+
+        let id = WebhookId::try_from(id)?;
+        let webhook = self
+            .repositories
+            .webhook_repository()
+            .find_by_id(id.clone())
+            .await?
+            .ok_or(WebhookUseCaseError::NotFound(id))?;
+        ensure!(webhook.is_visible_to(&actor));
+
+        Ok(WebhookDto::from(webhook))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::{
+        entity::{permission::PermissionDeniedError, user::UserRole},
+        test::{fixture, repository::MockRepositories},
+    };
+
+    use crate::{
+        shared::{adapter::MockAdapters, context::TestContext},
+        webhook::{WebhookUseCase, WebhookUseCaseError},
+    };
+
+    #[tokio::test]
+    async fn 管理者はwebhookを取得できる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .webhook_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::webhook::webhook1())));
+        let adapters = MockAdapters::default();
+        let use_case = WebhookUseCase::new(Arc::new(repositories), Arc::new(adapters));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::Administrator));
+        let res = use_case
+            .find_by_id(&ctx, fixture::webhook::id1().value().to_string())
+            .await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn 実委人管理者はwebhookを取得できない() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .webhook_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::webhook::webhook1())));
+        let adapters = MockAdapters::default();
+        let use_case = WebhookUseCase::new(Arc::new(repositories), Arc::new(adapters));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeOperator));
+        let res = use_case
+            .find_by_id(&ctx, fixture::webhook::id1().value().to_string())
+            .await;
+        assert!(matches!(
+            res,
+            Err(WebhookUseCaseError::PermissionDeniedError(
+                PermissionDeniedError
+            ))
+        ));
+    }
+}
@@ -0,0 +1,69 @@
+use sos24_domain::{
+    ensure,
+    entity::permission::Permissions,
+    repository::{webhook::WebhookRepository, Repositories},
+};
+
+use crate::{
+    shared::{adapter::Adapters, context::ContextProvider},
+    webhook::{dto::WebhookDto, WebhookUseCase, WebhookUseCaseError},
+};
+
+impl<R: Repositories, A: Adapters> WebhookUseCase<R, A> {
+    pub async fn list(
+        &self,
+        ctx: &impl ContextProvider,
+    ) -> Result<Vec<WebhookDto>, WebhookUseCaseError> {
+        let actor = ctx.actor(&*self.repositories).await?;
+        ensure!(actor.has_permission(Permissions::READ_WEBHOOK_ALL));
+
+        let webhook_list = self.repositories.webhook_repository().list().await?;
+        Ok(webhook_list.into_iter().map(WebhookDto::from).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::{
+        entity::{permission::PermissionDeniedError, user::UserRole},
+        test::{fixture, repository::MockRepositories},
+    };
+
+    use crate::{
+        shared::{adapter::MockAdapters, context::TestContext},
+        webhook::{WebhookUseCase, WebhookUseCaseError},
+    };
+
+    #[tokio::test]
+    async fn 管理者はwebhook一覧を取得できる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .webhook_repository_mut()
+            .expect_list()
+            .returning(|| Ok(vec![fixture::webhook::webhook1()]));
+        let adapters = MockAdapters::default();
+        let use_case = WebhookUseCase::new(Arc::new(repositories), Arc::new(adapters));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::Administrator));
+        let res = use_case.list(&ctx).await;
+        assert!(matches!(res, Ok(list) if list.len() == 1));
+    }
+
+    #[tokio::test]
+    async fn 実委人管理者はwebhook一覧を取得できない() {
+        let repositories = MockRepositories::default();
+        let adapters = MockAdapters::default();
+        let use_case = WebhookUseCase::new(Arc::new(repositories), Arc::new(adapters));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::CommitteeOperator));
+        let res = use_case.list(&ctx).await;
+        assert!(matches!(
+            res,
+            Err(WebhookUseCaseError::PermissionDeniedError(
+                PermissionDeniedError
+            ))
+        ));
+    }
+}
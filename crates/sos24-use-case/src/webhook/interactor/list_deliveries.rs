@@ -0,0 +1,94 @@
+use sos24_domain::{
+    ensure,
+    entity::webhook::WebhookId,
+    repository::{
+        webhook::WebhookRepository, webhook_delivery::WebhookDeliveryRepository, Repositories,
+    },
+};
+
+use crate::{
+    shared::{adapter::Adapters, context::ContextProvider},
+    webhook::{dto::WebhookDeliveryDto, WebhookUseCase, WebhookUseCaseError},
+};
+
+impl<R: Repositories, A: Adapters> WebhookUseCase<R, A> {
+    pub async fn list_deliveries(
+        &self,
+        ctx: &impl ContextProvider,
+        webhook_id: String,
+    ) -> Result<Vec<WebhookDeliveryDto>, WebhookUseCaseError> {
+        let actor = ctx.actor(&*self.repositories).await?;
+
+        let webhook_id = WebhookId::try_from(webhook_id)?;
+        let webhook = self
+            .repositories
+            .webhook_repository()
+            .find_by_id(webhook_id.clone())
+            .await?
+            .ok_or(WebhookUseCaseError::NotFound(webhook_id))?;
+        ensure!(webhook.is_visible_to(&actor));
+
+        let delivery_list = self
+            .repositories
+            .webhook_delivery_repository()
+            .find_by_webhook_id(webhook.id().clone())
+            .await?;
+        Ok(delivery_list
+            .into_iter()
+            .map(WebhookDeliveryDto::from)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::{
+        entity::user::UserRole,
+        test::{fixture, repository::MockRepositories},
+    };
+
+    use crate::{
+        shared::{adapter::MockAdapters, context::TestContext},
+        webhook::{WebhookUseCase, WebhookUseCaseError},
+    };
+
+    #[tokio::test]
+    async fn 管理者はwebhookの配信履歴を取得できる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .webhook_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::webhook::webhook1())));
+        repositories
+            .webhook_delivery_repository_mut()
+            .expect_find_by_webhook_id()
+            .returning(|_| Ok(vec![fixture::webhook_delivery::webhook_delivery1()]));
+        let adapters = MockAdapters::default();
+        let use_case = WebhookUseCase::new(Arc::new(repositories), Arc::new(adapters));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::Administrator));
+        let res = use_case
+            .list_deliveries(&ctx, fixture::webhook::id1().value().to_string())
+            .await;
+        assert!(matches!(res, Ok(list) if list.len() == 1));
+    }
+
+    #[tokio::test]
+    async fn 存在しないwebhookの配信履歴は取得できない() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .webhook_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(None));
+        let adapters = MockAdapters::default();
+        let use_case = WebhookUseCase::new(Arc::new(repositories), Arc::new(adapters));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::Administrator));
+        let res = use_case
+            .list_deliveries(&ctx, fixture::webhook::id1().value().to_string())
+            .await;
+        assert!(matches!(res, Err(WebhookUseCaseError::NotFound(_))));
+    }
+}
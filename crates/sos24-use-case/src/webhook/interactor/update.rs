@@ -0,0 +1,127 @@
+use sos24_domain::{
+    ensure,
+    entity::webhook::{WebhookEventType, WebhookFormat, WebhookId, WebhookName, WebhookUrl},
+    repository::{webhook::WebhookRepository, Repositories},
+};
+
+use crate::{
+    shared::{adapter::Adapters, context::ContextProvider},
+    webhook::{
+        dto::{WebhookEventTypeDto, WebhookFormatDto},
+        WebhookUseCase, WebhookUseCaseError,
+    },
+};
+
+#[derive(Debug)]
+pub struct UpdateWebhookCommand {
+    pub id: String,
+    pub name: String,
+    pub url: String,
+    pub format: WebhookFormatDto,
+    pub event_types: Vec<WebhookEventTypeDto>,
+}
+
+impl<R: Repositories, A: Adapters> WebhookUseCase<R, A> {
+    pub async fn update(
+        &self,
+        ctx: &impl ContextProvider,
+        webhook_data: UpdateWebhookCommand,
+    ) -> Result<(), WebhookUseCaseError> {
+        let actor = ctx.actor(&*self.repositories).await?;
+
+        let id = WebhookId::try_from(webhook_data.id)?;
+        let webhook = self
+            .repositories
+            .webhook_repository()
+            .find_by_id(id.clone())
+            .await?
+            .ok_or(WebhookUseCaseError::NotFound(id))?;
+        ensure!(webhook.is_visible_to(&actor));
+
+        let mut new_webhook = webhook;
+        new_webhook.set_name(&actor, WebhookName::new(webhook_data.name))?;
+        new_webhook.set_url(&actor, WebhookUrl::try_from(webhook_data.url)?)?;
+        new_webhook.set_format(&actor, WebhookFormat::from(webhook_data.format))?;
+        new_webhook.set_event_types(
+            &actor,
+            webhook_data
+                .event_types
+                .into_iter()
+                .map(WebhookEventType::from)
+                .collect(),
+        )?;
+
+        self.repositories
+            .webhook_repository()
+            .update(new_webhook)
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sos24_domain::{
+        entity::user::UserRole,
+        test::{fixture, repository::MockRepositories},
+    };
+
+    use crate::{
+        shared::{adapter::MockAdapters, context::TestContext},
+        webhook::{
+            dto::{WebhookEventTypeDto, WebhookFormatDto},
+            interactor::update::UpdateWebhookCommand,
+            WebhookUseCase, WebhookUseCaseError,
+        },
+    };
+
+    fn command(url: String) -> UpdateWebhookCommand {
+        UpdateWebhookCommand {
+            id: fixture::webhook::id1().value().to_string(),
+            name: fixture::webhook::name2().value(),
+            url,
+            format: WebhookFormatDto::Slack,
+            event_types: vec![WebhookEventTypeDto::NewsPublished],
+        }
+    }
+
+    #[tokio::test]
+    async fn 管理者はwebhookを更新できる() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .webhook_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::webhook::webhook1())));
+        repositories
+            .webhook_repository_mut()
+            .expect_update()
+            .returning(|_| Ok(()));
+        let adapters = MockAdapters::default();
+        let use_case = WebhookUseCase::new(Arc::new(repositories), Arc::new(adapters));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::Administrator));
+        let res = use_case
+            .update(&ctx, command(fixture::webhook::url1().value()))
+            .await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn 不正なurlには更新できない() {
+        let mut repositories = MockRepositories::default();
+        repositories
+            .webhook_repository_mut()
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(fixture::webhook::webhook1())));
+        let adapters = MockAdapters::default();
+        let use_case = WebhookUseCase::new(Arc::new(repositories), Arc::new(adapters));
+
+        let ctx = TestContext::new(fixture::actor::actor1(UserRole::Administrator));
+        let res = use_case
+            .update(&ctx, command("ftp://example.com".to_string()))
+            .await;
+        assert!(matches!(res, Err(WebhookUseCaseError::WebhookUrlError(_))));
+    }
+}
@@ -0,0 +1,2 @@
+ALTER TABLE news ADD COLUMN included_projects UUID[] NOT NULL DEFAULT '{}';
+ALTER TABLE news ADD COLUMN excluded_projects UUID[] NOT NULL DEFAULT '{}';
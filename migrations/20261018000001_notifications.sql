@@ -0,0 +1,31 @@
+CREATE TYPE notification_kind AS ENUM ('news_published', 'form_started', 'invitation_received');
+
+CREATE TABLE notifications (
+  id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+
+  user_id TEXT NOT NULL REFERENCES users(id),
+  kind notification_kind NOT NULL,
+  title TEXT NOT NULL,
+  body TEXT NOT NULL,
+  url TEXT NOT NULL,
+  read_at TIMESTAMPTZ DEFAULT NULL,
+
+  created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  deleted_at TIMESTAMPTZ DEFAULT NULL
+);
+
+CREATE INDEX notifications_user_id_idx ON notifications (user_id, created_at DESC);
+
+/*
+// TRIGGERS (notifications)
+*/
+CREATE TRIGGER refresh_notifications_updated_at_step1
+    BEFORE UPDATE ON notifications FOR EACH ROW
+    EXECUTE PROCEDURE refresh_updated_at_step1();
+CREATE TRIGGER refresh_notifications_updated_at_step2
+    BEFORE UPDATE OF updated_at ON notifications FOR EACH ROW
+    EXECUTE PROCEDURE refresh_updated_at_step2();
+CREATE TRIGGER refresh_notifications_updated_at_step3
+    BEFORE UPDATE ON notifications FOR EACH ROW
+    EXECUTE PROCEDURE refresh_updated_at_step3();
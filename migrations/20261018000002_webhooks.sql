@@ -0,0 +1,62 @@
+CREATE TYPE webhook_format AS ENUM ('json', 'slack');
+CREATE TYPE webhook_event_type AS ENUM ('project_created', 'project_updated', 'project_deleted', 'form_answer_submitted', 'news_published');
+CREATE TYPE webhook_delivery_status AS ENUM ('pending', 'succeeded', 'failed');
+
+CREATE TABLE webhooks (
+  id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+
+  name TEXT NOT NULL,
+  url TEXT NOT NULL,
+  secret TEXT NOT NULL,
+  format webhook_format NOT NULL,
+  event_types webhook_event_type[] NOT NULL,
+
+  created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  deleted_at TIMESTAMPTZ DEFAULT NULL
+);
+
+CREATE TABLE webhook_deliveries (
+  id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+
+  webhook_id UUID NOT NULL REFERENCES webhooks(id) ON DELETE CASCADE,
+  event_type webhook_event_type NOT NULL,
+  payload TEXT NOT NULL,
+  status webhook_delivery_status NOT NULL,
+  attempts INTEGER NOT NULL DEFAULT 0,
+  response_status INTEGER DEFAULT NULL,
+  last_error TEXT DEFAULT NULL,
+  next_attempt_at TIMESTAMPTZ DEFAULT NULL,
+
+  created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX webhook_deliveries_webhook_id_idx ON webhook_deliveries (webhook_id, created_at DESC);
+CREATE INDEX webhook_deliveries_next_attempt_at_idx ON webhook_deliveries (next_attempt_at) WHERE status = 'pending';
+
+/*
+// TRIGGERS (webhooks)
+*/
+CREATE TRIGGER refresh_webhooks_updated_at_step1
+    BEFORE UPDATE ON webhooks FOR EACH ROW
+    EXECUTE PROCEDURE refresh_updated_at_step1();
+CREATE TRIGGER refresh_webhooks_updated_at_step2
+    BEFORE UPDATE OF updated_at ON webhooks FOR EACH ROW
+    EXECUTE PROCEDURE refresh_updated_at_step2();
+CREATE TRIGGER refresh_webhooks_updated_at_step3
+    BEFORE UPDATE ON webhooks FOR EACH ROW
+    EXECUTE PROCEDURE refresh_updated_at_step3();
+
+/*
+// TRIGGERS (webhook_deliveries)
+*/
+CREATE TRIGGER refresh_webhook_deliveries_updated_at_step1
+    BEFORE UPDATE ON webhook_deliveries FOR EACH ROW
+    EXECUTE PROCEDURE refresh_updated_at_step1();
+CREATE TRIGGER refresh_webhook_deliveries_updated_at_step2
+    BEFORE UPDATE OF updated_at ON webhook_deliveries FOR EACH ROW
+    EXECUTE PROCEDURE refresh_updated_at_step2();
+CREATE TRIGGER refresh_webhook_deliveries_updated_at_step3
+    BEFORE UPDATE ON webhook_deliveries FOR EACH ROW
+    EXECUTE PROCEDURE refresh_updated_at_step3();
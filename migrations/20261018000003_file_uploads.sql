@@ -0,0 +1,17 @@
+ALTER TABLE files
+  ADD COLUMN size BIGINT DEFAULT NULL,
+  ADD COLUMN content_type TEXT DEFAULT NULL;
+
+CREATE TABLE file_uploads (
+  id UUID PRIMARY KEY,
+
+  name TEXT NOT NULL,
+  url TEXT NOT NULL,
+  owner_project UUID REFERENCES projects(id),
+  uploader TEXT NOT NULL REFERENCES users(id),
+  expires_at TIMESTAMPTZ NOT NULL,
+
+  created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX file_uploads_expires_at_idx ON file_uploads (expires_at);
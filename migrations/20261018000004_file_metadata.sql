@@ -0,0 +1,3 @@
+ALTER TABLE files
+  ADD COLUMN checksum TEXT DEFAULT NULL,
+  ADD COLUMN uploader TEXT DEFAULT NULL REFERENCES users(id);
@@ -0,0 +1,5 @@
+CREATE TYPE file_scan_status AS ENUM ('pending', 'clean', 'infected');
+
+-- 既存のファイルもスキャンが終わるまではダウンロードさせない
+ALTER TABLE files
+  ADD COLUMN scan_status file_scan_status NOT NULL DEFAULT 'pending';
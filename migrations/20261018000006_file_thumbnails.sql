@@ -0,0 +1,5 @@
+CREATE TYPE file_thumbnail_status AS ENUM ('pending', 'ready', 'unavailable');
+
+-- 既存のファイルもcronjobでサムネイルを生成する
+ALTER TABLE files
+  ADD COLUMN thumbnail_status file_thumbnail_status NOT NULL DEFAULT 'pending';
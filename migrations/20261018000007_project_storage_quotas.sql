@@ -0,0 +1,21 @@
+-- 既定の上限と異なる容量を個別に設定した企画のみ保持する
+CREATE TABLE project_storage_quotas (
+  project_id UUID PRIMARY KEY REFERENCES projects(id) ON DELETE CASCADE,
+  quota BIGINT NOT NULL CHECK (quota >= 0),
+
+  created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+/*
+// TRIGGERS (project_storage_quotas)
+*/
+CREATE TRIGGER refresh_project_storage_quotas_updated_at_step1
+    BEFORE UPDATE ON project_storage_quotas FOR EACH ROW
+    EXECUTE PROCEDURE refresh_updated_at_step1();
+CREATE TRIGGER refresh_project_storage_quotas_updated_at_step2
+    BEFORE UPDATE OF updated_at ON project_storage_quotas FOR EACH ROW
+    EXECUTE PROCEDURE refresh_updated_at_step2();
+CREATE TRIGGER refresh_project_storage_quotas_updated_at_step3
+    BEFORE UPDATE ON project_storage_quotas FOR EACH ROW
+    EXECUTE PROCEDURE refresh_updated_at_step3();
@@ -0,0 +1,3 @@
+-- 参照されなくなったとして自動でソフトデリートされたファイルを、管理者による削除と区別する
+ALTER TABLE files
+  ADD COLUMN garbage_collected_at TIMESTAMPTZ;
@@ -0,0 +1,35 @@
+CREATE TYPE file_export_status AS ENUM ('pending', 'running', 'completed', 'failed');
+
+-- 申請に添付されたファイルをまとめたアーカイブの作成状況
+-- 申請はMongoDBに保存されているため、form_idには外部キー制約を付けない
+CREATE TABLE file_exports (
+  id UUID PRIMARY KEY,
+  form_id UUID NOT NULL,
+  requested_by TEXT NOT NULL REFERENCES users(id),
+  status file_export_status NOT NULL DEFAULT 'pending',
+  -- 差分エクスポートの場合、この時刻より後に更新された回答のファイルのみを含める
+  since TIMESTAMPTZ DEFAULT NULL,
+  total INTEGER NOT NULL DEFAULT 0 CHECK (total >= 0),
+  processed INTEGER NOT NULL DEFAULT 0 CHECK (processed >= 0),
+  key TEXT DEFAULT NULL,
+  error TEXT DEFAULT NULL,
+
+  created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX file_exports_form_id_idx ON file_exports (form_id, created_at);
+CREATE INDEX file_exports_status_idx ON file_exports (status);
+
+/*
+// TRIGGERS (file_exports)
+*/
+CREATE TRIGGER refresh_file_exports_updated_at_step1
+    BEFORE UPDATE ON file_exports FOR EACH ROW
+    EXECUTE PROCEDURE refresh_updated_at_step1();
+CREATE TRIGGER refresh_file_exports_updated_at_step2
+    BEFORE UPDATE OF updated_at ON file_exports FOR EACH ROW
+    EXECUTE PROCEDURE refresh_updated_at_step2();
+CREATE TRIGGER refresh_file_exports_updated_at_step3
+    BEFORE UPDATE ON file_exports FOR EACH ROW
+    EXECUTE PROCEDURE refresh_updated_at_step3();
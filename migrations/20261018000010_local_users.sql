@@ -0,0 +1,23 @@
+-- ローカル認証基盤 (AUTH_PROVIDER=local) で管理するユーザーの認証情報
+-- Firebaseを利用する場合は使用されない
+CREATE TABLE local_users (
+  id TEXT PRIMARY KEY,
+  email TEXT NOT NULL UNIQUE,
+  password_hash TEXT NOT NULL,
+
+  created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+/*
+// TRIGGERS (local_users)
+*/
+CREATE TRIGGER refresh_local_users_updated_at_step1
+    BEFORE UPDATE ON local_users FOR EACH ROW
+    EXECUTE PROCEDURE refresh_updated_at_step1();
+CREATE TRIGGER refresh_local_users_updated_at_step2
+    BEFORE UPDATE OF updated_at ON local_users FOR EACH ROW
+    EXECUTE PROCEDURE refresh_updated_at_step2();
+CREATE TRIGGER refresh_local_users_updated_at_step3
+    BEFORE UPDATE ON local_users FOR EACH ROW
+    EXECUTE PROCEDURE refresh_updated_at_step3();
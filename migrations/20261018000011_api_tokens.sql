@@ -0,0 +1,16 @@
+-- スクリプトなどから利用する長期間有効なAPIトークン
+-- トークンそのものは保存せず、SHA-256のハッシュ値のみを保存する
+CREATE TABLE api_tokens (
+  id UUID PRIMARY KEY,
+  user_id TEXT NOT NULL REFERENCES users(id),
+  name TEXT NOT NULL,
+  -- トークンのスコープに対応する権限のビット列
+  permissions BIGINT NOT NULL,
+  token_hash TEXT NOT NULL UNIQUE,
+  expires_at TIMESTAMPTZ DEFAULT NULL,
+  last_used_at TIMESTAMPTZ DEFAULT NULL,
+
+  created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX api_tokens_user_id_idx ON api_tokens (user_id);
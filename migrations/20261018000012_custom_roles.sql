@@ -0,0 +1,33 @@
+-- 組み込みのロールとは別に、実委人が権限を組み合わせて定義するロール
+CREATE TABLE custom_roles (
+  id UUID PRIMARY KEY,
+  name TEXT NOT NULL UNIQUE,
+  -- ロールが持つ権限のビット列
+  permissions BIGINT NOT NULL,
+
+  created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE TABLE user_custom_roles (
+  user_id TEXT NOT NULL REFERENCES users(id),
+  role_id UUID NOT NULL REFERENCES custom_roles(id) ON DELETE CASCADE,
+
+  created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  PRIMARY KEY (user_id, role_id)
+);
+
+CREATE INDEX user_custom_roles_role_id_idx ON user_custom_roles (role_id);
+
+/*
+// TRIGGERS (custom_roles)
+*/
+CREATE TRIGGER refresh_custom_roles_updated_at_step1
+    BEFORE UPDATE ON custom_roles FOR EACH ROW
+    EXECUTE PROCEDURE refresh_updated_at_step1();
+CREATE TRIGGER refresh_custom_roles_updated_at_step2
+    BEFORE UPDATE OF updated_at ON custom_roles FOR EACH ROW
+    EXECUTE PROCEDURE refresh_updated_at_step2();
+CREATE TRIGGER refresh_custom_roles_updated_at_step3
+    BEFORE UPDATE ON custom_roles FOR EACH ROW
+    EXECUTE PROCEDURE refresh_updated_at_step3();
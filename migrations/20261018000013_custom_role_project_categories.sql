@@ -0,0 +1,4 @@
+-- カスタムロールの権限を適用する企画区分のビット列
+-- NULLの場合はすべての企画区分に適用する
+ALTER TABLE custom_roles
+  ADD COLUMN project_categories INTEGER DEFAULT NULL;
@@ -0,0 +1,14 @@
+-- 管理者が他のユーザーになりすまして行ったリクエストの記録
+CREATE TABLE impersonation_logs (
+  id UUID PRIMARY KEY,
+  -- なりすましを行った管理者
+  impersonator_id TEXT NOT NULL REFERENCES users(id),
+  -- なりすまされたユーザー
+  user_id TEXT NOT NULL REFERENCES users(id),
+  -- リクエストのメソッドとパス (例: "GET /projects/me")
+  request TEXT NOT NULL,
+
+  created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX impersonation_logs_created_at_idx ON impersonation_logs (created_at);
@@ -0,0 +1,37 @@
+-- 電話番号を国内形式の数字のみに正規化する (例: "090-1234-5678", "+81 90 1234 5678" -> "09012345678")
+-- 正規化すると他のユーザーと同じ番号になってしまう場合は、UNIQUE制約に違反するため変更せずに残す
+WITH normalized AS (
+  SELECT
+    id,
+    regexp_replace(
+      regexp_replace(
+        translate(phone_number, '０１２３４５６７８９＋', '0123456789+'),
+        '^\s*\+\s*8\s*1[^0-9]*(\(\s*0\s*\)|0)?', '0'
+      ),
+      '[^0-9]', '', 'g'
+    ) AS phone_number
+  FROM users
+), unique_normalized AS (
+  SELECT phone_number FROM normalized GROUP BY phone_number HAVING COUNT(*) = 1
+)
+UPDATE users
+SET phone_number = normalized.phone_number
+FROM normalized
+INNER JOIN unique_normalized ON normalized.phone_number = unique_normalized.phone_number
+WHERE users.id = normalized.id AND users.phone_number <> normalized.phone_number;
+
+-- 正規化できなかった電話番号は手作業で直す必要があるため、一覧を出力する
+DO $$
+DECLARE
+  invalid_user RECORD;
+BEGIN
+  FOR invalid_user IN
+    SELECT id, phone_number FROM users
+    WHERE deleted_at IS NULL
+      AND phone_number !~ '^0([1-9][0-9]{8}|[25-9]0[0-9]{8}|800[0-9]{7})$'
+    ORDER BY id
+  LOOP
+    RAISE NOTICE '電話番号を正規化できませんでした: id=%, phone_number=%',
+      invalid_user.id, invalid_user.phone_number;
+  END LOOP;
+END $$;
@@ -0,0 +1,2 @@
+-- clamdの上限を超えるなどの理由でスキャンできなかったファイル
+ALTER TYPE file_scan_status ADD VALUE 'unscannable';
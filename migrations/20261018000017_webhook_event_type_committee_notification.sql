@@ -0,0 +1,2 @@
+-- 実委向けの通知をWebhookで配信するためのイベント種別
+ALTER TYPE webhook_event_type ADD VALUE 'committee_notification';
@@ -0,0 +1,8 @@
+-- 企画への配布などのために単独でアップロードされたファイルは、参照されていなくても回収しない
+ALTER TABLE files
+  ADD COLUMN is_standalone BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE file_uploads
+  ADD COLUMN is_standalone BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- 既存の公開ファイルには配布されたものが含まれうるので、誤って回収しないよう単独のファイルとして扱う
+UPDATE files SET is_standalone = TRUE WHERE owner_project IS NULL;
@@ -0,0 +1,3 @@
+-- スキャナが設定されていなかったため、スキャンせずにダウンロードを許可したファイル
+-- スキャナが設定されたら改めてスキャンする
+ALTER TYPE file_scan_status ADD VALUE 'not_scanned';